@@ -8,8 +8,68 @@
 //!
 //! Generates Rust source code from FIX dictionary definitions.
 
-use ironfix_dictionary::schema::{Dictionary, FieldDef, FieldType, MessageDef};
+use ironfix_dictionary::schema::{Dictionary, FieldDef, FieldRef, FieldType, GroupDef, MessageDef};
+use std::collections::HashSet;
 use std::fmt::Write;
+use std::io::Write as _;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Helper functions emitted into the messages module when any message has
+/// repeating groups.
+const GROUP_HELPERS: &str = r#"
+    /// Finds the first field with the given tag within a group entry.
+    fn find_field<'f, 'a>(entry: &'f [FieldRef<'a>], tag: u32) -> Option<&'f FieldRef<'a>> {
+        entry.iter().find(|f| f.tag == tag)
+    }
+
+    /// Looks up a required field within a group entry, failing if it is absent.
+    fn required_in<'a>(entry: &[FieldRef<'a>], tag: u32) -> Result<FieldRef<'a>, DecodeError> {
+        find_field(entry, tag).copied().ok_or(DecodeError::MissingRequiredField { tag })
+    }
+
+    /// Decodes the entries of a repeating group.
+    ///
+    /// Each entry starts at the delimiter field and extends while the tags
+    /// belong to the group. When the count field is absent, a required group
+    /// fails with `MissingRequiredField` and an optional one has no entries.
+    fn decode_group<'a, T>(
+        fields: &[FieldRef<'a>],
+        count_tag: u32,
+        delimiter_tag: u32,
+        member_tags: &[u32],
+        required: bool,
+        decode_entry: impl Fn(&[FieldRef<'a>]) -> Result<T, DecodeError>,
+    ) -> Result<Vec<T>, DecodeError> {
+        let Some(start) = fields.iter().position(|f| f.tag == count_tag) else {
+            if required {
+                return Err(DecodeError::MissingRequiredField { tag: count_tag });
+            }
+            return Ok(Vec::new());
+        };
+        let expected: u32 = fields[start].parse()?;
+
+        let mut entries = Vec::new();
+        let mut pos = start + 1;
+        while pos < fields.len() && fields[pos].tag == delimiter_tag {
+            let end = fields[pos + 1..]
+                .iter()
+                .position(|f| f.tag == delimiter_tag || !member_tags.contains(&f.tag))
+                .map_or(fields.len(), |offset| pos + 1 + offset);
+            entries.push(decode_entry(&fields[pos..end])?);
+            pos = end;
+        }
+
+        if entries.len() != expected as usize {
+            return Err(DecodeError::GroupCountMismatch {
+                count_tag,
+                expected,
+                actual: entries.len() as u32,
+            });
+        }
+        Ok(entries)
+    }
+"#;
 
 /// Configuration for code generation.
 #[derive(Debug, Clone)]
@@ -20,8 +80,17 @@ pub struct GeneratorConfig {
     pub generate_messages: bool,
     /// Whether to generate component traits.
     pub generate_components: bool,
+    /// Whether to generate Rust enums for fields with enumerated values.
+    ///
+    /// When enabled, message fields referencing such a field use the enum type
+    /// instead of the raw FIX type.
+    pub generate_enums: bool,
     /// Module visibility (e.g., "pub", "pub(crate)").
     pub visibility: String,
+    /// Whether [`CodeGenerator::write_to_file`] formats the output with `rustfmt`.
+    ///
+    /// The unformatted output is written when `rustfmt` is unavailable or fails.
+    pub rustfmt: bool,
 }
 
 impl Default for GeneratorConfig {
@@ -30,11 +99,22 @@ impl Default for GeneratorConfig {
             generate_fields: true,
             generate_messages: true,
             generate_components: true,
+            generate_enums: false,
             visibility: "pub".to_string(),
+            rustfmt: true,
         }
     }
 }
 
+/// Where generated decode logic reads its fields from.
+#[derive(Debug, Clone, Copy)]
+enum FieldSource {
+    /// A whole message, via `raw: &RawMessage`.
+    Message,
+    /// A single group entry, via `entry: &[FieldRef]`.
+    Entry,
+}
+
 /// Code generator for FIX dictionaries.
 #[derive(Debug)]
 pub struct CodeGenerator {
@@ -81,6 +161,10 @@ impl CodeGenerator {
             self.generate_fields_module(&mut code, dict);
         }
 
+        if self.has_enums(dict) {
+            self.generate_enums_module(&mut code, dict);
+        }
+
         if self.config.generate_messages {
             self.generate_messages_module(&mut code, dict);
         }
@@ -88,6 +172,175 @@ impl CodeGenerator {
         code
     }
 
+    /// Generates Rust source code from a dictionary and writes it to a file.
+    ///
+    /// The file starts with a `// @generated` marker. When enabled in the
+    /// configuration, the source is formatted with `rustfmt` if it is available.
+    ///
+    /// # Arguments
+    /// * `dict` - The FIX dictionary to generate code from
+    /// * `path` - The file to write
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn write_to_file(&self, dict: &Dictionary, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut code = String::from("// @generated\n");
+        code.push_str(&self.generate(dict));
+
+        if self.config.rustfmt
+            && let Some(formatted) = rustfmt(&code)
+        {
+            code = formatted;
+        }
+
+        std::fs::write(path, code)
+    }
+
+    /// Returns the enum type name for a field, if an enum is generated for it.
+    fn enum_name(&self, field: &FieldDef) -> Option<String> {
+        let has_values = field.values.as_ref().is_some_and(|v| !v.is_empty());
+        (self.config.generate_enums && has_values).then(|| to_pascal_case(&field.name))
+    }
+
+    /// Returns true if any enum types are generated for the dictionary.
+    fn has_enums(&self, dict: &Dictionary) -> bool {
+        dict.fields().any(|f| self.enum_name(f).is_some())
+    }
+
+    /// Returns the Rust type used for a field within generated messages.
+    fn rust_type(&self, field: &FieldDef) -> String {
+        match self.enum_name(field) {
+            Some(name) => format!("enums::{}", name),
+            None => field_type_to_rust(&field.field_type).to_string(),
+        }
+    }
+
+    /// Generates the enums module with one enum per enumerated field.
+    fn generate_enums_module(&self, code: &mut String, dict: &Dictionary) {
+        writeln!(code, "/// Enumerated field values.").unwrap();
+        writeln!(code, "{} mod enums {{", self.config.visibility).unwrap();
+        writeln!(code, "    use ironfix_core::error::DecodeError;").unwrap();
+        writeln!(code, "    use ironfix_core::field::FixField;").unwrap();
+
+        let mut fields: Vec<_> = dict
+            .fields()
+            .filter_map(|f| self.enum_name(f).map(|name| (name, f)))
+            .collect();
+        fields.sort_by_key(|(_, f)| f.tag);
+
+        for (enum_name, field) in fields {
+            writeln!(code).unwrap();
+            self.generate_field_enum(code, &enum_name, field);
+        }
+
+        writeln!(code, "}}").unwrap();
+        writeln!(code).unwrap();
+    }
+
+    /// Generates an enum, its byte conversions, and its `FixField` impl.
+    fn generate_field_enum(&self, code: &mut String, enum_name: &str, field: &FieldDef) {
+        let mut values: Vec<_> = field.values.iter().flatten().collect();
+        values.sort();
+
+        let mut variants: Vec<(String, &str, &str)> = Vec::with_capacity(values.len());
+        for (value, description) in values {
+            let mut variant = to_variant_name(description);
+            if variants.iter().any(|(v, _, _)| *v == variant) {
+                variant = format!("{}{}", variant, to_variant_name(value));
+            }
+            variants.push((variant, value, description));
+        }
+
+        writeln!(code, "    /// {} (tag {}) values.", field.name, field.tag).unwrap();
+        writeln!(
+            code,
+            "    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]"
+        )
+        .unwrap();
+        writeln!(code, "    pub enum {} {{", enum_name).unwrap();
+        for (variant, value, description) in &variants {
+            writeln!(code, "        /// {} ({}).", description, value).unwrap();
+            writeln!(code, "        {},", variant).unwrap();
+        }
+        writeln!(code, "    }}").unwrap();
+        writeln!(code).unwrap();
+
+        writeln!(code, "    impl {} {{", enum_name).unwrap();
+        writeln!(
+            code,
+            "        /// Parses a value from its FIX wire representation."
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        pub fn from_bytes(bytes: &[u8]) -> Option<Self> {{"
+        )
+        .unwrap();
+        writeln!(code, "            match bytes {{").unwrap();
+        for (variant, value, _) in &variants {
+            writeln!(
+                code,
+                "                b{:?} => Some(Self::{}),",
+                value, variant
+            )
+            .unwrap();
+        }
+        writeln!(code, "                _ => None,").unwrap();
+        writeln!(code, "            }}").unwrap();
+        writeln!(code, "        }}").unwrap();
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "        /// Returns the FIX wire representation of this value."
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        pub const fn to_bytes(self) -> &'static [u8] {{"
+        )
+        .unwrap();
+        writeln!(code, "            match self {{").unwrap();
+        for (variant, value, _) in &variants {
+            writeln!(code, "                Self::{} => b{:?},", variant, value).unwrap();
+        }
+        writeln!(code, "            }}").unwrap();
+        writeln!(code, "        }}").unwrap();
+        writeln!(code, "    }}").unwrap();
+        writeln!(code).unwrap();
+
+        writeln!(code, "    impl FixField for {} {{", enum_name).unwrap();
+        writeln!(code, "        const TAG: u32 = {};", field.tag).unwrap();
+        writeln!(code, "        type Value = Self;").unwrap();
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "        fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {{"
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "            Self::from_bytes(bytes).ok_or_else(|| DecodeError::InvalidFieldValue {{"
+        )
+        .unwrap();
+        writeln!(code, "                tag: Self::TAG,").unwrap();
+        writeln!(
+            code,
+            "                reason: format!(\"unknown value '{{}}'\", String::from_utf8_lossy(bytes)),"
+        )
+        .unwrap();
+        writeln!(code, "            }})").unwrap();
+        writeln!(code, "        }}").unwrap();
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "        fn encode(value: &Self::Value, buf: &mut Vec<u8>) {{"
+        )
+        .unwrap();
+        writeln!(code, "            buf.extend_from_slice(value.to_bytes());").unwrap();
+        writeln!(code, "        }}").unwrap();
+        writeln!(code, "    }}").unwrap();
+    }
+
     /// Generates the fields module with tag constants.
     fn generate_fields_module(&self, code: &mut String, dict: &Dictionary) {
         writeln!(code, "/// Field tag constants.").unwrap();
@@ -119,18 +372,103 @@ impl CodeGenerator {
         writeln!(code, "/// Message type definitions.").unwrap();
         writeln!(code, "{} mod messages {{", self.config.visibility).unwrap();
         writeln!(code, "    use super::fields;").unwrap();
-        writeln!(code).unwrap();
 
         let mut messages: Vec<_> = dict.messages().collect();
         messages.sort_by(|a, b| a.msg_type.cmp(&b.msg_type));
 
+        if !messages.is_empty() {
+            let uses_enums = messages
+                .iter()
+                .any(|msg| self.uses_enums(&msg.fields, &msg.groups, dict));
+            let uses_groups = messages.iter().any(|msg| !msg.groups.is_empty());
+            self.generate_message_helpers(code, uses_enums, uses_groups);
+        }
+        writeln!(code).unwrap();
+
+        let mut generated_entries = HashSet::new();
         for msg in messages {
+            self.generate_group_entries(code, &msg.groups, dict, &mut generated_entries);
             self.generate_message_struct(code, msg, dict);
+            self.generate_message_impl(code, msg, dict);
+            self.generate_message_builder(code, msg, dict);
         }
 
         writeln!(code, "}}").unwrap();
     }
 
+    /// Returns whether any of the fields, including those in groups, use an enum type.
+    fn uses_enums(&self, fields: &[FieldRef], groups: &[GroupDef], dict: &Dictionary) -> bool {
+        fields.iter().any(|field_ref| {
+            dict.get_field(field_ref.tag)
+                .is_some_and(|field_def| self.enum_name(field_def).is_some())
+        }) || groups
+            .iter()
+            .any(|group| self.uses_enums(&group.fields, &group.groups, dict))
+    }
+
+    /// Generates the imports and helper functions shared by message impls.
+    fn generate_message_helpers(&self, code: &mut String, uses_enums: bool, uses_groups: bool) {
+        if uses_enums {
+            writeln!(code, "    use super::enums;").unwrap();
+        }
+        writeln!(
+            code,
+            "    use ironfix_core::error::{{DecodeError, EncodeError}};"
+        )
+        .unwrap();
+        if uses_enums {
+            writeln!(code, "    use ironfix_core::field::{{FieldRef, FixField}};").unwrap();
+        } else {
+            writeln!(code, "    use ironfix_core::field::FieldRef;").unwrap();
+        }
+        writeln!(
+            code,
+            "    use ironfix_core::message::{{FixMessage, RawMessage}};"
+        )
+        .unwrap();
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "    /// Looks up a required field, failing if it is absent."
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "    fn required<'a>(raw: &RawMessage<'a>, tag: u32) -> Result<FieldRef<'a>, DecodeError> {{"
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        raw.get_field(tag).copied().ok_or(DecodeError::MissingRequiredField {{ tag }})"
+        )
+        .unwrap();
+        writeln!(code, "    }}").unwrap();
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "    /// Appends a `tag=value<SOH>` field to the buffer."
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "    fn put_field(buf: &mut Vec<u8>, tag: u32, value: &[u8]) {{"
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        buf.extend_from_slice(tag.to_string().as_bytes());"
+        )
+        .unwrap();
+        writeln!(code, "        buf.push(b'=');").unwrap();
+        writeln!(code, "        buf.extend_from_slice(value);").unwrap();
+        writeln!(code, "        buf.push(0x01);").unwrap();
+        writeln!(code, "    }}").unwrap();
+
+        if uses_groups {
+            code.push_str(GROUP_HELPERS);
+        }
+    }
+
     /// Generates a message struct.
     fn generate_message_struct(&self, code: &mut String, msg: &MessageDef, dict: &Dictionary) {
         let struct_name = to_pascal_case(&msg.name);
@@ -143,11 +481,25 @@ impl CodeGenerator {
         .unwrap();
         writeln!(code, "    #[derive(Debug, Clone)]").unwrap();
         writeln!(code, "    pub struct {} {{", struct_name).unwrap();
+        self.generate_struct_fields(code, &msg.fields, &msg.groups, dict);
+        writeln!(code, "    }}").unwrap();
+        writeln!(code).unwrap();
+    }
 
-        for field_ref in &msg.fields {
+    /// Generates the fields of a message or group entry struct.
+    ///
+    /// Each repeating group becomes a `Vec` of its entry struct.
+    fn generate_struct_fields(
+        &self,
+        code: &mut String,
+        fields: &[FieldRef],
+        groups: &[GroupDef],
+        dict: &Dictionary,
+    ) {
+        for field_ref in fields {
             if let Some(field_def) = dict.get_field(field_ref.tag) {
                 let field_name = to_snake_case(&field_ref.name);
-                let rust_type = field_type_to_rust(&field_def.field_type);
+                let rust_type = self.rust_type(field_def);
 
                 if field_ref.required {
                     writeln!(code, "        pub {}: {},", field_name, rust_type).unwrap();
@@ -157,6 +509,393 @@ impl CodeGenerator {
             }
         }
 
+        for group in groups {
+            writeln!(
+                code,
+                "        pub {}: Vec<{}>,",
+                to_snake_case(&group.name),
+                group_entry_name(group)
+            )
+            .unwrap();
+        }
+    }
+
+    /// Generates the entry structs for a list of repeating groups.
+    ///
+    /// Nested groups are generated before their parent. Groups sharing a name
+    /// across messages are generated once.
+    fn generate_group_entries(
+        &self,
+        code: &mut String,
+        groups: &[GroupDef],
+        dict: &Dictionary,
+        generated: &mut HashSet<String>,
+    ) {
+        for group in groups {
+            self.generate_group_entries(code, &group.groups, dict, generated);
+
+            let entry_name = group_entry_name(group);
+            if !generated.insert(entry_name.clone()) {
+                continue;
+            }
+
+            writeln!(
+                code,
+                "    /// Entry of the {} repeating group (count tag {}).",
+                group.name, group.count_tag
+            )
+            .unwrap();
+            writeln!(code, "    #[derive(Debug, Clone)]").unwrap();
+            writeln!(code, "    pub struct {} {{", entry_name).unwrap();
+            self.generate_struct_fields(code, &group.fields, &group.groups, dict);
+            writeln!(code, "    }}").unwrap();
+            writeln!(code).unwrap();
+
+            let fields = resolve_fields(&group.fields, dict);
+
+            writeln!(code, "    impl {} {{", entry_name).unwrap();
+            writeln!(
+                code,
+                "        /// Decodes one entry from the fields belonging to it."
+            )
+            .unwrap();
+            writeln!(
+                code,
+                "        fn decode_entry(entry: &[FieldRef<'_>]) -> Result<Self, DecodeError> {{"
+            )
+            .unwrap();
+            writeln!(code, "            Ok(Self {{").unwrap();
+            self.generate_field_decoders(code, &fields, &group.groups, dict, FieldSource::Entry);
+            writeln!(code, "            }})").unwrap();
+            writeln!(code, "        }}").unwrap();
+            writeln!(code).unwrap();
+            writeln!(code, "        /// Encodes this entry's fields.").unwrap();
+            writeln!(code, "        fn encode_entry(&self, buf: &mut Vec<u8>) {{").unwrap();
+            self.generate_field_encoders(code, &fields, &group.groups, dict);
+            writeln!(code, "        }}").unwrap();
+            writeln!(code, "    }}").unwrap();
+            writeln!(code).unwrap();
+        }
+    }
+
+    /// Returns an expression decoding the `FieldRef` expression `field` into the
+    /// field's Rust type, and whether that expression is fallible.
+    fn decode_expr(&self, field_def: &FieldDef, field: &str) -> (String, bool) {
+        if let Some(enum_name) = self.enum_name(field_def) {
+            return (
+                format!("enums::{}::decode({}.as_bytes())", enum_name, field),
+                true,
+            );
+        }
+        let (conversion, fallible) = field_type_decode(&field_def.field_type);
+        (format!("{}.{}", field, conversion), fallible)
+    }
+
+    /// Returns an expression yielding the encoded bytes of `value` for a field.
+    fn encode_expr(&self, field_def: &FieldDef, value: &str, by_ref: bool) -> String {
+        if self.enum_name(field_def).is_some() {
+            return format!("{}.to_bytes()", value);
+        }
+        field_type_encode(&field_def.field_type, value, by_ref)
+    }
+
+    /// Generates the struct initializer lines decoding each field and group.
+    fn generate_field_decoders(
+        &self,
+        code: &mut String,
+        fields: &[(&FieldRef, &FieldDef)],
+        groups: &[GroupDef],
+        dict: &Dictionary,
+        source: FieldSource,
+    ) {
+        for (field_ref, field_def) in fields {
+            let field_name = to_snake_case(&field_ref.name);
+            let const_name = to_screaming_snake_case(&field_def.name);
+
+            if field_ref.required {
+                let field = match source {
+                    FieldSource::Message => format!("required(raw, fields::{})?", const_name),
+                    FieldSource::Entry => format!("required_in(entry, fields::{})?", const_name),
+                };
+                let (decode, fallible) = self.decode_expr(field_def, &field);
+                let try_op = if fallible { "?" } else { "" };
+                writeln!(
+                    code,
+                    "                {}: {}{},",
+                    field_name, decode, try_op
+                )
+                .unwrap();
+            } else {
+                let lookup = match source {
+                    FieldSource::Message => format!("raw.get_field(fields::{})", const_name),
+                    FieldSource::Entry => format!("find_field(entry, fields::{})", const_name),
+                };
+                let (decode, fallible) = self.decode_expr(field_def, "f");
+                let transpose = if fallible { ".transpose()?" } else { "" };
+                writeln!(
+                    code,
+                    "                {}: {}.map(|f| {}){},",
+                    field_name, lookup, decode, transpose
+                )
+                .unwrap();
+            }
+        }
+
+        let slice = match source {
+            FieldSource::Message => "&all_fields",
+            FieldSource::Entry => "entry",
+        };
+        for group in groups {
+            let member_tags: Vec<_> = group_member_tags(group)
+                .iter()
+                .map(|tag| tag_expr(*tag, dict))
+                .collect();
+            writeln!(
+                code,
+                "                {}: decode_group({}, {}, {}, &[{}], {}, {}::decode_entry)?,",
+                to_snake_case(&group.name),
+                slice,
+                tag_expr(group.count_tag, dict),
+                tag_expr(group.delimiter_tag, dict),
+                member_tags.join(", "),
+                group.required,
+                group_entry_name(group)
+            )
+            .unwrap();
+        }
+    }
+
+    /// Generates the statements encoding each field and group into `buf`.
+    fn generate_field_encoders(
+        &self,
+        code: &mut String,
+        fields: &[(&FieldRef, &FieldDef)],
+        groups: &[GroupDef],
+        dict: &Dictionary,
+    ) {
+        for (field_ref, field_def) in fields {
+            let field_name = to_snake_case(&field_ref.name);
+            let const_name = to_screaming_snake_case(&field_def.name);
+
+            if field_ref.required {
+                let value = self.encode_expr(field_def, &format!("self.{}", field_name), false);
+                writeln!(
+                    code,
+                    "            put_field(buf, fields::{}, {});",
+                    const_name, value
+                )
+                .unwrap();
+            } else {
+                let value = self.encode_expr(field_def, "value", true);
+                writeln!(
+                    code,
+                    "            if let Some(value) = &self.{} {{",
+                    field_name
+                )
+                .unwrap();
+                writeln!(
+                    code,
+                    "                put_field(buf, fields::{}, {});",
+                    const_name, value
+                )
+                .unwrap();
+                writeln!(code, "            }}").unwrap();
+            }
+        }
+
+        // A required group writes its count even when empty, so that it
+        // decodes again; an empty optional group is omitted.
+        for group in groups {
+            let group_name = to_snake_case(&group.name);
+            let indent = if group.required {
+                "            "
+            } else {
+                writeln!(code, "            if !self.{}.is_empty() {{", group_name).unwrap();
+                "                "
+            };
+            writeln!(
+                code,
+                "{}put_field(buf, {}, self.{}.len().to_string().as_bytes());",
+                indent,
+                tag_expr(group.count_tag, dict),
+                group_name
+            )
+            .unwrap();
+            writeln!(code, "{}for entry in &self.{} {{", indent, group_name).unwrap();
+            writeln!(code, "{}    entry.encode_entry(buf);", indent).unwrap();
+            writeln!(code, "{}}}", indent).unwrap();
+            if !group.required {
+                writeln!(code, "            }}").unwrap();
+            }
+        }
+    }
+
+    /// Generates the `FixMessage` implementation for a message struct.
+    ///
+    /// Decoding and encoding are keyed on each field's tag constant and use the
+    /// conversion matching the field's FIX type. Required fields fail decoding
+    /// with `MissingRequiredField` when absent; optional fields are skipped.
+    fn generate_message_impl(&self, code: &mut String, msg: &MessageDef, dict: &Dictionary) {
+        let struct_name = to_pascal_case(&msg.name);
+        let fields = resolve_fields(&msg.fields, dict);
+
+        writeln!(code, "    impl FixMessage for {} {{", struct_name).unwrap();
+        writeln!(
+            code,
+            "        const MSG_TYPE: &'static str = {:?};",
+            msg.msg_type
+        )
+        .unwrap();
+        writeln!(code).unwrap();
+
+        // from_raw
+        writeln!(
+            code,
+            "        fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {{"
+        )
+        .unwrap();
+        if !msg.groups.is_empty() {
+            writeln!(
+                code,
+                "            let all_fields: Vec<FieldRef<'_>> = raw.fields().copied().collect();"
+            )
+            .unwrap();
+        }
+        writeln!(code, "            Ok(Self {{").unwrap();
+        self.generate_field_decoders(code, &fields, &msg.groups, dict, FieldSource::Message);
+        writeln!(code, "            }})").unwrap();
+        writeln!(code, "        }}").unwrap();
+        writeln!(code).unwrap();
+
+        // encode
+        let buf_name = if fields.is_empty() && msg.groups.is_empty() {
+            "_buf"
+        } else {
+            "buf"
+        };
+        writeln!(
+            code,
+            "        fn encode(&self, {}: &mut Vec<u8>) -> Result<(), EncodeError> {{",
+            buf_name
+        )
+        .unwrap();
+        self.generate_field_encoders(code, &fields, &msg.groups, dict);
+        writeln!(code, "            Ok(())").unwrap();
+        writeln!(code, "        }}").unwrap();
+        writeln!(code, "    }}").unwrap();
+        writeln!(code).unwrap();
+    }
+
+    /// Generates a builder for a message struct.
+    ///
+    /// Required fields are taken by `new()`, optional fields get chainable
+    /// setters and default to `None`.
+    fn generate_message_builder(&self, code: &mut String, msg: &MessageDef, dict: &Dictionary) {
+        let struct_name = to_pascal_case(&msg.name);
+        let builder_name = format!("{}Builder", struct_name);
+        let fields: Vec<_> = msg
+            .fields
+            .iter()
+            .filter_map(|field_ref| {
+                dict.get_field(field_ref.tag)
+                    .map(|field_def| (to_snake_case(&field_ref.name), field_ref, field_def))
+            })
+            .collect();
+        let required: Vec<_> = fields
+            .iter()
+            .filter(|(_, field_ref, _)| field_ref.required)
+            .collect();
+
+        writeln!(code, "    /// Builder for [`{}`].", struct_name).unwrap();
+        writeln!(code, "    #[derive(Debug, Clone)]").unwrap();
+        writeln!(code, "    pub struct {} {{", builder_name).unwrap();
+        writeln!(code, "        message: {},", struct_name).unwrap();
+        writeln!(code, "    }}").unwrap();
+        writeln!(code).unwrap();
+
+        writeln!(code, "    impl {} {{", builder_name).unwrap();
+        writeln!(
+            code,
+            "        /// Creates a new builder from the required fields."
+        )
+        .unwrap();
+        writeln!(code, "        #[must_use]").unwrap();
+        if required.len() > 7 {
+            writeln!(code, "        #[allow(clippy::too_many_arguments)]").unwrap();
+        }
+        let params: Vec<_> = required
+            .iter()
+            .map(|(name, _, field_def)| format!("{}: {}", name, self.rust_type(field_def)))
+            .collect();
+        writeln!(code, "        pub fn new({}) -> Self {{", params.join(", ")).unwrap();
+        writeln!(code, "            Self {{").unwrap();
+        writeln!(code, "                message: {} {{", struct_name).unwrap();
+        for (name, field_ref, _) in &fields {
+            if field_ref.required {
+                writeln!(code, "                    {},", name).unwrap();
+            } else {
+                writeln!(code, "                    {}: None,", name).unwrap();
+            }
+        }
+        for group in &msg.groups {
+            writeln!(
+                code,
+                "                    {}: Vec::new(),",
+                to_snake_case(&group.name)
+            )
+            .unwrap();
+        }
+        writeln!(code, "                }},").unwrap();
+        writeln!(code, "            }}").unwrap();
+        writeln!(code, "        }}").unwrap();
+
+        for (name, field_ref, field_def) in &fields {
+            if field_ref.required {
+                continue;
+            }
+            writeln!(code).unwrap();
+            writeln!(
+                code,
+                "        /// Sets {} (tag {}).",
+                field_def.name, field_def.tag
+            )
+            .unwrap();
+            writeln!(code, "        #[must_use]").unwrap();
+            writeln!(
+                code,
+                "        pub fn {}(mut self, value: {}) -> Self {{",
+                name,
+                self.rust_type(field_def)
+            )
+            .unwrap();
+            writeln!(code, "            self.message.{} = Some(value);", name).unwrap();
+            writeln!(code, "            self").unwrap();
+            writeln!(code, "        }}").unwrap();
+        }
+
+        for group in &msg.groups {
+            let group_name = to_snake_case(&group.name);
+            writeln!(code).unwrap();
+            writeln!(code, "        /// Sets the {} group entries.", group.name).unwrap();
+            writeln!(code, "        #[must_use]").unwrap();
+            writeln!(
+                code,
+                "        pub fn {}(mut self, entries: Vec<{}>) -> Self {{",
+                group_name,
+                group_entry_name(group)
+            )
+            .unwrap();
+            writeln!(code, "            self.message.{} = entries;", group_name).unwrap();
+            writeln!(code, "            self").unwrap();
+            writeln!(code, "        }}").unwrap();
+        }
+
+        writeln!(code).unwrap();
+        writeln!(code, "        /// Builds the message.").unwrap();
+        writeln!(code, "        #[must_use]").unwrap();
+        writeln!(code, "        pub fn build(self) -> {} {{", struct_name).unwrap();
+        writeln!(code, "            self.message").unwrap();
+        writeln!(code, "        }}").unwrap();
         writeln!(code, "    }}").unwrap();
         writeln!(code).unwrap();
     }
@@ -168,54 +907,151 @@ impl Default for CodeGenerator {
     }
 }
 
-/// Converts a string to SCREAMING_SNAKE_CASE.
-fn to_screaming_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut prev_lower = false;
+/// Formats Rust source with `rustfmt`, returning `None` if it is unavailable or fails.
+fn rustfmt(code: &str) -> Option<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    child.stdin.take()?.write_all(code.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+/// Pairs each field reference with its definition, skipping unknown tags.
+fn resolve_fields<'a>(
+    fields: &'a [FieldRef],
+    dict: &'a Dictionary,
+) -> Vec<(&'a FieldRef, &'a FieldDef)> {
+    fields
+        .iter()
+        .filter_map(|field_ref| {
+            dict.get_field(field_ref.tag)
+                .map(|field_def| (field_ref, field_def))
+        })
+        .collect()
+}
+
+/// Returns the name of the struct generated for a group's entries.
+fn group_entry_name(group: &GroupDef) -> String {
+    format!("{}Entry", to_pascal_case(&group.name))
+}
 
-    for c in s.chars() {
-        if c.is_uppercase() && prev_lower {
-            result.push('_');
+/// Returns every tag that may appear within an entry of `group`, including
+/// the count tags and members of nested groups.
+fn group_member_tags(group: &GroupDef) -> Vec<u32> {
+    let mut tags: Vec<u32> = group.fields.iter().map(|field_ref| field_ref.tag).collect();
+    for nested in &group.groups {
+        tags.push(nested.count_tag);
+        tags.extend(group_member_tags(nested));
+    }
+    tags
+}
+
+/// Returns the expression naming `tag`: its field constant when the dictionary
+/// defines it, otherwise the literal tag number.
+fn tag_expr(tag: u32, dict: &Dictionary) -> String {
+    dict.get_field(tag).map_or_else(
+        || tag.to_string(),
+        |field_def| format!("fields::{}", to_screaming_snake_case(&field_def.name)),
+    )
+}
+
+/// Splits an identifier into words.
+///
+/// Words are separated by non-alphanumeric characters and by case changes.
+/// A run of uppercase letters is kept together as an acronym, ending before
+/// the capital that starts the next word (`MDEntryPx` -> `MD`, `Entry`, `Px`).
+/// Digits stay attached to the preceding word (`Price2` -> `Price2`).
+fn split_words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for part in s.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let bytes = part.as_bytes();
+        let mut start = 0;
+
+        for i in 1..bytes.len() {
+            let prev = bytes[i - 1];
+            let cur = bytes[i];
+            let next_lower = bytes.get(i + 1).is_some_and(u8::is_ascii_lowercase);
+
+            let boundary = cur.is_ascii_uppercase()
+                && (prev.is_ascii_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_ascii_uppercase() && next_lower));
+
+            if boundary {
+                words.push(&part[start..i]);
+                start = i;
+            }
+        }
+
+        if start < part.len() {
+            words.push(&part[start..]);
         }
-        result.push(c.to_ascii_uppercase());
-        prev_lower = c.is_lowercase();
     }
 
-    result
+    words
+}
+
+/// Converts a string to SCREAMING_SNAKE_CASE.
+fn to_screaming_snake_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| word.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
 }
 
 /// Converts a string to snake_case.
 fn to_snake_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Converts a string to PascalCase.
+///
+/// Acronyms keep their capitalization, matching QuickFIX naming (`NoMDEntries`).
+fn to_pascal_case(s: &str) -> String {
     let mut result = String::new();
-    let mut prev_lower = false;
 
-    for c in s.chars() {
-        if c.is_uppercase() && prev_lower {
-            result.push('_');
+    for word in split_words(s) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars);
         }
-        result.push(c.to_ascii_lowercase());
-        prev_lower = c.is_lowercase();
     }
 
     result
 }
 
-/// Converts a string to PascalCase.
-fn to_pascal_case(s: &str) -> String {
+/// Converts an enum value description (e.g. `SELL_SHORT`) to a variant name (`SellShort`).
+fn to_variant_name(description: &str) -> String {
     let mut result = String::new();
-    let mut capitalize_next = true;
-
-    for c in s.chars() {
-        if c == '_' || c == ' ' {
-            capitalize_next = true;
-        } else if capitalize_next {
-            result.push(c.to_ascii_uppercase());
-            capitalize_next = false;
-        } else {
-            result.push(c);
+
+    for word in description.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars.map(|c| c.to_ascii_lowercase()));
         }
     }
 
+    if result.is_empty() || result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, 'V');
+    }
+
     result
 }
 
@@ -258,6 +1094,34 @@ fn field_type_to_rust(field_type: &FieldType) -> &'static str {
     }
 }
 
+/// Returns the `FieldRef` conversion that decodes a field of the given type,
+/// and whether that conversion is fallible.
+fn field_type_decode(field_type: &FieldType) -> (&'static str, bool) {
+    match field_type_to_rust(field_type) {
+        "i64" => ("as_i64()", true),
+        "rust_decimal::Decimal" => ("as_decimal()", true),
+        "char" => ("as_char()", true),
+        "bool" => ("as_bool()", true),
+        "Vec<u8>" => ("as_bytes().to_vec()", false),
+        _ => ("to_string()", true),
+    }
+}
+
+/// Returns an expression yielding the encoded bytes of `value` for the given type.
+///
+/// `by_ref` indicates that `value` is a reference (e.g. bound by `if let Some(value)`)
+/// rather than a field place expression.
+fn field_type_encode(field_type: &FieldType, value: &str, by_ref: bool) -> String {
+    match field_type_to_rust(field_type) {
+        "i64" | "rust_decimal::Decimal" | "char" => format!("{}.to_string().as_bytes()", value),
+        "bool" if by_ref => format!("if *{} {{ b\"Y\" }} else {{ b\"N\" }}", value),
+        "bool" => format!("if {} {{ b\"Y\" }} else {{ b\"N\" }}", value),
+        "Vec<u8>" if by_ref => value.to_string(),
+        "Vec<u8>" => format!("&{}", value),
+        _ => format!("{}.as_bytes()", value),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -267,18 +1131,150 @@ mod tests {
         assert_eq!(to_screaming_snake_case("MsgType"), "MSG_TYPE");
         assert_eq!(to_screaming_snake_case("ClOrdID"), "CL_ORD_ID");
         assert_eq!(to_screaming_snake_case("BeginString"), "BEGIN_STRING");
+        assert_eq!(to_screaming_snake_case("NoMDEntries"), "NO_MD_ENTRIES");
+        assert_eq!(to_screaming_snake_case("MDEntryPx"), "MD_ENTRY_PX");
+        assert_eq!(to_screaming_snake_case("SecurityID"), "SECURITY_ID");
+        assert_eq!(to_screaming_snake_case("Price2"), "PRICE2");
     }
 
     #[test]
     fn test_to_snake_case() {
         assert_eq!(to_snake_case("MsgType"), "msg_type");
         assert_eq!(to_snake_case("ClOrdID"), "cl_ord_id");
+        assert_eq!(to_snake_case("NoMDEntries"), "no_md_entries");
+        assert_eq!(to_snake_case("MDEntryPx"), "md_entry_px");
+        assert_eq!(to_snake_case("SecurityID"), "security_id");
+        assert_eq!(to_snake_case("Leg2Symbol"), "leg2_symbol");
+        assert_eq!(to_snake_case("new_order_single"), "new_order_single");
     }
 
     #[test]
     fn test_to_pascal_case() {
         assert_eq!(to_pascal_case("new_order_single"), "NewOrderSingle");
         assert_eq!(to_pascal_case("execution_report"), "ExecutionReport");
+        assert_eq!(to_pascal_case("ClOrdID"), "ClOrdID");
+        assert_eq!(to_pascal_case("NoMDEntries"), "NoMDEntries");
+        assert_eq!(to_pascal_case("MDEntryPx"), "MDEntryPx");
+        assert_eq!(to_pascal_case("SecurityID"), "SecurityID");
+    }
+
+    #[test]
+    fn test_to_variant_name() {
+        assert_eq!(to_variant_name("BUY"), "Buy");
+        assert_eq!(to_variant_name("SELL_SHORT_EXEMPT"), "SellShortExempt");
+        assert_eq!(to_variant_name("1_DAY"), "V1Day");
+    }
+
+    #[test]
+    fn test_generate_enums() {
+        use ironfix_dictionary::schema::{FieldRef, MessageCategory, Version};
+        use std::collections::HashMap;
+
+        let mut dict = Dictionary::new(Version::Fix44);
+        let values = HashMap::from([
+            ("1".to_string(), "BUY".to_string()),
+            ("2".to_string(), "SELL".to_string()),
+            ("5".to_string(), "SELL_SHORT".to_string()),
+        ]);
+        dict.add_field(FieldDef::new(54, "Side", FieldType::Char).with_values(values));
+        dict.add_message(MessageDef {
+            msg_type: "D".to_string(),
+            name: "NewOrderSingle".to_string(),
+            category: MessageCategory::App,
+            fields: vec![FieldRef {
+                tag: 54,
+                name: "Side".to_string(),
+                required: true,
+            }],
+            groups: vec![],
+            components: vec![],
+        });
+
+        let config = GeneratorConfig {
+            generate_enums: true,
+            ..GeneratorConfig::default()
+        };
+        let code = CodeGenerator::with_config(config).generate(&dict);
+
+        assert!(code.contains("pub enum Side {"));
+        assert!(code.contains("        Buy,\n"));
+        assert!(code.contains("        Sell,\n"));
+        assert!(code.contains("        SellShort,\n"));
+        assert!(code.contains("b\"5\" => Some(Self::SellShort),"));
+        assert!(code.contains("impl FixField for Side {"));
+        assert!(code.contains("pub side: enums::Side,"));
+
+        let plain = CodeGenerator::new().generate(&dict);
+        assert!(!plain.contains("pub enum Side"));
+        assert!(plain.contains("pub side: char,"));
+    }
+
+    #[test]
+    fn test_generate_groups() {
+        use ironfix_dictionary::schema::{MessageCategory, Version};
+
+        let field_ref = |tag: u32, name: &str, required: bool| FieldRef {
+            tag,
+            name: name.to_string(),
+            required,
+        };
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(55, "Symbol", FieldType::String));
+        dict.add_field(FieldDef::new(78, "NoAllocs", FieldType::NumInGroup));
+        dict.add_field(FieldDef::new(79, "AllocAccount", FieldType::String));
+        dict.add_field(FieldDef::new(80, "AllocQty", FieldType::Qty));
+        dict.add_message(MessageDef {
+            msg_type: "J".to_string(),
+            name: "AllocationInstruction".to_string(),
+            category: MessageCategory::App,
+            fields: vec![field_ref(55, "Symbol", true)],
+            groups: vec![GroupDef {
+                count_tag: 78,
+                name: "NoAllocs".to_string(),
+                delimiter_tag: 79,
+                fields: vec![
+                    field_ref(79, "AllocAccount", true),
+                    field_ref(80, "AllocQty", false),
+                ],
+                groups: vec![],
+                required: true,
+            }],
+            components: vec![],
+        });
+
+        let code = CodeGenerator::new().generate(&dict);
+
+        assert!(code.contains("pub struct NoAllocsEntry {"));
+        assert!(code.contains("pub alloc_account: String,"));
+        assert!(code.contains("pub alloc_qty: Option<rust_decimal::Decimal>,"));
+        assert!(
+            code.contains("fn decode_entry(entry: &[FieldRef<'_>]) -> Result<Self, DecodeError> {")
+        );
+        assert!(code.contains("pub no_allocs: Vec<NoAllocsEntry>,"));
+        assert!(code.contains(
+            "decode_group(&all_fields, fields::NO_ALLOCS, fields::ALLOC_ACCOUNT, \
+             &[fields::ALLOC_ACCOUNT, fields::ALLOC_QTY], true, NoAllocsEntry::decode_entry)?"
+        ));
+        assert!(code.contains("fn decode_group<'a, T>("));
+    }
+
+    #[test]
+    fn test_write_to_file() {
+        use ironfix_dictionary::schema::Version;
+
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(11, "ClOrdID", FieldType::String));
+
+        let path = std::env::temp_dir().join(format!(
+            "ironfix_codegen_write_to_file_{}.rs",
+            std::process::id()
+        ));
+        CodeGenerator::new().write_to_file(&dict, &path).unwrap();
+        let code = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(code.starts_with("// @generated\n"));
+        assert!(code.contains("pub const CL_ORD_ID: u32 = 11;"));
     }
 
     #[test]
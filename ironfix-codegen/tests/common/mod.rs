@@ -0,0 +1,87 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Shared helpers for code generation tests.
+
+use ironfix_dictionary::schema::{
+    Dictionary, FieldDef, FieldRef, FieldType, GroupDef, MessageCategory, MessageDef, Version,
+};
+use std::collections::HashMap;
+
+fn field_ref(tag: u32, name: &str, required: bool) -> FieldRef {
+    FieldRef {
+        tag,
+        name: name.to_string(),
+        required,
+    }
+}
+
+/// Builds the dictionary that the `fixtures/` files are generated from.
+pub fn sample_dictionary() -> Dictionary {
+    let mut dict = Dictionary::new(Version::Fix44);
+    dict.add_field(FieldDef::new(11, "ClOrdID", FieldType::String));
+    dict.add_field(FieldDef::new(38, "OrderQty", FieldType::Qty));
+    dict.add_field(FieldDef::new(44, "Price", FieldType::Price));
+    dict.add_field(FieldDef::new(78, "NoAllocs", FieldType::NumInGroup));
+    dict.add_field(FieldDef::new(70, "AllocID", FieldType::String));
+    dict.add_field(FieldDef::new(73, "NoOrders", FieldType::NumInGroup));
+    dict.add_field(FieldDef::new(79, "AllocAccount", FieldType::String));
+    dict.add_field(FieldDef::new(80, "AllocQty", FieldType::Qty));
+    dict.add_field(
+        FieldDef::new(54, "Side", FieldType::Char).with_values(HashMap::from([
+            ("1".to_string(), "BUY".to_string()),
+            ("2".to_string(), "SELL".to_string()),
+            ("5".to_string(), "SELL_SHORT".to_string()),
+        ])),
+    );
+    dict.add_field(FieldDef::new(55, "Symbol", FieldType::String));
+    dict.add_field(FieldDef::new(114, "LocateReqd", FieldType::Boolean));
+    dict.add_field(FieldDef::new(423, "PriceType", FieldType::Int));
+
+    dict.add_message(MessageDef {
+        msg_type: "D".to_string(),
+        name: "NewOrderSingle".to_string(),
+        category: MessageCategory::App,
+        fields: vec![
+            field_ref(11, "ClOrdID", true),
+            field_ref(55, "Symbol", true),
+            field_ref(54, "Side", true),
+            field_ref(38, "OrderQty", true),
+            field_ref(44, "Price", false),
+            field_ref(114, "LocateReqd", false),
+            field_ref(423, "PriceType", false),
+        ],
+        groups: vec![GroupDef {
+            count_tag: 78,
+            name: "NoAllocs".to_string(),
+            delimiter_tag: 79,
+            fields: vec![
+                field_ref(79, "AllocAccount", true),
+                field_ref(80, "AllocQty", false),
+            ],
+            groups: vec![],
+            required: false,
+        }],
+        components: vec![],
+    });
+    dict.add_message(MessageDef {
+        msg_type: "J".to_string(),
+        name: "AllocationInstruction".to_string(),
+        category: MessageCategory::App,
+        fields: vec![field_ref(70, "AllocID", true)],
+        groups: vec![GroupDef {
+            count_tag: 73,
+            name: "NoOrders".to_string(),
+            delimiter_tag: 11,
+            fields: vec![field_ref(11, "ClOrdID", true)],
+            groups: vec![],
+            required: true,
+        }],
+        components: vec![],
+    });
+
+    dict
+}
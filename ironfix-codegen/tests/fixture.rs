@@ -0,0 +1,42 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Golden tests keeping `fixtures/` in sync with the generator.
+//!
+//! Run with `IRONFIX_BLESS=1` to rewrite the fixtures after an intentional
+//! change to the generated output.
+
+mod common;
+
+use ironfix_codegen::{CodeGenerator, GeneratorConfig};
+
+/// Compares generated code against a fixture file, rewriting it when blessing.
+fn check_fixture(name: &str, code: &str) {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+
+    if std::env::var_os("IRONFIX_BLESS").is_some() {
+        std::fs::write(&path, code).unwrap();
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(code, expected, "{} is out of date", name);
+}
+
+#[test]
+fn test_generated_code_matches_fixture() {
+    let code = CodeGenerator::new().generate(&common::sample_dictionary());
+    check_fixture("generated.rs", &code);
+}
+
+#[test]
+fn test_generated_enums_code_matches_fixture() {
+    let config = GeneratorConfig {
+        generate_enums: true,
+        ..GeneratorConfig::default()
+    };
+    let code = CodeGenerator::with_config(config).generate(&common::sample_dictionary());
+    check_fixture("generated_enums.rs", &code);
+}
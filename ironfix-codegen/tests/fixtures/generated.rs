@@ -0,0 +1,311 @@
+//! Generated FIX FIX.4.4 definitions.
+//!
+//! This file was automatically generated. Do not edit.
+
+/// Field tag constants.
+pub mod fields {
+    pub const CL_ORD_ID: u32 = 11;
+    pub const ORDER_QTY: u32 = 38;
+    pub const PRICE: u32 = 44;
+    pub const SIDE: u32 = 54;
+    pub const SYMBOL: u32 = 55;
+    pub const ALLOC_ID: u32 = 70;
+    pub const NO_ORDERS: u32 = 73;
+    pub const NO_ALLOCS: u32 = 78;
+    pub const ALLOC_ACCOUNT: u32 = 79;
+    pub const ALLOC_QTY: u32 = 80;
+    pub const LOCATE_REQD: u32 = 114;
+    pub const PRICE_TYPE: u32 = 423;
+}
+
+/// Message type definitions.
+pub mod messages {
+    use super::fields;
+    use ironfix_core::error::{DecodeError, EncodeError};
+    use ironfix_core::field::FieldRef;
+    use ironfix_core::message::{FixMessage, RawMessage};
+
+    /// Looks up a required field, failing if it is absent.
+    fn required<'a>(raw: &RawMessage<'a>, tag: u32) -> Result<FieldRef<'a>, DecodeError> {
+        raw.get_field(tag).copied().ok_or(DecodeError::MissingRequiredField { tag })
+    }
+
+    /// Appends a `tag=value<SOH>` field to the buffer.
+    fn put_field(buf: &mut Vec<u8>, tag: u32, value: &[u8]) {
+        buf.extend_from_slice(tag.to_string().as_bytes());
+        buf.push(b'=');
+        buf.extend_from_slice(value);
+        buf.push(0x01);
+    }
+
+    /// Finds the first field with the given tag within a group entry.
+    fn find_field<'f, 'a>(entry: &'f [FieldRef<'a>], tag: u32) -> Option<&'f FieldRef<'a>> {
+        entry.iter().find(|f| f.tag == tag)
+    }
+
+    /// Looks up a required field within a group entry, failing if it is absent.
+    fn required_in<'a>(entry: &[FieldRef<'a>], tag: u32) -> Result<FieldRef<'a>, DecodeError> {
+        find_field(entry, tag).copied().ok_or(DecodeError::MissingRequiredField { tag })
+    }
+
+    /// Decodes the entries of a repeating group.
+    ///
+    /// Each entry starts at the delimiter field and extends while the tags
+    /// belong to the group. When the count field is absent, a required group
+    /// fails with `MissingRequiredField` and an optional one has no entries.
+    fn decode_group<'a, T>(
+        fields: &[FieldRef<'a>],
+        count_tag: u32,
+        delimiter_tag: u32,
+        member_tags: &[u32],
+        required: bool,
+        decode_entry: impl Fn(&[FieldRef<'a>]) -> Result<T, DecodeError>,
+    ) -> Result<Vec<T>, DecodeError> {
+        let Some(start) = fields.iter().position(|f| f.tag == count_tag) else {
+            if required {
+                return Err(DecodeError::MissingRequiredField { tag: count_tag });
+            }
+            return Ok(Vec::new());
+        };
+        let expected: u32 = fields[start].parse()?;
+
+        let mut entries = Vec::new();
+        let mut pos = start + 1;
+        while pos < fields.len() && fields[pos].tag == delimiter_tag {
+            let end = fields[pos + 1..]
+                .iter()
+                .position(|f| f.tag == delimiter_tag || !member_tags.contains(&f.tag))
+                .map_or(fields.len(), |offset| pos + 1 + offset);
+            entries.push(decode_entry(&fields[pos..end])?);
+            pos = end;
+        }
+
+        if entries.len() != expected as usize {
+            return Err(DecodeError::GroupCountMismatch {
+                count_tag,
+                expected,
+                actual: entries.len() as u32,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Entry of the NoAllocs repeating group (count tag 78).
+    #[derive(Debug, Clone)]
+    pub struct NoAllocsEntry {
+        pub alloc_account: String,
+        pub alloc_qty: Option<rust_decimal::Decimal>,
+    }
+
+    impl NoAllocsEntry {
+        /// Decodes one entry from the fields belonging to it.
+        fn decode_entry(entry: &[FieldRef<'_>]) -> Result<Self, DecodeError> {
+            Ok(Self {
+                alloc_account: required_in(entry, fields::ALLOC_ACCOUNT)?.to_string()?,
+                alloc_qty: find_field(entry, fields::ALLOC_QTY).map(|f| f.as_decimal()).transpose()?,
+            })
+        }
+
+        /// Encodes this entry's fields.
+        fn encode_entry(&self, buf: &mut Vec<u8>) {
+            put_field(buf, fields::ALLOC_ACCOUNT, self.alloc_account.as_bytes());
+            if let Some(value) = &self.alloc_qty {
+                put_field(buf, fields::ALLOC_QTY, value.to_string().as_bytes());
+            }
+        }
+    }
+
+    /// NewOrderSingle message (MsgType=D).
+    #[derive(Debug, Clone)]
+    pub struct NewOrderSingle {
+        pub cl_ord_id: String,
+        pub symbol: String,
+        pub side: char,
+        pub order_qty: rust_decimal::Decimal,
+        pub price: Option<rust_decimal::Decimal>,
+        pub locate_reqd: Option<bool>,
+        pub price_type: Option<i64>,
+        pub no_allocs: Vec<NoAllocsEntry>,
+    }
+
+    impl FixMessage for NewOrderSingle {
+        const MSG_TYPE: &'static str = "D";
+
+        fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+            let all_fields: Vec<FieldRef<'_>> = raw.fields().copied().collect();
+            Ok(Self {
+                cl_ord_id: required(raw, fields::CL_ORD_ID)?.to_string()?,
+                symbol: required(raw, fields::SYMBOL)?.to_string()?,
+                side: required(raw, fields::SIDE)?.as_char()?,
+                order_qty: required(raw, fields::ORDER_QTY)?.as_decimal()?,
+                price: raw.get_field(fields::PRICE).map(|f| f.as_decimal()).transpose()?,
+                locate_reqd: raw.get_field(fields::LOCATE_REQD).map(|f| f.as_bool()).transpose()?,
+                price_type: raw.get_field(fields::PRICE_TYPE).map(|f| f.as_i64()).transpose()?,
+                no_allocs: decode_group(&all_fields, fields::NO_ALLOCS, fields::ALLOC_ACCOUNT, &[fields::ALLOC_ACCOUNT, fields::ALLOC_QTY], false, NoAllocsEntry::decode_entry)?,
+            })
+        }
+
+        fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+            put_field(buf, fields::CL_ORD_ID, self.cl_ord_id.as_bytes());
+            put_field(buf, fields::SYMBOL, self.symbol.as_bytes());
+            put_field(buf, fields::SIDE, self.side.to_string().as_bytes());
+            put_field(buf, fields::ORDER_QTY, self.order_qty.to_string().as_bytes());
+            if let Some(value) = &self.price {
+                put_field(buf, fields::PRICE, value.to_string().as_bytes());
+            }
+            if let Some(value) = &self.locate_reqd {
+                put_field(buf, fields::LOCATE_REQD, if *value { b"Y" } else { b"N" });
+            }
+            if let Some(value) = &self.price_type {
+                put_field(buf, fields::PRICE_TYPE, value.to_string().as_bytes());
+            }
+            if !self.no_allocs.is_empty() {
+                put_field(buf, fields::NO_ALLOCS, self.no_allocs.len().to_string().as_bytes());
+                for entry in &self.no_allocs {
+                    entry.encode_entry(buf);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Builder for [`NewOrderSingle`].
+    #[derive(Debug, Clone)]
+    pub struct NewOrderSingleBuilder {
+        message: NewOrderSingle,
+    }
+
+    impl NewOrderSingleBuilder {
+        /// Creates a new builder from the required fields.
+        #[must_use]
+        pub fn new(cl_ord_id: String, symbol: String, side: char, order_qty: rust_decimal::Decimal) -> Self {
+            Self {
+                message: NewOrderSingle {
+                    cl_ord_id,
+                    symbol,
+                    side,
+                    order_qty,
+                    price: None,
+                    locate_reqd: None,
+                    price_type: None,
+                    no_allocs: Vec::new(),
+                },
+            }
+        }
+
+        /// Sets Price (tag 44).
+        #[must_use]
+        pub fn price(mut self, value: rust_decimal::Decimal) -> Self {
+            self.message.price = Some(value);
+            self
+        }
+
+        /// Sets LocateReqd (tag 114).
+        #[must_use]
+        pub fn locate_reqd(mut self, value: bool) -> Self {
+            self.message.locate_reqd = Some(value);
+            self
+        }
+
+        /// Sets PriceType (tag 423).
+        #[must_use]
+        pub fn price_type(mut self, value: i64) -> Self {
+            self.message.price_type = Some(value);
+            self
+        }
+
+        /// Sets the NoAllocs group entries.
+        #[must_use]
+        pub fn no_allocs(mut self, entries: Vec<NoAllocsEntry>) -> Self {
+            self.message.no_allocs = entries;
+            self
+        }
+
+        /// Builds the message.
+        #[must_use]
+        pub fn build(self) -> NewOrderSingle {
+            self.message
+        }
+    }
+
+    /// Entry of the NoOrders repeating group (count tag 73).
+    #[derive(Debug, Clone)]
+    pub struct NoOrdersEntry {
+        pub cl_ord_id: String,
+    }
+
+    impl NoOrdersEntry {
+        /// Decodes one entry from the fields belonging to it.
+        fn decode_entry(entry: &[FieldRef<'_>]) -> Result<Self, DecodeError> {
+            Ok(Self {
+                cl_ord_id: required_in(entry, fields::CL_ORD_ID)?.to_string()?,
+            })
+        }
+
+        /// Encodes this entry's fields.
+        fn encode_entry(&self, buf: &mut Vec<u8>) {
+            put_field(buf, fields::CL_ORD_ID, self.cl_ord_id.as_bytes());
+        }
+    }
+
+    /// AllocationInstruction message (MsgType=J).
+    #[derive(Debug, Clone)]
+    pub struct AllocationInstruction {
+        pub alloc_id: String,
+        pub no_orders: Vec<NoOrdersEntry>,
+    }
+
+    impl FixMessage for AllocationInstruction {
+        const MSG_TYPE: &'static str = "J";
+
+        fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+            let all_fields: Vec<FieldRef<'_>> = raw.fields().copied().collect();
+            Ok(Self {
+                alloc_id: required(raw, fields::ALLOC_ID)?.to_string()?,
+                no_orders: decode_group(&all_fields, fields::NO_ORDERS, fields::CL_ORD_ID, &[fields::CL_ORD_ID], true, NoOrdersEntry::decode_entry)?,
+            })
+        }
+
+        fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+            put_field(buf, fields::ALLOC_ID, self.alloc_id.as_bytes());
+            put_field(buf, fields::NO_ORDERS, self.no_orders.len().to_string().as_bytes());
+            for entry in &self.no_orders {
+                entry.encode_entry(buf);
+            }
+            Ok(())
+        }
+    }
+
+    /// Builder for [`AllocationInstruction`].
+    #[derive(Debug, Clone)]
+    pub struct AllocationInstructionBuilder {
+        message: AllocationInstruction,
+    }
+
+    impl AllocationInstructionBuilder {
+        /// Creates a new builder from the required fields.
+        #[must_use]
+        pub fn new(alloc_id: String) -> Self {
+            Self {
+                message: AllocationInstruction {
+                    alloc_id,
+                    no_orders: Vec::new(),
+                },
+            }
+        }
+
+        /// Sets the NoOrders group entries.
+        #[must_use]
+        pub fn no_orders(mut self, entries: Vec<NoOrdersEntry>) -> Self {
+            self.message.no_orders = entries;
+            self
+        }
+
+        /// Builds the message.
+        #[must_use]
+        pub fn build(self) -> AllocationInstruction {
+            self.message
+        }
+    }
+
+}
@@ -0,0 +1,248 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Compile and round-trip checks for generated code.
+//!
+//! The files in `fixtures/` are the generator output for the sample dictionary
+//! (kept in sync by the `fixture` tests). Including them as modules proves the
+//! emitted code compiles.
+
+use ironfix_core::error::DecodeError;
+use ironfix_core::message::FixMessage;
+use ironfix_tagvalue::{Decoder, Encoder};
+use rust_decimal::Decimal;
+
+#[allow(dead_code)]
+#[rustfmt::skip]
+#[path = "fixtures/generated.rs"]
+mod generated;
+
+#[allow(dead_code)]
+#[rustfmt::skip]
+#[path = "fixtures/generated_enums.rs"]
+mod generated_enums;
+
+use generated::messages::{
+    AllocationInstruction, NewOrderSingle, NewOrderSingleBuilder, NoAllocsEntry,
+};
+
+/// Wraps an encoded message body in a complete FIX message.
+fn frame<M: FixMessage>(message: &M) -> Vec<u8> {
+    let mut body = Vec::new();
+    message.encode(&mut body).unwrap();
+
+    let mut encoder = Encoder::new("FIX.4.4");
+    encoder.put_str(35, M::MSG_TYPE);
+    let mut decoder = Decoder::new(&body);
+    while let Some(field) = decoder.next_field() {
+        encoder.put_raw(field.tag, field.value);
+    }
+    encoder.finish().to_vec()
+}
+
+#[test]
+fn test_generated_message_round_trip() {
+    let order = NewOrderSingle {
+        cl_ord_id: "ORDER1".to_string(),
+        symbol: "AAPL".to_string(),
+        side: '1',
+        order_qty: Decimal::new(100, 0),
+        price: Some(Decimal::new(15025, 2)),
+        locate_reqd: Some(true),
+        price_type: None,
+        no_allocs: vec![],
+    };
+
+    let bytes = frame(&order);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    let decoded = NewOrderSingle::from_raw(&raw).unwrap();
+
+    assert_eq!(NewOrderSingle::MSG_TYPE, "D");
+    assert_eq!(decoded.cl_ord_id, "ORDER1");
+    assert_eq!(decoded.symbol, "AAPL");
+    assert_eq!(decoded.side, '1');
+    assert_eq!(decoded.order_qty, Decimal::new(100, 0));
+    assert_eq!(decoded.price, Some(Decimal::new(15025, 2)));
+    assert_eq!(decoded.locate_reqd, Some(true));
+    assert_eq!(decoded.price_type, None);
+}
+
+#[test]
+fn test_generated_builder_defaults() {
+    let order = NewOrderSingleBuilder::new(
+        "ORDER1".to_string(),
+        "AAPL".to_string(),
+        '2',
+        Decimal::new(10, 0),
+    )
+    .build();
+
+    assert_eq!(order.cl_ord_id, "ORDER1");
+    assert_eq!(order.symbol, "AAPL");
+    assert_eq!(order.side, '2');
+    assert_eq!(order.order_qty, Decimal::new(10, 0));
+    assert_eq!(order.price, None);
+    assert_eq!(order.locate_reqd, None);
+    assert_eq!(order.price_type, None);
+    assert!(order.no_allocs.is_empty());
+}
+
+#[test]
+fn test_generated_builder_setters() {
+    let order = NewOrderSingleBuilder::new(
+        "ORDER1".to_string(),
+        "AAPL".to_string(),
+        '1',
+        Decimal::new(10, 0),
+    )
+    .price(Decimal::new(9950, 2))
+    .price_type(2)
+    .build();
+
+    assert_eq!(order.price, Some(Decimal::new(9950, 2)));
+    assert_eq!(order.locate_reqd, None);
+    assert_eq!(order.price_type, Some(2));
+}
+
+#[test]
+fn test_generated_group_round_trip() {
+    let order = NewOrderSingleBuilder::new(
+        "ORDER1".to_string(),
+        "AAPL".to_string(),
+        '1',
+        Decimal::new(300, 0),
+    )
+    .no_allocs(vec![
+        NoAllocsEntry {
+            alloc_account: "ACC1".to_string(),
+            alloc_qty: Some(Decimal::new(100, 0)),
+        },
+        NoAllocsEntry {
+            alloc_account: "ACC2".to_string(),
+            alloc_qty: None,
+        },
+    ])
+    .price_type(2)
+    .build();
+
+    let bytes = frame(&order);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    let decoded = NewOrderSingle::from_raw(&raw).unwrap();
+
+    assert_eq!(raw.get_field_str(78), Some("2"));
+    assert_eq!(decoded.no_allocs.len(), 2);
+    assert_eq!(decoded.no_allocs[0].alloc_account, "ACC1");
+    assert_eq!(decoded.no_allocs[0].alloc_qty, Some(Decimal::new(100, 0)));
+    assert_eq!(decoded.no_allocs[1].alloc_account, "ACC2");
+    assert_eq!(decoded.no_allocs[1].alloc_qty, None);
+    assert_eq!(decoded.price_type, Some(2));
+}
+
+#[test]
+fn test_generated_group_count_mismatch() {
+    let bytes = {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_str(11, "ORDER1");
+        encoder.put_str(55, "AAPL");
+        encoder.put_str(54, "1");
+        encoder.put_str(38, "100");
+        encoder.put_str(78, "2");
+        encoder.put_str(79, "ACC1");
+        encoder.finish().to_vec()
+    };
+    let raw = Decoder::new(&bytes).decode().unwrap();
+
+    assert!(NewOrderSingle::from_raw(&raw).is_err());
+}
+
+#[test]
+fn test_generated_required_group_present() {
+    let bytes = {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "J");
+        encoder.put_str(70, "ALLOC1");
+        encoder.put_str(73, "1");
+        encoder.put_str(11, "ORDER1");
+        encoder.finish().to_vec()
+    };
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    let decoded = AllocationInstruction::from_raw(&raw).unwrap();
+
+    assert_eq!(decoded.no_orders.len(), 1);
+    assert_eq!(decoded.no_orders[0].cl_ord_id, "ORDER1");
+}
+
+#[test]
+fn test_generated_empty_required_group_round_trip() {
+    let allocation = AllocationInstruction {
+        alloc_id: "ALLOC1".to_string(),
+        no_orders: vec![],
+    };
+
+    let bytes = frame(&allocation);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    let decoded = AllocationInstruction::from_raw(&raw).unwrap();
+
+    assert_eq!(raw.get_field_str(73), Some("0"));
+    assert_eq!(decoded.alloc_id, "ALLOC1");
+    assert!(decoded.no_orders.is_empty());
+}
+
+#[test]
+fn test_generated_missing_required_group() {
+    let bytes = {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "J");
+        encoder.put_str(70, "ALLOC1");
+        encoder.finish().to_vec()
+    };
+    let raw = Decoder::new(&bytes).decode().unwrap();
+
+    assert!(matches!(
+        AllocationInstruction::from_raw(&raw),
+        Err(DecodeError::MissingRequiredField { tag: 73 })
+    ));
+}
+
+#[test]
+fn test_generated_message_missing_required_field() {
+    let bytes = {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_str(11, "ORDER1");
+        encoder.finish().to_vec()
+    };
+    let raw = Decoder::new(&bytes).decode().unwrap();
+
+    assert!(NewOrderSingle::from_raw(&raw).is_err());
+}
+
+#[test]
+fn test_generated_enum_message_round_trip() {
+    use generated_enums::enums::Side;
+    use generated_enums::messages::NewOrderSingle;
+
+    let order = NewOrderSingle {
+        cl_ord_id: "ORDER2".to_string(),
+        symbol: "MSFT".to_string(),
+        side: Side::SellShort,
+        order_qty: Decimal::new(50, 0),
+        price: None,
+        locate_reqd: None,
+        price_type: Some(2),
+        no_allocs: vec![],
+    };
+
+    let bytes = frame(&order);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    let decoded = NewOrderSingle::from_raw(&raw).unwrap();
+
+    assert_eq!(raw.get_field_str(54), Some("5"));
+    assert_eq!(decoded.side, Side::SellShort);
+    assert_eq!(decoded.price_type, Some(2));
+    assert_eq!(Side::from_bytes(b"9"), None);
+}
@@ -9,11 +9,13 @@
 //! This module provides a unified error hierarchy using `thiserror` for typed,
 //! domain-specific errors across all IronFix operations.
 
-use std::ops::Range;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::ops::Range;
 use thiserror::Error;
 
 /// Result type alias using [`FixError`] as the error type.
-pub type Result<T> = std::result::Result<T, FixError>;
+pub type Result<T> = core::result::Result<T, FixError>;
 
 /// Top-level error type for all IronFix operations.
 #[derive(Debug, Error)]
@@ -35,6 +37,7 @@ pub enum FixError {
     Store(#[from] StoreError),
 
     /// I/O error from underlying transport.
+    #[cfg(feature = "std")]
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -58,6 +61,15 @@ pub enum DecodeError {
     #[error("invalid body length value")]
     InvalidBodyLength,
 
+    /// BodyLength does not match the number of bytes in the body.
+    #[error("incorrect body length: declared {declared}, actual {actual}")]
+    IncorrectBodyLength {
+        /// Declared BodyLength value.
+        declared: usize,
+        /// Bytes between BodyLength and CheckSum.
+        actual: usize,
+    },
+
     /// Missing MsgType field (tag 35).
     #[error("missing msg type field (tag 35)")]
     MissingMsgType,
@@ -106,9 +118,22 @@ pub enum DecodeError {
         actual: u32,
     },
 
+    /// Standard header field missing or out of order (strict decoding).
+    #[error("header field out of order: expected tag {expected}, found tag {found}")]
+    HeaderOutOfOrder {
+        /// The tag expected at this header position.
+        expected: u32,
+        /// The tag found at this header position.
+        found: u32,
+    },
+
+    /// Invalid time-of-day value.
+    #[error("invalid time: {0}")]
+    InvalidTime(String),
+
     /// Invalid UTF-8 in string field.
     #[error("invalid utf-8 in field: {0}")]
-    InvalidUtf8(#[from] std::str::Utf8Error),
+    InvalidUtf8(#[from] core::str::Utf8Error),
 
     /// Message exceeds maximum allowed size.
     #[error("message too large: {size} bytes exceeds maximum {max_size}")]
@@ -118,6 +143,55 @@ pub enum DecodeError {
         /// Maximum allowed size in bytes.
         max_size: usize,
     },
+
+    /// Message has more fields than the decoder accepts.
+    #[error("message has more than {max} fields")]
+    TooManyFields {
+        /// Maximum number of fields accepted.
+        max: usize,
+    },
+
+    /// Error annotated with the byte offset of the field that failed.
+    #[error("{source} at byte offset {offset}")]
+    AtOffset {
+        /// Byte offset of the field in the decoded input.
+        offset: usize,
+        /// The underlying error.
+        #[source]
+        source: Box<DecodeError>,
+    },
+}
+
+impl DecodeError {
+    /// Annotates the error with the byte offset of the failing field.
+    ///
+    /// # Arguments
+    /// * `offset` - Byte offset of the field in the decoded input
+    #[must_use]
+    pub fn at_offset(self, offset: usize) -> Self {
+        Self::AtOffset {
+            offset,
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the byte offset recorded on this error, if any.
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        match self {
+            Self::AtOffset { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error without offset context.
+    #[must_use]
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::AtOffset { source, .. } => source.root(),
+            other => other,
+        }
+    }
 }
 
 /// Errors that occur during FIX message encoding.
@@ -158,6 +232,16 @@ pub enum EncodeError {
         /// Maximum allowed length.
         max_length: usize,
     },
+
+    /// A framing field was written into the message body.
+    ///
+    /// BeginString (8), BodyLength (9) and CheckSum (10) are written by the
+    /// encoder when the message is finished.
+    #[error("framing field tag {tag} must not be written to the message body")]
+    FramingFieldInBody {
+        /// The tag number of the framing field.
+        tag: u32,
+    },
 }
 
 /// Errors in FIX session layer operations.
@@ -278,6 +362,15 @@ pub enum StoreError {
     Io(String),
 }
 
+/// Error parsing a [`CompId`](crate::types::CompId) longer than
+/// [`COMP_ID_MAX_LEN`](crate::types::COMP_ID_MAX_LEN).
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("comp id too long: {length} bytes exceeds max {max}", max = crate::types::COMP_ID_MAX_LEN)]
+pub struct CompIdTooLong {
+    /// Length of the rejected input in bytes.
+    pub length: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,6 +394,15 @@ mod tests {
         assert!(matches!(fix_err, FixError::Decode(DecodeError::Incomplete)));
     }
 
+    #[test]
+    fn test_decode_error_at_offset() {
+        let err = DecodeError::InvalidTag("3x".to_string()).at_offset(42);
+        assert_eq!(err.to_string(), "invalid tag format: 3x at byte offset 42");
+        assert_eq!(err.offset(), Some(42));
+        assert_eq!(err.root(), &DecodeError::InvalidTag("3x".to_string()));
+        assert_eq!(DecodeError::Incomplete.offset(), None);
+    }
+
     #[test]
     fn test_session_error_display() {
         let err = SessionError::SequenceGap {
@@ -13,11 +13,31 @@
 //! - [`FixField`]: Trait for typed field access
 
 use crate::error::DecodeError;
+use crate::scaled::{MAX_SCALE, ScaledPrice, ScaledQty, parse_scaled};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use bytes::Bytes;
+use core::fmt;
+use core::str::FromStr;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::str::FromStr;
+
+/// How text field values that are not valid UTF-8 are interpreted.
+///
+/// FIX text fields are ASCII, but legacy counterparties may send Latin-1
+/// in free-text fields such as Text (tag 58).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TextPolicy {
+    /// Reject values that are not valid UTF-8.
+    #[default]
+    Strict,
+    /// Replace invalid UTF-8 sequences with U+FFFD.
+    Lossy,
+    /// Interpret every byte as a Latin-1 character.
+    Latin1,
+}
 
 /// FIX field tag number.
 ///
@@ -116,7 +136,44 @@ impl<'a> FieldRef<'a> {
     /// # Errors
     /// Returns `DecodeError::InvalidUtf8` if the value is not valid UTF-8.
     pub fn as_str(&self) -> Result<&'a str, DecodeError> {
-        std::str::from_utf8(self.value).map_err(DecodeError::from)
+        core::str::from_utf8(self.value).map_err(DecodeError::from)
+    }
+
+    /// Returns the value as text, replacing invalid UTF-8 sequences with
+    /// U+FFFD.
+    ///
+    /// Borrows the value when it is valid UTF-8.
+    #[must_use]
+    pub fn as_str_lossy(&self) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.value)
+    }
+
+    /// Returns the value interpreted as Latin-1 (ISO 8859-1).
+    ///
+    /// Every byte maps to the code point of the same value, so this never
+    /// fails. Borrows the value when it is plain ASCII.
+    #[must_use]
+    pub fn as_latin1(&self) -> Cow<'a, str> {
+        match core::str::from_utf8(self.value) {
+            Ok(s) if s.is_ascii() => Cow::Borrowed(s),
+            _ => Cow::Owned(self.value.iter().map(|&b| char::from(b)).collect()),
+        }
+    }
+
+    /// Returns the value as text under the given policy.
+    ///
+    /// # Arguments
+    /// * `policy` - How to treat bytes that are not valid UTF-8
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidUtf8` if the policy is
+    /// [`TextPolicy::Strict`] and the value is not valid UTF-8.
+    pub fn as_text(&self, policy: TextPolicy) -> Result<Cow<'a, str>, DecodeError> {
+        match policy {
+            TextPolicy::Strict => self.as_str().map(Cow::Borrowed),
+            TextPolicy::Lossy => Ok(self.as_str_lossy()),
+            TextPolicy::Latin1 => Ok(self.as_latin1()),
+        }
     }
 
     /// Returns the value as an owned String.
@@ -135,10 +192,21 @@ impl<'a> FieldRef<'a> {
         let s = self.as_str()?;
         s.parse().map_err(|_| DecodeError::InvalidFieldValue {
             tag: self.tag,
-            reason: format!("failed to parse '{}' as {}", s, std::any::type_name::<T>()),
+            reason: format!("failed to parse '{}' as {}", s, core::any::type_name::<T>()),
         })
     }
 
+    /// Decodes the value using a typed [`FixField`] definition.
+    ///
+    /// This bridges raw field access to generated enum and value types,
+    /// delegating to [`FixField::decode`].
+    ///
+    /// # Errors
+    /// Returns `DecodeError` if the value cannot be decoded as `T::Value`.
+    pub fn as_enum<T: FixField>(&self) -> Result<T::Value, DecodeError> {
+        T::decode(self.value)
+    }
+
     /// Returns the value as a u64.
     ///
     /// # Errors
@@ -163,6 +231,42 @@ impl<'a> FieldRef<'a> {
         self.parse()
     }
 
+    /// Returns the value as an `i64` mantissa scaled by `10^SCALE`.
+    ///
+    /// Parses the decimal wire form directly, without going through
+    /// [`Decimal`]; `"125.25"` at scale 4 yields `1_252_500`.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidFieldValue` if the value is not a decimal
+    /// number, has more than `SCALE` significant decimal places, or does not
+    /// fit in an `i64`.
+    pub fn as_scaled_i64<const SCALE: u32>(&self) -> Result<i64, DecodeError> {
+        const { assert!(SCALE <= MAX_SCALE, "scale exceeds MAX_SCALE") };
+        parse_scaled(self.value, SCALE).map_err(|reason| DecodeError::InvalidFieldValue {
+            tag: self.tag,
+            reason: reason.to_string(),
+        })
+    }
+
+    /// Returns the value as a [`ScaledPrice`].
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidFieldValue` under the same conditions as
+    /// [`as_scaled_i64`](Self::as_scaled_i64).
+    pub fn as_price<const SCALE: u32>(&self) -> Result<ScaledPrice<SCALE>, DecodeError> {
+        self.as_scaled_i64::<SCALE>()
+            .map(ScaledPrice::from_mantissa)
+    }
+
+    /// Returns the value as a [`ScaledQty`].
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidFieldValue` under the same conditions as
+    /// [`as_scaled_i64`](Self::as_scaled_i64).
+    pub fn as_qty<const SCALE: u32>(&self) -> Result<ScaledQty<SCALE>, DecodeError> {
+        self.as_scaled_i64::<SCALE>().map(ScaledQty::from_mantissa)
+    }
+
     /// Returns the value as a bool (FIX uses 'Y'/'N').
     ///
     /// # Errors
@@ -193,6 +297,17 @@ impl<'a> FieldRef<'a> {
         }
     }
 
+    /// Returns the space-separated values of a multi-value field.
+    ///
+    /// FIX `MultipleCharValue` and `MultipleStringValue` fields (such as
+    /// ExecInst) carry several values separated by single spaces. Empty
+    /// entries produced by repeated spaces are skipped.
+    pub fn values(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.value
+            .split(|&b| b == b' ')
+            .filter(|value| !value.is_empty())
+    }
+
     /// Returns the raw bytes of the value.
     #[inline]
     #[must_use]
@@ -354,6 +469,23 @@ mod tests {
         assert_eq!(field.as_str().unwrap(), "ORDER123");
     }
 
+    #[test]
+    fn test_field_ref_text_policies() {
+        let field = FieldRef::new(58, b"caf\xe9");
+        assert!(matches!(field.as_str(), Err(DecodeError::InvalidUtf8(_))));
+        assert!(field.as_text(TextPolicy::Strict).is_err());
+        assert_eq!(field.as_latin1(), "café");
+        assert_eq!(field.as_text(TextPolicy::Latin1).unwrap(), "café");
+        assert_eq!(field.as_str_lossy(), "caf\u{fffd}");
+        assert_eq!(field.as_text(TextPolicy::Lossy).unwrap(), "caf\u{fffd}");
+
+        let ascii = FieldRef::new(58, b"plain");
+        assert!(matches!(ascii.as_latin1(), Cow::Borrowed("plain")));
+        assert!(matches!(ascii.as_str_lossy(), Cow::Borrowed("plain")));
+        // Valid UTF-8 is still read byte by byte under Latin-1.
+        assert_eq!(FieldRef::new(58, "é".as_bytes()).as_latin1(), "Ã©");
+    }
+
     #[test]
     fn test_field_ref_as_u64() {
         let field = FieldRef::new(34, b"12345");
@@ -374,6 +506,40 @@ mod tests {
         assert_eq!(field.as_char().unwrap(), '1');
     }
 
+    #[test]
+    fn test_field_ref_values() {
+        let field = FieldRef::new(18, b"G 1  M");
+        let values: Vec<&[u8]> = field.values().collect();
+        assert_eq!(values, [&b"G"[..], b"1", b"M"]);
+        assert_eq!(FieldRef::new(18, b"").values().count(), 0);
+    }
+
+    struct SideField;
+
+    impl FixField for SideField {
+        const TAG: u32 = 54;
+        type Value = crate::types::Side;
+
+        fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+            FieldRef::new(Self::TAG, bytes).parse()
+        }
+
+        fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+            buf.push(value.as_char() as u8);
+        }
+    }
+
+    #[test]
+    fn test_field_ref_as_enum() {
+        use crate::types::Side;
+
+        assert_eq!(
+            FieldRef::new(54, b"2").as_enum::<SideField>().unwrap(),
+            Side::Sell
+        );
+        assert!(FieldRef::new(54, b"Z").as_enum::<SideField>().is_err());
+    }
+
     #[test]
     fn test_field_ref_invalid_utf8() {
         let field = FieldRef::new(1, &[0xFF, 0xFE]);
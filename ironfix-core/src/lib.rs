@@ -13,18 +13,39 @@
 //! - **Field types**: `FieldTag`, `FieldValue`, and the `FixField` trait
 //! - **Message types**: `RawMessage`, `OwnedMessage`, and the `FixMessage` trait
 //! - **Core types**: `SeqNum`, `Timestamp`, `CompID`, `MsgType`
+//! - **Fixed-point types**: `ScaledPrice` and `ScaledQty` for integer price math
 //!
 //! ## Zero-Copy Design
 //!
 //! The core abstractions support both zero-copy borrowed views (for hot-path processing)
 //! and owned representations (for storage and cross-thread transfer).
+//!
+//! ## `no_std` Support
+//!
+//! The `std` feature is enabled by default. Without it the crate builds on
+//! `core` and `alloc` only: field access, message views, tag parsing and the
+//! core types remain available, while [`Timestamp::now`] and the I/O error
+//! variant are compiled out.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod error;
 pub mod field;
 pub mod message;
+pub mod scaled;
 pub mod types;
 
-pub use error::{DecodeError, EncodeError, FixError, Result, SessionError, StoreError};
-pub use field::{FieldRef, FieldTag, FieldValue, FixField};
-pub use message::{FixMessage, MsgType, OwnedMessage, RawMessage};
-pub use types::{CompId, SeqNum, Side, Timestamp};
+pub use error::{
+    CompIdTooLong, DecodeError, EncodeError, FixError, Result, SessionError, StoreError,
+};
+pub use field::{FieldRef, FieldTag, FieldValue, FixField, TextPolicy};
+pub use message::{
+    FieldIndex, FixMessage, INLINE_FIELDS, MessageArena, MessageBuilder, MessageLayout, MsgType,
+    OwnedMessage, RawMessage, StandardLayout,
+};
+pub use scaled::{ScaledPrice, ScaledQty};
+pub use types::{
+    CompId, Country, Currency, Exchange, ExecInst, FixTime, HandlInst, SeqNum, Side, Timestamp,
+};
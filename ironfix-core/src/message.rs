@@ -8,17 +8,23 @@
 //!
 //! This module provides:
 //! - [`RawMessage`]: Zero-copy view into a FIX message buffer
+//! - [`FieldIndex`]: Constant-time tag lookup over a `RawMessage`
 //! - [`OwnedMessage`]: Owned message for storage and cross-thread transfer
+//! - [`MessageBuilder`]: Builder for framed in-memory messages
 //! - [`MsgType`]: Enumeration of FIX message types
+//! - [`MessageLayout`]: Header/trailer membership used to split message sections
 //! - [`FixMessage`]: Trait for typed message access
 
-use crate::error::DecodeError;
-use crate::field::FieldRef;
-use bytes::Bytes;
+use crate::error::{DecodeError, EncodeError};
+use crate::field::{FieldRef, FixField};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use bytes::{BufMut, Bytes, BytesMut};
+use core::fmt;
+use core::ops::Range;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
-use std::fmt;
-use std::ops::Range;
 
 /// Standard FIX message types.
 ///
@@ -149,8 +155,8 @@ pub enum MsgType {
     Custom(String),
 }
 
-impl std::str::FromStr for MsgType {
-    type Err = std::convert::Infallible;
+impl core::str::FromStr for MsgType {
+    type Err = core::convert::Infallible;
 
     /// Creates a MsgType from a string value.
     ///
@@ -310,6 +316,46 @@ impl MsgType {
     pub fn is_app(&self) -> bool {
         !self.is_admin()
     }
+
+    /// Returns true if this is a market data message.
+    #[must_use]
+    pub fn is_market_data(&self) -> bool {
+        matches!(
+            self,
+            Self::MarketDataRequest
+                | Self::MarketDataSnapshotFullRefresh
+                | Self::MarketDataIncrementalRefresh
+                | Self::MarketDataRequestReject
+        )
+    }
+
+    /// Returns true if this message submits, amends, cancels or reports on
+    /// an order.
+    #[must_use]
+    pub fn is_order_related(&self) -> bool {
+        matches!(
+            self,
+            Self::NewOrderSingle
+                | Self::NewOrderList
+                | Self::OrderCancelRequest
+                | Self::OrderCancelReplaceRequest
+                | Self::ExecutionReport
+                | Self::OrderCancelReject
+        )
+    }
+
+    /// Returns true if this is a quoting message.
+    #[must_use]
+    pub fn is_quote_related(&self) -> bool {
+        matches!(
+            self,
+            Self::QuoteRequest
+                | Self::Quote
+                | Self::QuoteCancel
+                | Self::MassQuote
+                | Self::MassQuoteAcknowledgement
+        )
+    }
 }
 
 impl fmt::Display for MsgType {
@@ -318,6 +364,44 @@ impl fmt::Display for MsgType {
     }
 }
 
+/// Tags of the standard message header (FIX 4.x and FIXT.1.1).
+pub const STANDARD_HEADER_TAGS: &[u32] = &[
+    8, 9, 35, 49, 56, 115, 128, 90, 91, 34, 50, 142, 57, 143, 116, 144, 129, 145, 43, 97, 52, 122,
+    212, 213, 347, 369, 627, 628, 629, 630, 1128, 1129, 1156,
+];
+
+/// Tags of the standard message trailer.
+pub const STANDARD_TRAILER_TAGS: &[u32] = &[93, 89, 10];
+
+/// Decides which tags belong to the message header and trailer.
+///
+/// Every other tag is part of the body. Dictionaries implement this from
+/// their header and trailer definitions; [`StandardLayout`] uses the
+/// standard tag sets.
+pub trait MessageLayout {
+    /// Returns true if the tag belongs to the message header.
+    fn is_header_field(&self, tag: u32) -> bool;
+
+    /// Returns true if the tag belongs to the message trailer.
+    fn is_trailer_field(&self, tag: u32) -> bool;
+}
+
+/// Layout using [`STANDARD_HEADER_TAGS`] and [`STANDARD_TRAILER_TAGS`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StandardLayout;
+
+impl MessageLayout for StandardLayout {
+    #[inline]
+    fn is_header_field(&self, tag: u32) -> bool {
+        STANDARD_HEADER_TAGS.contains(&tag)
+    }
+
+    #[inline]
+    fn is_trailer_field(&self, tag: u32) -> bool {
+        STANDARD_TRAILER_TAGS.contains(&tag)
+    }
+}
+
 /// Zero-copy view into a FIX message buffer.
 ///
 /// This struct holds references to the original message buffer,
@@ -373,7 +457,7 @@ impl<'a> RawMessage<'a> {
     /// Returns the BeginString value (e.g., "FIX.4.4").
     #[must_use]
     pub fn begin_string(&self) -> &'a str {
-        std::str::from_utf8(&self.buffer[self.begin_string.clone()]).unwrap_or("")
+        core::str::from_utf8(&self.buffer[self.begin_string.clone()]).unwrap_or("")
     }
 
     /// Returns the message type.
@@ -396,6 +480,47 @@ impl<'a> RawMessage<'a> {
         self.fields.len()
     }
 
+    /// Returns an iterator over the header fields, in message order.
+    ///
+    /// # Arguments
+    /// * `layout` - Decides header membership, e.g. [`StandardLayout`] or a dictionary
+    pub fn header_fields<'l, L: MessageLayout + ?Sized>(
+        &self,
+        layout: &'l L,
+    ) -> impl Iterator<Item = &FieldRef<'a>> + use<'_, 'a, 'l, L> {
+        self.fields
+            .iter()
+            .filter(move |f| layout.is_header_field(f.tag))
+    }
+
+    /// Returns an iterator over the body fields, in message order.
+    ///
+    /// Body fields are those neither in the header nor the trailer.
+    ///
+    /// # Arguments
+    /// * `layout` - Decides header and trailer membership
+    pub fn body_fields<'l, L: MessageLayout + ?Sized>(
+        &self,
+        layout: &'l L,
+    ) -> impl Iterator<Item = &FieldRef<'a>> + use<'_, 'a, 'l, L> {
+        self.fields
+            .iter()
+            .filter(move |f| !layout.is_header_field(f.tag) && !layout.is_trailer_field(f.tag))
+    }
+
+    /// Returns an iterator over the trailer fields, in message order.
+    ///
+    /// # Arguments
+    /// * `layout` - Decides trailer membership
+    pub fn trailer_fields<'l, L: MessageLayout + ?Sized>(
+        &self,
+        layout: &'l L,
+    ) -> impl Iterator<Item = &FieldRef<'a>> + use<'_, 'a, 'l, L> {
+        self.fields
+            .iter()
+            .filter(move |f| layout.is_trailer_field(f.tag))
+    }
+
     /// Gets a field by tag number.
     ///
     /// # Arguments
@@ -408,6 +533,17 @@ impl<'a> RawMessage<'a> {
         self.fields.iter().find(|f| f.tag == tag)
     }
 
+    /// Gets every field with the given tag, in message order.
+    ///
+    /// Tags repeat within repeating groups; [`get_field`](Self::get_field)
+    /// only returns the first occurrence.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    pub fn get_all_fields(&self, tag: u32) -> impl Iterator<Item = &FieldRef<'a>> {
+        self.fields.iter().filter(move |f| f.tag == tag)
+    }
+
     /// Gets a field value as a string.
     ///
     /// # Arguments
@@ -427,12 +563,74 @@ impl<'a> RawMessage<'a> {
     ///
     /// # Errors
     /// Returns `DecodeError` if the field is not found or cannot be parsed.
-    pub fn get_field_as<T: std::str::FromStr>(&self, tag: u32) -> Result<T, DecodeError> {
+    pub fn get_field_as<T: core::str::FromStr>(&self, tag: u32) -> Result<T, DecodeError> {
         self.get_field(tag)
             .ok_or(DecodeError::MissingRequiredField { tag })?
             .parse()
     }
 
+    /// Gets a field value decoded through a typed [`FixField`] definition.
+    ///
+    /// The field is looked up by `T::TAG`.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::MissingRequiredField` if the field is not found,
+    /// or the error from [`FixField::decode`] if the value is invalid.
+    pub fn get_field_enum<T: FixField>(&self) -> Result<T::Value, DecodeError> {
+        self.get_field(T::TAG)
+            .ok_or(DecodeError::MissingRequiredField { tag: T::TAG })?
+            .as_enum::<T>()
+    }
+
+    /// Gets a length-prefixed data field (e.g. RawDataLength(95)/RawData(96)).
+    ///
+    /// The value of a data field may legitimately contain SOH, so instead of
+    /// relying on the parsed field boundaries, the declared length is used to
+    /// slice exactly that many bytes from the start of the data field value.
+    ///
+    /// # Arguments
+    /// * `len_tag` - The tag of the length field
+    /// * `data_tag` - The tag of the data field
+    ///
+    /// # Returns
+    /// The data bytes, or `None` if either field is missing, the length is
+    /// invalid, or the declared length runs past the end of the buffer.
+    #[must_use]
+    pub fn get_data_field(&self, len_tag: u32, data_tag: u32) -> Option<&'a [u8]> {
+        let len: usize = self.get_field(len_tag)?.as_str().ok()?.parse().ok()?;
+        let data = self.get_field(data_tag)?;
+        let start = (data.value.as_ptr() as usize).checked_sub(self.buffer.as_ptr() as usize)?;
+        let end = start.checked_add(len)?;
+        self.buffer.get(start..end)
+    }
+
+    /// Gets SecureData (tag 91), whose length is SecureDataLen (tag 90).
+    ///
+    /// # Returns
+    /// The data bytes, or `None` as for [`get_data_field`](Self::get_data_field).
+    #[must_use]
+    pub fn secure_data(&self) -> Option<&'a [u8]> {
+        self.get_data_field(90, 91)
+    }
+
+    /// Gets XMLData (tag 213), whose length is XMLDataLen (tag 212).
+    ///
+    /// # Returns
+    /// The XML bytes, or `None` as for [`get_data_field`](Self::get_data_field).
+    #[must_use]
+    pub fn xml_data(&self) -> Option<&'a [u8]> {
+        self.get_data_field(212, 213)
+    }
+
+    /// Gets Signature (tag 89), whose length is SignatureLength (tag 93).
+    ///
+    /// # Returns
+    /// The data bytes, or `None` as for [`get_data_field`](Self::get_data_field).
+    #[must_use]
+    pub fn signature(&self) -> Option<&'a [u8]> {
+        self.get_data_field(93, 89)
+    }
+
     /// Returns the message body range.
     #[inline]
     #[must_use]
@@ -459,12 +657,185 @@ impl<'a> RawMessage<'a> {
     pub fn to_owned(&self) -> OwnedMessage {
         OwnedMessage::from_raw(self)
     }
+
+    /// Builds a tag-to-position index for repeated lookups.
+    ///
+    /// [`get_field`](Self::get_field) scans the fields on every call; when a
+    /// handler reads many fields of the same message, build the index once
+    /// and query it instead.
+    #[must_use]
+    pub fn index(&self) -> FieldIndex<'_, 'a> {
+        FieldIndex::new(self)
+    }
+}
+
+/// Tags below this bound are indexed in a directly addressed table.
+const DENSE_INDEX_TAGS: u32 = 1024;
+
+/// Tag-to-position index over the fields of a [`RawMessage`].
+///
+/// Created by [`RawMessage::index`]. Lookups of tags below 1024, which cover
+/// the standard FIX fields, are constant time; higher tags, such as
+/// user-defined fields, use a binary search. A repeated tag resolves to its
+/// first occurrence, like [`RawMessage::get_field`].
+#[derive(Debug, Clone)]
+pub struct FieldIndex<'m, 'a> {
+    /// The indexed message.
+    message: &'m RawMessage<'a>,
+    /// Position plus one of the first field with each low tag; zero if absent.
+    dense: Vec<u32>,
+    /// (tag, position) of the first field with each high tag, sorted by tag.
+    sparse: Vec<(u32, u32)>,
+}
+
+impl<'m, 'a> FieldIndex<'m, 'a> {
+    /// Indexes the fields of `message`.
+    fn new(message: &'m RawMessage<'a>) -> Self {
+        let dense_len = message
+            .fields
+            .iter()
+            .map(|field| field.tag)
+            .filter(|&tag| tag < DENSE_INDEX_TAGS)
+            .max()
+            .map_or(0, |tag| tag as usize + 1);
+        let mut dense = alloc::vec![0u32; dense_len];
+        let mut sparse = Vec::new();
+
+        for (position, field) in message.fields.iter().enumerate() {
+            let position = position as u32;
+            if field.tag < DENSE_INDEX_TAGS {
+                let slot = &mut dense[field.tag as usize];
+                if *slot == 0 {
+                    *slot = position + 1;
+                }
+            } else {
+                sparse.push((field.tag, position));
+            }
+        }
+        // A stable sort keeps the first occurrence of each tag first.
+        sparse.sort_by_key(|&(tag, _)| tag);
+        sparse.dedup_by_key(|&mut (tag, _)| tag);
+
+        Self {
+            message,
+            dense,
+            sparse,
+        }
+    }
+
+    /// Returns the position of the first field with the given tag.
+    fn position(&self, tag: u32) -> Option<usize> {
+        if tag < DENSE_INDEX_TAGS {
+            let slot = *self.dense.get(tag as usize)?;
+            return slot.checked_sub(1).map(|position| position as usize);
+        }
+        self.sparse
+            .binary_search_by_key(&tag, |&(tag, _)| tag)
+            .ok()
+            .map(|index| self.sparse[index].1 as usize)
+    }
+
+    /// Gets a field by tag number.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    ///
+    /// # Returns
+    /// The first field with the given tag, or `None` if not found.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&'m FieldRef<'a>> {
+        self.position(tag)
+            .map(|position| &self.message.fields[position])
+    }
+
+    /// Gets a field value as a string.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    ///
+    /// # Returns
+    /// The field value as a string, or `None` if not found or invalid UTF-8.
+    #[must_use]
+    pub fn get_str(&self, tag: u32) -> Option<&'a str> {
+        self.get(tag).and_then(|field| field.as_str().ok())
+    }
+
+    /// Returns true if the message has a field with the given tag.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, tag: u32) -> bool {
+        self.position(tag).is_some()
+    }
+
+    /// Returns the indexed message.
+    #[inline]
+    #[must_use]
+    pub const fn message(&self) -> &'m RawMessage<'a> {
+        self.message
+    }
+}
+
+/// Number of field offsets an [`OwnedMessage`] stores without allocating.
+pub const INLINE_FIELDS: usize = 16;
+
+/// Field offsets of an [`OwnedMessage`]: (tag, value_range).
+type FieldOffsets = SmallVec<[(u32, Range<usize>); INLINE_FIELDS]>;
+
+/// Reusable buffer pool for [`OwnedMessage::from_raw_in`].
+///
+/// Messages copied into the arena are carved from one shared allocation.
+/// Once every message taken from it has been dropped, the allocation is
+/// reused in place, so a steady stream of short-lived messages does not
+/// allocate per message. A message still held when the arena runs out of
+/// room keeps its chunk alive and the arena moves on to a new one.
+#[derive(Debug, Default)]
+pub struct MessageArena {
+    buffer: BytesMut,
+}
+
+impl MessageArena {
+    /// Creates an empty arena that allocates on first use.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an arena with room for `capacity` message bytes.
+    ///
+    /// # Arguments
+    /// * `capacity` - Initial size of the shared allocation in bytes
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: BytesMut::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes that can be copied in without allocating.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// Copies `bytes` into the arena and returns them as a shared buffer.
+    fn copy(&mut self, bytes: &[u8]) -> Bytes {
+        self.buffer.reserve(bytes.len());
+        self.buffer.extend_from_slice(bytes);
+        self.buffer.split().freeze()
+    }
 }
 
 /// Owned FIX message for storage and cross-thread transfer.
 ///
 /// Unlike [`RawMessage`], this struct owns its data and can be
 /// safely sent across threads or stored for later use.
+///
+/// Offsets for up to [`INLINE_FIELDS`] fields are stored inline. Copying
+/// through [`from_raw_in`](Self::from_raw_in) also takes the buffer from a
+/// reusable [`MessageArena`], so such messages need no heap allocation of
+/// their own.
 #[derive(Debug, Clone)]
 pub struct OwnedMessage {
     /// The complete message buffer.
@@ -472,31 +843,69 @@ pub struct OwnedMessage {
     /// The parsed message type.
     msg_type: MsgType,
     /// Field offsets: (tag, value_range).
-    field_offsets: Vec<(u32, Range<usize>)>,
+    field_offsets: FieldOffsets,
+    /// Whether fields changed since BodyLength and CheckSum were computed.
+    needs_reframe: bool,
 }
 
 impl OwnedMessage {
     /// Creates an OwnedMessage from a RawMessage.
     ///
+    /// Every field is kept in message order, including repeated tags.
+    ///
+    /// If a field value does not lie within the raw buffer, as can happen
+    /// with a hand-built `RawMessage`, the fields are re-encoded into a new
+    /// buffer and the message is marked as needing a reframe.
+    ///
     /// # Arguments
     /// * `raw` - The raw message to copy
     #[must_use]
     pub fn from_raw(raw: &RawMessage<'_>) -> Self {
-        let buffer = Bytes::copy_from_slice(raw.buffer);
-        let field_offsets = raw
-            .fields
-            .iter()
-            .map(|f| {
-                let start = f.value.as_ptr() as usize - raw.buffer.as_ptr() as usize;
-                let end = start + f.value.len();
-                (f.tag, start..end)
-            })
-            .collect();
+        match raw_offsets(raw) {
+            Some(field_offsets) => Self {
+                buffer: Bytes::copy_from_slice(raw.buffer),
+                msg_type: raw.msg_type.clone(),
+                field_offsets,
+                needs_reframe: false,
+            },
+            None => Self::reencoded(raw),
+        }
+    }
 
+    /// Creates an OwnedMessage from a RawMessage, copying its bytes into
+    /// `arena`.
+    ///
+    /// Behaves like [`from_raw`](Self::from_raw), but the buffer is carved
+    /// from the arena instead of freshly allocated. A message of at most
+    /// [`INLINE_FIELDS`] fields with a standard MsgType then performs no heap
+    /// allocation once the arena has warmed up.
+    ///
+    /// # Arguments
+    /// * `raw` - The raw message to copy
+    /// * `arena` - The buffer pool to copy into
+    #[must_use]
+    pub fn from_raw_in(raw: &RawMessage<'_>, arena: &mut MessageArena) -> Self {
+        match raw_offsets(raw) {
+            Some(field_offsets) => Self {
+                buffer: arena.copy(raw.buffer),
+                msg_type: raw.msg_type.clone(),
+                field_offsets,
+                needs_reframe: false,
+            },
+            None => Self::reencoded(raw),
+        }
+    }
+
+    /// Re-encodes the fields of a RawMessage whose values do not all lie in
+    /// its buffer.
+    fn reencoded(raw: &RawMessage<'_>) -> Self {
+        let (buffer, field_offsets) =
+            encode_fields(raw.fields.iter().map(|f| (f.tag, f.value)), 0x01);
         Self {
             buffer,
             msg_type: raw.msg_type.clone(),
             field_offsets,
+            needs_reframe: true,
         }
     }
 
@@ -511,7 +920,8 @@ impl OwnedMessage {
         Self {
             buffer,
             msg_type,
-            field_offsets,
+            field_offsets: FieldOffsets::from_vec(field_offsets),
+            needs_reframe: false,
         }
     }
 
@@ -558,6 +968,27 @@ impl OwnedMessage {
             .map(|(_, range)| &self.buffer[range.clone()])
     }
 
+    /// Gets every field value with the given tag, in message order.
+    ///
+    /// Tags repeat within repeating groups; [`get_field`](Self::get_field)
+    /// only returns the first occurrence.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    pub fn get_all_fields(&self, tag: u32) -> impl Iterator<Item = &[u8]> {
+        self.field_offsets
+            .iter()
+            .filter(move |(t, _)| *t == tag)
+            .map(|(_, range)| &self.buffer[range.clone()])
+    }
+
+    /// Returns all fields as `(tag, value)` pairs, in message order.
+    pub fn fields(&self) -> impl Iterator<Item = (u32, &[u8])> {
+        self.field_offsets
+            .iter()
+            .map(|(tag, range)| (*tag, &self.buffer[range.clone()]))
+    }
+
     /// Gets a field value as a string.
     ///
     /// # Arguments
@@ -568,7 +999,7 @@ impl OwnedMessage {
     #[must_use]
     pub fn get_field_str(&self, tag: u32) -> Option<&str> {
         self.get_field(tag)
-            .and_then(|b| std::str::from_utf8(b).ok())
+            .and_then(|b| core::str::from_utf8(b).ok())
     }
 
     /// Returns the number of fields.
@@ -578,11 +1009,444 @@ impl OwnedMessage {
         self.field_offsets.len()
     }
 
+    /// Returns a borrowed [`RawMessage`] view of this message.
+    ///
+    /// The view is built from the stored field offsets without re-parsing the
+    /// buffer. The body range starts after BodyLength and spans the declared
+    /// length, as when decoding; without BodyLength it covers the whole buffer
+    /// up to CheckSum.
+    #[must_use]
+    pub fn as_raw(&self) -> RawMessage<'_> {
+        let range_of = |tag: u32| {
+            self.field_offsets
+                .iter()
+                .find(|(t, _)| *t == tag)
+                .map(|(_, range)| range.clone())
+        };
+        let begin_string = range_of(8).unwrap_or(0..0);
+        let (body_start, declared_len) = range_of(9).map_or((0, None), |range| {
+            let declared = core::str::from_utf8(&self.buffer[range.clone()])
+                .ok()
+                .and_then(|len| len.parse::<usize>().ok());
+            (range.end + 1, declared)
+        });
+        let body_end = match declared_len {
+            Some(len) => body_start.saturating_add(len).min(self.buffer.len()),
+            None => self
+                .field_offsets
+                .iter()
+                .rev()
+                .find(|(tag, _)| *tag == 10)
+                .and_then(|(_, range)| range.start.checked_sub(3))
+                .unwrap_or(self.buffer.len()),
+        };
+        let fields = self
+            .fields()
+            .map(|(tag, value)| FieldRef::new(tag, value))
+            .collect();
+
+        RawMessage::new(
+            &self.buffer,
+            begin_string,
+            body_start.min(body_end)..body_end,
+            self.msg_type.clone(),
+            fields,
+        )
+    }
+
     /// Consumes the message and returns the underlying buffer.
     #[must_use]
     pub fn into_bytes(self) -> Bytes {
         self.buffer
     }
+
+    /// Sets a field value, replacing the first field with the same tag.
+    ///
+    /// A new field is inserted before the CheckSum field, or appended if there
+    /// is none. BodyLength and CheckSum are left stale; call
+    /// [`reframe`](Self::reframe) once all modifications are done.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `value` - The new field value
+    pub fn set_field(&mut self, tag: u32, value: &[u8]) {
+        let mut fields: Vec<(u32, &[u8])> = self.fields().collect();
+        match fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value,
+            None => {
+                let pos = fields
+                    .iter()
+                    .position(|(t, _)| *t == 10)
+                    .unwrap_or(fields.len());
+                fields.insert(pos, (tag, value));
+            }
+        }
+
+        let (buffer, field_offsets) = encode_fields(fields, self.delimiter());
+        self.buffer = buffer;
+        self.field_offsets = field_offsets;
+        self.needs_reframe = true;
+        if tag == 35 {
+            // MsgType parsing is infallible.
+            self.msg_type = String::from_utf8_lossy(value).parse().unwrap();
+        }
+    }
+
+    /// Removes every field with the given tag.
+    ///
+    /// Like [`set_field`](Self::set_field), this leaves BodyLength and
+    /// CheckSum stale until [`reframe`](Self::reframe) is called.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    ///
+    /// # Returns
+    /// `true` if at least one field was removed.
+    pub fn remove_field(&mut self, tag: u32) -> bool {
+        if !self.field_offsets.iter().any(|(t, _)| *t == tag) {
+            return false;
+        }
+
+        let fields = self.fields().filter(|(t, _)| *t != tag);
+        let (buffer, field_offsets) = encode_fields(fields, self.delimiter());
+        self.buffer = buffer;
+        self.field_offsets = field_offsets;
+        self.needs_reframe = true;
+        true
+    }
+
+    /// Returns true if fields were modified since the message was last framed.
+    #[inline]
+    #[must_use]
+    pub const fn needs_reframe(&self) -> bool {
+        self.needs_reframe
+    }
+
+    /// Rebuilds the message framing after modification.
+    ///
+    /// Recomputes BodyLength (tag 9) over the fields between it and CheckSum,
+    /// then recomputes CheckSum (tag 10). Fields keep their order.
+    ///
+    /// # Errors
+    /// Returns `EncodeError::MissingRequiredField` if BeginString (tag 8) is
+    /// not among the message's parsed fields.
+    pub fn reframe(&mut self) -> Result<(), EncodeError> {
+        let begin_string = self
+            .get_field(8)
+            .ok_or(EncodeError::MissingRequiredField { tag: 8 })?;
+        let body: Vec<(u32, &[u8])> = self
+            .fields()
+            .filter(|(tag, _)| !matches!(tag, 8..=10))
+            .collect();
+        let body_len: usize = body
+            .iter()
+            .map(|(tag, value)| tag_len(*tag) + value.len() + 2)
+            .sum();
+        let body_len = body_len.to_string();
+
+        let delimiter = self.delimiter();
+        let mut buffer = BytesMut::with_capacity(self.buffer.len() + 8);
+        let mut field_offsets = FieldOffsets::with_capacity(body.len() + 3);
+        put_field(&mut buffer, &mut field_offsets, 8, begin_string, delimiter);
+        put_field(
+            &mut buffer,
+            &mut field_offsets,
+            9,
+            body_len.as_bytes(),
+            delimiter,
+        );
+        for (tag, value) in body {
+            put_field(&mut buffer, &mut field_offsets, tag, value, delimiter);
+        }
+        put_field(&mut buffer, &mut field_offsets, 10, b"000", delimiter);
+
+        self.buffer = buffer.freeze();
+        self.field_offsets = field_offsets;
+        self.recompute_checksum()?;
+        self.needs_reframe = false;
+        Ok(())
+    }
+
+    /// Recomputes the CheckSum (tag 10) value in place.
+    ///
+    /// The checksum covers every byte before the `10=` field. BodyLength is
+    /// not touched; use [`reframe`](Self::reframe) when fields have changed.
+    ///
+    /// # Errors
+    /// Returns `EncodeError::MissingRequiredField` if there is no CheckSum
+    /// field, or `EncodeError::InvalidFieldValue` if its value is not three
+    /// bytes long.
+    pub fn recompute_checksum(&mut self) -> Result<(), EncodeError> {
+        let range = self
+            .field_offsets
+            .iter()
+            .rev()
+            .find(|(tag, _)| *tag == 10)
+            .map(|(_, range)| range.clone())
+            .ok_or(EncodeError::MissingRequiredField { tag: 10 })?;
+        if range.len() != 3 {
+            return Err(EncodeError::InvalidFieldValue {
+                tag: 10,
+                reason: "checksum must be three digits".to_string(),
+            });
+        }
+
+        // Checksummed bytes end before "10=".
+        let end = range
+            .start
+            .checked_sub(3)
+            .filter(|&end| {
+                range.end <= self.buffer.len() && &self.buffer[end..range.start] == b"10="
+            })
+            .ok_or_else(|| EncodeError::InvalidFieldValue {
+                tag: 10,
+                reason: "checksum field is not framed as 10=".to_string(),
+            })?;
+        let checksum = self.buffer[..end]
+            .iter()
+            .fold(0u8, |sum, &b| sum.wrapping_add(b));
+
+        let mut buffer = BytesMut::from(&self.buffer[..]);
+        buffer[range].copy_from_slice(format!("{:03}", checksum).as_bytes());
+        self.buffer = buffer.freeze();
+        Ok(())
+    }
+
+    /// Checks that the buffer still holds a well-formed, correctly framed
+    /// message.
+    ///
+    /// The buffer is re-parsed independently of the stored field offsets: it
+    /// must start with BeginString (8), BodyLength (9) and MsgType (35) and
+    /// end with CheckSum (10), whose value and the declared BodyLength must
+    /// match the bytes. Use it to catch store corruption before resending a
+    /// stored message.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::ChecksumMismatch` or
+    /// `DecodeError::IncorrectBodyLength` if a framing value is wrong, or
+    /// another `DecodeError` if the buffer is not a complete message.
+    pub fn verify(&self) -> Result<(), DecodeError> {
+        let buffer = &self.buffer[..];
+        let delimiter = self.delimiter();
+        let mut offset = 0;
+
+        if scan_field(buffer, &mut offset, delimiter)?.0 != 8 {
+            return Err(DecodeError::InvalidBeginString);
+        }
+        let (tag, value) = scan_field(buffer, &mut offset, delimiter)?;
+        if tag != 9 {
+            return Err(DecodeError::MissingBodyLength);
+        }
+        let declared_length = core::str::from_utf8(&buffer[value])
+            .ok()
+            .and_then(|len| len.parse::<usize>().ok())
+            .ok_or(DecodeError::InvalidBodyLength)?;
+        let body_start = offset;
+        if scan_field(buffer, &mut offset, delimiter)?.0 != 35 {
+            return Err(DecodeError::MissingMsgType);
+        }
+
+        let (checksum_start, checksum) = loop {
+            let field_start = offset;
+            let (tag, value) = scan_field(buffer, &mut offset, delimiter)?;
+            if tag == 10 {
+                break (field_start, &buffer[value]);
+            }
+        };
+        if offset != buffer.len() {
+            return Err(DecodeError::InvalidFieldValue {
+                tag: 10,
+                reason: "trailing bytes after checksum".to_string(),
+            });
+        }
+
+        let actual = checksum_start - body_start;
+        if actual != declared_length {
+            return Err(DecodeError::IncorrectBodyLength {
+                declared: declared_length,
+                actual,
+            });
+        }
+
+        let declared = match checksum {
+            [a, b, c] if checksum.iter().all(u8::is_ascii_digit) => u8::try_from(
+                u32::from(a - b'0') * 100 + u32::from(b - b'0') * 10 + u32::from(c - b'0'),
+            )
+            .ok(),
+            _ => None,
+        }
+        .ok_or_else(|| DecodeError::InvalidFieldValue {
+            tag: 10,
+            reason: "invalid checksum format".to_string(),
+        })?;
+        let calculated = buffer[..checksum_start]
+            .iter()
+            .fold(0u8, |sum, &b| sum.wrapping_add(b));
+        if calculated != declared {
+            return Err(DecodeError::ChecksumMismatch {
+                calculated,
+                declared,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the field delimiter used in the buffer, defaulting to SOH.
+    fn delimiter(&self) -> u8 {
+        self.field_offsets
+            .first()
+            .and_then(|(_, range)| self.buffer.get(range.end))
+            .copied()
+            .unwrap_or(0x01)
+    }
+}
+
+/// Builder for framed in-memory messages.
+///
+/// Fields are given as `(tag, value)` pairs in body order. The builder writes
+/// BeginString, BodyLength and MsgType first and appends a valid CheckSum, so
+/// the result can be viewed as a [`RawMessage`] through
+/// [`OwnedMessage::as_raw`] without an encode/decode round trip.
+#[derive(Debug, Clone)]
+pub struct MessageBuilder {
+    begin_string: String,
+    msg_type: MsgType,
+    fields: Vec<(u32, Vec<u8>)>,
+}
+
+impl MessageBuilder {
+    /// Creates a new builder.
+    ///
+    /// # Arguments
+    /// * `begin_string` - The BeginString value (e.g. "FIX.4.4")
+    /// * `msg_type` - The message type
+    #[must_use]
+    pub fn new(begin_string: impl Into<String>, msg_type: MsgType) -> Self {
+        Self {
+            begin_string: begin_string.into(),
+            msg_type,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Appends a body field.
+    ///
+    /// Framing tags (8, 9, 10) and MsgType (35) are written by the builder
+    /// and ignored here.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `value` - The field value
+    #[must_use]
+    pub fn field(mut self, tag: u32, value: impl AsRef<[u8]>) -> Self {
+        if !matches!(tag, 8..=10 | 35) {
+            self.fields.push((tag, value.as_ref().to_vec()));
+        }
+        self
+    }
+
+    /// Builds the framed message with BodyLength and CheckSum computed.
+    #[must_use]
+    pub fn build(self) -> OwnedMessage {
+        let header = [
+            (8, self.begin_string.as_bytes()),
+            (35, self.msg_type.as_str().as_bytes()),
+        ];
+        let fields = header.into_iter().chain(
+            self.fields
+                .iter()
+                .map(|(tag, value)| (*tag, value.as_slice())),
+        );
+        let (buffer, field_offsets) = encode_fields(fields, 0x01);
+
+        let mut message = OwnedMessage {
+            buffer,
+            msg_type: self.msg_type,
+            field_offsets,
+            needs_reframe: false,
+        };
+        // BeginString is always present, so framing cannot fail.
+        message
+            .reframe()
+            .expect("builder messages always have BeginString");
+        message
+    }
+}
+
+/// Returns the value ranges of a RawMessage's fields within its buffer, or
+/// `None` if a value lies outside it.
+fn raw_offsets(raw: &RawMessage<'_>) -> Option<FieldOffsets> {
+    let base = raw.buffer.as_ptr() as usize;
+    raw.fields
+        .iter()
+        .map(|f| {
+            let start = (f.value.as_ptr() as usize).checked_sub(base)?;
+            let end = start.checked_add(f.value.len())?;
+            (end <= raw.buffer.len()).then_some((f.tag, start..end))
+        })
+        .collect()
+}
+
+/// Encodes fields into a new buffer, returning it with the value ranges.
+fn encode_fields<'f>(
+    fields: impl IntoIterator<Item = (u32, &'f [u8])>,
+    delimiter: u8,
+) -> (Bytes, FieldOffsets) {
+    let mut buffer = BytesMut::new();
+    let mut field_offsets = FieldOffsets::new();
+    for (tag, value) in fields {
+        put_field(&mut buffer, &mut field_offsets, tag, value, delimiter);
+    }
+    (buffer.freeze(), field_offsets)
+}
+
+/// Appends `tag=value<delimiter>` to `buffer`, recording the value's range.
+fn put_field(
+    buffer: &mut BytesMut,
+    field_offsets: &mut FieldOffsets,
+    tag: u32,
+    value: &[u8],
+    delimiter: u8,
+) {
+    buffer.put_slice(tag.to_string().as_bytes());
+    buffer.put_u8(b'=');
+    let start = buffer.len();
+    buffer.put_slice(value);
+    field_offsets.push((tag, start..buffer.len()));
+    buffer.put_u8(delimiter);
+}
+
+/// Parses the `tag=value<delimiter>` field at `offset`, advancing past it.
+///
+/// # Returns
+/// The tag and the value's range within `buffer`.
+fn scan_field(
+    buffer: &[u8],
+    offset: &mut usize,
+    delimiter: u8,
+) -> Result<(u32, Range<usize>), DecodeError> {
+    let rest = buffer.get(*offset..).unwrap_or_default();
+    let equals = rest
+        .iter()
+        .position(|&b| b == b'=')
+        .ok_or(DecodeError::Incomplete)?;
+    let tag_bytes = &rest[..equals];
+    let tag = (!tag_bytes.is_empty() && tag_bytes.iter().all(u8::is_ascii_digit))
+        .then(|| core::str::from_utf8(tag_bytes).ok()?.parse().ok())
+        .flatten()
+        .ok_or_else(|| DecodeError::InvalidTag(String::from_utf8_lossy(tag_bytes).into_owned()))?;
+    let value_start = *offset + equals + 1;
+    let value_len = buffer[value_start..]
+        .iter()
+        .position(|&b| b == delimiter)
+        .ok_or(DecodeError::Incomplete)?;
+    *offset = value_start + value_len + 1;
+    Ok((tag, value_start..value_start + value_len))
+}
+
+/// Returns the number of decimal digits in a tag.
+fn tag_len(tag: u32) -> usize {
+    tag.checked_ilog10().map_or(1, |digits| digits as usize + 1)
 }
 
 /// Trait for typed FIX message access.
@@ -640,6 +1504,29 @@ mod tests {
         assert!(!MsgType::ExecutionReport.is_admin());
     }
 
+    #[test]
+    fn test_msg_type_categories() {
+        assert!(MsgType::MarketDataRequest.is_market_data());
+        assert!(MsgType::MarketDataIncrementalRefresh.is_market_data());
+        assert!(!MsgType::Quote.is_market_data());
+
+        assert!(MsgType::NewOrderSingle.is_order_related());
+        assert!(MsgType::ExecutionReport.is_order_related());
+        assert!(MsgType::OrderCancelReject.is_order_related());
+        assert!(!MsgType::OrderStatusRequest.is_order_related());
+
+        assert!(MsgType::QuoteRequest.is_quote_related());
+        assert!(MsgType::MassQuote.is_quote_related());
+        assert!(MsgType::MassQuoteAcknowledgement.is_quote_related());
+        assert!(!MsgType::QuoteStatusRequest.is_quote_related());
+
+        let heartbeat = MsgType::Heartbeat;
+        assert!(!heartbeat.is_market_data());
+        assert!(!heartbeat.is_order_related());
+        assert!(!heartbeat.is_quote_related());
+        assert!(!MsgType::Custom("U1".to_string()).is_order_related());
+    }
+
     #[test]
     fn test_msg_type_custom() {
         let custom: MsgType = "XX".parse().unwrap();
@@ -647,6 +1534,134 @@ mod tests {
         assert_eq!(custom.as_str(), "XX");
     }
 
+    #[test]
+    fn test_raw_message_get_data_field() {
+        let buffer: &[u8] = b"8=FIX.4.4\x0135=B\x0195=5\x0196=ab\x01cd\x0158=text\x01";
+        // A plain SOH split would stop the RawData value at the embedded SOH.
+        let fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::from_iter([
+            FieldRef::new(8, &buffer[2..9]),
+            FieldRef::new(35, &buffer[13..14]),
+            FieldRef::new(95, &buffer[18..19]),
+            FieldRef::new(96, &buffer[23..25]),
+            FieldRef::new(58, &buffer[32..36]),
+        ]);
+        let raw = RawMessage::new(buffer, 2..9, 10..buffer.len(), MsgType::News, fields);
+
+        assert_eq!(raw.get_field(96).unwrap().as_bytes(), b"ab");
+        assert_eq!(raw.get_data_field(95, 96), Some(&b"ab\x01cd"[..]));
+        assert_eq!(raw.get_data_field(95, 999), None);
+        assert_eq!(raw.get_data_field(58, 96), None);
+    }
+
+    #[test]
+    fn test_raw_message_get_field_enum() {
+        use crate::types::Side;
+
+        struct SideField;
+
+        impl FixField for SideField {
+            const TAG: u32 = 54;
+            type Value = Side;
+
+            fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+                FieldRef::new(Self::TAG, bytes).parse()
+            }
+
+            fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+                buf.push(value.as_char() as u8);
+            }
+        }
+
+        struct OrdTypeField;
+
+        impl FixField for OrdTypeField {
+            const TAG: u32 = 40;
+            type Value = char;
+
+            fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+                FieldRef::new(Self::TAG, bytes).as_char()
+            }
+
+            fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+                buf.push(*value as u8);
+            }
+        }
+
+        let buffer: &[u8] = b"35=D\x0154=2\x01";
+        let fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::from_iter([
+            FieldRef::new(35, &buffer[3..4]),
+            FieldRef::new(54, &buffer[8..9]),
+        ]);
+        let raw = RawMessage::new(
+            buffer,
+            0..0,
+            0..buffer.len(),
+            MsgType::NewOrderSingle,
+            fields,
+        );
+
+        assert_eq!(raw.get_field_enum::<SideField>().unwrap(), Side::Sell);
+        assert_eq!(
+            raw.get_field_enum::<OrdTypeField>(),
+            Err(DecodeError::MissingRequiredField { tag: 40 })
+        );
+    }
+
+    #[test]
+    fn test_message_builder() {
+        let msg = MessageBuilder::new("FIX.4.4", MsgType::NewOrderSingle)
+            .field(49, "SENDER")
+            .field(56, "TARGET")
+            .field(11, "ORD1")
+            .field(54, b"1")
+            .build();
+
+        assert_eq!(
+            msg.as_bytes(),
+            b"8=FIX.4.4\x019=38\x0135=D\x0149=SENDER\x0156=TARGET\x0111=ORD1\x0154=1\x0110=087\x01"
+        );
+        let checksum = msg.as_bytes()[..msg.len() - 7]
+            .iter()
+            .fold(0u8, |sum, &b| sum.wrapping_add(b));
+        assert_eq!(
+            msg.get_field_str(10),
+            Some(format!("{:03}", checksum).as_str())
+        );
+
+        let raw = msg.as_raw();
+        assert_eq!(raw.begin_string(), "FIX.4.4");
+        assert_eq!(raw.msg_type(), &MsgType::NewOrderSingle);
+        assert_eq!(raw.get_field_str(49), Some("SENDER"));
+        assert_eq!(raw.get_field_as::<u32>(54).unwrap(), 1);
+        assert_eq!(raw.body_range().len(), 38);
+        assert_eq!(
+            raw.fields().map(|f| f.tag).collect::<Vec<_>>(),
+            [8, 9, 35, 49, 56, 11, 54, 10]
+        );
+    }
+
+    #[test]
+    fn test_raw_message_sections() {
+        let msg = MessageBuilder::new("FIX.4.4", MsgType::NewOrderSingle)
+            .field(49, "SENDER")
+            .field(56, "TARGET")
+            .field(34, "2")
+            .field(11, "ORD1")
+            .field(55, "AAPL")
+            .build();
+        let raw = msg.as_raw();
+
+        let tags = |fields: &mut dyn Iterator<Item = &FieldRef<'_>>| {
+            fields.map(|f| f.tag).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            tags(&mut raw.header_fields(&StandardLayout)),
+            [8, 9, 35, 49, 56, 34]
+        );
+        assert_eq!(tags(&mut raw.body_fields(&StandardLayout)), [11, 55]);
+        assert_eq!(tags(&mut raw.trailer_fields(&StandardLayout)), [10]);
+    }
+
     #[test]
     fn test_owned_message_field_access() {
         // Buffer: "8=FIX.4.4\x0135=D\x0149=SENDER\x01"
@@ -661,4 +1676,282 @@ mod tests {
         assert_eq!(msg.get_field_str(49), Some("SENDER"));
         assert_eq!(msg.get_field_str(999), None);
     }
+
+    #[test]
+    fn test_repeated_tags_survive_to_owned() {
+        let buffer: &[u8] = b"8=FIX.4.4\x0135=D\x0178=2\x0179=ACC1\x0179=ACC2\x01";
+        let fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::from_iter([
+            FieldRef::new(8, &buffer[2..9]),
+            FieldRef::new(35, &buffer[13..14]),
+            FieldRef::new(78, &buffer[18..19]),
+            FieldRef::new(79, &buffer[23..27]),
+            FieldRef::new(79, &buffer[31..35]),
+        ]);
+        let raw = RawMessage::new(
+            buffer,
+            2..9,
+            10..buffer.len(),
+            MsgType::NewOrderSingle,
+            fields,
+        );
+
+        let raw_values: Vec<_> = raw.get_all_fields(79).map(FieldRef::as_bytes).collect();
+        assert_eq!(raw_values, [b"ACC1", b"ACC2"]);
+
+        let owned = raw.to_owned();
+        assert_eq!(owned.field_count(), 5);
+        assert_eq!(owned.get_field(79), Some(&b"ACC1"[..]));
+        let owned_values: Vec<_> = owned.get_all_fields(79).collect();
+        assert_eq!(owned_values, [b"ACC1", b"ACC2"]);
+
+        let tags: Vec<_> = owned.fields().map(|(tag, _)| tag).collect();
+        assert_eq!(tags, [8, 35, 78, 79, 79]);
+        assert_eq!(owned.get_all_fields(999).count(), 0);
+        assert_eq!(owned.into_bytes(), buffer);
+    }
+
+    #[test]
+    fn test_owned_message_set_field_and_reframe() {
+        let buffer = Bytes::from_static(b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01");
+        let field_offsets = vec![(8, 2..9), (9, 12..13), (35, 17..18), (10, 22..25)];
+        let mut msg = OwnedMessage::new(buffer, MsgType::Heartbeat, field_offsets);
+
+        assert!(!msg.needs_reframe());
+        msg.set_field(43, b"Y");
+        msg.set_field(35, b"1");
+        assert!(msg.needs_reframe());
+        assert_eq!(msg.msg_type(), &MsgType::TestRequest);
+        msg.reframe().unwrap();
+        assert!(!msg.needs_reframe());
+
+        assert_eq!(msg.get_field_str(35), Some("1"));
+        assert_eq!(msg.get_field_str(43), Some("Y"));
+        assert_eq!(msg.get_field_str(9), Some("10"));
+
+        let bytes = msg.as_bytes();
+        let checksum_start = bytes.len() - 7;
+        assert!(bytes.starts_with(b"8=FIX.4.4\x019=10\x0135=1\x0143=Y\x0110="));
+        let expected = bytes[..checksum_start]
+            .iter()
+            .fold(0u8, |sum, &b| sum.wrapping_add(b));
+        assert_eq!(
+            msg.get_field_str(10),
+            Some(format!("{:03}", expected).as_str())
+        );
+    }
+
+    #[test]
+    fn test_owned_message_as_raw() {
+        let buffer = Bytes::from_static(b"8=FIX.4.4\x019=11\x0135=D\x0134=42\x0110=000\x01");
+        let field_offsets = vec![
+            (8, 2..9),
+            (9, 12..14),
+            (35, 18..19),
+            (34, 23..25),
+            (10, 29..32),
+        ];
+        let owned = OwnedMessage::new(buffer, MsgType::NewOrderSingle, field_offsets);
+
+        let raw = owned.as_raw();
+        assert_eq!(raw.begin_string(), "FIX.4.4");
+        assert_eq!(raw.msg_type(), &MsgType::NewOrderSingle);
+        assert_eq!(raw.get_field_as::<u64>(34).unwrap(), 42);
+        assert_eq!(raw.field_count(), owned.field_count());
+        assert_eq!(raw.body_range(), &(15..26));
+        assert_eq!(
+            &owned.as_bytes()[raw.body_range().clone()],
+            b"35=D\x0134=42\x01"
+        );
+    }
+
+    #[test]
+    fn test_owned_message_as_raw_clamps_oversized_body_length() {
+        let buffer =
+            Bytes::from_static(b"8=FIX.4.4\x019=18446744073709551615\x0135=0\x0110=000\x01");
+        let field_offsets = vec![(8, 2..9), (9, 12..32), (35, 36..37), (10, 41..44)];
+        let owned = OwnedMessage::new(buffer, MsgType::Heartbeat, field_offsets);
+
+        let raw = owned.as_raw();
+        assert_eq!(raw.body_range(), &(33..45));
+        assert_eq!(raw.get_field_str(35), Some("0"));
+    }
+
+    #[test]
+    fn test_owned_message_as_raw_without_framing() {
+        let buffer = Bytes::from_static(b"35=0\x01");
+        let owned = OwnedMessage::new(buffer, MsgType::Heartbeat, vec![(35, 3..4)]);
+
+        let raw = owned.as_raw();
+        assert_eq!(raw.begin_string(), "");
+        assert_eq!(raw.body_range(), &(0..5));
+        assert_eq!(raw.get_field_str(35), Some("0"));
+    }
+
+    #[test]
+    fn test_raw_message_index_matches_linear_lookup() {
+        let mut fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::new();
+        fields.push(FieldRef::new(35, b"D"));
+        fields.push(FieldRef::new(55, b"IBM"));
+        fields.push(FieldRef::new(5001, b"CUSTOM"));
+        fields.push(FieldRef::new(448, b"PARTY1"));
+        fields.push(FieldRef::new(448, b"PARTY2"));
+        fields.push(FieldRef::new(9000, b"FIRST"));
+        fields.push(FieldRef::new(9000, b"SECOND"));
+        let raw = RawMessage::new(b"", 0..0, 0..0, MsgType::NewOrderSingle, fields);
+        let index = raw.index();
+
+        for tag in [0, 1, 35, 54, 55, 448, 1023, 1024, 5001, 9000, u32::MAX] {
+            // The index resolves to the very same field as the linear scan.
+            assert_eq!(
+                index.get(tag).map(core::ptr::from_ref),
+                raw.get_field(tag).map(core::ptr::from_ref),
+                "tag {tag}"
+            );
+            assert_eq!(index.contains(tag), raw.get_field(tag).is_some());
+        }
+        assert_eq!(index.get_str(448), Some("PARTY1"));
+        assert_eq!(index.get_str(9000), Some("FIRST"));
+        assert_eq!(index.message().field_count(), 7);
+
+        let empty = RawMessage::new(b"", 0..0, 0..0, MsgType::Heartbeat, SmallVec::new());
+        assert!(empty.index().get(35).is_none());
+    }
+
+    #[test]
+    fn test_owned_message_from_raw_with_detached_fields() {
+        let mut fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::new();
+        fields.push(FieldRef::new(35, b"0"));
+        fields.push(FieldRef::new(112, b"TEST"));
+        let raw = RawMessage::new(b"", 0..0, 0..0, MsgType::Heartbeat, fields);
+
+        let owned = OwnedMessage::from_raw(&raw);
+        assert!(owned.needs_reframe());
+        assert_eq!(owned.as_bytes(), b"35=0\x01112=TEST\x01");
+        assert_eq!(owned.get_field_str(112), Some("TEST"));
+    }
+
+    #[test]
+    fn test_owned_message_from_raw_in_arena() {
+        let first = MessageBuilder::new("FIX.4.4", MsgType::Heartbeat)
+            .field(112, "ONE")
+            .build();
+        let second = MessageBuilder::new("FIX.4.4", MsgType::TestRequest)
+            .field(112, "TWO")
+            .build();
+        let mut arena = MessageArena::with_capacity(256);
+
+        let a = OwnedMessage::from_raw_in(&first.as_raw(), &mut arena);
+        let b = OwnedMessage::from_raw_in(&second.as_raw(), &mut arena);
+        assert_eq!(a.as_bytes(), first.as_bytes());
+        assert_eq!(b.as_bytes(), second.as_bytes());
+        assert_eq!(a.get_field_str(112), Some("ONE"));
+        assert_eq!(b.msg_type(), &MsgType::TestRequest);
+        assert_eq!(
+            b.fields().collect::<Vec<_>>(),
+            OwnedMessage::from_raw(&second.as_raw())
+                .fields()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_owned_message_remove_field() {
+        let buffer = Bytes::from_static(b"35=D\x0111=A\x0158=x\x0111=B\x01");
+        let field_offsets = vec![(35, 3..4), (11, 8..9), (58, 13..14), (11, 18..19)];
+        let mut msg = OwnedMessage::new(buffer, MsgType::NewOrderSingle, field_offsets);
+
+        assert!(!msg.remove_field(1));
+        assert!(!msg.needs_reframe());
+        assert!(msg.remove_field(11));
+        assert!(msg.needs_reframe());
+        assert_eq!(msg.as_bytes(), b"35=D\x0158=x\x01");
+        assert_eq!(msg.get_field_str(58), Some("x"));
+        assert_eq!(msg.field_count(), 2);
+    }
+
+    #[test]
+    fn test_owned_message_reframe_requires_fields() {
+        let mut msg = OwnedMessage::new(Bytes::from_static(b"raw"), MsgType::Heartbeat, vec![]);
+        assert_eq!(
+            msg.reframe(),
+            Err(EncodeError::MissingRequiredField { tag: 8 })
+        );
+        assert_eq!(
+            msg.recompute_checksum(),
+            Err(EncodeError::MissingRequiredField { tag: 10 })
+        );
+
+        let mut msg = OwnedMessage::new(
+            Bytes::from_static(b"000\x01"),
+            MsgType::Heartbeat,
+            vec![(10, 0..3)],
+        );
+        assert!(matches!(
+            msg.recompute_checksum(),
+            Err(EncodeError::InvalidFieldValue { tag: 10, .. })
+        ));
+        assert_eq!(msg.as_raw().body_range(), &(0..4));
+    }
+
+    #[test]
+    fn test_owned_message_verify() {
+        let msg = MessageBuilder::new("FIX.4.4", MsgType::NewOrderSingle)
+            .field(11, "ORD-1")
+            .field(55, "AAPL")
+            .build();
+        assert_eq!(msg.verify(), Ok(()));
+
+        let mut bytes = msg.as_bytes().to_vec();
+        let symbol = msg
+            .field_offsets
+            .iter()
+            .find(|(tag, _)| *tag == 55)
+            .unwrap()
+            .1
+            .start;
+        bytes[symbol] ^= 0x01;
+        let corrupt = OwnedMessage::new(
+            Bytes::from(bytes),
+            MsgType::NewOrderSingle,
+            msg.field_offsets.to_vec(),
+        );
+        assert!(matches!(
+            corrupt.verify(),
+            Err(DecodeError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_owned_message_verify_framing() {
+        let msg = MessageBuilder::new("FIX.4.4", MsgType::Heartbeat).build();
+        let with_bytes = |bytes: &[u8]| {
+            OwnedMessage::new(Bytes::copy_from_slice(bytes), MsgType::Heartbeat, vec![])
+        };
+
+        let mut extended = msg.as_bytes().to_vec();
+        extended.extend_from_slice(b"x");
+        assert!(with_bytes(&extended).verify().is_err());
+
+        let truncated = &msg.as_bytes()[..msg.len() - 1];
+        assert_eq!(with_bytes(truncated).verify(), Err(DecodeError::Incomplete));
+
+        let mut unframed = msg.clone();
+        unframed.set_field(112, b"TEST");
+        assert!(matches!(
+            unframed.verify(),
+            Err(DecodeError::IncorrectBodyLength { .. })
+        ));
+        unframed.reframe().unwrap();
+        assert_eq!(unframed.verify(), Ok(()));
+
+        assert!(with_bytes(b"35=0\x01").verify().is_err());
+    }
+
+    #[test]
+    fn test_tag_len() {
+        assert_eq!(tag_len(0), 1);
+        assert_eq!(tag_len(9), 1);
+        assert_eq!(tag_len(10), 2);
+        assert_eq!(tag_len(1128), 4);
+    }
 }
@@ -0,0 +1,439 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Fixed-point price and quantity types.
+//!
+//! This module provides integer-backed alternatives to [`Decimal`] for hot
+//! paths where only addition, subtraction and comparison are needed:
+//! - [`ScaledPrice`]: Price stored as an `i64` mantissa with a const scale
+//! - [`ScaledQty`]: Quantity stored as an `i64` mantissa with a const scale
+//!
+//! A value of `mantissa` at scale `SCALE` represents
+//! `mantissa / 10^SCALE`, so `ScaledPrice::<4>` holds `125.25` as
+//! `1_252_500`. Parsing is exact: input with more decimal places than
+//! `SCALE` is rejected unless the extra digits are zeros.
+
+use crate::error::DecodeError;
+use crate::field::FieldRef;
+use arrayvec::ArrayVec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use rust_decimal::Decimal;
+
+/// Largest supported scale; `10^18` is the largest power of ten in an `i64`.
+pub const MAX_SCALE: u32 = 18;
+
+/// Maximum length of a formatted scaled value: sign, 19 digits, a decimal
+/// point and a leading zero.
+pub const MAX_FIX_LEN: usize = 22;
+
+/// Defines a fixed-point type stored as an `i64` mantissa scaled by
+/// `10^SCALE`, parsed and reported against the given FIX field.
+macro_rules! scaled_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $tag:literal, $field:literal
+    ) => {
+        $(#[$meta])*
+        ///
+        /// `SCALE` must not exceed [`MAX_SCALE`]; constructing a value at a
+        /// larger scale fails to compile.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name<const SCALE: u32>(i64);
+
+        impl<const SCALE: u32> $name<SCALE> {
+            /// The value zero.
+            pub const ZERO: Self = Self::from_mantissa(0);
+
+            /// `10^SCALE`, the mantissa of the value one.
+            pub const FACTOR: i64 = factor(SCALE);
+
+            /// Creates a value from its raw mantissa.
+            ///
+            /// # Arguments
+            /// * `mantissa` - The value multiplied by `10^SCALE`
+            #[inline]
+            #[must_use]
+            pub const fn from_mantissa(mantissa: i64) -> Self {
+                const { assert!(SCALE <= MAX_SCALE, "scale exceeds MAX_SCALE") };
+                Self(mantissa)
+            }
+
+            /// Returns the raw mantissa (the value multiplied by `10^SCALE`).
+            #[inline]
+            #[must_use]
+            pub const fn mantissa(self) -> i64 {
+                self.0
+            }
+
+            /// Parses a value from FIX decimal bytes such as `b"125.25"`.
+            ///
+            /// # Arguments
+            /// * `bytes` - The raw field value
+            ///
+            /// # Errors
+            #[doc = concat!(
+                "Returns `DecodeError::InvalidFieldValue` (reported against ",
+                $field,
+                ",\ntag ",
+                stringify!($tag),
+                ") if the value is not a decimal number, has more than `SCALE`\n",
+                "significant decimal places, or does not fit in an `i64` mantissa."
+            )]
+            pub fn from_fix_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+                FieldRef::new($tag, bytes)
+                    .as_scaled_i64::<SCALE>()
+                    .map(Self::from_mantissa)
+            }
+
+            /// Formats the value in FIX decimal form, without trailing zeros.
+            #[must_use]
+            pub fn to_fix_bytes(self) -> ArrayVec<u8, MAX_FIX_LEN> {
+                format_scaled(self.0, SCALE)
+            }
+
+            /// Converts the value to a [`Decimal`].
+            #[must_use]
+            pub fn to_decimal(self) -> Decimal {
+                Decimal::new(self.0, SCALE)
+            }
+
+            /// Converts a [`Decimal`] to this scale.
+            ///
+            /// # Arguments
+            /// * `value` - The decimal to convert
+            ///
+            /// # Returns
+            /// `None` if the value has more than `SCALE` significant decimal
+            /// places or does not fit in an `i64` mantissa.
+            #[must_use]
+            pub fn from_decimal(value: Decimal) -> Option<Self> {
+                rescale(value, SCALE).map(Self::from_mantissa)
+            }
+
+            /// Adds two values, returning `None` on overflow.
+            #[inline]
+            #[must_use]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_add(rhs.0) {
+                    Some(mantissa) => Some(Self(mantissa)),
+                    None => None,
+                }
+            }
+
+            /// Subtracts two values, returning `None` on overflow.
+            #[inline]
+            #[must_use]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_sub(rhs.0) {
+                    Some(mantissa) => Some(Self(mantissa)),
+                    None => None,
+                }
+            }
+
+            /// Multiplies the value by an integer, returning `None` on overflow.
+            ///
+            /// # Arguments
+            /// * `rhs` - The integer factor
+            #[inline]
+            #[must_use]
+            pub const fn checked_mul_int(self, rhs: i64) -> Option<Self> {
+                match self.0.checked_mul(rhs) {
+                    Some(mantissa) => Some(Self(mantissa)),
+                    None => None,
+                }
+            }
+        }
+
+        impl<const SCALE: u32> Default for $name<SCALE> {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const SCALE: u32> Add for $name<SCALE> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl<const SCALE: u32> AddAssign for $name<SCALE> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<const SCALE: u32> Sub for $name<SCALE> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl<const SCALE: u32> SubAssign for $name<SCALE> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<const SCALE: u32> Neg for $name<SCALE> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl<const SCALE: u32> fmt::Display for $name<SCALE> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let bytes = self.to_fix_bytes();
+                f.write_str(core::str::from_utf8(&bytes).map_err(|_| fmt::Error)?)
+            }
+        }
+
+        impl<const SCALE: u32> FromStr for $name<SCALE> {
+            type Err = DecodeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_fix_bytes(s.as_bytes())
+            }
+        }
+
+        impl<const SCALE: u32> From<$name<SCALE>> for Decimal {
+            fn from(value: $name<SCALE>) -> Self {
+                value.to_decimal()
+            }
+        }
+    };
+}
+
+scaled_type! {
+    /// Price stored as an `i64` mantissa scaled by `10^SCALE`.
+    ///
+    /// Parsed from and formatted to the FIX decimal wire form without going
+    /// through [`Decimal`]. Use it for Price (tag 44), LastPx (tag 31) and
+    /// similar fields.
+    ScaledPrice, 44, "Price"
+}
+
+scaled_type! {
+    /// Quantity stored as an `i64` mantissa scaled by `10^SCALE`.
+    ///
+    /// Parsed from and formatted to the FIX decimal wire form without going
+    /// through [`Decimal`]. Use it for OrderQty (tag 38), LastQty (tag 32)
+    /// and similar fields.
+    ScaledQty, 38, "OrderQty"
+}
+
+/// Returns `10^scale`, failing const evaluation if `scale > MAX_SCALE`.
+const fn factor(scale: u32) -> i64 {
+    assert!(scale <= MAX_SCALE, "scale exceeds MAX_SCALE");
+    10i64.pow(scale)
+}
+
+/// Parses FIX decimal bytes into a mantissa at the given scale.
+///
+/// # Returns
+/// The mantissa, or a description of why the input is invalid.
+pub(crate) fn parse_scaled(bytes: &[u8], scale: u32) -> Result<i64, &'static str> {
+    let (negative, digits) = match bytes {
+        [b'-', rest @ ..] => (true, rest),
+        _ => (false, bytes),
+    };
+    let (int_part, frac_part) = match digits.iter().position(|&b| b == b'.') {
+        Some(dot) => (&digits[..dot], &digits[dot + 1..]),
+        None => (digits, &digits[..0]),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err("expected a decimal number");
+    }
+
+    let mut magnitude: u64 = 0;
+    for &b in int_part {
+        magnitude = push_digit(magnitude, b)?;
+    }
+    for i in 0..scale as usize {
+        magnitude = push_digit(magnitude, frac_part.get(i).copied().unwrap_or(b'0'))?;
+    }
+    let extra = frac_part.get(scale as usize..).unwrap_or_default();
+    if let Some(&b) = extra.iter().find(|&&b| b != b'0') {
+        return Err(if b.is_ascii_digit() {
+            "too many decimal places for scale"
+        } else {
+            "invalid digit"
+        });
+    }
+
+    if negative {
+        0i64.checked_sub_unsigned(magnitude)
+            .ok_or("value out of range")
+    } else {
+        i64::try_from(magnitude).map_err(|_| "value out of range")
+    }
+}
+
+/// Appends one decimal digit to an accumulated magnitude.
+fn push_digit(magnitude: u64, b: u8) -> Result<u64, &'static str> {
+    if !b.is_ascii_digit() {
+        return Err("invalid digit");
+    }
+    magnitude
+        .checked_mul(10)
+        .and_then(|m| m.checked_add(u64::from(b - b'0')))
+        .ok_or("value out of range")
+}
+
+/// Formats a mantissa at the given scale, trimming trailing zeros.
+fn format_scaled(mantissa: i64, scale: u32) -> ArrayVec<u8, MAX_FIX_LEN> {
+    let mut digits = ArrayVec::<u8, 20>::new();
+    let mut magnitude = mantissa.unsigned_abs();
+    loop {
+        digits.push(b'0' + (magnitude % 10) as u8);
+        magnitude /= 10;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    let scale = scale as usize;
+    while digits.len() <= scale {
+        digits.push(b'0');
+    }
+    digits.reverse();
+
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    let frac_len = frac_part
+        .iter()
+        .rposition(|&b| b != b'0')
+        .map_or(0, |i| i + 1);
+
+    let mut out = ArrayVec::new();
+    if mantissa < 0 {
+        out.push(b'-');
+    }
+    out.extend(int_part.iter().copied());
+    if frac_len > 0 {
+        out.push(b'.');
+        out.extend(frac_part[..frac_len].iter().copied());
+    }
+    out
+}
+
+/// Converts a decimal to a mantissa at the given scale, if exact.
+fn rescale(value: Decimal, scale: u32) -> Option<i64> {
+    let value = value.normalize();
+    let shift = scale.checked_sub(value.scale())?;
+    let mantissa = value.mantissa().checked_mul(10i128.checked_pow(shift)?)?;
+    i64::try_from(mantissa).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaled_price_round_trip() {
+        let price = ScaledPrice::<4>::from_fix_bytes(b"125.25").unwrap();
+        assert_eq!(price.mantissa(), 1_252_500);
+        assert_eq!(price.to_fix_bytes().as_slice(), b"125.25");
+        assert_eq!(price.to_string(), "125.25");
+    }
+
+    #[test]
+    fn test_scaled_parse_forms() {
+        let parse = |s: &str| s.parse::<ScaledPrice<4>>().map(ScaledPrice::mantissa);
+        assert_eq!(parse("100"), Ok(1_000_000));
+        assert_eq!(parse("100."), Ok(1_000_000));
+        assert_eq!(parse(".5"), Ok(5_000));
+        assert_eq!(parse("-0.0001"), Ok(-1));
+        assert_eq!(parse("1.25000000"), Ok(12_500));
+        assert!(parse("").is_err());
+        assert!(parse("-").is_err());
+        assert!(parse(".").is_err());
+        assert!(parse("1.23456").is_err());
+        assert!(parse("1.2a").is_err());
+        assert!(parse("1.0000x").is_err());
+        assert!(parse("+1").is_err());
+        assert!(parse("1e3").is_err());
+        assert!(parse("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_scaled_extremes() {
+        let max = ScaledQty::<0>::from_mantissa(i64::MAX);
+        let min = ScaledQty::<0>::from_mantissa(i64::MIN);
+        assert_eq!(max.to_string().parse::<ScaledQty<0>>(), Ok(max));
+        assert_eq!(min.to_string().parse::<ScaledQty<0>>(), Ok(min));
+
+        let tiny = ScaledPrice::<18>::from_mantissa(i64::MIN);
+        assert_eq!(tiny.to_string(), "-9.223372036854775808");
+        assert_eq!(tiny.to_string().parse::<ScaledPrice<18>>(), Ok(tiny));
+        assert_eq!(
+            ScaledPrice::<18>::from_mantissa(1).to_string(),
+            "0.000000000000000001"
+        );
+    }
+
+    #[test]
+    fn test_scaled_arithmetic() {
+        let a: ScaledQty<2> = "10.50".parse().unwrap();
+        let b: ScaledQty<2> = "0.25".parse().unwrap();
+        assert_eq!((a + b).to_string(), "10.75");
+        assert_eq!((b - a).to_string(), "-10.25");
+        assert_eq!((-a).mantissa(), -1050);
+        assert_eq!(a.checked_mul_int(3).unwrap().to_string(), "31.5");
+        assert!(
+            ScaledQty::<2>::from_mantissa(i64::MAX)
+                .checked_add(b)
+                .is_none()
+        );
+
+        let mut total = ScaledQty::<2>::ZERO;
+        total += a;
+        total -= b;
+        assert_eq!(total.mantissa(), 1025);
+        assert_eq!(ScaledQty::<2>::FACTOR, 100);
+    }
+
+    #[test]
+    fn test_scaled_decimal_conversion() {
+        let price: ScaledPrice<4> = "125.25".parse().unwrap();
+        let decimal = Decimal::new(12525, 2);
+        assert_eq!(price.to_decimal(), decimal);
+        assert_eq!(Decimal::from(price), decimal);
+        assert_eq!(ScaledPrice::<4>::from_decimal(decimal), Some(price));
+        assert_eq!(
+            ScaledPrice::<4>::from_decimal(Decimal::new(1_250_000, 4)),
+            Some(ScaledPrice::from_mantissa(1_250_000))
+        );
+        assert_eq!(ScaledPrice::<4>::from_decimal(Decimal::new(1, 5)), None);
+        assert_eq!(ScaledPrice::<4>::from_decimal(Decimal::MAX), None);
+    }
+
+    #[test]
+    fn test_field_ref_as_scaled() {
+        let field = FieldRef::new(44, b"101.5");
+        assert_eq!(field.as_scaled_i64::<2>().unwrap(), 10_150);
+        assert_eq!(field.as_price::<3>().unwrap().mantissa(), 101_500);
+        assert_eq!(
+            FieldRef::new(38, b"200").as_qty::<0>().unwrap().mantissa(),
+            200
+        );
+        assert!(matches!(
+            FieldRef::new(31, b"1.005").as_price::<2>(),
+            Err(DecodeError::InvalidFieldValue { tag: 31, .. })
+        ));
+    }
+}
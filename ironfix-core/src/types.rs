@@ -9,15 +9,24 @@
 //! This module provides fundamental types used throughout the IronFix engine:
 //! - [`SeqNum`]: Sequence number wrapper with atomic operations
 //! - [`Timestamp`]: FIX-formatted timestamp with nanosecond precision
+//! - [`FixTime`]: UTC time of day for time-only fields
 //! - [`CompId`]: Component identifier (SenderCompID, TargetCompID)
+//! - [`Currency`], [`Country`], [`Exchange`]: Validated ISO code values
 //! - [`Side`]: Order side enumeration
+//! - [`HandlInst`]: Order handling instruction enumeration
+//! - [`ExecInst`]: Multi-value execution instructions
 
+use crate::error::{CompIdTooLong, DecodeError};
+use crate::field::FieldRef;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use arrayvec::ArrayString;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use core::fmt;
+use core::str::FromStr;
 use num_derive::{FromPrimitive, ToPrimitive};
-use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 /// Maximum length for CompID strings in bytes.
 pub const COMP_ID_MAX_LEN: usize = 32;
@@ -123,7 +132,35 @@ impl Timestamp {
         }
     }
 
+    /// Parses a date-only FIX value (`YYYYMMDD`) as midnight UTC of that date.
+    ///
+    /// Used for UtcDateOnly and LocalMktDate fields such as TradeDate (75) and
+    /// TradeOriginationDate (432).
+    ///
+    /// # Arguments
+    /// * `s` - The date string
+    ///
+    /// # Returns
+    /// `None` if the value is not eight digits forming a valid calendar date
+    /// on or after 1970-01-01.
+    #[must_use]
+    pub fn from_fix_date(s: &str) -> Option<Self> {
+        if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let year = s[..4].parse().ok()?;
+        let month = s[4..6].parse().ok()?;
+        let day = s[6..].parse().ok()?;
+        let midnight = NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(0, 0, 0)?;
+        let nanos = midnight.and_utc().timestamp_nanos_opt()?;
+        u64::try_from(nanos).ok().map(Self::from_nanos)
+    }
+
     /// Returns the current UTC timestamp.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
     #[inline]
     #[must_use]
     pub fn now() -> Self {
@@ -167,13 +204,24 @@ impl Timestamp {
     pub fn format_millis(self) -> ArrayString<21> {
         let dt = self.to_datetime();
         let mut buf = ArrayString::new();
-        let _ = std::fmt::write(
+        let _ = core::fmt::write(
             &mut buf,
             format_args!("{}", dt.format("%Y%m%d-%H:%M:%S%.3f")),
         );
         buf
     }
 
+    /// Formats the UTC date of the timestamp in FIX date-only format.
+    ///
+    /// Format: `YYYYMMDD`
+    #[must_use]
+    pub fn format_date(self) -> ArrayString<8> {
+        let dt = self.to_datetime();
+        let mut buf = ArrayString::new();
+        let _ = core::fmt::write(&mut buf, format_args!("{}", dt.format("%Y%m%d")));
+        buf
+    }
+
     /// Formats the timestamp in FIX format with microsecond precision.
     ///
     /// Format: `YYYYMMDD-HH:MM:SS.ssssss`
@@ -181,7 +229,7 @@ impl Timestamp {
     pub fn format_micros(self) -> ArrayString<24> {
         let dt = self.to_datetime();
         let mut buf = ArrayString::new();
-        let _ = std::fmt::write(
+        let _ = core::fmt::write(
             &mut buf,
             format_args!("{}", dt.format("%Y%m%d-%H:%M:%S%.6f")),
         );
@@ -189,6 +237,7 @@ impl Timestamp {
     }
 }
 
+#[cfg(feature = "std")]
 impl Default for Timestamp {
     fn default() -> Self {
         Self::now()
@@ -209,10 +258,171 @@ impl fmt::Display for Timestamp {
     }
 }
 
+/// UTC time of day with nanosecond precision.
+///
+/// Represents UtcTimeOnly fields formatted as `HH:MM:SS` with an optional
+/// fraction of up to nine digits (e.g. `HH:MM:SS.sss`). The leap second
+/// `23:59:60` is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FixTime {
+    /// Nanoseconds since midnight.
+    nanos_since_midnight: u64,
+}
+
+impl FixTime {
+    /// Creates a time of day from hours, minutes, seconds and milliseconds.
+    ///
+    /// # Arguments
+    /// * `hour` - Hour (0-23)
+    /// * `minute` - Minute (0-59)
+    /// * `second` - Second (0-59, or 60 at 23:59)
+    /// * `milli` - Millisecond (0-999)
+    ///
+    /// # Returns
+    /// `None` if any component is out of range.
+    #[must_use]
+    pub const fn from_hms_milli(hour: u32, minute: u32, second: u32, milli: u32) -> Option<Self> {
+        if milli > 999 {
+            return None;
+        }
+        Self::from_hms_nano(hour, minute, second, milli * 1_000_000)
+    }
+
+    /// Creates a time of day from hours, minutes, seconds and nanoseconds.
+    const fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
+        let leap = second == 60 && hour == 23 && minute == 59;
+        if hour > 23 || minute > 59 || (second > 59 && !leap) || nano > 999_999_999 {
+            return None;
+        }
+        let secs = (hour * 3600 + minute * 60 + second) as u64;
+        Some(Self {
+            nanos_since_midnight: secs * 1_000_000_000 + nano as u64,
+        })
+    }
+
+    /// Returns nanoseconds since midnight.
+    #[inline]
+    #[must_use]
+    pub const fn as_nanos(self) -> u64 {
+        self.nanos_since_midnight
+    }
+
+    /// Returns the hour (0-23).
+    #[inline]
+    #[must_use]
+    pub const fn hour(self) -> u32 {
+        if self.is_leap_second() {
+            return 23;
+        }
+        (self.nanos_since_midnight / 3_600_000_000_000) as u32
+    }
+
+    /// Returns the minute (0-59).
+    #[inline]
+    #[must_use]
+    pub const fn minute(self) -> u32 {
+        if self.is_leap_second() {
+            return 59;
+        }
+        (self.nanos_since_midnight / 60_000_000_000 % 60) as u32
+    }
+
+    /// Returns the second (0-59, or 60 during a leap second).
+    #[inline]
+    #[must_use]
+    pub const fn second(self) -> u32 {
+        if self.is_leap_second() {
+            return 60;
+        }
+        (self.nanos_since_midnight / 1_000_000_000 % 60) as u32
+    }
+
+    /// Returns true if this time falls within the leap second `23:59:60`.
+    #[inline]
+    #[must_use]
+    pub const fn is_leap_second(self) -> bool {
+        self.nanos_since_midnight >= 86_400_000_000_000
+    }
+
+    /// Returns the millisecond within the second (0-999).
+    #[inline]
+    #[must_use]
+    pub const fn millisecond(self) -> u32 {
+        (self.nanos_since_midnight % 1_000_000_000 / 1_000_000) as u32
+    }
+
+    /// Formats the time with millisecond precision.
+    ///
+    /// Format: `HH:MM:SS.sss`
+    #[must_use]
+    pub fn format_millis(self) -> ArrayString<12> {
+        let mut buf = ArrayString::new();
+        let _ = core::fmt::write(
+            &mut buf,
+            format_args!(
+                "{:02}:{:02}:{:02}.{:03}",
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.millisecond()
+            ),
+        );
+        buf
+    }
+}
+
+impl FromStr for FixTime {
+    type Err = DecodeError;
+
+    /// Parses `HH:MM:SS` with an optional fraction of 1 to 9 digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DecodeError::InvalidTime(s.to_string());
+        let bytes = s.as_bytes();
+        if bytes.len() < 8 || bytes[2] != b':' || bytes[5] != b':' {
+            return Err(invalid());
+        }
+
+        let two_digits = |pos: usize| -> Option<u32> {
+            let (hi, lo) = (bytes[pos], bytes[pos + 1]);
+            (hi.is_ascii_digit() && lo.is_ascii_digit())
+                .then(|| u32::from(hi - b'0') * 10 + u32::from(lo - b'0'))
+        };
+        let hour = two_digits(0).ok_or_else(invalid)?;
+        let minute = two_digits(3).ok_or_else(invalid)?;
+        let second = two_digits(6).ok_or_else(invalid)?;
+
+        let nano = match &bytes[8..] {
+            [] => 0,
+            [b'.', fraction @ ..]
+                if (1..=9).contains(&fraction.len()) && fraction.iter().all(u8::is_ascii_digit) =>
+            {
+                let digits = fraction
+                    .iter()
+                    .fold(0, |acc, &b| acc * 10 + u32::from(b - b'0'));
+                digits * 10u32.pow(9 - fraction.len() as u32)
+            }
+            _ => return Err(invalid()),
+        };
+
+        Self::from_hms_nano(hour, minute, second, nano).ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for FixTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format_millis())
+    }
+}
+
 /// Component identifier for FIX sessions.
 ///
 /// Used for SenderCompID (tag 49), TargetCompID (tag 56), and related fields.
 /// Maximum length is 32 characters as per FIX specification.
+///
+/// Over-length input is rejected by [`new`](Self::new), which returns `None`,
+/// and by [`FromStr`], whose [`CompIdTooLong`] error reports the length.
+/// Lenient callers can use [`new_truncated`](Self::new_truncated) instead,
+/// which keeps the first [`COMP_ID_MAX_LEN`] bytes.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[repr(transparent)]
 #[serde(transparent)]
@@ -231,6 +441,24 @@ impl CompId {
         ArrayString::from(s).ok().map(Self)
     }
 
+    /// Creates a CompId from at most the first [`COMP_ID_MAX_LEN`] bytes of
+    /// a string slice.
+    ///
+    /// Longer input is cut at the last character boundary within the limit,
+    /// so a multi-byte character is never split.
+    ///
+    /// # Arguments
+    /// * `s` - The component identifier string
+    #[must_use]
+    pub fn new_truncated(s: &str) -> Self {
+        let mut end = s.len().min(COMP_ID_MAX_LEN);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        // The prefix is at most COMP_ID_MAX_LEN bytes, so it always fits.
+        Self(ArrayString::from(&s[..end]).expect("prefix fits in CompId"))
+    }
+
     /// Returns the CompId as a string slice.
     #[inline]
     #[must_use]
@@ -266,21 +494,239 @@ impl fmt::Display for CompId {
 }
 
 impl FromStr for CompId {
-    type Err = arrayvec::CapacityError<()>;
+    type Err = CompIdTooLong;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(CompIdTooLong { length: s.len() })
+    }
+}
+
+/// ISO 4217 currency code.
+///
+/// Used for Currency (tag 15) and related fields. The value is validated on
+/// construction: exactly three uppercase ASCII letters.
+///
+/// Serializes as its code (e.g. `"USD"`) and is validated again on
+/// deserialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Currency(ArrayString<3>);
+
+impl Currency {
+    /// Creates a Currency from an ISO 4217 code.
+    ///
+    /// # Arguments
+    /// * `s` - The code, e.g. `"USD"`
+    ///
+    /// # Returns
+    /// `Some(Currency)` if the code is well formed, `None` otherwise.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        iso_code(s, |b| b.is_ascii_uppercase()).map(Self)
+    }
+
+    /// Returns the code as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for Currency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or_else(|| DecodeError::InvalidFieldValue {
+            tag: 15,
+            reason: format!("invalid currency '{}'", s),
+        })
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// ISO 3166-1 alpha-2 country code.
+///
+/// Used for Country (tag 421) and related fields. The value is validated on
+/// construction: exactly two uppercase ASCII letters.
+///
+/// Serializes as its code (e.g. `"US"`) and is validated again on
+/// deserialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Country(ArrayString<2>);
+
+impl Country {
+    /// Creates a Country from an ISO 3166-1 alpha-2 code.
+    ///
+    /// # Arguments
+    /// * `s` - The code, e.g. `"US"`
+    ///
+    /// # Returns
+    /// `Some(Country)` if the code is well formed, `None` otherwise.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        iso_code(s, |b| b.is_ascii_uppercase()).map(Self)
+    }
+
+    /// Returns the code as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for Country {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Country {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or_else(|| DecodeError::InvalidFieldValue {
+            tag: 421,
+            reason: format!("invalid country '{}'", s),
+        })
+    }
+}
+
+impl Serialize for Country {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// ISO 10383 market identifier code (MIC).
+///
+/// Used for SecurityExchange (tag 207) and related fields. The value is validated on
+/// construction: exactly four uppercase ASCII letters or digits.
+///
+/// Serializes as its code (e.g. `"XNYS"`) and is validated again on
+/// deserialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Exchange(ArrayString<4>);
+
+impl Exchange {
+    /// Creates an Exchange from an ISO 10383 market identifier code.
+    ///
+    /// # Arguments
+    /// * `s` - The code, e.g. `"XNYS"`
+    ///
+    /// # Returns
+    /// `Some(Exchange)` if the code is well formed, `None` otherwise.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        iso_code(s, |b| b.is_ascii_uppercase() || b.is_ascii_digit()).map(Self)
+    }
+
+    /// Returns the code as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for Exchange {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Exchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Exchange {
+    type Err = DecodeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        ArrayString::try_from(s)
-            .map(Self)
-            .map_err(|_| arrayvec::CapacityError::new(()))
+        Self::new(s).ok_or_else(|| DecodeError::InvalidFieldValue {
+            tag: 207,
+            reason: format!("invalid exchange '{}'", s),
+        })
+    }
+}
+
+impl Serialize for Exchange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Exchange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Validates a fixed-length ISO code.
+///
+/// # Arguments
+/// * `s` - The candidate code
+/// * `valid` - Predicate every byte must satisfy
+///
+/// # Returns
+/// The code if it is exactly `N` bytes long and every byte is valid.
+fn iso_code<const N: usize>(s: &str, valid: fn(u8) -> bool) -> Option<ArrayString<N>> {
+    if s.len() == N && s.bytes().all(valid) {
+        ArrayString::from(s).ok()
+    } else {
+        None
     }
 }
 
 /// Order side enumeration (tag 54).
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, FromPrimitive, ToPrimitive,
-)]
+///
+/// Serializes as its single-character FIX code (e.g. `"1"` for [`Side::Buy`]),
+/// so serialized values can be used directly on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive)]
 #[repr(u8)]
-#[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     /// Buy order.
     Buy = b'1',
@@ -375,6 +821,37 @@ impl fmt::Display for Side {
     }
 }
 
+impl FromStr for Side {
+    type Err = DecodeError;
+
+    /// Parses a side from its single-character FIX code.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| DecodeError::InvalidFieldValue {
+            tag: 54,
+            reason: format!("invalid side '{}'", s),
+        })
+    }
+}
+
+impl Serialize for Side {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.as_char())
+    }
+}
+
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let c = char::deserialize(deserializer)?;
+        Self::from_char(c)
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Char(c), &"a FIX side code"))
+    }
+}
+
 impl TryFrom<u8> for Side {
     type Error = ();
 
@@ -383,6 +860,213 @@ impl TryFrom<u8> for Side {
     }
 }
 
+/// Order handling instruction (tag 21).
+///
+/// Serializes as its single-character FIX code (e.g. `"1"` for
+/// [`HandlInst::AutomatedPrivate`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive)]
+#[repr(u8)]
+pub enum HandlInst {
+    /// Automated execution order, private, no broker intervention.
+    AutomatedPrivate = b'1',
+    /// Automated execution order, public, broker intervention OK.
+    AutomatedPublic = b'2',
+    /// Manual order, best execution.
+    Manual = b'3',
+}
+
+impl HandlInst {
+    /// Creates a HandlInst from a single character.
+    ///
+    /// # Arguments
+    /// * `c` - The character representing the handling instruction
+    ///
+    /// # Returns
+    /// `Some(HandlInst)` if the character is valid, `None` otherwise.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '1' => Some(Self::AutomatedPrivate),
+            '2' => Some(Self::AutomatedPublic),
+            '3' => Some(Self::Manual),
+            _ => None,
+        }
+    }
+
+    /// Returns the character representation of this handling instruction.
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        self as u8 as char
+    }
+}
+
+impl fmt::Display for HandlInst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl FromStr for HandlInst {
+    type Err = DecodeError;
+
+    /// Parses a handling instruction from its single-character FIX code.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| DecodeError::InvalidFieldValue {
+            tag: 21,
+            reason: format!("invalid handl inst '{}'", s),
+        })
+    }
+}
+
+impl Serialize for HandlInst {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.as_char())
+    }
+}
+
+impl<'de> Deserialize<'de> for HandlInst {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let c = char::deserialize(deserializer)?;
+        Self::from_char(c).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Char(c), &"a FIX HandlInst code")
+        })
+    }
+}
+
+impl TryFrom<u8> for HandlInst {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_char(value as char).ok_or(())
+    }
+}
+
+/// Execution instructions (tag 18).
+///
+/// ExecInst is a multi-value char field: the wire value is a space-separated
+/// list of single-character codes such as `"G 1"` (all-or-none, not held).
+/// The codes are kept as characters rather than an enum because the set
+/// grows with every FIX version.
+///
+/// Serializes as its wire string.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ExecInst {
+    values: Vec<char>,
+}
+
+impl ExecInst {
+    /// Creates execution instructions from a list of codes.
+    ///
+    /// # Arguments
+    /// * `values` - The instruction codes, in wire order
+    #[must_use]
+    pub fn new(values: impl IntoIterator<Item = char>) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+        }
+    }
+
+    /// Parses execution instructions from a field value.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw field value
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidFieldValue` if any entry is not a single
+    /// ASCII character.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        FieldRef::new(18, bytes)
+            .values()
+            .map(|value| match value {
+                [c] if c.is_ascii() => Ok(*c as char),
+                _ => Err(DecodeError::InvalidFieldValue {
+                    tag: 18,
+                    reason: format!("invalid exec inst '{}'", String::from_utf8_lossy(value)),
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|values| Self { values })
+    }
+
+    /// Returns the instruction codes, in wire order.
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &[char] {
+        &self.values
+    }
+
+    /// Returns true if the given instruction code is present.
+    ///
+    /// # Arguments
+    /// * `code` - The instruction code to look for
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, code: char) -> bool {
+        self.values.contains(&code)
+    }
+
+    /// Returns the number of instruction codes.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no instruction codes.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds an instruction code.
+    ///
+    /// # Arguments
+    /// * `code` - The instruction code to append
+    pub fn push(&mut self, code: char) {
+        self.values.push(code);
+    }
+}
+
+impl fmt::Display for ExecInst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, code) in self.values.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", code)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ExecInst {
+    type Err = DecodeError;
+
+    /// Parses execution instructions from their space-separated wire form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl Serialize for ExecInst {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExecInst {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,6 +1101,82 @@ mod tests {
         assert!(formatted.starts_with("19700101-00:00:00"));
     }
 
+    #[test]
+    fn test_timestamp_from_fix_date() {
+        let ts = Timestamp::from_fix_date("20240115").unwrap();
+        assert_eq!(ts.as_millis(), 1_705_276_800_000);
+        assert_eq!(ts.format_millis().as_str(), "20240115-00:00:00.000");
+        assert_eq!(ts.format_date().as_str(), "20240115");
+
+        let round_trip = Timestamp::from_fix_date(&ts.format_date()).unwrap();
+        assert_eq!(round_trip, ts);
+    }
+
+    #[test]
+    fn test_timestamp_from_fix_date_invalid() {
+        assert_eq!(Timestamp::from_fix_date("2024011"), None);
+        assert_eq!(Timestamp::from_fix_date("202401155"), None);
+        assert_eq!(Timestamp::from_fix_date("2024-115"), None);
+        assert_eq!(Timestamp::from_fix_date("+2024011"), None);
+        assert_eq!(Timestamp::from_fix_date("20240230"), None);
+        assert_eq!(Timestamp::from_fix_date("19691231"), None);
+    }
+
+    #[test]
+    fn test_timestamp_format_date_drops_time() {
+        let ts = Timestamp::from_millis(1_705_276_800_000 + 13 * 3_600_000);
+        assert_eq!(ts.format_date().as_str(), "20240115");
+    }
+
+    #[test]
+    fn test_fix_time_round_trip() {
+        let time: FixTime = "13:45:30.250".parse().unwrap();
+        assert_eq!(time.hour(), 13);
+        assert_eq!(time.minute(), 45);
+        assert_eq!(time.second(), 30);
+        assert_eq!(time.millisecond(), 250);
+        assert_eq!(time.format_millis().as_str(), "13:45:30.250");
+        assert_eq!(time, FixTime::from_hms_milli(13, 45, 30, 250).unwrap());
+        assert_eq!(time.to_string().parse::<FixTime>().unwrap(), time);
+    }
+
+    #[test]
+    fn test_fix_time_precision() {
+        let seconds: FixTime = "09:30:00".parse().unwrap();
+        assert_eq!(seconds.as_nanos(), 34_200_000_000_000);
+
+        let nanos: FixTime = "09:30:00.123456789".parse().unwrap();
+        assert_eq!(nanos.as_nanos(), 34_200_123_456_789);
+        assert_eq!(nanos.format_millis().as_str(), "09:30:00.123");
+
+        let leap: FixTime = "23:59:60.500".parse().unwrap();
+        assert!(leap.is_leap_second());
+        assert_eq!(leap.format_millis().as_str(), "23:59:60.500");
+    }
+
+    #[test]
+    fn test_fix_time_invalid() {
+        for input in [
+            "25:00:00",
+            "12:60:00",
+            "12:00:60",
+            "12:00",
+            "12-00-00",
+            "1a:00:00",
+            "12:00:00.",
+            "12:00:00.1234567890",
+            "12:00:00Z",
+        ] {
+            assert_eq!(
+                input.parse::<FixTime>(),
+                Err(DecodeError::InvalidTime(input.to_string())),
+                "{input}"
+            );
+        }
+        assert_eq!(FixTime::from_hms_milli(24, 0, 0, 0), None);
+        assert_eq!(FixTime::from_hms_milli(0, 0, 0, 1000), None);
+    }
+
     #[test]
     fn test_comp_id() {
         let id = CompId::new("SENDER").unwrap();
@@ -429,6 +1189,38 @@ mod tests {
     fn test_comp_id_too_long() {
         let long_str = "A".repeat(COMP_ID_MAX_LEN + 1);
         assert!(CompId::new(&long_str).is_none());
+        assert_eq!(
+            long_str.parse::<CompId>(),
+            Err(CompIdTooLong {
+                length: COMP_ID_MAX_LEN + 1
+            })
+        );
+        assert_eq!(
+            CompIdTooLong { length: 33 }.to_string(),
+            "comp id too long: 33 bytes exceeds max 32"
+        );
+    }
+
+    #[test]
+    fn test_comp_id_exactly_max() {
+        let max = "B".repeat(COMP_ID_MAX_LEN);
+        assert_eq!(CompId::new(&max).unwrap().len(), COMP_ID_MAX_LEN);
+        assert_eq!(max.parse::<CompId>().unwrap().as_str(), max);
+        assert_eq!(CompId::new_truncated(&max).as_str(), max);
+    }
+
+    #[test]
+    fn test_comp_id_new_truncated() {
+        assert_eq!(CompId::new_truncated("SENDER").as_str(), "SENDER");
+
+        let long_str = "C".repeat(COMP_ID_MAX_LEN + 8);
+        let id = CompId::new_truncated(&long_str);
+        assert_eq!(id.as_str(), &long_str[..COMP_ID_MAX_LEN]);
+
+        // A two-byte character straddling the limit is dropped whole.
+        let straddling = format!("{}é", "D".repeat(COMP_ID_MAX_LEN - 1));
+        let id = CompId::new_truncated(&straddling);
+        assert_eq!(id.len(), COMP_ID_MAX_LEN - 1);
     }
 
     #[test]
@@ -451,4 +1243,111 @@ mod tests {
         assert_eq!(Side::Buy.to_string(), "1");
         assert_eq!(Side::Sell.to_string(), "2");
     }
+
+    #[test]
+    fn test_side_from_str() {
+        assert_eq!("1".parse::<Side>().unwrap(), Side::Buy);
+        assert_eq!("2".parse::<Side>().unwrap(), Side::Sell);
+        assert_eq!("G".parse::<Side>().unwrap(), Side::Borrow);
+        assert!("".parse::<Side>().is_err());
+        assert!("12".parse::<Side>().is_err());
+        assert!("BUY".parse::<Side>().is_err());
+    }
+
+    #[test]
+    fn test_side_serde_uses_fix_code() {
+        assert_eq!(serde_json::to_string(&Side::Buy).unwrap(), "\"1\"");
+        assert_eq!(serde_json::to_string(&Side::SellShort).unwrap(), "\"5\"");
+
+        let side: Side = serde_json::from_str("\"2\"").unwrap();
+        assert_eq!(side, Side::Sell);
+        assert!(serde_json::from_str::<Side>("\"X\"").is_err());
+        assert!(serde_json::from_str::<Side>("\"12\"").is_err());
+
+        let de = de::IntoDeserializer::<de::value::Error>::into_deserializer('7');
+        assert_eq!(Side::deserialize(de), Ok(Side::Undisclosed));
+
+        let json = serde_json::to_string(&Side::CrossShortExempt).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Side>(&json).unwrap(),
+            Side::CrossShortExempt
+        );
+    }
+
+    #[test]
+    fn test_handl_inst_round_trip() {
+        for handl_inst in [
+            HandlInst::AutomatedPrivate,
+            HandlInst::AutomatedPublic,
+            HandlInst::Manual,
+        ] {
+            let wire = handl_inst.to_string();
+            assert_eq!(wire.parse::<HandlInst>().unwrap(), handl_inst);
+            assert_eq!(HandlInst::try_from(wire.as_bytes()[0]), Ok(handl_inst));
+
+            let json = serde_json::to_string(&handl_inst).unwrap();
+            assert_eq!(
+                serde_json::from_str::<HandlInst>(&json).unwrap(),
+                handl_inst
+            );
+        }
+        assert_eq!(HandlInst::AutomatedPrivate.as_char(), '1');
+        assert!("4".parse::<HandlInst>().is_err());
+        assert!("12".parse::<HandlInst>().is_err());
+    }
+
+    #[test]
+    fn test_exec_inst_parses_multiple_values() {
+        let exec_inst: ExecInst = "G 1".parse().unwrap();
+        assert_eq!(exec_inst.len(), 2);
+        assert_eq!(exec_inst.values(), ['G', '1']);
+        assert!(exec_inst.contains('G'));
+        assert!(!exec_inst.contains('M'));
+        assert_eq!(exec_inst.to_string(), "G 1");
+
+        assert!("".parse::<ExecInst>().unwrap().is_empty());
+        assert!("G 12".parse::<ExecInst>().is_err());
+    }
+
+    #[test]
+    fn test_exec_inst_serde_uses_wire_form() {
+        let exec_inst = ExecInst::new(['6', 'M']);
+        let json = serde_json::to_string(&exec_inst).unwrap();
+        assert_eq!(json, "\"6 M\"");
+        assert_eq!(serde_json::from_str::<ExecInst>(&json).unwrap(), exec_inst);
+    }
+
+    #[test]
+    fn test_iso_codes_accept_valid_input() {
+        assert_eq!("USD".parse::<Currency>().unwrap().as_str(), "USD");
+        assert_eq!("US".parse::<Country>().unwrap().as_str(), "US");
+        assert_eq!("XNYS".parse::<Exchange>().unwrap().as_str(), "XNYS");
+        assert_eq!(Exchange::new("XS2B").unwrap().to_string(), "XS2B");
+    }
+
+    #[test]
+    fn test_iso_codes_reject_malformed_input() {
+        for bad in ["", "US", "usd", "USDX", "U$D", "ÜSD"] {
+            assert!(bad.parse::<Currency>().is_err(), "{bad:?}");
+        }
+        for bad in ["", "U", "us", "USA", "U1"] {
+            assert!(bad.parse::<Country>().is_err(), "{bad:?}");
+        }
+        for bad in ["", "XNY", "xnys", "XNYSE", "XN-S"] {
+            assert!(bad.parse::<Exchange>().is_err(), "{bad:?}");
+        }
+        assert!(matches!(
+            "usd".parse::<Currency>(),
+            Err(DecodeError::InvalidFieldValue { tag: 15, .. })
+        ));
+    }
+
+    #[test]
+    fn test_iso_codes_serde_validates() {
+        let currency = Currency::new("EUR").unwrap();
+        let json = serde_json::to_string(&currency).unwrap();
+        assert_eq!(json, "\"EUR\"");
+        assert_eq!(serde_json::from_str::<Currency>(&json).unwrap(), currency);
+        assert!(serde_json::from_str::<Country>("\"usa\"").is_err());
+    }
 }
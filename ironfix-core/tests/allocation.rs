@@ -0,0 +1,96 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Heap allocation behavior of the owned message hot path.
+
+use ironfix_core::message::{MessageArena, MessageBuilder, MsgType, OwnedMessage};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts allocations made by threads that have opted in.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations `f` makes on this thread.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+    ALLOCATIONS.load(Ordering::SeqCst) - before
+}
+
+#[test]
+fn test_from_raw_in_does_not_allocate_per_message() {
+    // BeginString, BodyLength, MsgType, seven body fields and CheckSum.
+    let quote = MessageBuilder::new("FIX.4.4", MsgType::MarketDataIncrementalRefresh)
+        .field(49, "VENUE")
+        .field(56, "CLIENT")
+        .field(34, "42")
+        .field(52, "20260127-10:00:00.000")
+        .field(55, "EURUSD")
+        .field(270, "1.08345")
+        .field(271, "1000000")
+        .build();
+    let raw = quote.as_raw();
+    assert_eq!(raw.field_count(), 11);
+
+    let mut arena = MessageArena::with_capacity(4096);
+    // Warm up: the first copy turns the arena into a shared allocation.
+    drop(OwnedMessage::from_raw_in(&raw, &mut arena));
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10_000 {
+            let message = OwnedMessage::from_raw_in(&raw, &mut arena);
+            assert_eq!(message.get_field_str(55), Some("EURUSD"));
+        }
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn test_from_raw_allocates_buffer_per_message() {
+    let quote = MessageBuilder::new("FIX.4.4", MsgType::Heartbeat)
+        .field(112, "PING")
+        .build();
+    let raw = quote.as_raw();
+
+    let allocations = count_allocations(|| {
+        for _ in 0..100 {
+            let _ = OwnedMessage::from_raw(&raw);
+        }
+    });
+    assert_eq!(allocations, 100);
+}
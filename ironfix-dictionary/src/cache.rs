@@ -0,0 +1,142 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Compact binary serialization of dictionaries.
+//!
+//! Parsing a large dictionary at every startup is slow. A dictionary can be
+//! parsed once, e.g. in a build script, written with
+//! [`Dictionary::to_cache_bytes`], embedded with `include_bytes!` and loaded
+//! at runtime with [`Dictionary::from_cache_bytes`].
+
+use crate::error::DictionaryError;
+use crate::schema::Dictionary;
+
+/// Magic bytes identifying a dictionary cache.
+const CACHE_MAGIC: &[u8; 4] = b"IFXD";
+
+/// Version of the cache layout, bumped when the schema types change.
+const CACHE_VERSION: u8 = 1;
+
+impl Dictionary {
+    /// Serializes the dictionary into compact cache bytes.
+    ///
+    /// The bytes start with a short header identifying the format version,
+    /// followed by the postcard encoding of the dictionary.
+    ///
+    /// # Errors
+    /// Returns `DictionaryError::Cache` if the dictionary cannot be encoded.
+    pub fn to_cache_bytes(&self) -> Result<Vec<u8>, DictionaryError> {
+        let mut bytes = CACHE_MAGIC.to_vec();
+        bytes.push(CACHE_VERSION);
+        let body =
+            postcard::to_allocvec(self).map_err(|e| DictionaryError::Cache(e.to_string()))?;
+        bytes.extend_from_slice(&body);
+        Ok(bytes)
+    }
+
+    /// Loads a dictionary from bytes written by [`to_cache_bytes`](Self::to_cache_bytes).
+    ///
+    /// # Arguments
+    /// * `bytes` - The cache bytes
+    ///
+    /// # Errors
+    /// Returns `DictionaryError::InvalidCacheHeader` or
+    /// `DictionaryError::UnsupportedCacheVersion` if the bytes are not a cache
+    /// of this format version, or `DictionaryError::Cache` if they are corrupt.
+    pub fn from_cache_bytes(bytes: &[u8]) -> Result<Self, DictionaryError> {
+        let body = bytes
+            .strip_prefix(CACHE_MAGIC)
+            .ok_or(DictionaryError::InvalidCacheHeader)?;
+        let (&version, body) = body
+            .split_first()
+            .ok_or(DictionaryError::InvalidCacheHeader)?;
+        if version != CACHE_VERSION {
+            return Err(DictionaryError::UnsupportedCacheVersion(version));
+        }
+        postcard::from_bytes(body).map_err(|e| DictionaryError::Cache(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{
+        ComponentDef, FieldDef, FieldRef, FieldType, GroupDef, MessageCategory, MessageDef, Version,
+    };
+    use std::collections::HashMap;
+
+    fn sample_dictionary() -> Dictionary {
+        let field_ref = |tag, name: &str, required| FieldRef {
+            tag,
+            name: name.to_string(),
+            required,
+        };
+
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(11, "ClOrdID", FieldType::String));
+        dict.add_field(
+            FieldDef::new(54, "Side", FieldType::Char)
+                .with_values(HashMap::from([
+                    ("1".to_string(), "BUY".to_string()),
+                    ("2".to_string(), "SELL".to_string()),
+                ]))
+                .with_description("Side of order"),
+        );
+        dict.add_field(FieldDef::new(44, "Price", FieldType::Price));
+        dict.add_component(ComponentDef {
+            name: "Parties".to_string(),
+            fields: Vec::new(),
+            groups: vec![GroupDef {
+                count_tag: 453,
+                name: "NoPartyIDs".to_string(),
+                delimiter_tag: 448,
+                fields: vec![field_ref(448, "PartyID", false)],
+                groups: Vec::new(),
+                required: false,
+            }],
+            components: Vec::new(),
+        });
+        dict.add_message(MessageDef {
+            msg_type: "D".to_string(),
+            name: "NewOrderSingle".to_string(),
+            category: MessageCategory::App,
+            fields: vec![field_ref(11, "ClOrdID", true), field_ref(54, "Side", true)],
+            groups: Vec::new(),
+            components: vec!["Parties".to_string()],
+        });
+        dict.header = vec![field_ref(8, "BeginString", true)];
+        dict
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let dict = sample_dictionary();
+        let bytes = dict.to_cache_bytes().unwrap();
+        assert_eq!(Dictionary::from_cache_bytes(&bytes).unwrap(), dict);
+    }
+
+    #[test]
+    fn test_cache_rejects_foreign_bytes() {
+        let mut bytes = sample_dictionary().to_cache_bytes().unwrap();
+        assert_eq!(
+            Dictionary::from_cache_bytes(b"<fix/>").unwrap_err(),
+            DictionaryError::InvalidCacheHeader
+        );
+
+        bytes[4] = CACHE_VERSION + 1;
+        assert_eq!(
+            Dictionary::from_cache_bytes(&bytes).unwrap_err(),
+            DictionaryError::UnsupportedCacheVersion(CACHE_VERSION + 1)
+        );
+
+        bytes[4] = CACHE_VERSION;
+        bytes.truncate(bytes.len() / 2);
+        assert!(matches!(
+            Dictionary::from_cache_bytes(&bytes),
+            Err(DictionaryError::Cache(_))
+        ));
+    }
+}
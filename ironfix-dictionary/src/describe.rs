@@ -0,0 +1,129 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Human-readable rendering of raw messages.
+//!
+//! This module resolves field names and enumerated value descriptions from a
+//! [`Dictionary`] so raw FIX messages can be logged legibly.
+
+use crate::schema::Dictionary;
+use ironfix_core::message::RawMessage;
+use std::fmt;
+
+/// Extension trait rendering a [`RawMessage`] using a [`Dictionary`].
+pub trait DescribeMessage {
+    /// Renders the message with one field per line, formatted as
+    /// `FieldName (tag) = value [ValueDescription]`.
+    ///
+    /// Fields missing from the dictionary are named `Unknown`. The value
+    /// description is only shown for enumerated values the dictionary defines.
+    ///
+    /// # Arguments
+    /// * `dict` - The dictionary used to resolve names
+    #[must_use]
+    fn describe(&self, dict: &Dictionary) -> String;
+
+    /// Returns a wrapper implementing `Display` with the same output as
+    /// [`describe`](Self::describe), avoiding an intermediate `String`.
+    ///
+    /// # Arguments
+    /// * `dict` - The dictionary used to resolve names
+    #[must_use]
+    fn display<'d>(&self, dict: &'d Dictionary) -> MessageDisplay<'_, 'd>;
+}
+
+impl<'a> DescribeMessage for RawMessage<'a> {
+    fn describe(&self, dict: &Dictionary) -> String {
+        self.display(dict).to_string()
+    }
+
+    fn display<'d>(&self, dict: &'d Dictionary) -> MessageDisplay<'_, 'd> {
+        MessageDisplay {
+            fields: self
+                .fields()
+                .map(|field| (field.tag, field.value))
+                .collect(),
+            dict,
+        }
+    }
+}
+
+/// `Display` wrapper rendering a message's fields with dictionary names.
+///
+/// Created by [`DescribeMessage::display`].
+#[derive(Debug)]
+pub struct MessageDisplay<'m, 'd> {
+    /// Fields as `(tag, value)` pairs in message order.
+    fields: Vec<(u32, &'m [u8])>,
+    /// Dictionary used to resolve names.
+    dict: &'d Dictionary,
+}
+
+impl fmt::Display for MessageDisplay<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (tag, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            let value = String::from_utf8_lossy(value);
+            let field_def = self.dict.get_field(*tag);
+            let name = field_def.map_or("Unknown", |def| def.name.as_str());
+            write!(f, "{} ({}) = {}", name, tag, value)?;
+
+            let description = field_def
+                .and_then(|def| def.values.as_ref())
+                .and_then(|values| values.get(value.as_ref()));
+            if let Some(description) = description {
+                write!(f, " [{}]", description)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldDef, FieldType, Version};
+    use ironfix_core::field::FieldRef;
+    use ironfix_core::message::MsgType;
+    use smallvec::SmallVec;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_describe_message() {
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(35, "MsgType", FieldType::String));
+        dict.add_field(
+            FieldDef::new(54, "Side", FieldType::Char).with_values(HashMap::from([
+                ("1".to_string(), "BUY".to_string()),
+                ("2".to_string(), "SELL".to_string()),
+            ])),
+        );
+
+        let buffer: &[u8] = b"35=D\x0154=2\x019999=X\x01";
+        let fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::from_iter([
+            FieldRef::new(35, &buffer[3..4]),
+            FieldRef::new(54, &buffer[8..9]),
+            FieldRef::new(9999, &buffer[15..16]),
+        ]);
+        let raw = RawMessage::new(
+            buffer,
+            0..0,
+            0..buffer.len(),
+            MsgType::NewOrderSingle,
+            fields,
+        );
+
+        let description = raw.describe(&dict);
+        assert_eq!(
+            description,
+            "MsgType (35) = D\nSide (54) = 2 [SELL]\nUnknown (9999) = X"
+        );
+        assert_eq!(raw.display(&dict).to_string(), description);
+    }
+}
@@ -0,0 +1,33 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Error types for dictionary loading.
+
+use thiserror::Error;
+
+/// Errors that occur while loading a dictionary.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum DictionaryError {
+    /// Cache bytes were not produced by `Dictionary::to_cache_bytes`.
+    #[error("invalid dictionary cache header")]
+    InvalidCacheHeader,
+
+    /// Cache bytes were written by an incompatible format version.
+    #[error("unsupported dictionary cache version {0}")]
+    UnsupportedCacheVersion(u8),
+
+    /// Cache bytes could not be encoded or decoded.
+    #[error("dictionary cache error: {0}")]
+    Cache(String),
+
+    /// The document could not be read or is not well-formed XML.
+    #[error("xml error: {0}")]
+    Xml(String),
+
+    /// The document is XML but does not describe a valid specification.
+    #[error("invalid specification: {0}")]
+    InvalidSpec(String),
+}
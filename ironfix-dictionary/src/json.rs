@@ -0,0 +1,309 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! JSON serialization of raw messages.
+//!
+//! This module converts a [`RawMessage`] into a [`serde_json::Value`] for
+//! downstream systems. With a [`Dictionary`], keys are field names, values
+//! are typed per the field definitions and repeating groups become nested
+//! arrays of objects. Requires the `json` feature.
+
+use crate::schema::{Dictionary, GroupDef};
+use crate::typed::convert;
+use ironfix_core::field::FieldValue;
+use ironfix_core::message::RawMessage;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+/// Extension trait serializing a [`RawMessage`] to JSON.
+pub trait ToJson {
+    /// Serializes the message to a JSON object.
+    ///
+    /// Without a dictionary, keys are tag numbers and values are strings.
+    /// With a dictionary, keys are field names (tags not in the dictionary
+    /// keep their number), values are converted as in
+    /// [`Dictionary::decode_typed`], and each repeating group of the message
+    /// is an array of objects keyed by its name. Decimal values (prices,
+    /// quantities, amounts) are strings holding their exact decimal form, as
+    /// a JSON number would round them to `f64`. A key occurring more than
+    /// once outside a known group holds an array of its values.
+    ///
+    /// # Arguments
+    /// * `dict` - Optional dictionary used to name, type and group fields
+    #[must_use]
+    fn to_json(&self, dict: Option<&Dictionary>) -> Value;
+}
+
+impl<'a> ToJson for RawMessage<'a> {
+    fn to_json(&self, dict: Option<&Dictionary>) -> Value {
+        let fields: Vec<(u32, &[u8])> = self
+            .fields()
+            .map(|field| (field.tag, field.value))
+            .collect();
+        let mut groups = HashMap::new();
+        if let Some(dict) = dict
+            && let Some(message) = dict.get_message(self.msg_type().as_str())
+        {
+            collect_groups(
+                dict,
+                &message.groups,
+                &message.components,
+                &mut HashSet::new(),
+                &mut groups,
+            );
+        }
+
+        let mut serializer = Serializer {
+            dict,
+            groups,
+            fields: &fields,
+            pos: 0,
+        };
+        let mut object = Map::new();
+        while serializer.pos < fields.len() {
+            serializer.next_into(&mut object, None);
+        }
+        Value::Object(object)
+    }
+}
+
+/// Walks a message's fields, building JSON objects.
+struct Serializer<'d, 'f> {
+    /// Dictionary used to name and type fields.
+    dict: Option<&'d Dictionary>,
+    /// Groups of the message by count tag.
+    groups: HashMap<u32, &'d GroupDef>,
+    /// Fields as `(tag, value)` pairs in message order.
+    fields: &'f [(u32, &'f [u8])],
+    /// Index of the next field.
+    pos: usize,
+}
+
+impl Serializer<'_, '_> {
+    /// Consumes the next field, or the whole group it starts, into `object`.
+    ///
+    /// Within a group entry, `member_groups` resolves nested groups.
+    fn next_into(&mut self, object: &mut Map<String, Value>, member_groups: Option<&[GroupDef]>) {
+        let (tag, value) = self.fields[self.pos];
+        self.pos += 1;
+
+        let group = match member_groups {
+            Some(nested) => nested.iter().find(|group| group.count_tag == tag),
+            None => self.groups.get(&tag).copied(),
+        };
+        match group {
+            Some(group) => {
+                let entries = self.group_entries(group);
+                insert(object, group.name.clone(), Value::Array(entries));
+            }
+            None => {
+                let field_def = self.dict.and_then(|dict| dict.get_field(tag));
+                let (key, value) = match field_def {
+                    Some(def) => (def.name.clone(), to_value(convert(def.field_type, value))),
+                    None => (
+                        tag.to_string(),
+                        Value::String(String::from_utf8_lossy(value).into_owned()),
+                    ),
+                };
+                insert(object, key, value);
+            }
+        }
+    }
+
+    /// Consumes the entries of a group whose count field was just read.
+    ///
+    /// Each entry starts at the delimiter field and extends while the tags
+    /// belong to the group.
+    fn group_entries(&mut self, group: &GroupDef) -> Vec<Value> {
+        let mut entries = Vec::new();
+        while self.pos < self.fields.len() && self.fields[self.pos].0 == group.delimiter_tag {
+            let mut entry = Map::new();
+            self.next_into(&mut entry, Some(&group.groups));
+            while self.pos < self.fields.len() {
+                let tag = self.fields[self.pos].0;
+                if tag == group.delimiter_tag || !is_member(group, tag) {
+                    break;
+                }
+                self.next_into(&mut entry, Some(&group.groups));
+            }
+            entries.push(Value::Object(entry));
+        }
+        entries
+    }
+}
+
+/// Returns true if the tag is a field or nested group count of the group.
+fn is_member(group: &GroupDef, tag: u32) -> bool {
+    group.fields.iter().any(|field| field.tag == tag)
+        || group.groups.iter().any(|nested| nested.count_tag == tag)
+}
+
+/// Inserts a value, turning repeated keys into arrays.
+fn insert(object: &mut Map<String, Value>, key: String, value: Value) {
+    match object.get_mut(&key) {
+        Some(Value::Array(values)) if !value.is_array() => values.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+        None => {
+            object.insert(key, value);
+        }
+    }
+}
+
+/// Converts a typed field value to JSON.
+fn to_value(value: FieldValue) -> Value {
+    match value {
+        FieldValue::String(s) => Value::String(s),
+        FieldValue::Int(v) => Value::from(v),
+        FieldValue::UInt(v) => Value::from(v),
+        FieldValue::Decimal(d) => Value::String(d.to_string()),
+        FieldValue::Bool(b) => Value::Bool(b),
+        FieldValue::Char(c) => Value::String(c.to_string()),
+        FieldValue::Data(d) => Value::String(String::from_utf8_lossy(&d).into_owned()),
+    }
+}
+
+/// Collects the groups of a message or component, including those of its
+/// components, by count tag.
+fn collect_groups<'a>(
+    dict: &'a Dictionary,
+    groups: &'a [GroupDef],
+    components: &'a [String],
+    path: &mut HashSet<&'a str>,
+    out: &mut HashMap<u32, &'a GroupDef>,
+) {
+    for group in groups {
+        out.entry(group.count_tag).or_insert(group);
+    }
+    for name in components {
+        let Some(component) = dict.get_component(name) else {
+            continue;
+        };
+        if !path.insert(name.as_str()) {
+            continue;
+        }
+        collect_groups(dict, &component.groups, &component.components, path, out);
+        path.remove(name.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldDef, FieldRef, FieldType, MessageCategory, MessageDef, Version};
+    use ironfix_tagvalue::{Decoder, Encoder};
+    use serde_json::json;
+
+    fn field_ref(tag: u32, name: &str) -> FieldRef {
+        FieldRef {
+            tag,
+            name: name.to_string(),
+            required: false,
+        }
+    }
+
+    fn order_dictionary() -> Dictionary {
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(35, "MsgType", FieldType::String));
+        dict.add_field(FieldDef::new(11, "ClOrdID", FieldType::String));
+        dict.add_field(FieldDef::new(38, "OrderQty", FieldType::Qty));
+        dict.add_field(FieldDef::new(44, "Price", FieldType::Price));
+        dict.add_field(FieldDef::new(114, "LocateReqd", FieldType::Boolean));
+        dict.add_field(FieldDef::new(78, "NoAllocs", FieldType::NumInGroup));
+        dict.add_field(FieldDef::new(79, "AllocAccount", FieldType::String));
+        dict.add_field(FieldDef::new(80, "AllocQty", FieldType::Qty));
+        dict.add_message(MessageDef {
+            msg_type: "D".to_string(),
+            name: "NewOrderSingle".to_string(),
+            category: MessageCategory::App,
+            fields: vec![
+                field_ref(11, "ClOrdID"),
+                field_ref(38, "OrderQty"),
+                field_ref(44, "Price"),
+                field_ref(114, "LocateReqd"),
+            ],
+            groups: vec![GroupDef {
+                count_tag: 78,
+                name: "NoAllocs".to_string(),
+                delimiter_tag: 79,
+                fields: vec![field_ref(79, "AllocAccount"), field_ref(80, "AllocQty")],
+                groups: Vec::new(),
+                required: false,
+            }],
+            components: Vec::new(),
+        });
+        dict
+    }
+
+    fn encode_order() -> Vec<u8> {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_str(11, "ORD-1");
+        encoder.put_str(38, "300");
+        encoder.put_str(44, "101.25");
+        encoder.put_str(114, "Y");
+        encoder.put_str(78, "2");
+        encoder.put_str(79, "ACC1");
+        encoder.put_str(80, "100");
+        encoder.put_str(79, "ACC2");
+        encoder.put_str(80, "200");
+        encoder.put_str(5001, "custom");
+        encoder.finish().to_vec()
+    }
+
+    #[test]
+    fn test_to_json_with_dictionary() {
+        let dict = order_dictionary();
+        let bytes = encode_order();
+        let raw = Decoder::new(&bytes).decode().unwrap();
+
+        let value = raw.to_json(Some(&dict));
+        assert_eq!(value["MsgType"], json!("D"));
+        assert_eq!(value["ClOrdID"], json!("ORD-1"));
+        assert_eq!(value["OrderQty"], json!("300"));
+        assert_eq!(value["Price"], json!("101.25"));
+        assert_eq!(value["LocateReqd"], json!(true));
+        assert_eq!(
+            value["NoAllocs"],
+            json!([
+                {"AllocAccount": "ACC1", "AllocQty": "100"},
+                {"AllocAccount": "ACC2", "AllocQty": "200"},
+            ])
+        );
+        assert_eq!(value["5001"], json!("custom"));
+    }
+
+    #[test]
+    fn test_to_json_keeps_decimal_precision() {
+        let dict = order_dictionary();
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_str(44, "12345678901234567.89");
+        encoder.put_str(38, "0.10");
+        let bytes = encoder.finish();
+        let raw = Decoder::new(&bytes).decode().unwrap();
+
+        let value = raw.to_json(Some(&dict));
+        assert_eq!(value["Price"], json!("12345678901234567.89"));
+        assert_eq!(value["OrderQty"], json!("0.10"));
+    }
+
+    #[test]
+    fn test_to_json_without_dictionary() {
+        let bytes = encode_order();
+        let raw = Decoder::new(&bytes).decode().unwrap();
+
+        let value = raw.to_json(None);
+        assert_eq!(value["35"], json!("D"));
+        assert_eq!(value["11"], json!("ORD-1"));
+        assert_eq!(value["44"], json!("101.25"));
+        assert_eq!(value["78"], json!("2"));
+        assert_eq!(value["79"], json!(["ACC1", "ACC2"]));
+        assert_eq!(value["80"], json!(["100", "200"]));
+    }
+}
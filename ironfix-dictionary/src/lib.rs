@@ -11,9 +11,26 @@
 //! This crate provides:
 //! - **Schema definitions**: Field, message, and component definitions
 //! - **Dictionary parsing**: QuickFIX XML format parser
+//! - **Orchestra import**: FIX Orchestra repository documents
 //! - **Runtime validation**: Message validation against dictionary rules
 //! - **Embedded dictionaries**: Pre-loaded FIX 4.0 through 5.0 SP2 specifications
+//! - **Message rendering**: Human-readable output of raw messages
+//! - **Typed decoding**: Field values converted per their dictionary type
+//! - **Binary cache**: Compact serialization for fast startup
+//! - **JSON output**: Raw messages as JSON objects (`json` feature)
 
+pub mod cache;
+pub mod describe;
+pub mod error;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod orchestra;
 pub mod schema;
+pub mod typed;
 
+pub use describe::{DescribeMessage, MessageDisplay};
+pub use error::DictionaryError;
+#[cfg(feature = "json")]
+pub use json::ToJson;
 pub use schema::{ComponentDef, Dictionary, FieldDef, FieldType, GroupDef, MessageDef, Version};
+pub use typed::TypedMessage;
@@ -0,0 +1,425 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! FIX Orchestra repository importer.
+//!
+//! Newer FIX specifications are published as Orchestra XML. This module maps
+//! an Orchestra repository's code sets, fields, components, groups and
+//! messages onto the [`Dictionary`] schema types. Elements are matched by
+//! local name, so any namespace prefix is accepted.
+
+use crate::error::DictionaryError;
+use crate::schema::{
+    ComponentDef, Dictionary, FieldDef, FieldRef, FieldType, GroupDef, MessageCategory, MessageDef,
+    Version,
+};
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::io::Read;
+
+/// Maximum nesting of components and groups inlined into a group.
+const MAX_DEPTH: usize = 32;
+
+/// Name of the component holding the standard header fields.
+const STANDARD_HEADER: &str = "StandardHeader";
+
+/// Name of the component holding the standard trailer fields.
+const STANDARD_TRAILER: &str = "StandardTrailer";
+
+impl Dictionary {
+    /// Parses a FIX Orchestra repository document.
+    ///
+    /// # Arguments
+    /// * `reader` - Source of the Orchestra XML
+    ///
+    /// # Errors
+    /// Returns `DictionaryError::Xml` if the document cannot be read or
+    /// parsed, or `DictionaryError::InvalidSpec` if it is not a valid
+    /// Orchestra repository.
+    pub fn from_orchestra_xml(mut reader: impl Read) -> Result<Self, DictionaryError> {
+        let mut xml = String::new();
+        reader
+            .read_to_string(&mut xml)
+            .map_err(|e| DictionaryError::Xml(e.to_string()))?;
+        Self::from_orchestra_str(&xml)
+    }
+
+    /// Parses a FIX Orchestra repository document held in a string.
+    ///
+    /// Fields whose type names a code set take the code set's underlying
+    /// type, with its codes as enumerated values. Messages in the `Session`
+    /// category are admin messages. The `StandardHeader` and
+    /// `StandardTrailer` components become the dictionary's header and
+    /// trailer and are not listed among message components. Components
+    /// referenced inside a group are inlined into the group, since groups
+    /// carry no component list. The version is taken from the repository's
+    /// `version` attribute, defaulting to FIX 5.0 SP2.
+    ///
+    /// # Arguments
+    /// * `xml` - The Orchestra XML
+    ///
+    /// # Errors
+    /// Returns `DictionaryError::Xml` if the document is not well-formed, or
+    /// `DictionaryError::InvalidSpec` if it is not a valid Orchestra
+    /// repository.
+    pub fn from_orchestra_str(xml: &str) -> Result<Self, DictionaryError> {
+        let document = Document::parse(xml).map_err(|e| DictionaryError::Xml(e.to_string()))?;
+        let root = document.root_element();
+        if root.tag_name().name() != "repository" {
+            return Err(invalid("root element is not a repository"));
+        }
+
+        let version = root
+            .attribute("version")
+            .and_then(parse_version)
+            .unwrap_or(Version::Fix50Sp2);
+        let mut importer = Importer {
+            dict: Dictionary::new(version),
+            components: HashMap::new(),
+            groups: HashMap::new(),
+        };
+        importer.import(root)?;
+        Ok(importer.dict)
+    }
+}
+
+/// State used while importing a repository.
+struct Importer<'a, 'input> {
+    /// Dictionary being built.
+    dict: Dictionary,
+    /// Component elements by id.
+    components: HashMap<u32, Node<'a, 'input>>,
+    /// Group elements by id.
+    groups: HashMap<u32, Node<'a, 'input>>,
+}
+
+/// Fields, groups and component names declared by an element.
+type Members = (Vec<FieldRef>, Vec<GroupDef>, Vec<String>);
+
+/// Underlying type and `value -> code name` map of a code set.
+type CodeSet = (FieldType, HashMap<String, String>);
+
+impl<'a, 'input> Importer<'a, 'input> {
+    /// Imports all sections of the repository.
+    fn import(&mut self, root: Node<'a, 'input>) -> Result<(), DictionaryError> {
+        let code_sets = code_sets(root)?;
+
+        for field in section(root, "fields", "field") {
+            let tag = id(field)?;
+            let name = required_attribute(field, "name")?;
+            let type_name = required_attribute(field, "type")?;
+            let def = match code_sets.get(type_name) {
+                Some((field_type, values)) => {
+                    FieldDef::new(tag, name, *field_type).with_values(values.clone())
+                }
+                None => FieldDef::new(tag, name, parse_field_type(type_name)),
+            };
+            self.dict.add_field(def);
+        }
+
+        for component in section(root, "components", "component") {
+            self.components.insert(id(component)?, component);
+        }
+        for group in section(root, "groups", "group") {
+            self.groups.insert(id(group)?, group);
+        }
+
+        for component in section(root, "components", "component") {
+            let name = required_attribute(component, "name")?;
+            let (fields, groups, components) = self.members(component)?;
+            match name {
+                STANDARD_HEADER => self.dict.header = fields.clone(),
+                STANDARD_TRAILER => self.dict.trailer = fields.clone(),
+                _ => {}
+            }
+            self.dict.add_component(ComponentDef {
+                name: name.to_string(),
+                fields,
+                groups,
+                components,
+            });
+        }
+
+        for message in section(root, "messages", "message") {
+            let structure = child(message, "structure").unwrap_or(message);
+            let (fields, groups, mut components) = self.members(structure)?;
+            components.retain(|name| name != STANDARD_HEADER && name != STANDARD_TRAILER);
+            let category = match message.attribute("category") {
+                Some("Session") => MessageCategory::Admin,
+                _ => MessageCategory::App,
+            };
+            self.dict.add_message(MessageDef {
+                msg_type: required_attribute(message, "msgType")?.to_string(),
+                name: required_attribute(message, "name")?.to_string(),
+                category,
+                fields,
+                groups,
+                components,
+            });
+        }
+        Ok(())
+    }
+
+    /// Collects the field, group and component references of an element.
+    fn members(&self, node: Node<'a, 'input>) -> Result<Members, DictionaryError> {
+        let mut fields = Vec::new();
+        let mut groups = Vec::new();
+        let mut components = Vec::new();
+
+        for member in node.children().filter(Node::is_element) {
+            match member.tag_name().name() {
+                "fieldRef" => fields.push(self.field_ref(member)?),
+                "groupRef" => groups.push(self.group(member, 0)?),
+                "componentRef" => {
+                    let component = self.component(member)?;
+                    components.push(required_attribute(component, "name")?.to_string());
+                }
+                _ => {}
+            }
+        }
+        Ok((fields, groups, components))
+    }
+
+    /// Appends an element's fields and groups, inlining its components.
+    fn inline(
+        &self,
+        node: Node<'a, 'input>,
+        depth: usize,
+        fields: &mut Vec<FieldRef>,
+        groups: &mut Vec<GroupDef>,
+    ) -> Result<(), DictionaryError> {
+        if depth > MAX_DEPTH {
+            return Err(invalid("components nested too deeply"));
+        }
+        for member in node.children().filter(Node::is_element) {
+            match member.tag_name().name() {
+                "fieldRef" => fields.push(self.field_ref(member)?),
+                "groupRef" => groups.push(self.group(member, depth)?),
+                "componentRef" => {
+                    self.inline(self.component(member)?, depth + 1, fields, groups)?
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves a `fieldRef` against the imported fields.
+    fn field_ref(&self, node: Node<'_, '_>) -> Result<FieldRef, DictionaryError> {
+        let tag = id(node)?;
+        let field = self
+            .dict
+            .get_field(tag)
+            .ok_or_else(|| invalid(format!("reference to unknown field {tag}")))?;
+        Ok(FieldRef {
+            tag,
+            name: field.name.clone(),
+            required: is_required(node),
+        })
+    }
+
+    /// Resolves a `componentRef` to its component element.
+    fn component(&self, node: Node<'_, '_>) -> Result<Node<'a, 'input>, DictionaryError> {
+        let component_id = id(node)?;
+        self.components
+            .get(&component_id)
+            .copied()
+            .ok_or_else(|| invalid(format!("reference to unknown component {component_id}")))
+    }
+
+    /// Builds the group definition referenced by a `groupRef`.
+    fn group(&self, node: Node<'_, '_>, depth: usize) -> Result<GroupDef, DictionaryError> {
+        if depth > MAX_DEPTH {
+            return Err(invalid("groups nested too deeply"));
+        }
+        let group_id = id(node)?;
+        let group = self
+            .groups
+            .get(&group_id)
+            .copied()
+            .ok_or_else(|| invalid(format!("reference to unknown group {group_id}")))?;
+        let name = required_attribute(group, "name")?;
+        let count_tag = child(group, "numInGroup")
+            .ok_or_else(|| invalid(format!("group {name} has no numInGroup")))
+            .and_then(id)?;
+
+        let mut fields = Vec::new();
+        let mut groups = Vec::new();
+        self.inline(group, depth + 1, &mut fields, &mut groups)?;
+
+        let delimiter_tag = fields
+            .first()
+            .map(|field| field.tag)
+            .ok_or_else(|| invalid(format!("group {name} has no fields")))?;
+        Ok(GroupDef {
+            count_tag,
+            name: name.to_string(),
+            delimiter_tag,
+            fields,
+            groups,
+            required: is_required(node),
+        })
+    }
+}
+
+/// Reads the code sets as `name -> (underlying type, value -> code name)`.
+fn code_sets(root: Node<'_, '_>) -> Result<HashMap<String, CodeSet>, DictionaryError> {
+    let mut code_sets = HashMap::new();
+    for code_set in section(root, "codeSets", "codeSet") {
+        let name = required_attribute(code_set, "name")?;
+        let field_type = parse_field_type(required_attribute(code_set, "type")?);
+        let mut values = HashMap::new();
+        for code in code_set
+            .children()
+            .filter(|node| node.tag_name().name() == "code")
+        {
+            values.insert(
+                required_attribute(code, "value")?.to_string(),
+                required_attribute(code, "name")?.to_string(),
+            );
+        }
+        code_sets.insert(name.to_string(), (field_type, values));
+    }
+    Ok(code_sets)
+}
+
+/// Returns the `item` elements of every `container` child of the root.
+fn section<'a, 'input>(
+    root: Node<'a, 'input>,
+    container: &'static str,
+    item: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    root.children()
+        .filter(move |node| node.tag_name().name() == container)
+        .flat_map(move |node| {
+            node.children()
+                .filter(move |child| child.tag_name().name() == item)
+        })
+}
+
+/// Returns the first child element with the given local name.
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|child| child.tag_name().name() == name)
+}
+
+/// Returns an attribute that the element must carry.
+fn required_attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, DictionaryError> {
+    node.attribute(name).ok_or_else(|| {
+        invalid(format!(
+            "<{}> is missing attribute {name}",
+            node.tag_name().name()
+        ))
+    })
+}
+
+/// Returns the element's numeric `id` attribute.
+fn id(node: Node<'_, '_>) -> Result<u32, DictionaryError> {
+    let value = required_attribute(node, "id")?;
+    value
+        .parse()
+        .map_err(|_| invalid(format!("invalid id {value:?}")))
+}
+
+/// Returns whether a reference's `presence` attribute is `required`.
+fn is_required(node: Node<'_, '_>) -> bool {
+    node.attribute("presence") == Some("required")
+}
+
+/// Maps an Orchestra datatype name to a field type.
+fn parse_field_type(type_name: &str) -> FieldType {
+    type_name.parse().unwrap_or(FieldType::String)
+}
+
+/// Maps a repository version such as `FIX.5.0SP2` to a [`Version`].
+fn parse_version(version: &str) -> Option<Version> {
+    const VERSIONS: [(&str, Version); 9] = [
+        ("FIX.5.0SP2", Version::Fix50Sp2),
+        ("FIX.5.0SP1", Version::Fix50Sp1),
+        ("FIX.5.0", Version::Fix50),
+        ("FIXT.1.1", Version::Fixt11),
+        ("FIX.4.4", Version::Fix44),
+        ("FIX.4.3", Version::Fix43),
+        ("FIX.4.2", Version::Fix42),
+        ("FIX.4.1", Version::Fix41),
+        ("FIX.4.0", Version::Fix40),
+    ];
+    VERSIONS
+        .iter()
+        .find(|(prefix, _)| version.starts_with(prefix))
+        .map(|(_, version)| *version)
+}
+
+/// Creates an invalid specification error.
+fn invalid(reason: impl Into<String>) -> DictionaryError {
+    DictionaryError::InvalidSpec(reason.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORCHESTRA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<fixr:repository xmlns:fixr="http://fixprotocol.io/2020/orchestra/repository" name="Venue" version="FIX.4.4">
+  <fixr:codeSets>
+    <fixr:codeSet name="SideCodeSet" id="54" type="char">
+      <fixr:code name="Buy" id="54001" value="1"/>
+      <fixr:code name="Sell" id="54002" value="2"/>
+    </fixr:codeSet>
+  </fixr:codeSets>
+  <fixr:fields>
+    <fixr:field id="11" name="ClOrdID" type="String"/>
+    <fixr:field id="44" name="Price" type="Price"/>
+    <fixr:field id="54" name="Side" type="SideCodeSet"/>
+  </fixr:fields>
+  <fixr:messages>
+    <fixr:message name="NewOrderSingle" id="14" msgType="D" category="SingleGeneralOrderHandling">
+      <fixr:structure>
+        <fixr:fieldRef id="11" presence="required"/>
+        <fixr:fieldRef id="54" presence="required"/>
+        <fixr:fieldRef id="44"/>
+      </fixr:structure>
+    </fixr:message>
+  </fixr:messages>
+</fixr:repository>"#;
+
+    #[test]
+    fn test_from_orchestra_xml() {
+        let dict = Dictionary::from_orchestra_xml(ORCHESTRA.as_bytes()).unwrap();
+        assert_eq!(dict.version, Version::Fix44);
+
+        let side = dict.get_field_by_name("Side").unwrap();
+        assert_eq!(side.tag, 54);
+        assert_eq!(side.field_type, FieldType::Char);
+        let values = side.values.as_ref().unwrap();
+        assert_eq!(values.get("1").map(String::as_str), Some("Buy"));
+        assert_eq!(values.get("2").map(String::as_str), Some("Sell"));
+        assert_eq!(dict.get_field(44).unwrap().field_type, FieldType::Price);
+
+        let message = dict.get_message("D").unwrap();
+        assert_eq!(message.name, "NewOrderSingle");
+        assert_eq!(message.category, MessageCategory::App);
+        let fields: Vec<(u32, bool)> = message
+            .fields
+            .iter()
+            .map(|field| (field.tag, field.required))
+            .collect();
+        assert_eq!(fields, [(11, true), (54, true), (44, false)]);
+    }
+
+    #[test]
+    fn test_from_orchestra_rejects_unknown_reference() {
+        let xml = ORCHESTRA.replace(r#"<fixr:fieldRef id="44"/>"#, r#"<fixr:fieldRef id="99"/>"#);
+        assert_eq!(
+            Dictionary::from_orchestra_str(&xml).unwrap_err(),
+            DictionaryError::InvalidSpec("reference to unknown field 99".to_string())
+        );
+        assert!(matches!(
+            Dictionary::from_orchestra_str("<fixr:repository"),
+            Err(DictionaryError::Xml(_))
+        ));
+    }
+}
@@ -13,8 +13,10 @@
 //! - [`GroupDef`]: Repeating group definitions
 //! - [`Dictionary`]: Complete FIX version dictionary
 
+use ironfix_core::message::{MessageLayout, StandardLayout};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use tracing::warn;
 
 /// FIX protocol version.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -53,6 +55,26 @@ impl Version {
         }
     }
 
+    /// Returns the version identified by a BeginString (tag 8) value.
+    ///
+    /// `"FIXT.1.1"` maps to [`Fixt11`](Self::Fixt11); the application version
+    /// of a FIXT session is resolved separately through ApplVerID.
+    ///
+    /// # Arguments
+    /// * `begin_string` - The BeginString field value
+    #[must_use]
+    pub fn from_begin_string(begin_string: &str) -> Option<Self> {
+        match begin_string {
+            "FIX.4.0" => Some(Self::Fix40),
+            "FIX.4.1" => Some(Self::Fix41),
+            "FIX.4.2" => Some(Self::Fix42),
+            "FIX.4.3" => Some(Self::Fix43),
+            "FIX.4.4" => Some(Self::Fix44),
+            "FIXT.1.1" => Some(Self::Fixt11),
+            _ => None,
+        }
+    }
+
     /// Returns the ApplVerID for FIX 5.0+ versions.
     #[must_use]
     pub const fn appl_ver_id(&self) -> Option<&'static str> {
@@ -64,6 +86,51 @@ impl Version {
         }
     }
 
+    /// Returns the version identified by an ApplVerID (tag 1128/1137) value.
+    ///
+    /// Besides the FIX 5.0+ identifiers, the FIX 4.x codes `"2"` to `"6"`
+    /// are recognised since FIXT sessions may carry those application versions.
+    ///
+    /// # Arguments
+    /// * `appl_ver_id` - The ApplVerID field value
+    #[must_use]
+    pub fn from_appl_ver_id(appl_ver_id: &str) -> Option<Self> {
+        match appl_ver_id {
+            "2" => Some(Self::Fix40),
+            "3" => Some(Self::Fix41),
+            "4" => Some(Self::Fix42),
+            "5" => Some(Self::Fix43),
+            "6" => Some(Self::Fix44),
+            "7" => Some(Self::Fix50),
+            "8" => Some(Self::Fix50Sp1),
+            "9" => Some(Self::Fix50Sp2),
+            _ => None,
+        }
+    }
+
+    /// Resolves the effective application version of a message.
+    ///
+    /// For FIX 4.x sessions the BeginString fully determines the version and
+    /// `self` is returned. For FIXT sessions a recognised ApplVerID takes
+    /// precedence; otherwise `self` is the session default, with bare
+    /// [`Fixt11`](Self::Fixt11) defaulting to [`Fix50Sp2`](Self::Fix50Sp2).
+    ///
+    /// # Arguments
+    /// * `appl_ver_id` - The message's ApplVerID (tag 1128) or the session's
+    ///   DefaultApplVerID (tag 1137), if present
+    #[must_use]
+    pub fn resolve_application(&self, appl_ver_id: Option<&str>) -> Self {
+        if !self.uses_fixt() {
+            return *self;
+        }
+
+        match appl_ver_id.and_then(Self::from_appl_ver_id) {
+            Some(version) => version,
+            None if *self == Self::Fixt11 => Self::Fix50Sp2,
+            None => *self,
+        }
+    }
+
     /// Returns true if this version uses FIXT transport.
     #[must_use]
     pub const fn uses_fixt(&self) -> bool {
@@ -240,7 +307,7 @@ impl FieldType {
 }
 
 /// Definition of a FIX field.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FieldDef {
     /// Field tag number.
     pub tag: u32,
@@ -288,7 +355,7 @@ impl FieldDef {
 }
 
 /// Reference to a field within a message or component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FieldRef {
     /// Field tag number.
     pub tag: u32,
@@ -299,7 +366,7 @@ pub struct FieldRef {
 }
 
 /// Definition of a repeating group.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GroupDef {
     /// Tag of the count field (NumInGroup).
     pub count_tag: u32,
@@ -316,7 +383,7 @@ pub struct GroupDef {
 }
 
 /// Definition of a reusable component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ComponentDef {
     /// Component name.
     pub name: String,
@@ -329,7 +396,7 @@ pub struct ComponentDef {
 }
 
 /// Definition of a FIX message.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MessageDef {
     /// Message type value (tag 35).
     pub msg_type: String,
@@ -355,7 +422,7 @@ pub enum MessageCategory {
 }
 
 /// Complete FIX dictionary for a specific version.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Dictionary {
     /// FIX version.
     pub version: Version,
@@ -447,6 +514,161 @@ impl Dictionary {
     pub fn components(&self) -> impl Iterator<Item = &ComponentDef> {
         self.components.values()
     }
+
+    /// Merges an overlay dictionary into this one.
+    ///
+    /// Fields, messages and components from the overlay are added, replacing
+    /// existing definitions with the same tag, message type or name. Each
+    /// replacement that changes a definition is logged as a warning. Header
+    /// and trailer fields from the overlay are appended if not already
+    /// present. The version of this dictionary is kept.
+    ///
+    /// # Arguments
+    /// * `overlay` - The dictionary with custom definitions, e.g. venue fields
+    pub fn merge(&mut self, overlay: Dictionary) {
+        for field in overlay.fields.into_values() {
+            if let Some(existing) = self.fields.get(&field.tag) {
+                if existing.name != field.name || existing.field_type != field.field_type {
+                    warn!(
+                        tag = field.tag,
+                        existing = %existing.name,
+                        overlay = %field.name,
+                        "overlay replaces field definition"
+                    );
+                }
+                if existing.name != field.name {
+                    self.fields_by_name.remove(&existing.name);
+                }
+            }
+            if let Some(&tag) = self.fields_by_name.get(&field.name)
+                && tag != field.tag
+            {
+                warn!(
+                    name = %field.name,
+                    existing = tag,
+                    overlay = field.tag,
+                    "overlay reassigns field name"
+                );
+                self.fields.remove(&tag);
+            }
+            self.add_field(field);
+        }
+
+        for message in overlay.messages.into_values() {
+            if self.messages.contains_key(&message.msg_type) {
+                warn!(msg_type = %message.msg_type, "overlay replaces message definition");
+            }
+            self.add_message(message);
+        }
+
+        for component in overlay.components.into_values() {
+            if self.components.contains_key(&component.name) {
+                warn!(name = %component.name, "overlay replaces component definition");
+            }
+            self.add_component(component);
+        }
+
+        for field in overlay.header {
+            if !self.header.iter().any(|existing| existing.tag == field.tag) {
+                self.header.push(field);
+            }
+        }
+        for field in overlay.trailer {
+            if !self
+                .trailer
+                .iter()
+                .any(|existing| existing.tag == field.tag)
+            {
+                self.trailer.push(field);
+            }
+        }
+    }
+
+    /// Returns a message's fields with all components expanded.
+    ///
+    /// The message's own fields come first, then one entry per repeating
+    /// group (its count field, named after the group), then the expansion
+    /// of each referenced component in order, applying the same layout
+    /// recursively. A component referencing itself directly or indirectly
+    /// is expanded only once along that path; unknown components are
+    /// skipped.
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type value (tag 35)
+    ///
+    /// # Returns
+    /// The flattened fields, or an empty list if the message is unknown.
+    #[must_use]
+    pub fn resolve_message_fields(&self, msg_type: &str) -> Vec<FieldRef> {
+        let mut resolved = Vec::new();
+        if let Some(message) = self.get_message(msg_type) {
+            let mut path = HashSet::new();
+            self.resolve_into(
+                &message.fields,
+                &message.groups,
+                &message.components,
+                &mut path,
+                &mut resolved,
+            );
+        }
+        resolved
+    }
+
+    /// Appends fields, group count fields and expanded components.
+    fn resolve_into<'a>(
+        &'a self,
+        fields: &[FieldRef],
+        groups: &[GroupDef],
+        components: &'a [String],
+        path: &mut HashSet<&'a str>,
+        resolved: &mut Vec<FieldRef>,
+    ) {
+        resolved.extend_from_slice(fields);
+        resolved.extend(groups.iter().map(|group| FieldRef {
+            tag: group.count_tag,
+            name: group.name.clone(),
+            required: group.required,
+        }));
+
+        for name in components {
+            let Some(component) = self.get_component(name) else {
+                continue;
+            };
+            if !path.insert(name.as_str()) {
+                continue;
+            }
+            self.resolve_into(
+                &component.fields,
+                &component.groups,
+                &component.components,
+                path,
+                resolved,
+            );
+            path.remove(name.as_str());
+        }
+    }
+}
+
+impl MessageLayout for Dictionary {
+    /// Uses the dictionary's header definition, or the standard header tags
+    /// when the dictionary defines none.
+    fn is_header_field(&self, tag: u32) -> bool {
+        if self.header.is_empty() {
+            StandardLayout.is_header_field(tag)
+        } else {
+            self.header.iter().any(|field| field.tag == tag)
+        }
+    }
+
+    /// Uses the dictionary's trailer definition, or the standard trailer tags
+    /// when the dictionary defines none.
+    fn is_trailer_field(&self, tag: u32) -> bool {
+        if self.trailer.is_empty() {
+            StandardLayout.is_trailer_field(tag)
+        } else {
+            self.trailer.iter().any(|field| field.tag == tag)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -467,6 +689,51 @@ mod tests {
         assert_eq!(Version::Fix50Sp2.appl_ver_id(), Some("9"));
     }
 
+    #[test]
+    fn test_version_from_begin_string() {
+        assert_eq!(Version::from_begin_string("FIX.4.2"), Some(Version::Fix42));
+        assert_eq!(
+            Version::from_begin_string("FIXT.1.1"),
+            Some(Version::Fixt11)
+        );
+        assert_eq!(Version::from_begin_string("FIX4.4"), None);
+    }
+
+    #[test]
+    fn test_version_from_appl_ver_id() {
+        assert_eq!(Version::from_appl_ver_id("6"), Some(Version::Fix44));
+        assert_eq!(Version::from_appl_ver_id("7"), Some(Version::Fix50));
+        assert_eq!(Version::from_appl_ver_id("8"), Some(Version::Fix50Sp1));
+        assert_eq!(Version::from_appl_ver_id("9"), Some(Version::Fix50Sp2));
+        assert_eq!(Version::from_appl_ver_id("10"), None);
+    }
+
+    #[test]
+    fn test_version_resolve_application() {
+        let fixt = Version::Fixt11;
+        assert_eq!(fixt.resolve_application(Some("7")), Version::Fix50);
+        assert_eq!(fixt.resolve_application(Some("8")), Version::Fix50Sp1);
+        assert_eq!(fixt.resolve_application(Some("9")), Version::Fix50Sp2);
+        assert_eq!(fixt.resolve_application(None), Version::Fix50Sp2);
+        assert_eq!(fixt.resolve_application(Some("X")), Version::Fix50Sp2);
+
+        // A session default applies when the message carries no ApplVerID.
+        assert_eq!(
+            Version::Fix50Sp1.resolve_application(None),
+            Version::Fix50Sp1
+        );
+        assert_eq!(
+            Version::Fix50Sp1.resolve_application(Some("9")),
+            Version::Fix50Sp2
+        );
+
+        // FIX 4.x sessions ignore ApplVerID.
+        assert_eq!(
+            Version::Fix44.resolve_application(Some("9")),
+            Version::Fix44
+        );
+    }
+
     #[test]
     fn test_field_type_from_str() {
         assert_eq!("INT".parse::<FieldType>().unwrap(), FieldType::Int);
@@ -495,4 +762,107 @@ mod tests {
         assert!(dict.get_field_by_name("MsgType").is_some());
         assert!(dict.get_field(999).is_none());
     }
+
+    #[test]
+    fn test_resolve_message_fields() {
+        let field = |tag, name: &str| FieldRef {
+            tag,
+            name: name.to_string(),
+            required: false,
+        };
+
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_component(ComponentDef {
+            name: "Instrument".to_string(),
+            fields: vec![field(55, "Symbol"), field(48, "SecurityID")],
+            groups: Vec::new(),
+            components: vec!["InstrumentExtension".to_string()],
+        });
+        dict.add_component(ComponentDef {
+            name: "InstrumentExtension".to_string(),
+            fields: vec![field(668, "DeliveryForm")],
+            groups: Vec::new(),
+            // Cycle back to the outer component.
+            components: vec!["Instrument".to_string()],
+        });
+        dict.add_message(MessageDef {
+            msg_type: "D".to_string(),
+            name: "NewOrderSingle".to_string(),
+            category: MessageCategory::App,
+            fields: vec![field(11, "ClOrdID"), field(54, "Side")],
+            groups: Vec::new(),
+            components: vec!["Instrument".to_string(), "Missing".to_string()],
+        });
+
+        let tags: Vec<u32> = dict
+            .resolve_message_fields("D")
+            .iter()
+            .map(|field| field.tag)
+            .collect();
+        assert_eq!(tags, [11, 54, 55, 48, 668]);
+        assert!(dict.resolve_message_fields("ZZ").is_empty());
+    }
+
+    #[test]
+    fn test_dictionary_merge() {
+        let field = |tag, name: &str, required| FieldRef {
+            tag,
+            name: name.to_string(),
+            required,
+        };
+
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(11, "ClOrdID", FieldType::String));
+        dict.add_field(FieldDef::new(58, "Text", FieldType::String));
+
+        let mut overlay = Dictionary::new(Version::Fix44);
+        overlay.add_field(FieldDef::new(5001, "VenueTag", FieldType::Int));
+        overlay.add_field(FieldDef::new(58, "FreeText", FieldType::String));
+        overlay.add_message(MessageDef {
+            msg_type: "U1".to_string(),
+            name: "VenueNotice".to_string(),
+            category: MessageCategory::App,
+            fields: vec![field(11, "ClOrdID", true), field(5001, "VenueTag", false)],
+            groups: Vec::new(),
+            components: Vec::new(),
+        });
+
+        dict.merge(overlay);
+        assert_eq!(dict.version, Version::Fix44);
+        assert_eq!(dict.get_field(11).unwrap().name, "ClOrdID");
+        assert_eq!(
+            dict.get_field_by_name("VenueTag").unwrap().field_type,
+            FieldType::Int
+        );
+
+        // The overlay's definition wins and the old name no longer resolves.
+        assert_eq!(dict.get_field(58).unwrap().name, "FreeText");
+        assert!(dict.get_field_by_name("Text").is_none());
+
+        assert_eq!(dict.get_message("U1").unwrap().name, "VenueNotice");
+        let tags: Vec<u32> = dict
+            .resolve_message_fields("U1")
+            .iter()
+            .map(|field| field.tag)
+            .collect();
+        assert_eq!(tags, [11, 5001]);
+    }
+
+    #[test]
+    fn test_dictionary_message_layout() {
+        let mut dict = Dictionary::new(Version::Fix44);
+        assert!(dict.is_header_field(49));
+        assert!(dict.is_trailer_field(10));
+        assert!(!dict.is_header_field(11));
+
+        let header_field = |tag, name: &str| FieldRef {
+            tag,
+            name: name.to_string(),
+            required: true,
+        };
+        dict.header = vec![header_field(8, "BeginString"), header_field(5000, "Custom")];
+        assert!(dict.is_header_field(5000));
+        assert!(!dict.is_header_field(49));
+        assert!(dict.is_trailer_field(10));
+    }
 }
@@ -0,0 +1,205 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Dictionary-driven typed decoding.
+//!
+//! This module converts the fields of a [`RawMessage`] into [`FieldValue`]s
+//! according to the [`FieldType`] each tag has in a [`Dictionary`], giving
+//! typed access to messages without generated code.
+
+use crate::schema::{Dictionary, FieldType};
+use bytes::Bytes;
+use ironfix_core::field::FieldValue;
+use ironfix_core::message::{MsgType, RawMessage};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// A message whose field values are converted per the dictionary.
+///
+/// Created by [`Dictionary::decode_typed`]. Fields keep their message order,
+/// so tags repeated within groups appear once per occurrence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedMessage {
+    /// The message type.
+    msg_type: MsgType,
+    /// Fields as `(tag, value)` pairs in message order.
+    fields: Vec<(u32, FieldValue)>,
+}
+
+impl TypedMessage {
+    /// Returns the message type.
+    #[must_use]
+    pub const fn msg_type(&self) -> &MsgType {
+        &self.msg_type
+    }
+
+    /// Returns the first value of the given tag.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&FieldValue> {
+        self.fields
+            .iter()
+            .find(|(field_tag, _)| *field_tag == tag)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns every value of the given tag, in message order.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag
+    pub fn get_all(&self, tag: u32) -> impl Iterator<Item = &FieldValue> {
+        self.fields
+            .iter()
+            .filter(move |(field_tag, _)| *field_tag == tag)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over all fields in message order.
+    pub fn fields(&self) -> impl Iterator<Item = (u32, &FieldValue)> {
+        self.fields.iter().map(|(tag, value)| (*tag, value))
+    }
+
+    /// Returns the number of fields.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns true if the message has no fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl Dictionary {
+    /// Decodes a raw message into typed field values.
+    ///
+    /// Each field is converted according to its dictionary type: integer
+    /// types to `Int` or `UInt`, Float/Qty/Price/Amt and similar to
+    /// `Decimal`, Boolean to `Bool`, Char to `Char` and Data/XmlData to
+    /// `Data`. Tags missing from the dictionary, other types and values that
+    /// do not parse as their declared type fall back to `String`.
+    ///
+    /// # Arguments
+    /// * `raw` - The message to decode
+    #[must_use]
+    pub fn decode_typed(&self, raw: &RawMessage<'_>) -> TypedMessage {
+        let fields = raw
+            .fields()
+            .map(|field| {
+                let value = match self.get_field(field.tag) {
+                    Some(def) => convert(def.field_type, field.value),
+                    None => string_value(field.value),
+                };
+                (field.tag, value)
+            })
+            .collect();
+
+        TypedMessage {
+            msg_type: raw.msg_type().clone(),
+            fields,
+        }
+    }
+}
+
+/// Converts a field value to the variant matching its type.
+pub(crate) fn convert(field_type: FieldType, value: &[u8]) -> FieldValue {
+    let Ok(text) = std::str::from_utf8(value) else {
+        return string_value(value);
+    };
+    let typed = match field_type {
+        FieldType::Int => text.parse().ok().map(FieldValue::Int),
+        FieldType::Length
+        | FieldType::SeqNum
+        | FieldType::NumInGroup
+        | FieldType::TagNum
+        | FieldType::DayOfMonth => text.parse().ok().map(FieldValue::UInt),
+        FieldType::Float
+        | FieldType::Qty
+        | FieldType::Price
+        | FieldType::PriceOffset
+        | FieldType::Amt
+        | FieldType::Percentage => Decimal::from_str(text).ok().map(FieldValue::Decimal),
+        FieldType::Boolean => match text {
+            "Y" => Some(FieldValue::Bool(true)),
+            "N" => Some(FieldValue::Bool(false)),
+            _ => None,
+        },
+        FieldType::Char => {
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(FieldValue::Char(c)),
+                _ => None,
+            }
+        }
+        FieldType::Data | FieldType::XmlData => {
+            Some(FieldValue::Data(Bytes::copy_from_slice(value)))
+        }
+        _ => None,
+    };
+    typed.unwrap_or_else(|| FieldValue::String(text.to_string()))
+}
+
+/// Converts a field value to `FieldValue::String`, replacing invalid UTF-8.
+fn string_value(value: &[u8]) -> FieldValue {
+    FieldValue::String(String::from_utf8_lossy(value).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldDef, Version};
+    use ironfix_core::field::FieldRef;
+    use smallvec::SmallVec;
+
+    #[test]
+    fn test_decode_typed() {
+        let mut dict = Dictionary::new(Version::Fix44);
+        dict.add_field(FieldDef::new(44, "Price", FieldType::Price));
+        dict.add_field(FieldDef::new(38, "OrderQty", FieldType::Qty));
+        dict.add_field(FieldDef::new(54, "Side", FieldType::Char));
+        dict.add_field(FieldDef::new(34, "MsgSeqNum", FieldType::SeqNum));
+        dict.add_field(FieldDef::new(43, "PossDupFlag", FieldType::Boolean));
+        dict.add_field(FieldDef::new(5001, "Offset", FieldType::Int));
+
+        let buffer: &[u8] = b"34=7\x0144=101.25\x0138=x\x0154=1\x0143=Y\x015001=-3\x019999=ZZ\x01";
+        let fields: SmallVec<[FieldRef<'_>; 32]> = SmallVec::from_iter([
+            FieldRef::new(34, &buffer[3..4]),
+            FieldRef::new(44, &buffer[8..14]),
+            FieldRef::new(38, &buffer[18..19]),
+            FieldRef::new(54, &buffer[23..24]),
+            FieldRef::new(43, &buffer[28..29]),
+            FieldRef::new(5001, &buffer[35..37]),
+            FieldRef::new(9999, &buffer[43..45]),
+        ]);
+        let raw = RawMessage::new(
+            buffer,
+            0..0,
+            0..buffer.len(),
+            MsgType::NewOrderSingle,
+            fields,
+        );
+
+        let typed = dict.decode_typed(&raw);
+        assert_eq!(typed.msg_type(), &MsgType::NewOrderSingle);
+        assert_eq!(typed.len(), 7);
+        assert_eq!(typed.get(34), Some(&FieldValue::UInt(7)));
+        assert_eq!(
+            typed.get(44),
+            Some(&FieldValue::Decimal(Decimal::new(10125, 2)))
+        );
+        assert_eq!(typed.get(54), Some(&FieldValue::Char('1')));
+        assert_eq!(typed.get(43), Some(&FieldValue::Bool(true)));
+        assert_eq!(typed.get(5001), Some(&FieldValue::Int(-3)));
+
+        // Unparseable values and unknown tags fall back to strings.
+        assert_eq!(typed.get(38), Some(&FieldValue::String("x".to_string())));
+        assert_eq!(typed.get(9999), Some(&FieldValue::String("ZZ".to_string())));
+    }
+}
@@ -0,0 +1,326 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Server-side multi-session runtime.
+//!
+//! An [`Acceptor`] listens on one socket for any number of configured
+//! sessions. Each inbound connection is routed by the SenderCompID (49) and
+//! TargetCompID (56) of its Logon to the matching session, which then runs
+//! like an initiated one. A Logon matching no session, or a session that is
+//! already connected, is answered with a Logout and the connection closed.
+
+use crate::application::{Application, SessionId};
+use crate::handle::SessionHandle;
+use crate::logger::MessageLogger;
+use crate::metrics::SessionMetrics;
+use crate::router::MessageRouter;
+use crate::runtime::SessionRuntime;
+use futures::stream::FuturesUnordered;
+use futures::{SinkExt, StreamExt, future::join_all};
+use ironfix_core::error::SessionError;
+use ironfix_core::message::{MessageBuilder, MsgType, RawMessage};
+use ironfix_session::admin::Logout;
+use ironfix_session::clock::{Clock, system_clock};
+use ironfix_session::config::SessionConfig;
+use ironfix_tagvalue::Decoder;
+use ironfix_transport::codec::FixCodec;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+use tokio_util::codec::Framed;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// Default time to wait for the Logon of a new connection.
+pub const DEFAULT_LOGON_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// FIX acceptor serving a set of sessions from one listener.
+///
+/// Sessions are identified from the acceptor's side: a configuration with
+/// SenderCompID `SERVER` and TargetCompID `CLIENT` accepts a Logon sent by
+/// `CLIENT` to `SERVER` with the same BeginString.
+pub struct Acceptor<A: Application> {
+    /// Application callback handler.
+    application: Arc<A>,
+    /// One session runtime per configured session.
+    sessions: Vec<SessionRuntime<A>>,
+    /// Time to wait for the Logon of a new connection.
+    logon_timeout: Duration,
+    /// Parent of every session's shutdown token.
+    shutdown: CancellationToken,
+    /// Source of SendingTime for rejection Logouts.
+    clock: Arc<dyn Clock>,
+}
+
+impl<A: Application> std::fmt::Debug for Acceptor<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Acceptor")
+            .field("sessions", &self.sessions)
+            .field("logon_timeout", &self.logon_timeout)
+            .finish()
+    }
+}
+
+impl<A: Application> Acceptor<A> {
+    /// Creates an acceptor for the given sessions.
+    ///
+    /// # Arguments
+    /// * `application` - The application callbacks, shared by every session
+    /// * `configs` - The sessions to accept, from the acceptor's side
+    ///
+    /// # Errors
+    /// Returns `SessionError::Configuration` if a session configuration is
+    /// invalid.
+    pub fn new(
+        application: Arc<A>,
+        configs: impl IntoIterator<Item = SessionConfig>,
+    ) -> Result<Self, SessionError> {
+        let shutdown = CancellationToken::new();
+        let sessions = configs
+            .into_iter()
+            .map(|config| {
+                config.validate()?;
+                Ok(SessionRuntime::new(Arc::clone(&application), config)
+                    .with_shutdown_token(shutdown.child_token()))
+            })
+            .collect::<Result<_, SessionError>>()?;
+        Ok(Self {
+            application,
+            sessions,
+            logon_timeout: DEFAULT_LOGON_TIMEOUT,
+            shutdown,
+            clock: system_clock(),
+        })
+    }
+
+    /// Sets the time to wait for the Logon of a new connection.
+    #[must_use]
+    pub const fn with_logon_timeout(mut self, timeout: Duration) -> Self {
+        self.logon_timeout = timeout;
+        self
+    }
+
+    /// Sets the logger receiving every session's messages and events.
+    #[must_use]
+    pub fn with_message_logger(self, logger: Arc<dyn MessageLogger>) -> Self {
+        self.map_sessions(|session| session.with_message_logger(Arc::clone(&logger)))
+    }
+
+    /// Sets the handlers receiving messages accepted by the application.
+    #[must_use]
+    pub fn with_router(self, router: Arc<MessageRouter>) -> Self {
+        self.map_sessions(|session| session.with_router(Arc::clone(&router)))
+    }
+
+    /// Sets the source of heartbeat timing and SendingTime (tag 52).
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a `MockClock` in tests
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Arc::clone(&clock);
+        self.map_sessions(|session| session.with_clock(Arc::clone(&clock)))
+    }
+
+    /// Applies a builder method to every session.
+    fn map_sessions(mut self, f: impl FnMut(SessionRuntime<A>) -> SessionRuntime<A>) -> Self {
+        self.sessions = self.sessions.into_iter().map(f).collect();
+        self
+    }
+
+    /// Returns an iterator over the session identifiers.
+    pub fn sessions(&self) -> impl Iterator<Item = &SessionId> {
+        self.sessions.iter().map(SessionRuntime::session_id)
+    }
+
+    /// Returns the runtime of the given session.
+    fn runtime(&self, session_id: &SessionId) -> Option<&SessionRuntime<A>> {
+        self.sessions
+            .iter()
+            .find(|session| session.session_id() == session_id)
+    }
+
+    /// Returns a handle to the live state of the given session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    ///
+    /// # Returns
+    /// The session's handle, or `None` if the acceptor has no such session.
+    #[must_use]
+    pub fn session(&self, session_id: &SessionId) -> Option<SessionHandle> {
+        self.runtime(session_id).map(SessionRuntime::handle)
+    }
+
+    /// Returns the connection and message counters of the given session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    ///
+    /// # Returns
+    /// The session's metrics, or `None` if the acceptor has no such session.
+    #[must_use]
+    pub fn metrics(&self, session_id: &SessionId) -> Option<Arc<SessionMetrics>> {
+        self.runtime(session_id)
+            .map(|session| Arc::clone(session.metrics()))
+    }
+
+    /// Returns the token that stops the acceptor when cancelled.
+    #[must_use]
+    pub const fn shutdown_token(&self) -> &CancellationToken {
+        &self.shutdown
+    }
+
+    /// Stops accepting, logs out every connected session and waits for all
+    /// of them to stop.
+    pub async fn shutdown(&self) {
+        self.shutdown.cancel();
+        join_all(self.sessions.iter().map(SessionRuntime::shutdown)).await;
+    }
+
+    /// Accepts connections until a shutdown is requested.
+    ///
+    /// Connections are served concurrently. Once shut down, connected
+    /// sessions log out before this returns.
+    ///
+    /// # Arguments
+    /// * `listener` - The bound listening socket
+    pub async fn run(&self, listener: TcpListener) {
+        for session in &self.sessions {
+            self.application.on_create(session.session_id()).await;
+        }
+
+        let mut connections = FuturesUnordered::new();
+        loop {
+            tokio::select! {
+                _ = self.shutdown.cancelled() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => {
+                        debug!(%peer, "accepted connection");
+                        connections.push(self.accept(stream));
+                    }
+                    Err(e) => warn!(error = %e, "failed to accept connection"),
+                },
+                Some(()) = connections.next(), if !connections.is_empty() => {}
+            }
+        }
+        while connections.next().await.is_some() {}
+    }
+
+    /// Reads the Logon of a new connection and hands the connection to the
+    /// matching session.
+    ///
+    /// The checksum is not validated until the session is known; the session
+    /// then re-decodes the Logon with its own configuration.
+    async fn accept(&self, stream: TcpStream) {
+        let mut framed = Framed::new(stream, FixCodec::new().with_checksum_validation(false));
+        let frame = tokio::select! {
+            _ = self.shutdown.cancelled() => return,
+            frame = timeout(self.logon_timeout, framed.next()) => match frame {
+                Ok(Some(Ok(frame))) => frame,
+                Ok(Some(Err(e))) => {
+                    warn!(error = %e, "invalid first message");
+                    return;
+                }
+                Ok(None) => return,
+                Err(_) => {
+                    debug!("timed out waiting for logon");
+                    return;
+                }
+            },
+        };
+        let message = match Decoder::new(&frame)
+            .with_checksum_validation(false)
+            .decode()
+        {
+            Ok(message) => message,
+            Err(e) => {
+                warn!(error = %e, "undecodable first message");
+                return;
+            }
+        };
+        if *message.msg_type() != MsgType::Logon {
+            reject(
+                &mut framed,
+                &message,
+                "First message is not a Logon",
+                &*self.clock,
+            )
+            .await;
+            return;
+        }
+
+        let Some(session) = self.route(&message) else {
+            warn!(
+                sender = message.get_field_str(49).unwrap_or_default(),
+                target = message.get_field_str(56).unwrap_or_default(),
+                "logon for unknown session"
+            );
+            reject(&mut framed, &message, "Unknown session", &*self.clock).await;
+            return;
+        };
+        let Some(running) = session.claim() else {
+            warn!(session_id = %session.session_id(), "session already connected");
+            reject(
+                &mut framed,
+                &message,
+                "Session already connected",
+                &*self.clock,
+            )
+            .await;
+            return;
+        };
+        if let Err(e) = session.serve(running, framed, &frame).await {
+            info!(session_id = %session.session_id(), error = %e, "logon failed");
+        }
+    }
+
+    /// Returns the session a Logon is addressed to.
+    ///
+    /// The Logon's BeginString must match, its SenderCompID must be the
+    /// session's TargetCompID and its TargetCompID the session's
+    /// SenderCompID.
+    fn route(&self, logon: &RawMessage<'_>) -> Option<&SessionRuntime<A>> {
+        let sender = logon.get_field_str(49)?;
+        let target = logon.get_field_str(56)?;
+        self.sessions.iter().find(|session| {
+            let id = session.session_id();
+            id.begin_string == logon.begin_string()
+                && id.sender_comp_id == target
+                && id.target_comp_id == sender
+        })
+    }
+}
+
+/// Answers a Logon that cannot be served with a Logout and closes the
+/// connection.
+///
+/// No session is established, so the Logout mirrors the Logon's CompIDs and
+/// uses MsgSeqNum 1.
+async fn reject(
+    framed: &mut Framed<TcpStream, FixCodec>,
+    logon: &RawMessage<'_>,
+    text: &str,
+    clock: &dyn Clock,
+) {
+    let logout = Logout::new().with_text(text).encode();
+    let message = logout
+        .fields()
+        .fold(
+            MessageBuilder::new(logon.begin_string(), MsgType::Logout)
+                .field(49, logon.get_field_str(56).unwrap_or_default())
+                .field(56, logon.get_field_str(49).unwrap_or_default())
+                .field(34, "1")
+                .field(52, clock.timestamp().format_millis().as_str()),
+            |builder, (tag, value)| builder.field(tag, value),
+        )
+        .build();
+    if let Err(e) = framed.send(message.as_bytes()).await {
+        debug!(error = %e, "failed to send logout");
+    }
+    let _ = SinkExt::<&[u8]>::close(framed).await;
+}
@@ -9,8 +9,11 @@
 //! This module defines the callback interface for handling FIX messages,
 //! following the QuickFIX pattern with async support.
 
+use crate::reject::{BusinessRejectReason, build_business_reject, build_session_reject};
 use async_trait::async_trait;
-use ironfix_core::message::{OwnedMessage, RawMessage};
+use ironfix_core::message::{MsgType, OwnedMessage, RawMessage};
+use ironfix_session::config::SessionConfig;
+use std::sync::Mutex;
 
 /// Session identifier.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -57,6 +60,57 @@ impl SessionId {
         self.target_sub_id = Some(sub_id.into());
         self
     }
+
+    /// Returns the canonical string key for this session.
+    ///
+    /// The format is `BeginString-SenderCompID-TargetCompID`, with a sub ID
+    /// appended to its CompID after an underscore when set, e.g.
+    /// `FIX.4.4-SENDER_DESK-TARGET`. Every byte of a component other than an
+    /// ASCII letter, digit or `.` is percent-encoded as `%XX`, so `-` and `_`
+    /// only ever appear as separators and distinct sessions never share a
+    /// key. The key is the session id to pass to a message store.
+    #[must_use]
+    pub fn key(&self) -> String {
+        let mut key = String::new();
+        push_escaped(&mut key, &self.begin_string);
+        key.push('-');
+        push_escaped(&mut key, &self.sender_comp_id);
+        if let Some(sub_id) = &self.sender_sub_id {
+            key.push('_');
+            push_escaped(&mut key, sub_id);
+        }
+        key.push('-');
+        push_escaped(&mut key, &self.target_comp_id);
+        if let Some(sub_id) = &self.target_sub_id {
+            key.push('_');
+            push_escaped(&mut key, sub_id);
+        }
+        key
+    }
+}
+
+/// Appends `component` to `key`, percent-encoding every byte other than an
+/// ASCII letter, digit or `.`.
+fn push_escaped(key: &mut String, component: &str) {
+    for &byte in component.as_bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'.' {
+            key.push(char::from(byte));
+        } else {
+            key.push_str(&format!("%{byte:02X}"));
+        }
+    }
+}
+
+impl From<&SessionConfig> for SessionId {
+    fn from(config: &SessionConfig) -> Self {
+        Self {
+            begin_string: config.begin_string.clone(),
+            sender_comp_id: config.sender_comp_id.as_str().to_string(),
+            target_comp_id: config.target_comp_id.as_str().to_string(),
+            sender_sub_id: config.sender_sub_id.clone(),
+            target_sub_id: config.target_sub_id.clone(),
+        }
+    }
 }
 
 impl std::fmt::Display for SessionId {
@@ -91,12 +145,110 @@ impl RejectReason {
         }
     }
 
+    /// Creates a business-level rejection reason.
+    ///
+    /// # Arguments
+    /// * `reason` - The BusinessRejectReason code
+    /// * `text` - Human-readable rejection text
+    #[must_use]
+    pub fn business(reason: BusinessRejectReason, text: impl Into<String>) -> Self {
+        Self::new(reason.code(), text)
+    }
+
     /// Sets the reference tag.
     #[must_use]
     pub const fn with_ref_tag(mut self, tag: u32) -> Self {
         self.ref_tag = Some(tag);
         self
     }
+
+    /// Builds a BusinessMessageReject for the rejected message.
+    ///
+    /// The code is interpreted as a BusinessRejectReason; non-standard codes
+    /// are sent as [`BusinessRejectReason::Other`].
+    ///
+    /// # Arguments
+    /// * `ref_seq_num` - MsgSeqNum of the rejected message
+    /// * `ref_msg_type` - MsgType of the rejected message
+    /// * `ref_id` - Optional business-level ID of the rejected message
+    #[must_use]
+    pub fn to_business_reject(
+        &self,
+        ref_seq_num: u64,
+        ref_msg_type: &MsgType,
+        ref_id: Option<&str>,
+    ) -> OwnedMessage {
+        let reason =
+            BusinessRejectReason::from_code(self.code).unwrap_or(BusinessRejectReason::Other);
+        let text = (!self.text.is_empty()).then_some(self.text.as_str());
+        build_business_reject(ref_seq_num, ref_msg_type, reason, text, ref_id)
+    }
+
+    /// Builds a session-level Reject for the rejected message.
+    ///
+    /// The code is sent as the SessionRejectReason and the reference tag, if
+    /// any, as RefTagID.
+    ///
+    /// # Arguments
+    /// * `ref_seq_num` - MsgSeqNum of the rejected message
+    /// * `ref_msg_type` - MsgType of the rejected message
+    #[must_use]
+    pub fn to_session_reject(&self, ref_seq_num: u64, ref_msg_type: &MsgType) -> OwnedMessage {
+        let text = (!self.text.is_empty()).then_some(self.text.as_str());
+        build_session_reject(ref_seq_num, ref_msg_type, self.code, self.ref_tag, text)
+    }
+}
+
+/// Collects the messages a callback sends in response to a received message.
+///
+/// Queued messages only need their body fields: the engine adds the session
+/// header and trailer and sends them in order once the received message has
+/// been accepted. Messages queued by a callback that rejects are discarded.
+#[derive(Debug, Default)]
+pub struct Responder {
+    messages: Mutex<Vec<OwnedMessage>>,
+}
+
+impl Responder {
+    /// Creates an empty responder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a message to send on the session.
+    ///
+    /// # Arguments
+    /// * `message` - The message to send; header fields are added by the engine
+    pub fn send(&self, message: OwnedMessage) {
+        self.lock().push(message);
+    }
+
+    /// Returns the number of queued messages.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if no message has been queued.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Consumes the responder, returning the queued messages in order.
+    #[must_use]
+    pub fn into_messages(self) -> Vec<OwnedMessage> {
+        self.messages
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<OwnedMessage>> {
+        self.messages
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 /// Application callback interface for handling FIX messages.
@@ -137,6 +289,7 @@ pub trait Application: Send + Sync {
     /// # Arguments
     /// * `message` - The received message
     /// * `session_id` - The session identifier
+    /// * `responder` - Queues messages to send once the message is accepted
     ///
     /// # Returns
     /// `Ok(())` to accept, `Err(RejectReason)` to reject.
@@ -145,6 +298,7 @@ pub trait Application: Send + Sync {
         &self,
         message: &RawMessage<'_>,
         session_id: &SessionId,
+        responder: &Responder,
     ) -> Result<(), RejectReason>;
 
     /// Called before sending an application message.
@@ -161,15 +315,40 @@ pub trait Application: Send + Sync {
     /// # Arguments
     /// * `message` - The received message
     /// * `session_id` - The session identifier
+    /// * `responder` - Queues messages, such as an ExecutionReport answering
+    ///   an order, to send once the message is accepted
     ///
     /// # Returns
-    /// `Ok(())` to accept, `Err(RejectReason)` to reject.
+    /// `Ok(())` to accept, `Err(RejectReason)` to reject. Use
+    /// [`RejectReason::business`] for semantic errors so the rejection can be
+    /// answered with [`RejectReason::to_business_reject`].
     #[allow(clippy::wrong_self_convention)]
     async fn from_app(
         &self,
         message: &RawMessage<'_>,
         session_id: &SessionId,
+        responder: &Responder,
     ) -> Result<(), RejectReason>;
+
+    /// Called with the exact bytes of every received frame, before parsing.
+    ///
+    /// Runs synchronously on the receive path; the default does nothing.
+    /// Intended for audit logging or replay capture.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    /// * `bytes` - The framed message as read from the wire
+    fn on_raw_in(&self, _session_id: &SessionId, _bytes: &[u8]) {}
+
+    /// Called with the exact bytes of every outgoing message, before writing.
+    ///
+    /// Runs synchronously on the send path after `to_admin`/`to_app`; the
+    /// default does nothing.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    /// * `bytes` - The encoded message as written to the wire
+    fn on_raw_out(&self, _session_id: &SessionId, _bytes: &[u8]) {}
 }
 
 /// Default no-op application implementation.
@@ -190,6 +369,7 @@ impl Application for NoOpApplication {
         &self,
         _message: &RawMessage<'_>,
         _session_id: &SessionId,
+        _responder: &Responder,
     ) -> Result<(), RejectReason> {
         Ok(())
     }
@@ -200,6 +380,7 @@ impl Application for NoOpApplication {
         &self,
         _message: &RawMessage<'_>,
         _session_id: &SessionId,
+        _responder: &Responder,
     ) -> Result<(), RejectReason> {
         Ok(())
     }
@@ -208,6 +389,7 @@ impl Application for NoOpApplication {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ironfix_core::types::CompId;
 
     #[test]
     fn test_session_id() {
@@ -218,6 +400,41 @@ mod tests {
         assert_eq!(id.to_string(), "FIX.4.4:SENDER->TARGET");
     }
 
+    #[test]
+    fn test_session_id_from_config_key() {
+        let mut config = SessionConfig::new(
+            CompId::new("SENDER").unwrap(),
+            CompId::new("TARGET").unwrap(),
+            "FIX.4.4",
+        );
+        let id = SessionId::from(&config);
+        assert_eq!(id, SessionId::new("FIX.4.4", "SENDER", "TARGET"));
+        assert_eq!(id.key(), "FIX.4.4-SENDER-TARGET");
+        assert_eq!(SessionId::from(&config).key(), id.key());
+
+        config.sender_sub_id = Some("DESK".to_string());
+        config.target_sub_id = Some("ALGO".to_string());
+        assert_eq!(
+            SessionId::from(&config).key(),
+            "FIX.4.4-SENDER_DESK-TARGET_ALGO"
+        );
+    }
+
+    #[test]
+    fn test_session_id_key_escapes_separators() {
+        let sub_id = SessionId::new("FIX.4.4", "A", "B").with_sender_sub_id("C");
+        let underscore = SessionId::new("FIX.4.4", "A_C", "B");
+        assert_eq!(sub_id.key(), "FIX.4.4-A_C-B");
+        assert_eq!(underscore.key(), "FIX.4.4-A%5FC-B");
+
+        let dash = SessionId::new("FIX.4.4", "A-B", "C");
+        assert_eq!(dash.key(), "FIX.4.4-A%2DB-C");
+        assert_ne!(dash.key(), SessionId::new("FIX.4.4", "A", "B-C").key());
+
+        let path = SessionId::new("FIX.4.4", "../../etc/x", "TARGET%");
+        assert_eq!(path.key(), "FIX.4.4-..%2F..%2Fetc%2Fx-TARGET%25");
+    }
+
     #[test]
     fn test_reject_reason() {
         let reason = RejectReason::new(1, "Invalid tag").with_ref_tag(35);
@@ -226,6 +443,21 @@ mod tests {
         assert_eq!(reason.ref_tag, Some(35));
     }
 
+    #[test]
+    fn test_reject_reason_to_business_reject() {
+        let reason = RejectReason::business(BusinessRejectReason::UnknownId, "No such order");
+        let msg = reason.to_business_reject(12, &MsgType::OrderCancelRequest, Some("C-9"));
+        assert_eq!(msg.get_field_str(45), Some("12"));
+        assert_eq!(msg.get_field_str(372), Some("F"));
+        assert_eq!(msg.get_field_str(379), Some("C-9"));
+        assert_eq!(msg.get_field_str(380), Some("1"));
+        assert_eq!(msg.get_field_str(58), Some("No such order"));
+
+        let msg = RejectReason::new(999, "").to_business_reject(1, &MsgType::Quote, None);
+        assert_eq!(msg.get_field_str(380), Some("0"));
+        assert_eq!(msg.get_field(58), None);
+    }
+
     #[tokio::test]
     async fn test_noop_application() {
         let app = NoOpApplication;
@@ -235,4 +467,18 @@ mod tests {
         app.on_logon(&session_id).await;
         app.on_logout(&session_id).await;
     }
+
+    #[test]
+    fn test_responder_keeps_queue_order() {
+        let responder = Responder::new();
+        assert!(responder.is_empty());
+
+        responder.send(build_session_reject(1, &MsgType::Logon, 99, None, None));
+        responder.send(build_session_reject(2, &MsgType::Logon, 99, None, None));
+        assert_eq!(responder.len(), 2);
+
+        let messages = responder.into_messages();
+        assert_eq!(messages[0].get_field_str(45), Some("1"));
+        assert_eq!(messages[1].get_field_str(45), Some("2"));
+    }
 }
@@ -9,6 +9,12 @@
 //! This module provides a builder API for configuring FIX engines.
 
 use crate::application::{Application, NoOpApplication};
+use crate::engine::Engine;
+use crate::initiator::Initiator;
+use crate::logger::MessageLogger;
+use crate::reconnect::ReconnectPolicy;
+use crate::router::MessageRouter;
+use ironfix_core::error::SessionError;
 use ironfix_session::config::SessionConfig;
 use std::sync::Arc;
 use std::time::Duration;
@@ -26,8 +32,14 @@ pub struct EngineBuilder<A: Application = NoOpApplication> {
     connect_timeout: Duration,
     /// Reconnect interval.
     reconnect_interval: Duration,
+    /// Upper bound on the reconnect interval after backoff.
+    max_reconnect_interval: Duration,
     /// Maximum reconnect attempts.
     max_reconnect_attempts: u32,
+    /// Optional message and event log shared by all sessions.
+    message_logger: Option<Arc<dyn MessageLogger>>,
+    /// Optional typed message handlers shared by all sessions.
+    router: Option<Arc<MessageRouter>>,
 }
 
 impl Default for EngineBuilder<NoOpApplication> {
@@ -46,7 +58,10 @@ impl EngineBuilder<NoOpApplication> {
             use_tls: false,
             connect_timeout: Duration::from_secs(30),
             reconnect_interval: Duration::from_secs(5),
+            max_reconnect_interval: Duration::from_secs(60),
             max_reconnect_attempts: 10,
+            message_logger: None,
+            router: None,
         }
     }
 }
@@ -61,7 +76,10 @@ impl<A: Application> EngineBuilder<A> {
             use_tls: self.use_tls,
             connect_timeout: self.connect_timeout,
             reconnect_interval: self.reconnect_interval,
+            max_reconnect_interval: self.max_reconnect_interval,
             max_reconnect_attempts: self.max_reconnect_attempts,
+            message_logger: self.message_logger,
+            router: self.router,
         }
     }
 
@@ -73,6 +91,9 @@ impl<A: Application> EngineBuilder<A> {
     }
 
     /// Enables TLS for connections.
+    ///
+    /// TLS is not implemented yet, so building an engine with it enabled
+    /// fails instead of silently connecting in plaintext.
     #[must_use]
     pub const fn with_tls(mut self, enabled: bool) -> Self {
         self.use_tls = enabled;
@@ -87,12 +108,22 @@ impl<A: Application> EngineBuilder<A> {
     }
 
     /// Sets the reconnect interval.
+    ///
+    /// This is the delay before the first reconnect attempt; later attempts
+    /// double it up to the maximum reconnect interval.
     #[must_use]
     pub fn with_reconnect_interval(mut self, interval: Duration) -> Self {
         self.reconnect_interval = interval;
         self
     }
 
+    /// Sets the upper bound on the reconnect interval after backoff.
+    #[must_use]
+    pub fn with_max_reconnect_interval(mut self, interval: Duration) -> Self {
+        self.max_reconnect_interval = interval;
+        self
+    }
+
     /// Sets the maximum reconnect attempts.
     #[must_use]
     pub const fn with_max_reconnect_attempts(mut self, attempts: u32) -> Self {
@@ -100,6 +131,22 @@ impl<A: Application> EngineBuilder<A> {
         self
     }
 
+    /// Sets the logger receiving every session's messages and events.
+    #[must_use]
+    pub fn with_message_logger(mut self, logger: Arc<dyn MessageLogger>) -> Self {
+        self.message_logger = Some(logger);
+        self
+    }
+
+    /// Sets the typed handlers receiving messages accepted by the application.
+    ///
+    /// See [`SessionRuntime::with_router`](crate::runtime::SessionRuntime::with_router).
+    #[must_use]
+    pub fn with_router(mut self, router: MessageRouter) -> Self {
+        self.router = Some(Arc::new(router));
+        self
+    }
+
     /// Returns the configured sessions.
     #[must_use]
     pub fn sessions(&self) -> &[SessionConfig] {
@@ -124,17 +171,105 @@ impl<A: Application> EngineBuilder<A> {
         self.reconnect_interval
     }
 
+    /// Returns the upper bound on the reconnect interval after backoff.
+    #[must_use]
+    pub const fn max_reconnect_interval(&self) -> Duration {
+        self.max_reconnect_interval
+    }
+
     /// Returns the maximum reconnect attempts.
     #[must_use]
     pub const fn max_reconnect_attempts(&self) -> u32 {
         self.max_reconnect_attempts
     }
 
+    /// Returns the reconnect schedule built from the reconnect settings.
+    #[must_use]
+    pub const fn reconnect_policy(&self) -> ReconnectPolicy {
+        ReconnectPolicy::new(
+            self.reconnect_interval,
+            self.max_reconnect_interval,
+            self.max_reconnect_attempts,
+        )
+    }
+
     /// Returns the application handler.
     #[must_use]
     pub fn application(&self) -> Arc<A> {
         Arc::clone(&self.application)
     }
+
+    /// Builds an engine running an initiator for every configured session.
+    ///
+    /// # Arguments
+    /// * `addr` - The counterparty address, e.g. `"127.0.0.1:9876"`
+    ///
+    /// # Errors
+    /// Returns `SessionError::Configuration` if TLS is enabled or a session
+    /// configuration is invalid.
+    pub fn build(mut self, addr: impl Into<String>) -> Result<Engine<A>, SessionError> {
+        self.check_tls()?;
+        for config in &self.sessions {
+            config.validate()?;
+        }
+
+        let addr = addr.into();
+        let initiators = std::mem::take(&mut self.sessions)
+            .into_iter()
+            .map(|config| {
+                self.configure(Initiator::new(
+                    Arc::clone(&self.application),
+                    config,
+                    addr.clone(),
+                ))
+            })
+            .collect();
+        Ok(Engine::new(initiators))
+    }
+
+    /// Builds an initiator for the first configured session.
+    ///
+    /// # Arguments
+    /// * `addr` - The counterparty address, e.g. `"127.0.0.1:9876"`
+    ///
+    /// # Errors
+    /// Returns `SessionError::Configuration` if TLS is enabled, no session was
+    /// added or its configuration is invalid.
+    pub fn build_initiator(self, addr: impl Into<String>) -> Result<Initiator<A>, SessionError> {
+        self.check_tls()?;
+        let config = self
+            .sessions
+            .first()
+            .cloned()
+            .ok_or_else(|| SessionError::Configuration("no session configured".to_string()))?;
+        config.validate()?;
+
+        Ok(self.configure(Initiator::new(Arc::clone(&self.application), config, addr)))
+    }
+
+    /// Rejects TLS, which the runtime does not implement yet.
+    fn check_tls(&self) -> Result<(), SessionError> {
+        if self.use_tls {
+            return Err(SessionError::Configuration(
+                "TLS is not supported".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Applies the engine-wide settings to an initiator.
+    fn configure(&self, initiator: Initiator<A>) -> Initiator<A> {
+        let mut initiator = initiator
+            .with_connect_timeout(self.connect_timeout)
+            .with_reconnect_policy(self.reconnect_policy());
+        if let Some(logger) = &self.message_logger {
+            initiator = initiator.with_message_logger(Arc::clone(logger));
+        }
+        if let Some(router) = &self.router {
+            initiator = initiator.with_router(Arc::clone(router));
+        }
+        initiator
+    }
 }
 
 #[cfg(test)]
@@ -148,6 +283,10 @@ mod tests {
         assert!(!builder.use_tls());
         assert_eq!(builder.connect_timeout(), Duration::from_secs(30));
         assert_eq!(builder.max_reconnect_attempts(), 10);
+        assert_eq!(
+            builder.reconnect_policy(),
+            ReconnectPolicy::new(Duration::from_secs(5), Duration::from_secs(60), 10)
+        );
         assert!(builder.sessions().is_empty());
     }
 
@@ -168,4 +307,37 @@ mod tests {
         assert!(builder.use_tls());
         assert_eq!(builder.connect_timeout(), Duration::from_secs(60));
     }
+
+    #[test]
+    fn test_build_initiator_validates_begin_string() {
+        let config = SessionConfig::new(
+            CompId::new("SENDER").unwrap(),
+            CompId::new("TARGET").unwrap(),
+            "FIX4.4",
+        );
+
+        let result = EngineBuilder::new()
+            .add_session(config.clone())
+            .build_initiator("127.0.0.1:9876");
+        assert!(matches!(result, Err(SessionError::Configuration(_))));
+
+        let result = EngineBuilder::new()
+            .add_session(config)
+            .build("127.0.0.1:9876");
+        assert!(matches!(result, Err(SessionError::Configuration(_))));
+    }
+
+    #[test]
+    fn test_build_rejects_tls() {
+        let config = SessionConfig::new(
+            CompId::new("SENDER").unwrap(),
+            CompId::new("TARGET").unwrap(),
+            "FIX.4.4",
+        );
+        let builder = EngineBuilder::new().add_session(config).with_tls(true);
+        assert!(matches!(
+            builder.build("127.0.0.1:9876"),
+            Err(SessionError::Configuration(_))
+        ));
+    }
 }
@@ -0,0 +1,299 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Message dispatch to application callbacks.
+//!
+//! This module routes received messages to the [`Application`] callbacks and
+//! turns a returned [`RejectReason`](crate::application::RejectReason) into
+//! the reject message to send back. Messages the callbacks queue on a
+//! [`Responder`] are sent back once the message is accepted.
+//!
+//! Outgoing messages are passed through the `to_*` callbacks and reframed if
+//! the application modified them. Accepted messages can then be routed to
+//! typed handlers through a [`MessageRouter`].
+
+use crate::application::{Application, RejectReason, Responder, SessionId};
+use crate::router::MessageRouter;
+use ironfix_core::error::EncodeError;
+use ironfix_core::message::{OwnedMessage, RawMessage};
+use tracing::debug;
+
+/// Delivers a received message to the application.
+///
+/// Administrative messages go to [`Application::from_admin`] and are answered
+/// with a session-level Reject (MsgType=3) when rejected. Application messages
+/// go to [`Application::from_app`] and are answered with a
+/// BusinessMessageReject (MsgType=j), referencing the ClOrdID (tag 11) when the
+/// rejected message carries one.
+///
+/// # Arguments
+/// * `application` - The application callbacks
+/// * `message` - The received message
+/// * `session_id` - The session the message was received on
+/// * `responder` - Collects the messages the callback sends in response
+///
+/// # Returns
+/// The reject message to send to the counterparty, or `None` if the message
+/// was accepted.
+pub async fn deliver<A: Application + ?Sized>(
+    application: &A,
+    message: &RawMessage<'_>,
+    session_id: &SessionId,
+    responder: &Responder,
+) -> Option<OwnedMessage> {
+    let reason = if message.msg_type().is_admin() {
+        application
+            .from_admin(message, session_id, responder)
+            .await
+            .err()?
+    } else {
+        application
+            .from_app(message, session_id, responder)
+            .await
+            .err()?
+    };
+    Some(reject_message(&reason, message, session_id))
+}
+
+/// Passes a received message to the router's handler for its type.
+///
+/// Rejections are answered like in [`deliver`]: with a session-level Reject
+/// for administrative messages and a BusinessMessageReject otherwise.
+///
+/// # Arguments
+/// * `router` - The message handlers
+/// * `message` - The received message
+/// * `session_id` - The session the message was received on
+///
+/// # Returns
+/// The reject message to send to the counterparty, or `None` if the message
+/// was accepted or no handler is registered for its type.
+pub async fn route(
+    router: &MessageRouter,
+    message: &RawMessage<'_>,
+    session_id: &SessionId,
+) -> Option<OwnedMessage> {
+    let reason = router.dispatch(message, session_id).await?.err()?;
+    Some(reject_message(&reason, message, session_id))
+}
+
+/// Builds the reject answering a rejected message.
+fn reject_message(
+    reason: &RejectReason,
+    message: &RawMessage<'_>,
+    session_id: &SessionId,
+) -> OwnedMessage {
+    let ref_seq_num = message.get_field_as::<u64>(34).unwrap_or(0);
+    let msg_type = message.msg_type();
+
+    if msg_type.is_admin() {
+        debug!(%session_id, %msg_type, code = reason.code, "admin message rejected");
+        reason.to_session_reject(ref_seq_num, msg_type)
+    } else {
+        debug!(%session_id, %msg_type, code = reason.code, "application message rejected");
+        reason.to_business_reject(ref_seq_num, msg_type, message.get_field_str(11))
+    }
+}
+
+/// Passes an outgoing message to the application before it is sent.
+///
+/// Administrative messages go to [`Application::to_admin`] and application
+/// messages to [`Application::to_app`]. If the callback modified the message,
+/// its BodyLength and CheckSum are recomputed so it is ready for the wire.
+///
+/// # Arguments
+/// * `application` - The application callbacks
+/// * `message` - The outgoing message
+/// * `session_id` - The session the message is sent on
+///
+/// # Errors
+/// Returns an error if the modified message cannot be reframed.
+pub async fn prepare_outbound<A: Application + ?Sized>(
+    application: &A,
+    message: &mut OwnedMessage,
+    session_id: &SessionId,
+) -> Result<(), EncodeError> {
+    if message.msg_type().is_admin() {
+        application.to_admin(message, session_id).await;
+    } else {
+        application.to_app(message, session_id).await;
+    }
+
+    if message.needs_reframe() {
+        message.reframe()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::{NoOpApplication, RejectReason};
+    use crate::reject::BusinessRejectReason;
+    use async_trait::async_trait;
+    use ironfix_core::message::{MessageBuilder, MsgType};
+    use ironfix_tagvalue::{Decoder, Encoder};
+
+    /// Rejects logons with a bad password and orders for unknown symbols, and
+    /// stamps an Account on outgoing application messages.
+    struct StrictApplication;
+
+    #[async_trait]
+    impl Application for StrictApplication {
+        async fn on_create(&self, _session_id: &SessionId) {}
+
+        async fn on_logon(&self, _session_id: &SessionId) {}
+
+        async fn on_logout(&self, _session_id: &SessionId) {}
+
+        async fn to_admin(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+        async fn from_admin(
+            &self,
+            message: &RawMessage<'_>,
+            _session_id: &SessionId,
+            _responder: &Responder,
+        ) -> Result<(), RejectReason> {
+            match message.get_field_str(554) {
+                Some("secret") | None => Ok(()),
+                Some(_) => Err(RejectReason::new(5, "Bad password").with_ref_tag(554)),
+            }
+        }
+
+        async fn to_app(&self, message: &mut OwnedMessage, _session_id: &SessionId) {
+            message.set_field(1, b"ACCT-7");
+        }
+
+        async fn from_app(
+            &self,
+            message: &RawMessage<'_>,
+            _session_id: &SessionId,
+            responder: &Responder,
+        ) -> Result<(), RejectReason> {
+            match message.get_field_str(55) {
+                Some("AAPL") => {
+                    let mut ack = MessageBuilder::new("FIX.4.4", MsgType::ExecutionReport);
+                    if let Some(cl_ord_id) = message.get_field(11) {
+                        ack = ack.field(11, cl_ord_id.value);
+                    }
+                    responder.send(ack.build());
+                    Ok(())
+                }
+                _ => Err(RejectReason::business(
+                    BusinessRejectReason::UnknownSecurity,
+                    "Unknown symbol",
+                )),
+            }
+        }
+    }
+
+    fn encode(fields: &[(u32, &str)]) -> Vec<u8> {
+        let mut encoder = Encoder::new("FIX.4.4");
+        for (tag, value) in fields {
+            encoder.put_str(*tag, value);
+        }
+        encoder.finish().to_vec()
+    }
+
+    #[tokio::test]
+    async fn test_deliver_accepted() {
+        let session_id = SessionId::new("FIX.4.4", "SENDER", "TARGET");
+        let bytes = encode(&[(35, "D"), (34, "2"), (11, "ORD-1"), (55, "AAPL")]);
+        let message = Decoder::new(&bytes).decode().unwrap();
+
+        assert!(
+            deliver(&StrictApplication, &message, &session_id, &Responder::new())
+                .await
+                .is_none()
+        );
+        assert!(
+            deliver(&NoOpApplication, &message, &session_id, &Responder::new())
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver_collects_responses() {
+        let session_id = SessionId::new("FIX.4.4", "SENDER", "TARGET");
+        let bytes = encode(&[(35, "D"), (34, "2"), (11, "ORD-1"), (55, "AAPL")]);
+        let message = Decoder::new(&bytes).decode().unwrap();
+        let responder = Responder::new();
+
+        assert!(
+            deliver(&StrictApplication, &message, &session_id, &responder)
+                .await
+                .is_none()
+        );
+        let responses = responder.into_messages();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].msg_type(), &MsgType::ExecutionReport);
+        assert_eq!(responses[0].get_field_str(11), Some("ORD-1"));
+    }
+
+    #[tokio::test]
+    async fn test_deliver_app_reject_produces_business_reject() {
+        let session_id = SessionId::new("FIX.4.4", "SENDER", "TARGET");
+        let bytes = encode(&[(35, "D"), (34, "7"), (11, "ORD-1"), (55, "ZZZZ")]);
+        let message = Decoder::new(&bytes).decode().unwrap();
+
+        let reject = deliver(&StrictApplication, &message, &session_id, &Responder::new())
+            .await
+            .unwrap();
+        assert_eq!(reject.msg_type(), &MsgType::BusinessMessageReject);
+        assert_eq!(reject.get_field_str(45), Some("7"));
+        assert_eq!(reject.get_field_str(372), Some("D"));
+        assert_eq!(reject.get_field_str(379), Some("ORD-1"));
+        assert_eq!(reject.get_field_str(380), Some("2"));
+        assert_eq!(reject.get_field_str(58), Some("Unknown symbol"));
+    }
+
+    #[tokio::test]
+    async fn test_deliver_admin_reject_produces_session_reject() {
+        let session_id = SessionId::new("FIX.4.4", "SENDER", "TARGET");
+        let bytes = encode(&[(35, "A"), (34, "1"), (554, "wrong")]);
+        let message = Decoder::new(&bytes).decode().unwrap();
+
+        let reject = deliver(&StrictApplication, &message, &session_id, &Responder::new())
+            .await
+            .unwrap();
+        assert_eq!(reject.msg_type(), &MsgType::Reject);
+        assert_eq!(reject.get_field_str(45), Some("1"));
+        assert_eq!(reject.get_field_str(371), Some("554"));
+        assert_eq!(reject.get_field_str(372), Some("A"));
+        assert_eq!(reject.get_field_str(373), Some("5"));
+        assert_eq!(reject.get_field_str(58), Some("Bad password"));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_outbound_reframes_modified_message() {
+        let session_id = SessionId::new("FIX.4.4", "SENDER", "TARGET");
+        let bytes = encode(&[(35, "D"), (34, "3"), (11, "ORD-1"), (55, "AAPL")]);
+        let mut message = Decoder::new(&bytes).decode().unwrap().to_owned();
+
+        prepare_outbound(&StrictApplication, &mut message, &session_id)
+            .await
+            .unwrap();
+        assert!(!message.needs_reframe());
+
+        let wire = Decoder::new(message.as_bytes()).decode().unwrap();
+        assert_eq!(wire.get_field_str(1), Some("ACCT-7"));
+        assert_eq!(wire.get_field_str(11), Some("ORD-1"));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_outbound_leaves_unmodified_message() {
+        let session_id = SessionId::new("FIX.4.4", "SENDER", "TARGET");
+        let bytes = encode(&[(35, "0"), (34, "4")]);
+        let mut message = Decoder::new(&bytes).decode().unwrap().to_owned();
+
+        prepare_outbound(&StrictApplication, &mut message, &session_id)
+            .await
+            .unwrap();
+        assert_eq!(message.as_bytes(), bytes.as_slice());
+        assert_eq!(message.get_field(1), None);
+    }
+}
@@ -0,0 +1,125 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Multi-session engine facade.
+//!
+//! An [`Engine`] owns one [`Initiator`] per configured session and gives
+//! access to each session's runtime state by [`SessionId`]. All sessions
+//! share one shutdown token, so they can be stopped together.
+
+use crate::application::{Application, SessionId};
+use crate::handle::SessionHandle;
+use crate::initiator::Initiator;
+use crate::metrics::SessionMetrics;
+use futures::future::join_all;
+use ironfix_core::error::SessionError;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+/// FIX engine running a set of initiator sessions.
+///
+/// Created by [`EngineBuilder::build`](crate::builder::EngineBuilder::build).
+#[derive(Debug)]
+pub struct Engine<A: Application> {
+    /// One initiator per configured session.
+    initiators: Vec<Initiator<A>>,
+    /// Parent of every session's shutdown token.
+    shutdown: CancellationToken,
+}
+
+impl<A: Application> Engine<A> {
+    /// Creates an engine from its initiators.
+    ///
+    /// Each initiator's shutdown token is replaced by a child of the
+    /// engine's token.
+    ///
+    /// # Arguments
+    /// * `initiators` - The sessions to run
+    #[must_use]
+    pub fn new(initiators: Vec<Initiator<A>>) -> Self {
+        let shutdown = CancellationToken::new();
+        let initiators = initiators
+            .into_iter()
+            .map(|initiator| initiator.with_shutdown_token(shutdown.child_token()))
+            .collect();
+        Self {
+            initiators,
+            shutdown,
+        }
+    }
+
+    /// Returns an iterator over the session identifiers.
+    pub fn sessions(&self) -> impl Iterator<Item = &SessionId> {
+        self.initiators.iter().map(Initiator::session_id)
+    }
+
+    /// Returns the initiator running the given session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    #[must_use]
+    pub fn initiator(&self, session_id: &SessionId) -> Option<&Initiator<A>> {
+        self.initiators
+            .iter()
+            .find(|initiator| initiator.session_id() == session_id)
+    }
+
+    /// Returns a handle to the live state of the given session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    ///
+    /// # Returns
+    /// The session's handle, or `None` if the engine has no such session.
+    #[must_use]
+    pub fn session(&self, session_id: &SessionId) -> Option<SessionHandle> {
+        self.initiator(session_id).map(Initiator::handle)
+    }
+
+    /// Returns the connection and message counters of the given session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    ///
+    /// # Returns
+    /// The session's metrics, or `None` if the engine has no such session.
+    #[must_use]
+    pub fn metrics(&self, session_id: &SessionId) -> Option<Arc<SessionMetrics>> {
+        self.initiator(session_id)
+            .map(|initiator| Arc::clone(initiator.metrics()))
+    }
+
+    /// Returns the token that shuts down every session when cancelled.
+    ///
+    /// Cancelling it, e.g. from a signal handler, has the same effect as
+    /// [`shutdown`](Self::shutdown) without waiting for the sessions to stop.
+    #[must_use]
+    pub const fn shutdown_token(&self) -> &CancellationToken {
+        &self.shutdown
+    }
+
+    /// Logs out every active session and waits for all of them to stop.
+    ///
+    /// Each session sends Logout, waits up to its `logout_timeout` for the
+    /// counterparty's Logout, closes its connection and flushes its message
+    /// store; [`run`](Self::run) then returns `Ok`.
+    pub async fn shutdown(&self) {
+        self.shutdown.cancel();
+        join_all(self.initiators.iter().map(Initiator::shutdown)).await;
+    }
+
+    /// Runs every session concurrently until all of them stop.
+    ///
+    /// # Errors
+    /// Returns the first error of a session that stopped with one, after all
+    /// sessions have stopped.
+    pub async fn run(&self) -> Result<(), SessionError> {
+        join_all(self.initiators.iter().map(Initiator::run))
+            .await
+            .into_iter()
+            .collect()
+    }
+}
@@ -0,0 +1,169 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Per-session runtime handle.
+//!
+//! A [`SessionHandle`] exposes the live state of a running session (logon
+//! status, last activity and sequence numbers) through atomics, so it can be
+//! cloned and polled from any thread without locking the session.
+
+use crate::application::SessionId;
+use ironfix_core::types::{SeqNum, Timestamp};
+use ironfix_session::sequence::SequenceManager;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Shared state behind every clone of a [`SessionHandle`].
+#[derive(Debug)]
+struct SessionState {
+    /// The session identifier.
+    session_id: SessionId,
+    /// The session's sequence numbers, shared with the runtime.
+    sequences: Arc<SequenceManager>,
+    /// Whether the session is currently logged on.
+    logged_on: AtomicBool,
+    /// Nanoseconds since the Unix epoch of the last send, or 0 if none.
+    last_sent: AtomicU64,
+    /// Nanoseconds since the Unix epoch of the last receive, or 0 if none.
+    last_received: AtomicU64,
+}
+
+/// Cloneable view of a running session's state.
+///
+/// Obtained from [`Initiator::handle`](crate::initiator::Initiator::handle)
+/// or [`Engine::session`](crate::engine::Engine::session). Every clone sees
+/// the same state, which the session runtime updates as it sends and
+/// receives.
+#[derive(Debug, Clone)]
+pub struct SessionHandle {
+    state: Arc<SessionState>,
+}
+
+impl SessionHandle {
+    /// Creates a handle for a session that is not logged on.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    /// * `sequences` - The session's sequence numbers
+    pub(crate) fn new(session_id: SessionId, sequences: Arc<SequenceManager>) -> Self {
+        Self {
+            state: Arc::new(SessionState {
+                session_id,
+                sequences,
+                logged_on: AtomicBool::new(false),
+                last_sent: AtomicU64::new(0),
+                last_received: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Returns the session identifier.
+    #[must_use]
+    pub fn session_id(&self) -> &SessionId {
+        &self.state.session_id
+    }
+
+    /// Returns true between a completed logon and the end of the session.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.state.logged_on.load(Ordering::Acquire)
+    }
+
+    /// Returns when the last message was written to the counterparty.
+    ///
+    /// # Returns
+    /// `None` if nothing has been sent yet.
+    #[must_use]
+    pub fn last_sent(&self) -> Option<Timestamp> {
+        load_timestamp(&self.state.last_sent)
+    }
+
+    /// Returns when the last message was received from the counterparty.
+    ///
+    /// # Returns
+    /// `None` if nothing has been received yet.
+    #[must_use]
+    pub fn last_received(&self) -> Option<Timestamp> {
+        load_timestamp(&self.state.last_received)
+    }
+
+    /// Returns the MsgSeqNum the next outgoing message will carry.
+    #[must_use]
+    pub fn next_sender_seq(&self) -> SeqNum {
+        self.state.sequences.next_sender_seq()
+    }
+
+    /// Returns the MsgSeqNum expected on the next incoming message.
+    #[must_use]
+    pub fn next_target_seq(&self) -> SeqNum {
+        self.state.sequences.next_target_seq()
+    }
+
+    /// Records whether the session is logged on.
+    pub(crate) fn set_logged_on(&self, logged_on: bool) {
+        self.state.logged_on.store(logged_on, Ordering::Release);
+    }
+
+    /// Records a message written to the counterparty.
+    ///
+    /// # Arguments
+    /// * `at` - When the message was sent, from the session's clock
+    pub(crate) fn record_sent(&self, at: Timestamp) {
+        store_timestamp(&self.state.last_sent, at);
+    }
+
+    /// Records a message received from the counterparty.
+    ///
+    /// # Arguments
+    /// * `at` - When the message was received, from the session's clock
+    pub(crate) fn record_received(&self, at: Timestamp) {
+        store_timestamp(&self.state.last_received, at);
+    }
+}
+
+/// Stores a time, in nanoseconds since the Unix epoch.
+fn store_timestamp(slot: &AtomicU64, at: Timestamp) {
+    // Clamp to 1 so a clock at the epoch still reads as "some activity".
+    slot.store(at.as_nanos().max(1), Ordering::Relaxed);
+}
+
+/// Loads a timestamp stored by [`store_timestamp`].
+fn load_timestamp(slot: &AtomicU64) -> Option<Timestamp> {
+    match slot.load(Ordering::Relaxed) {
+        0 => None,
+        nanos => Some(Timestamp::from_nanos(nanos)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_handle_tracks_state() {
+        let sequences = Arc::new(SequenceManager::new());
+        let handle = SessionHandle::new(
+            SessionId::new("FIX.4.4", "SENDER", "TARGET"),
+            Arc::clone(&sequences),
+        );
+        let clone = handle.clone();
+
+        assert!(!handle.is_logged_on());
+        assert_eq!(handle.last_sent(), None);
+        assert_eq!(handle.last_received(), None);
+        assert_eq!(handle.next_sender_seq().value(), 1);
+
+        sequences.allocate_sender_seq();
+        handle.set_logged_on(true);
+        handle.record_sent(Timestamp::from_nanos(42));
+
+        assert!(clone.is_logged_on());
+        assert_eq!(clone.last_sent(), Some(Timestamp::from_nanos(42)));
+        assert_eq!(clone.last_received(), None);
+        assert_eq!(clone.next_sender_seq().value(), 2);
+        assert_eq!(clone.session_id().sender_comp_id, "SENDER");
+    }
+}
@@ -0,0 +1,275 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Client-side session connector.
+//!
+//! An [`Initiator`] connects to a counterparty, logs on and runs the session
+//! on a [`SessionRuntime`], reconnecting with exponential backoff when the
+//! connection drops. Cancelling its shutdown token logs the session out and
+//! stops reconnecting.
+
+use crate::application::{Application, SessionId};
+use crate::handle::SessionHandle;
+use crate::logger::MessageLogger;
+use crate::metrics::SessionMetrics;
+use crate::reconnect::{Backoff, ReconnectPolicy};
+use crate::router::MessageRouter;
+use crate::runtime::{Connection, SessionRuntime, connection_error};
+use ironfix_core::error::SessionError;
+use ironfix_session::clock::Clock;
+use ironfix_session::config::SessionConfig;
+use ironfix_session::sequence::SequenceManager;
+use ironfix_store::MessageStore;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::{sleep, timeout};
+use tokio_util::codec::Framed;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+/// Client-side FIX session.
+///
+/// Runs a single session against one counterparty address. Sequence numbers
+/// persist across reconnects unless the session configuration resets them.
+pub struct Initiator<A: Application> {
+    /// The session's runtime.
+    runtime: SessionRuntime<A>,
+    /// Counterparty address.
+    addr: String,
+    /// Timeout for establishing the TCP connection.
+    connect_timeout: Duration,
+    /// Reconnect schedule.
+    reconnect: ReconnectPolicy,
+}
+
+impl<A: Application> std::fmt::Debug for Initiator<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Initiator")
+            .field("session_id", self.runtime.session_id())
+            .field("addr", &self.addr)
+            .field("reconnect", &self.reconnect)
+            .finish()
+    }
+}
+
+impl<A: Application> Initiator<A> {
+    /// Creates a new initiator.
+    ///
+    /// # Arguments
+    /// * `application` - The application callbacks
+    /// * `config` - The session configuration
+    /// * `addr` - The counterparty address, e.g. `"127.0.0.1:9876"`
+    #[must_use]
+    pub fn new(application: Arc<A>, config: SessionConfig, addr: impl Into<String>) -> Self {
+        Self {
+            runtime: SessionRuntime::new(application, config),
+            addr: addr.into(),
+            connect_timeout: Duration::from_secs(30),
+            reconnect: ReconnectPolicy::default(),
+        }
+    }
+
+    /// Sets the timeout for establishing the TCP connection.
+    #[must_use]
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the reconnect schedule.
+    #[must_use]
+    pub const fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    /// Sets the store keeping sent messages for resending.
+    ///
+    /// See [`SessionRuntime::with_message_store`].
+    #[must_use]
+    pub fn with_message_store(self, store: Arc<dyn MessageStore>) -> Self {
+        self.map_runtime(|runtime| runtime.with_message_store(store))
+    }
+
+    /// Sets the logger receiving every message and session event.
+    #[must_use]
+    pub fn with_message_logger(self, logger: Arc<dyn MessageLogger>) -> Self {
+        self.map_runtime(|runtime| runtime.with_message_logger(logger))
+    }
+
+    /// Sets the handlers receiving messages accepted by the application.
+    ///
+    /// See [`SessionRuntime::with_router`].
+    #[must_use]
+    pub fn with_router(self, router: Arc<MessageRouter>) -> Self {
+        self.map_runtime(|runtime| runtime.with_router(router))
+    }
+
+    /// Sets the token that requests a graceful shutdown when cancelled.
+    ///
+    /// # Arguments
+    /// * `token` - The shutdown token, e.g. a child of an engine-wide token
+    #[must_use]
+    pub fn with_shutdown_token(self, token: CancellationToken) -> Self {
+        self.map_runtime(|runtime| runtime.with_shutdown_token(token))
+    }
+
+    /// Sets the source of heartbeat timing and SendingTime (tag 52).
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a `MockClock` in tests
+    #[must_use]
+    pub fn with_clock(self, clock: Arc<dyn Clock>) -> Self {
+        self.map_runtime(|runtime| runtime.with_clock(clock))
+    }
+
+    /// Applies a builder method to the runtime.
+    fn map_runtime(mut self, f: impl FnOnce(SessionRuntime<A>) -> SessionRuntime<A>) -> Self {
+        self.runtime = f(self.runtime);
+        self
+    }
+
+    /// Returns the session's runtime.
+    #[must_use]
+    pub const fn runtime(&self) -> &SessionRuntime<A> {
+        &self.runtime
+    }
+
+    /// Returns the session identifier.
+    #[must_use]
+    pub const fn session_id(&self) -> &SessionId {
+        self.runtime.session_id()
+    }
+
+    /// Returns the session sequence numbers.
+    #[must_use]
+    pub fn sequences(&self) -> &SequenceManager {
+        self.runtime.sequences()
+    }
+
+    /// Returns a handle to the session's live state.
+    ///
+    /// The handle can be cloned and polled from any thread while the session
+    /// runs.
+    #[must_use]
+    pub fn handle(&self) -> SessionHandle {
+        self.runtime.handle()
+    }
+
+    /// Returns the session's connection and message counters.
+    #[must_use]
+    pub const fn metrics(&self) -> &Arc<SessionMetrics> {
+        self.runtime.metrics()
+    }
+
+    /// Returns the token that requests a graceful shutdown when cancelled.
+    #[must_use]
+    pub const fn shutdown_token(&self) -> &CancellationToken {
+        self.runtime.shutdown_token()
+    }
+
+    /// Logs the session out and waits for [`run`](Self::run) to return.
+    ///
+    /// See [`SessionRuntime::shutdown`].
+    pub async fn shutdown(&self) {
+        self.runtime.shutdown().await;
+    }
+
+    /// Runs the session, reconnecting whenever the connection drops.
+    ///
+    /// After a failed connect or logon, or a dropped session, the next
+    /// attempt waits according to the [`ReconnectPolicy`]. The attempt counter
+    /// is reset after every successful logon. [`Application::on_logout`] fires
+    /// each time a logged-on session ends.
+    ///
+    /// Returns `Ok` once a shutdown is requested through the shutdown token
+    /// or [`shutdown`](Self::shutdown).
+    ///
+    /// # Errors
+    /// Returns `SessionError::Connection` once the reconnect attempts are
+    /// exhausted.
+    pub async fn run(&self) -> Result<(), SessionError> {
+        let _running = self.runtime.start().await;
+        let result = self.reconnect_loop().await;
+        self.runtime.flush_store().await;
+        result
+    }
+
+    /// Connects and runs the session until a shutdown is requested or the
+    /// reconnect attempts are exhausted.
+    async fn reconnect_loop(&self) -> Result<(), SessionError> {
+        let runtime = &self.runtime;
+        let shutdown = runtime.shutdown_token();
+        let mut backoff = Backoff::new(self.reconnect);
+
+        loop {
+            let established = tokio::select! {
+                _ = shutdown.cancelled() => return Ok(()),
+                established = self.establish() => established,
+            };
+            match established {
+                Ok(mut connection) => {
+                    backoff.reset();
+                    runtime.run_logged_on(&mut connection).await;
+                }
+                Err(e) => {
+                    warn!(session_id = %self.session_id(), error = %e, "session not established");
+                    runtime.log_event(&format!("Session not established: {e}"));
+                }
+            }
+
+            if shutdown.is_cancelled() {
+                return Ok(());
+            }
+            if runtime.config().reset_on_disconnect {
+                runtime.reset_sequences().await;
+            }
+
+            let Some(delay) = backoff.next_delay() else {
+                return Err(SessionError::Connection(format!(
+                    "giving up after {} reconnect attempts",
+                    backoff.attempts()
+                )));
+            };
+            debug!(
+                session_id = %self.session_id(),
+                attempt = backoff.attempts(),
+                delay_ms = delay.as_millis() as u64,
+                "reconnecting"
+            );
+            tokio::select! {
+                _ = shutdown.cancelled() => return Ok(()),
+                _ = sleep(delay) => {}
+            }
+        }
+    }
+
+    /// Connects and logs on.
+    async fn establish(&self) -> Result<Connection, SessionError> {
+        let mut connection = self.connect().await?;
+        self.runtime.logon(&mut connection).await?;
+        Ok(connection)
+    }
+
+    /// Opens the TCP connection.
+    async fn connect(&self) -> Result<Connection, SessionError> {
+        let stream = timeout(self.connect_timeout, TcpStream::connect(&self.addr))
+            .await
+            .map_err(|_| {
+                SessionError::Connection(format!("timed out connecting to {}", self.addr))
+            })?
+            .map_err(connection_error)?;
+        self.runtime.metrics().record_connection();
+        self.runtime
+            .log_event(&format!("Connected to {}", self.addr));
+
+        Ok(self.runtime.connection(
+            Framed::new(stream, self.runtime.codec()),
+            self.runtime.config().heartbeat_interval,
+        ))
+    }
+}
@@ -11,11 +11,41 @@
 //! This crate provides:
 //! - **Initiator**: Client-side FIX engine for connecting to counterparties
 //! - **Acceptor**: Server-side FIX engine for accepting connections
+//! - **Session runtime**: Session state and message loop shared by both
 //! - **Application trait**: Callback interface for handling FIX messages
+//! - **Routing**: Message-type dispatch to typed handlers
 //! - **Builder API**: Fluent configuration for engine setup
+//! - **Session handles**: Lock-free view of each session's logon state and activity
+//! - **Metrics**: Per-session connection and message counters
+//! - **Logging**: QuickFIX-style per-session message and event logs
+//! - **Recovery**: SequenceReset messages for answering ResendRequests
 
+pub mod acceptor;
 pub mod application;
 pub mod builder;
+pub mod dispatch;
+pub mod engine;
+pub mod handle;
+pub mod initiator;
+pub mod logger;
+pub mod metrics;
+pub mod reconnect;
+pub mod recovery;
+pub mod reject;
+pub mod router;
+pub mod runtime;
 
-pub use application::Application;
+pub use acceptor::Acceptor;
+pub use application::{Application, Responder, SessionId};
 pub use builder::EngineBuilder;
+pub use dispatch::{deliver, prepare_outbound, route};
+pub use engine::Engine;
+pub use handle::SessionHandle;
+pub use initiator::Initiator;
+pub use logger::{FileMessageLogger, MessageLogger};
+pub use metrics::{MetricsSnapshot, SessionMetrics};
+pub use reconnect::{Backoff, ReconnectPolicy};
+pub use recovery::{ResendItem, build_resend, build_sequence_reset};
+pub use reject::{BusinessRejectReason, build_business_reject, build_session_reject};
+pub use router::MessageRouter;
+pub use runtime::SessionRuntime;
@@ -0,0 +1,203 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Message and event logging.
+//!
+//! This module defines the [`MessageLogger`] trait called by the session
+//! runtime for every message and session event, and a [`FileMessageLogger`]
+//! writing QuickFIX-style per-session log files.
+
+use crate::application::SessionId;
+use ironfix_core::types::Timestamp;
+use ironfix_session::clock::{Clock, system_clock};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+/// Sink for a session's messages and events.
+///
+/// Implementations must not block for long, as they are called on the
+/// session's send and receive paths.
+pub trait MessageLogger: Send + Sync + std::fmt::Debug {
+    /// Called with every message received from the counterparty.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    /// * `message` - The framed message bytes
+    fn on_incoming(&self, session_id: &SessionId, message: &[u8]);
+
+    /// Called with every message written to the counterparty.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    /// * `message` - The encoded message bytes
+    fn on_outgoing(&self, session_id: &SessionId, message: &[u8]);
+
+    /// Called on session events such as connect, logon and disconnect.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    /// * `text` - Description of the event
+    fn on_event(&self, session_id: &SessionId, text: &str);
+}
+
+/// Open log files of one session.
+#[derive(Debug)]
+struct SessionLogFiles {
+    /// Inbound and outbound messages.
+    messages: File,
+    /// Session events.
+    events: File,
+}
+
+/// Logger writing per-session message and event log files.
+///
+/// For each session, messages are appended to
+/// `<dir>/<session>.messages.log` and events to `<dir>/<session>.event.log`,
+/// where `<session>` is the [`SessionId::key`]. Each line
+/// is prefixed with a UTC timestamp. Files are opened on first use; write
+/// failures are reported through `tracing` and do not affect the session.
+#[derive(Debug)]
+pub struct FileMessageLogger {
+    /// Directory containing the log files.
+    dir: PathBuf,
+    /// Open files by session.
+    files: Mutex<HashMap<SessionId, SessionLogFiles>>,
+    /// Source of the line timestamps.
+    clock: Arc<dyn Clock>,
+}
+
+impl FileMessageLogger {
+    /// Creates a logger writing into the given directory.
+    ///
+    /// # Arguments
+    /// * `dir` - The log directory; created if missing
+    ///
+    /// # Errors
+    /// Returns an error if the directory cannot be created.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            files: Mutex::new(HashMap::new()),
+            clock: system_clock(),
+        })
+    }
+
+    /// Sets the clock timestamping each line.
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a `MockClock` in tests
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the log directory.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the path of a session's message log.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    #[must_use]
+    pub fn messages_path(&self, session_id: &SessionId) -> PathBuf {
+        self.dir
+            .join(format!("{}.messages.log", file_stem(session_id)))
+    }
+
+    /// Returns the path of a session's event log.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session identifier
+    #[must_use]
+    pub fn event_path(&self, session_id: &SessionId) -> PathBuf {
+        self.dir
+            .join(format!("{}.event.log", file_stem(session_id)))
+    }
+
+    /// Appends a timestamped line to one of a session's log files.
+    fn write(
+        &self,
+        session_id: &SessionId,
+        select: impl FnOnce(&mut SessionLogFiles) -> &mut File,
+        line: &[u8],
+    ) {
+        let now = self.clock.timestamp();
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        let result = match files.get_mut(session_id) {
+            Some(session_files) => write_line(select(session_files), now, line),
+            None => self.open(session_id).and_then(|mut session_files| {
+                let result = write_line(select(&mut session_files), now, line);
+                files.insert(session_id.clone(), session_files);
+                result
+            }),
+        };
+        if let Err(e) = result {
+            warn!(session_id = %session_id, error = %e, "failed to write FIX log");
+        }
+    }
+
+    /// Opens a session's log files in append mode.
+    fn open(&self, session_id: &SessionId) -> io::Result<SessionLogFiles> {
+        let append = |path: PathBuf| OpenOptions::new().create(true).append(true).open(path);
+        Ok(SessionLogFiles {
+            messages: append(self.messages_path(session_id))?,
+            events: append(self.event_path(session_id))?,
+        })
+    }
+}
+
+impl MessageLogger for FileMessageLogger {
+    fn on_incoming(&self, session_id: &SessionId, message: &[u8]) {
+        self.write(session_id, |files| &mut files.messages, message);
+    }
+
+    fn on_outgoing(&self, session_id: &SessionId, message: &[u8]) {
+        self.write(session_id, |files| &mut files.messages, message);
+    }
+
+    fn on_event(&self, session_id: &SessionId, text: &str) {
+        self.write(session_id, |files| &mut files.events, text.as_bytes());
+    }
+}
+
+/// Returns the file name prefix for a session's logs.
+///
+/// Only ASCII letters, digits, `.`, `_` and `-` are kept from the session
+/// key; every other byte is written as `%XX`. The prefix therefore never
+/// contains a path separator, and because the key always starts with the
+/// BeginString followed by `-` it is never `.` or `..`.
+fn file_stem(session_id: &SessionId) -> String {
+    let key = session_id.key();
+    let mut stem = String::with_capacity(key.len());
+    for &byte in key.as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-') {
+            stem.push(char::from(byte));
+        } else {
+            stem.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    stem
+}
+
+/// Writes `<timestamp> : <line>` followed by a newline.
+fn write_line(file: &mut File, timestamp: Timestamp, line: &[u8]) -> io::Result<()> {
+    let mut buf = Vec::with_capacity(line.len() + 32);
+    buf.extend_from_slice(timestamp.format_millis().as_bytes());
+    buf.extend_from_slice(b" : ");
+    buf.extend_from_slice(line);
+    buf.push(b'\n');
+    file.write_all(&buf)
+}
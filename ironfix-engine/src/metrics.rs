@@ -0,0 +1,218 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Per-session connection and message metrics.
+//!
+//! [`SessionMetrics`] holds lock-free counters updated by the session runtime
+//! on every send and receive, and can be read from any thread.
+
+use ironfix_core::message::MsgType;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Connection and message counters for one session.
+#[derive(Debug, Default)]
+pub struct SessionMetrics {
+    /// TCP connections established.
+    connections: AtomicU64,
+    /// Successful logons.
+    logons: AtomicU64,
+    /// Messages written to the counterparty.
+    messages_sent: AtomicU64,
+    /// Messages received from the counterparty.
+    messages_received: AtomicU64,
+    /// Bytes written to the counterparty.
+    bytes_sent: AtomicU64,
+    /// Bytes received from the counterparty.
+    bytes_received: AtomicU64,
+    /// ResendRequest messages sent.
+    resend_requests_sent: AtomicU64,
+    /// ResendRequest messages received.
+    resend_requests_received: AtomicU64,
+    /// MsgSeqNum of the last message sent.
+    last_sent_seq_num: AtomicU64,
+    /// MsgSeqNum of the last message received.
+    last_received_seq_num: AtomicU64,
+}
+
+/// Point-in-time copy of [`SessionMetrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// TCP connections established.
+    pub connections: u64,
+    /// Successful logons.
+    pub logons: u64,
+    /// Messages written to the counterparty.
+    pub messages_sent: u64,
+    /// Messages received from the counterparty.
+    pub messages_received: u64,
+    /// Bytes written to the counterparty.
+    pub bytes_sent: u64,
+    /// Bytes received from the counterparty.
+    pub bytes_received: u64,
+    /// ResendRequest messages sent.
+    pub resend_requests_sent: u64,
+    /// ResendRequest messages received.
+    pub resend_requests_received: u64,
+    /// MsgSeqNum of the last message sent.
+    pub last_sent_seq_num: u64,
+    /// MsgSeqNum of the last message received.
+    pub last_received_seq_num: u64,
+}
+
+impl SessionMetrics {
+    /// Creates a new set of zeroed metrics.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an established TCP connection.
+    #[inline]
+    pub fn record_connection(&self) {
+        self.connections.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a successful logon.
+    #[inline]
+    pub fn record_logon(&self) {
+        self.logons.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a message written to the counterparty.
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type
+    /// * `seq_num` - The message's MsgSeqNum
+    /// * `bytes` - The encoded message length
+    pub fn record_sent(&self, msg_type: &MsgType, seq_num: u64, bytes: usize) {
+        self.messages_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.last_sent_seq_num.store(seq_num, Ordering::Relaxed);
+        if *msg_type == MsgType::ResendRequest {
+            self.resend_requests_sent.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records a message received from the counterparty.
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type
+    /// * `seq_num` - The message's MsgSeqNum
+    /// * `bytes` - The encoded message length
+    pub fn record_received(&self, msg_type: &MsgType, seq_num: u64, bytes: usize) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+        self.last_received_seq_num.store(seq_num, Ordering::Relaxed);
+        if *msg_type == MsgType::ResendRequest {
+            self.resend_requests_received
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the number of TCP connections established.
+    #[must_use]
+    pub fn connections(&self) -> u64 {
+        self.connections.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of successful logons.
+    #[must_use]
+    pub fn logons(&self) -> u64 {
+        self.logons.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of messages written to the counterparty.
+    #[must_use]
+    pub fn messages_sent(&self) -> u64 {
+        self.messages_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of messages received from the counterparty.
+    #[must_use]
+    pub fn messages_received(&self) -> u64 {
+        self.messages_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes written to the counterparty.
+    #[must_use]
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes received from the counterparty.
+    #[must_use]
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of ResendRequest messages sent.
+    #[must_use]
+    pub fn resend_requests_sent(&self) -> u64 {
+        self.resend_requests_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of ResendRequest messages received.
+    #[must_use]
+    pub fn resend_requests_received(&self) -> u64 {
+        self.resend_requests_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the MsgSeqNum of the last message sent.
+    #[must_use]
+    pub fn last_sent_seq_num(&self) -> u64 {
+        self.last_sent_seq_num.load(Ordering::Relaxed)
+    }
+
+    /// Returns the MsgSeqNum of the last message received.
+    #[must_use]
+    pub fn last_received_seq_num(&self) -> u64 {
+        self.last_received_seq_num.load(Ordering::Relaxed)
+    }
+
+    /// Returns a copy of all counters.
+    ///
+    /// Counters are read individually, so a snapshot taken while the session
+    /// is active may mix values from consecutive messages.
+    #[must_use]
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            connections: self.connections(),
+            logons: self.logons(),
+            messages_sent: self.messages_sent(),
+            messages_received: self.messages_received(),
+            bytes_sent: self.bytes_sent(),
+            bytes_received: self.bytes_received(),
+            resend_requests_sent: self.resend_requests_sent(),
+            resend_requests_received: self.resend_requests_received(),
+            last_sent_seq_num: self.last_sent_seq_num(),
+            last_received_seq_num: self.last_received_seq_num(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_metrics_record() {
+        let metrics = SessionMetrics::new();
+        metrics.record_sent(&MsgType::Logon, 1, 70);
+        metrics.record_sent(&MsgType::ResendRequest, 2, 60);
+        metrics.record_received(&MsgType::Logon, 1, 72);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.messages_sent, 2);
+        assert_eq!(snapshot.bytes_sent, 130);
+        assert_eq!(snapshot.resend_requests_sent, 1);
+        assert_eq!(snapshot.last_sent_seq_num, 2);
+        assert_eq!(snapshot.messages_received, 1);
+        assert_eq!(snapshot.bytes_received, 72);
+        assert_eq!(snapshot.resend_requests_received, 0);
+        assert_eq!(snapshot.last_received_seq_num, 1);
+    }
+}
@@ -0,0 +1,160 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Reconnect scheduling for initiators.
+//!
+//! This module provides a [`ReconnectPolicy`] describing capped exponential
+//! backoff, and a [`Backoff`] tracker counting consecutive failed attempts.
+
+use std::time::Duration;
+
+/// Reconnect delays using capped exponential backoff.
+///
+/// The first retry waits the initial interval; each further retry doubles
+/// the delay up to the maximum interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnect attempt.
+    initial_interval: Duration,
+    /// Upper bound on the delay between attempts.
+    max_interval: Duration,
+    /// Maximum consecutive reconnect attempts before giving up.
+    max_attempts: u32,
+}
+
+impl ReconnectPolicy {
+    /// Creates a new reconnect policy.
+    ///
+    /// # Arguments
+    /// * `initial_interval` - Delay before the first reconnect attempt
+    /// * `max_interval` - Upper bound on the delay between attempts
+    /// * `max_attempts` - Maximum consecutive attempts; zero disables reconnecting
+    #[must_use]
+    pub const fn new(
+        initial_interval: Duration,
+        max_interval: Duration,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            initial_interval,
+            max_interval,
+            max_attempts,
+        }
+    }
+
+    /// Returns the delay before the given attempt.
+    ///
+    /// # Arguments
+    /// * `attempt` - The attempt number, starting at 1
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_interval
+            .saturating_mul(factor)
+            .min(self.max_interval)
+    }
+
+    /// Returns the delay before the first reconnect attempt.
+    #[must_use]
+    pub const fn initial_interval(&self) -> Duration {
+        self.initial_interval
+    }
+
+    /// Returns the upper bound on the delay between attempts.
+    #[must_use]
+    pub const fn max_interval(&self) -> Duration {
+        self.max_interval
+    }
+
+    /// Returns the maximum consecutive reconnect attempts.
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5), Duration::from_secs(60), 10)
+    }
+}
+
+/// Tracks consecutive reconnect attempts under a [`ReconnectPolicy`].
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    /// Policy deciding delays and the attempt limit.
+    policy: ReconnectPolicy,
+    /// Consecutive attempts made since the last reset.
+    attempts: u32,
+}
+
+impl Backoff {
+    /// Creates a new backoff tracker with no attempts made.
+    ///
+    /// # Arguments
+    /// * `policy` - The reconnect policy to follow
+    #[must_use]
+    pub const fn new(policy: ReconnectPolicy) -> Self {
+        Self {
+            policy,
+            attempts: 0,
+        }
+    }
+
+    /// Records a new attempt and returns the delay to wait before it.
+    ///
+    /// # Returns
+    /// The delay, or `None` once the policy's attempt limit is reached.
+    pub fn next_delay(&mut self) -> Option<Duration> {
+        if self.attempts >= self.policy.max_attempts {
+            return None;
+        }
+        self.attempts += 1;
+        Some(self.policy.delay(self.attempts))
+    }
+
+    /// Resets the attempt counter, e.g. after a successful logon.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+    }
+
+    /// Returns the number of attempts made since the last reset.
+    #[inline]
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_delay_doubles_up_to_cap() {
+        let policy = ReconnectPolicy::new(Duration::from_secs(1), Duration::from_secs(10), 10);
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(2), Duration::from_secs(2));
+        assert_eq!(policy.delay(4), Duration::from_secs(8));
+        assert_eq!(policy.delay(5), Duration::from_secs(10));
+        assert_eq!(policy.delay(100), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_backoff_gives_up_and_resets() {
+        let policy = ReconnectPolicy::new(Duration::from_millis(100), Duration::from_secs(1), 2);
+        let mut backoff = Backoff::new(policy);
+
+        assert_eq!(backoff.next_delay(), Some(Duration::from_millis(100)));
+        assert_eq!(backoff.next_delay(), Some(Duration::from_millis(200)));
+        assert_eq!(backoff.next_delay(), None);
+        assert_eq!(backoff.attempts(), 2);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Some(Duration::from_millis(100)));
+    }
+}
@@ -0,0 +1,321 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Message recovery.
+//!
+//! This module builds the messages used when answering a ResendRequest,
+//! such as SequenceReset (MsgType=4) in GapFill or Reset mode, and plans the
+//! full answer from the message store with [`build_resend`].
+
+use bytes::Bytes;
+use ironfix_core::error::StoreError;
+use ironfix_core::message::{MessageBuilder, MsgType, OwnedMessage, RawMessage};
+use ironfix_session::clock::Clock;
+use ironfix_store::MessageStore;
+use ironfix_tagvalue::Decoder;
+use std::collections::BTreeMap;
+
+/// One message of the answer to a ResendRequest.
+#[derive(Debug, Clone)]
+pub enum ResendItem {
+    /// A stored application message, marked as a possible duplicate.
+    ///
+    /// The message is complete and keeps its original MsgSeqNum.
+    Resend(OwnedMessage),
+    /// A SequenceReset-GapFill replacing messages that are not resent.
+    GapFill {
+        /// MsgSeqNum of the first replaced message, which the GapFill is
+        /// sent with instead of a newly allocated one.
+        msg_seq_num: u64,
+        /// The body-only message, as built by [`build_sequence_reset`].
+        message: OwnedMessage,
+    },
+}
+
+/// Builds a SequenceReset (MsgType=4) message.
+///
+/// In GapFill mode the message replaces skipped messages during a resend,
+/// so it carries PossDupFlag (tag 43) and OrigSendingTime (tag 122) and must
+/// be sent with the sequence number of the first skipped message. In Reset
+/// mode GapFillFlag is omitted and the message is not a possible duplicate.
+///
+/// The message is body-only; the session header, including MsgSeqNum
+/// (tag 34), and trailer are added when it is sent.
+///
+/// # Arguments
+/// * `new_seq_no` - Next sequence number the counterparty should expect (tag 36)
+/// * `gap_fill` - Whether to send in GapFill mode (tag 123)
+/// * `clock` - Source of OrigSendingTime
+#[must_use]
+pub fn build_sequence_reset(new_seq_no: u64, gap_fill: bool, clock: &dyn Clock) -> OwnedMessage {
+    let mut message = OwnedMessage::new(Bytes::new(), MsgType::SequenceReset, Vec::new());
+    message.set_field(35, MsgType::SequenceReset.as_str().as_bytes());
+    if gap_fill {
+        message.set_field(43, b"Y");
+        message.set_field(122, clock.timestamp().format_millis().as_bytes());
+        message.set_field(123, b"Y");
+    }
+    message.set_field(36, new_seq_no.to_string().as_bytes());
+    message
+}
+
+/// Copies a stored message for resending.
+///
+/// PossDupFlag (tag 43) and OrigSendingTime (tag 122) are header fields, so
+/// they follow a fresh SendingTime (tag 52) right after MsgSeqNum rather than
+/// being appended after the body.
+fn resend_copy(stored: &RawMessage<'_>, sending_time: &str) -> OwnedMessage {
+    let orig_sending_time = stored.get_field(52).map(|field| field.value);
+    let mut builder = MessageBuilder::new(stored.begin_string(), stored.msg_type().clone());
+    for field in stored.fields() {
+        match field.tag {
+            43 | 52 | 122 => {}
+            34 => {
+                builder = builder
+                    .field(34, field.value)
+                    .field(52, sending_time)
+                    .field(43, "Y");
+                if let Some(orig_sending_time) = orig_sending_time {
+                    builder = builder.field(122, orig_sending_time);
+                }
+            }
+            tag => builder = builder.field(tag, field.value),
+        }
+    }
+    builder.build()
+}
+
+/// Builds the answer to a ResendRequest from the message store.
+///
+/// Stored application messages are resent with PossDupFlag (tag 43) set,
+/// OrigSendingTime (tag 122) holding their original SendingTime and a fresh
+/// SendingTime (tag 52). Sequence numbers missing from the store, e.g. after
+/// pruning, and those holding administrative messages, which are never
+/// resent, are coalesced into SequenceReset-GapFill messages, each reaching
+/// up to the next resent message. A store reporting the range as
+/// unavailable therefore yields a single GapFill over the whole range.
+///
+/// # Arguments
+/// * `store` - The session's outgoing message store
+/// * `begin` - BeginSeqNo of the request (tag 7)
+/// * `end` - EndSeqNo of the request (tag 16), or 0 for infinity
+/// * `next_sender_seq` - The next sequence number the session will send;
+///   the request is clamped below it
+/// * `clock` - Source of the fresh SendingTime and of OrigSendingTime in
+///   GapFills
+///
+/// # Returns
+/// The messages to send, in sequence order.
+///
+/// # Errors
+/// Returns the `StoreError` if the store fails for a reason other than
+/// `StoreError::RangeNotAvailable` or `StoreError::NotFound`.
+pub async fn build_resend(
+    store: &dyn MessageStore,
+    begin: u64,
+    end: u64,
+    next_sender_seq: u64,
+    clock: &dyn Clock,
+) -> Result<Vec<ResendItem>, StoreError> {
+    let last = next_sender_seq.saturating_sub(1);
+    let end = if end == 0 { last } else { end.min(last) };
+    if begin == 0 || begin > end {
+        return Ok(Vec::new());
+    }
+
+    let stored = match store.get_range(begin, end).await {
+        Ok(stored) => stored,
+        Err(StoreError::RangeNotAvailable { .. } | StoreError::NotFound { .. }) => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    // Undecodable messages cannot be resent and are gap-filled like missing ones.
+    let sending_time = clock.timestamp().format_millis();
+    let resendable: BTreeMap<u64, OwnedMessage> = stored
+        .iter()
+        .filter_map(|message| {
+            let raw = Decoder::new(message.as_bytes()).decode().ok()?;
+            let seq_num = raw.get_field_as::<u64>(34).ok()?;
+            ((begin..=end).contains(&seq_num) && !raw.msg_type().is_admin())
+                .then(|| (seq_num, resend_copy(&raw, &sending_time)))
+        })
+        .collect();
+
+    let gap_fill = |msg_seq_num, new_seq_no| ResendItem::GapFill {
+        msg_seq_num,
+        message: build_sequence_reset(new_seq_no, true, clock),
+    };
+    let mut items = Vec::with_capacity(resendable.len() + 1);
+    let mut next = begin;
+    for (seq_num, message) in resendable {
+        if seq_num > next {
+            items.push(gap_fill(next, seq_num));
+        }
+        items.push(ResendItem::Resend(message));
+        next = seq_num + 1;
+    }
+    if next <= end {
+        items.push(gap_fill(next, end + 1));
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ironfix_core::message::MessageBuilder;
+    use ironfix_core::types::Timestamp;
+    use ironfix_session::clock::MockClock;
+    use ironfix_store::MemoryStore;
+
+    /// Returns a clock fixed at 2026-01-27 10:00:05 UTC.
+    fn clock() -> MockClock {
+        MockClock::new(Timestamp::from_nanos(1_769_508_005_000_000_000))
+    }
+
+    fn stored(msg_type: MsgType, seq_num: u64) -> OwnedMessage {
+        MessageBuilder::new("FIX.4.4", msg_type)
+            .field(49, "SENDER")
+            .field(56, "TARGET")
+            .field(34, seq_num.to_string())
+            .field(52, "20260127-10:00:00.000")
+            .build()
+    }
+
+    /// Returns (is_gap_fill, MsgSeqNum, NewSeqNo) for each item.
+    fn summary(items: &[ResendItem]) -> Vec<(bool, u64, Option<u64>)> {
+        items
+            .iter()
+            .map(|item| {
+                let field = |message: &OwnedMessage, tag| {
+                    message.get_field_str(tag).map(|v| v.parse().unwrap())
+                };
+                match item {
+                    ResendItem::Resend(message) => (false, field(message, 34).unwrap(), None),
+                    ResendItem::GapFill {
+                        msg_seq_num,
+                        message,
+                    } => {
+                        assert_eq!(message.get_field(34), None);
+                        (true, *msg_seq_num, field(message, 36))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_build_resend_gap_fills_missing_message() {
+        let store = MemoryStore::new();
+        for (seq_num, msg_type) in [
+            (1, MsgType::Logon),
+            (2, MsgType::NewOrderSingle),
+            (4, MsgType::NewOrderSingle),
+            (5, MsgType::Heartbeat),
+        ] {
+            store
+                .store(seq_num, stored(msg_type, seq_num).as_bytes())
+                .await
+                .unwrap();
+        }
+
+        let items = build_resend(&store, 1, 0, 6, &clock()).await.unwrap();
+        assert_eq!(
+            summary(&items),
+            [
+                (true, 1, Some(2)),
+                (false, 2, None),
+                (true, 3, Some(4)),
+                (false, 4, None),
+                (true, 5, Some(6)),
+            ]
+        );
+
+        let ResendItem::Resend(resent) = &items[1] else {
+            panic!("expected a resent message");
+        };
+        assert_eq!(resent.get_field_str(43), Some("Y"));
+        assert_eq!(resent.get_field_str(122), Some("20260127-10:00:00.000"));
+        assert_eq!(resent.get_field_str(52), Some("20260127-10:00:05.000"));
+        assert_eq!(resent.verify(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_build_resend_keeps_header_before_body() {
+        let store = MemoryStore::new();
+        let order = MessageBuilder::new("FIX.4.4", MsgType::NewOrderSingle)
+            .field(49, "SENDER")
+            .field(56, "TARGET")
+            .field(34, "1")
+            .field(52, "20260127-10:00:00.000")
+            .field(11, "ORD-1")
+            .field(55, "IBM")
+            .field(54, "1")
+            .field(38, "100")
+            .field(40, "1")
+            .build();
+        store.store(1, order.as_bytes()).await.unwrap();
+
+        let items = build_resend(&store, 1, 1, 2, &clock()).await.unwrap();
+        let [ResendItem::Resend(resent)] = items.as_slice() else {
+            panic!("expected one resent message");
+        };
+        let tags: Vec<u32> = resent.fields().map(|(tag, _)| tag).collect();
+        assert_eq!(
+            tags,
+            [8, 9, 35, 49, 56, 34, 52, 43, 122, 11, 55, 54, 38, 40, 10]
+        );
+        assert_eq!(resent.get_field_str(52), Some("20260127-10:00:05.000"));
+        assert_eq!(resent.get_field_str(122), Some("20260127-10:00:00.000"));
+        assert_eq!(resent.verify(), Ok(()));
+
+        let decoded = Decoder::new(resent.as_bytes())
+            .strict(true)
+            .decode()
+            .unwrap();
+        assert_eq!(decoded.get_field_str(43), Some("Y"));
+    }
+
+    #[tokio::test]
+    async fn test_build_resend_unavailable_range_is_one_gap_fill() {
+        let store = MemoryStore::new();
+        let clock = clock();
+        let items = build_resend(&store, 3, 7, 10, &clock).await.unwrap();
+        assert_eq!(summary(&items), [(true, 3, Some(8))]);
+
+        // The request is clamped to what has been sent.
+        let items = build_resend(&store, 3, 20, 10, &clock).await.unwrap();
+        assert_eq!(summary(&items), [(true, 3, Some(10))]);
+        assert!(
+            build_resend(&store, 10, 0, 10, &clock)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_build_sequence_reset_gap_fill() {
+        let msg = build_sequence_reset(9, true, &clock());
+
+        assert_eq!(msg.msg_type(), &MsgType::SequenceReset);
+        assert_eq!(msg.get_field_str(35), Some("4"));
+        assert_eq!(msg.get_field(34), None);
+        assert_eq!(msg.get_field_str(36), Some("9"));
+        assert_eq!(msg.get_field_str(123), Some("Y"));
+        assert_eq!(msg.get_field_str(43), Some("Y"));
+        assert_eq!(msg.get_field_str(122), Some("20260127-10:00:05.000"));
+    }
+
+    #[test]
+    fn test_build_sequence_reset_reset_mode() {
+        let msg = build_sequence_reset(100, false, &clock());
+
+        assert_eq!(msg.get_field(123), None);
+        assert_eq!(msg.get_field(43), None);
+        assert_eq!(msg.get_field(122), None);
+        assert_eq!(msg.as_bytes(), b"35=4\x0136=100\x01");
+    }
+}
@@ -0,0 +1,209 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Outbound message rejection.
+//!
+//! This module builds session-level Reject (MsgType=3) messages and
+//! BusinessMessageReject (MsgType=j) messages, the latter used when an
+//! application message is well-formed at the session level but cannot be
+//! processed by the application.
+
+use bytes::Bytes;
+use ironfix_core::message::{MsgType, OwnedMessage};
+
+/// BusinessRejectReason (tag 380) values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusinessRejectReason {
+    /// Other (0).
+    Other,
+    /// Unknown ID (1).
+    UnknownId,
+    /// Unknown security (2).
+    UnknownSecurity,
+    /// Unsupported message type (3).
+    UnsupportedMessageType,
+    /// Application not available (4).
+    ApplicationNotAvailable,
+    /// Conditionally required field missing (5).
+    ConditionallyRequiredFieldMissing,
+    /// Not authorized (6).
+    NotAuthorized,
+    /// DeliverTo firm not available at this time (7).
+    DeliverToFirmNotAvailable,
+    /// Invalid price increment (18).
+    InvalidPriceIncrement,
+}
+
+impl BusinessRejectReason {
+    /// Returns the FIX code for this reason.
+    #[must_use]
+    pub const fn code(&self) -> u32 {
+        match self {
+            Self::Other => 0,
+            Self::UnknownId => 1,
+            Self::UnknownSecurity => 2,
+            Self::UnsupportedMessageType => 3,
+            Self::ApplicationNotAvailable => 4,
+            Self::ConditionallyRequiredFieldMissing => 5,
+            Self::NotAuthorized => 6,
+            Self::DeliverToFirmNotAvailable => 7,
+            Self::InvalidPriceIncrement => 18,
+        }
+    }
+
+    /// Creates a reason from its FIX code.
+    ///
+    /// # Arguments
+    /// * `code` - The BusinessRejectReason value
+    ///
+    /// # Returns
+    /// `None` if the code is not a standard reason.
+    #[must_use]
+    pub const fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Other),
+            1 => Some(Self::UnknownId),
+            2 => Some(Self::UnknownSecurity),
+            3 => Some(Self::UnsupportedMessageType),
+            4 => Some(Self::ApplicationNotAvailable),
+            5 => Some(Self::ConditionallyRequiredFieldMissing),
+            6 => Some(Self::NotAuthorized),
+            7 => Some(Self::DeliverToFirmNotAvailable),
+            18 => Some(Self::InvalidPriceIncrement),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a BusinessMessageReject (MsgType=j) message.
+///
+/// The message holds MsgType and the reject body fields only; the standard
+/// header and trailer are added by the session when it is sent.
+///
+/// # Arguments
+/// * `ref_seq_num` - MsgSeqNum of the rejected message (tag 45)
+/// * `ref_msg_type` - MsgType of the rejected message (tag 372)
+/// * `reason` - Reason for the rejection (tag 380)
+/// * `text` - Optional explanation (tag 58)
+/// * `ref_id` - Optional business-level ID of the rejected message (tag 379)
+#[must_use]
+pub fn build_business_reject(
+    ref_seq_num: u64,
+    ref_msg_type: &MsgType,
+    reason: BusinessRejectReason,
+    text: Option<&str>,
+    ref_id: Option<&str>,
+) -> OwnedMessage {
+    let mut message = OwnedMessage::new(Bytes::new(), MsgType::BusinessMessageReject, Vec::new());
+    message.set_field(35, MsgType::BusinessMessageReject.as_str().as_bytes());
+    message.set_field(45, ref_seq_num.to_string().as_bytes());
+    message.set_field(372, ref_msg_type.as_str().as_bytes());
+    if let Some(ref_id) = ref_id {
+        message.set_field(379, ref_id.as_bytes());
+    }
+    message.set_field(380, reason.code().to_string().as_bytes());
+    if let Some(text) = text {
+        message.set_field(58, text.as_bytes());
+    }
+    message
+}
+
+/// Builds a session-level Reject (MsgType=3) message.
+///
+/// Like [`build_business_reject`], only MsgType and the reject body fields are
+/// included.
+///
+/// # Arguments
+/// * `ref_seq_num` - MsgSeqNum of the rejected message (tag 45)
+/// * `ref_msg_type` - MsgType of the rejected message (tag 372)
+/// * `reason_code` - SessionRejectReason value (tag 373)
+/// * `ref_tag` - Optional tag that caused the rejection (tag 371)
+/// * `text` - Optional explanation (tag 58)
+#[must_use]
+pub fn build_session_reject(
+    ref_seq_num: u64,
+    ref_msg_type: &MsgType,
+    reason_code: u32,
+    ref_tag: Option<u32>,
+    text: Option<&str>,
+) -> OwnedMessage {
+    let mut message = OwnedMessage::new(Bytes::new(), MsgType::Reject, Vec::new());
+    message.set_field(35, MsgType::Reject.as_str().as_bytes());
+    message.set_field(45, ref_seq_num.to_string().as_bytes());
+    if let Some(ref_tag) = ref_tag {
+        message.set_field(371, ref_tag.to_string().as_bytes());
+    }
+    message.set_field(372, ref_msg_type.as_str().as_bytes());
+    message.set_field(373, reason_code.to_string().as_bytes());
+    if let Some(text) = text {
+        message.set_field(58, text.as_bytes());
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_business_reject_reason_codes() {
+        assert_eq!(BusinessRejectReason::UnknownSecurity.code(), 2);
+        assert_eq!(BusinessRejectReason::InvalidPriceIncrement.code(), 18);
+        assert_eq!(
+            BusinessRejectReason::from_code(6),
+            Some(BusinessRejectReason::NotAuthorized)
+        );
+        assert_eq!(BusinessRejectReason::from_code(99), None);
+    }
+
+    #[test]
+    fn test_build_business_reject() {
+        let msg = build_business_reject(
+            42,
+            &MsgType::NewOrderSingle,
+            BusinessRejectReason::UnknownSecurity,
+            Some("Unknown symbol"),
+            Some("ORD-1"),
+        );
+
+        assert_eq!(msg.msg_type(), &MsgType::BusinessMessageReject);
+        assert_eq!(msg.get_field_str(35), Some("j"));
+        assert_eq!(msg.get_field_str(45), Some("42"));
+        assert_eq!(msg.get_field_str(372), Some("D"));
+        assert_eq!(msg.get_field_str(379), Some("ORD-1"));
+        assert_eq!(msg.get_field_str(380), Some("2"));
+        assert_eq!(msg.get_field_str(58), Some("Unknown symbol"));
+        assert_eq!(
+            msg.as_bytes(),
+            b"35=j\x0145=42\x01372=D\x01379=ORD-1\x01380=2\x0158=Unknown symbol\x01"
+        );
+    }
+
+    #[test]
+    fn test_build_session_reject() {
+        let msg = build_session_reject(3, &MsgType::Logon, 1, Some(553), Some("Bad user"));
+        assert_eq!(msg.msg_type(), &MsgType::Reject);
+        assert_eq!(
+            msg.as_bytes(),
+            b"35=3\x0145=3\x01371=553\x01372=A\x01373=1\x0158=Bad user\x01"
+        );
+    }
+
+    #[test]
+    fn test_build_business_reject_without_optional_fields() {
+        let msg = build_business_reject(
+            7,
+            &MsgType::Quote,
+            BusinessRejectReason::NotAuthorized,
+            None,
+            None,
+        );
+
+        assert_eq!(msg.get_field(379), None);
+        assert_eq!(msg.get_field(58), None);
+        assert_eq!(msg.as_bytes(), b"35=j\x0145=7\x01372=S\x01380=6\x01");
+    }
+}
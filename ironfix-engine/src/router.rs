@@ -0,0 +1,178 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Message-type routing to typed handlers.
+//!
+//! A [`MessageRouter`] maps message types to async handlers, replacing a
+//! hand-written `match` on the MsgType in [`Application`](crate::Application)
+//! callbacks. Handlers registered with [`MessageRouter::on`] receive the
+//! message already decoded into its [`FixMessage`] type.
+
+use crate::application::{RejectReason, SessionId};
+use crate::reject::BusinessRejectReason;
+use ironfix_core::error::DecodeError;
+use ironfix_core::message::{FixMessage, MsgType, OwnedMessage, RawMessage};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by a routed handler.
+pub type HandlerFuture = Pin<Box<dyn Future<Output = Result<(), RejectReason>> + Send>>;
+
+/// Type-erased handler, converting the raw message before the await point.
+type Handler = Box<dyn Fn(&RawMessage<'_>, &SessionId) -> HandlerFuture + Send + Sync>;
+
+/// SessionRejectReason for a missing required tag.
+const SESSION_REJECT_REQUIRED_TAG_MISSING: u32 = 1;
+/// SessionRejectReason for an incorrect value.
+const SESSION_REJECT_VALUE_INCORRECT: u32 = 5;
+/// SessionRejectReason for other problems.
+const SESSION_REJECT_OTHER: u32 = 99;
+
+/// Registry of handlers by message type.
+///
+/// At most one handler is registered per message type; registering another
+/// replaces it. Messages without a handler are left to the application.
+#[derive(Default)]
+pub struct MessageRouter {
+    /// Handlers by message type.
+    handlers: HashMap<MsgType, Handler>,
+}
+
+impl std::fmt::Debug for MessageRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageRouter")
+            .field("msg_types", &self.handlers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl MessageRouter {
+    /// Creates an empty router.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a handler for a typed message.
+    ///
+    /// The message is decoded with [`FixMessage::from_raw`] before the handler
+    /// is called. A message that fails to decode is rejected without calling
+    /// the handler: a missing field or invalid value is reported with the
+    /// offending tag.
+    ///
+    /// # Arguments
+    /// * `handler` - Called with the decoded message and the session
+    #[must_use]
+    pub fn on<T, F, Fut>(mut self, handler: F) -> Self
+    where
+        T: FixMessage + Send + 'static,
+        F: Fn(T, SessionId) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), RejectReason>> + Send + 'static,
+    {
+        let Ok(msg_type) = T::MSG_TYPE.parse::<MsgType>();
+        let handler: Handler = Box::new(move |message, session_id| match T::from_raw(message) {
+            Ok(decoded) => Box::pin(handler(decoded, session_id.clone())),
+            Err(error) => {
+                let reason = decode_reject(message.msg_type(), &error);
+                Box::pin(async move { Err(reason) })
+            }
+        });
+        self.handlers.insert(msg_type, handler);
+        self
+    }
+
+    /// Registers a handler for a message type, receiving the message
+    /// undecoded.
+    ///
+    /// Useful for custom message types without a [`FixMessage`]
+    /// implementation.
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type, e.g. `MsgType::Custom("U1".into())`
+    /// * `handler` - Called with an owned copy of the message and the session
+    #[must_use]
+    pub fn on_msg_type<F, Fut>(mut self, msg_type: MsgType, handler: F) -> Self
+    where
+        F: Fn(OwnedMessage, SessionId) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), RejectReason>> + Send + 'static,
+    {
+        let handler: Handler = Box::new(move |message, session_id| {
+            Box::pin(handler(message.to_owned(), session_id.clone()))
+        });
+        self.handlers.insert(msg_type, handler);
+        self
+    }
+
+    /// Returns true if a handler is registered for the message type.
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type
+    #[must_use]
+    pub fn handles(&self, msg_type: &MsgType) -> bool {
+        self.handlers.contains_key(msg_type)
+    }
+
+    /// Returns the number of registered handlers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Returns true if no handler is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Passes a message to the handler registered for its type.
+    ///
+    /// # Arguments
+    /// * `message` - The received message
+    /// * `session_id` - The session the message was received on
+    ///
+    /// # Returns
+    /// The handler's result, or `None` if no handler is registered for the
+    /// message type.
+    pub async fn dispatch(
+        &self,
+        message: &RawMessage<'_>,
+        session_id: &SessionId,
+    ) -> Option<Result<(), RejectReason>> {
+        let handler = self.handlers.get(message.msg_type())?;
+        Some(handler(message, session_id).await)
+    }
+}
+
+/// Converts a typed decoding failure into a reject reason.
+///
+/// Administrative messages use SessionRejectReason codes and application
+/// messages BusinessRejectReason codes.
+fn decode_reject(msg_type: &MsgType, error: &DecodeError) -> RejectReason {
+    let text = error.to_string();
+    let (code, ref_tag) = match (msg_type.is_admin(), error) {
+        (true, DecodeError::MissingRequiredField { tag }) => {
+            (SESSION_REJECT_REQUIRED_TAG_MISSING, Some(*tag))
+        }
+        (true, DecodeError::InvalidFieldValue { tag, .. }) => {
+            (SESSION_REJECT_VALUE_INCORRECT, Some(*tag))
+        }
+        (true, _) => (SESSION_REJECT_OTHER, None),
+        (false, DecodeError::MissingRequiredField { tag }) => (
+            BusinessRejectReason::ConditionallyRequiredFieldMissing.code(),
+            Some(*tag),
+        ),
+        (false, DecodeError::InvalidFieldValue { tag, .. }) => {
+            (BusinessRejectReason::Other.code(), Some(*tag))
+        }
+        (false, _) => (BusinessRejectReason::Other.code(), None),
+    };
+    let reason = RejectReason::new(code, text);
+    match ref_tag {
+        Some(tag) => reason.with_ref_tag(tag),
+        None => reason,
+    }
+}
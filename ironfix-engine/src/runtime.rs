@@ -0,0 +1,958 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Session runtime shared by initiated and accepted sessions.
+//!
+//! A [`SessionRuntime`] runs one logged-on session over an established
+//! connection: heartbeats, TestRequests, gap recovery, resends and message
+//! delivery. An [`Initiator`](crate::initiator::Initiator) drives it over the
+//! connections it opens, reconnecting when they drop, and an
+//! [`Acceptor`](crate::acceptor::Acceptor) over the inbound connections it
+//! routes to the session. Cancelling its shutdown token logs the session
+//! out.
+
+use crate::application::{Application, Responder, SessionId};
+use crate::dispatch::{deliver, prepare_outbound, route};
+use crate::handle::SessionHandle;
+use crate::logger::MessageLogger;
+use crate::metrics::SessionMetrics;
+use crate::recovery::{ResendItem, build_resend};
+use crate::reject::build_session_reject;
+use crate::router::MessageRouter;
+use futures::{SinkExt, StreamExt};
+use ironfix_core::error::{DecodeError, SessionError};
+use ironfix_core::message::{FixMessage, MessageBuilder, MsgType, OwnedMessage, RawMessage};
+use ironfix_session::admin::{Heartbeat, Logon, Logout, ResendRequest, TestRequest};
+use ironfix_session::clock::{Clock, system_clock};
+use ironfix_session::config::SessionConfig;
+use ironfix_session::heartbeat::{HeartbeatManager, generate_test_req_id};
+use ironfix_session::resend::ResendTracker;
+use ironfix_session::sequence::{SequenceManager, SequenceResult};
+use ironfix_store::{MemoryStore, MessageStore};
+use ironfix_tagvalue::Decoder;
+use ironfix_transport::codec::FixCodec;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use tokio::time::{Instant, timeout, timeout_at};
+use tokio_util::codec::Framed;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// SessionRejectReason sent for a message with an incorrect BodyLength.
+const REJECT_REASON_OTHER: u32 = 99;
+
+/// SessionRejectReason sent for a message with unexpected routing fields.
+const REJECT_REASON_COMP_ID_PROBLEM: u32 = 9;
+
+/// Interval at which heartbeat timers are checked.
+const TIMER_RESOLUTION: Duration = Duration::from_secs(1);
+
+/// An established connection and its heartbeat and resend state.
+pub(crate) struct Connection {
+    /// Framed TCP stream.
+    framed: Framed<TcpStream, FixCodec>,
+    /// Heartbeat timing for this connection.
+    heartbeat: HeartbeatManager,
+    /// ResendRequest awaiting the counterparty's answer.
+    resend: ResendTracker,
+}
+
+/// Marks the session as stopped when dropped, even if the future running it
+/// is cancelled.
+pub(crate) struct RunningGuard<'a>(&'a watch::Sender<bool>);
+
+impl Drop for RunningGuard<'_> {
+    fn drop(&mut self) {
+        self.0.send_replace(false);
+    }
+}
+
+/// FIX session runtime.
+///
+/// Holds the state of a single session. Sequence numbers and stored messages
+/// persist from one connection to the next unless the session configuration
+/// resets them.
+pub struct SessionRuntime<A: Application> {
+    /// Application callback handler.
+    application: Arc<A>,
+    /// Session configuration.
+    config: SessionConfig,
+    /// Session identifier passed to callbacks.
+    session_id: SessionId,
+    /// Session sequence numbers.
+    sequences: Arc<SequenceManager>,
+    /// Sent messages, kept to answer ResendRequests.
+    store: Arc<dyn MessageStore>,
+    /// Live session state shared with user code.
+    handle: SessionHandle,
+    /// Connection and message counters.
+    metrics: Arc<SessionMetrics>,
+    /// Optional message and event log.
+    logger: Option<Arc<dyn MessageLogger>>,
+    /// Optional typed handlers for accepted messages.
+    router: Option<Arc<MessageRouter>>,
+    /// Cancelled to request a graceful shutdown.
+    shutdown: CancellationToken,
+    /// Whether the session is currently running.
+    running: watch::Sender<bool>,
+    /// Source of heartbeat timing and SendingTime.
+    clock: Arc<dyn Clock>,
+}
+
+impl<A: Application> std::fmt::Debug for SessionRuntime<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionRuntime")
+            .field("session_id", &self.session_id)
+            .finish()
+    }
+}
+
+impl<A: Application> SessionRuntime<A> {
+    /// Creates a new session runtime.
+    ///
+    /// # Arguments
+    /// * `application` - The application callbacks
+    /// * `config` - The session configuration
+    #[must_use]
+    pub fn new(application: Arc<A>, config: SessionConfig) -> Self {
+        let session_id = SessionId::from(&config);
+        let sequences = Arc::new(SequenceManager::new());
+        let handle = SessionHandle::new(session_id.clone(), Arc::clone(&sequences));
+
+        Self {
+            application,
+            config,
+            session_id,
+            sequences,
+            store: Arc::new(MemoryStore::new()),
+            handle,
+            metrics: Arc::new(SessionMetrics::new()),
+            logger: None,
+            router: None,
+            shutdown: CancellationToken::new(),
+            running: watch::Sender::new(false),
+            clock: system_clock(),
+        }
+    }
+
+    /// Sets the store keeping sent messages for resending.
+    ///
+    /// Defaults to a [`MemoryStore`]. The store must not be shared with
+    /// another session.
+    ///
+    /// # Arguments
+    /// * `store` - The session's outgoing message store
+    #[must_use]
+    pub fn with_message_store(mut self, store: Arc<dyn MessageStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Sets the logger receiving every message and session event.
+    #[must_use]
+    pub fn with_message_logger(mut self, logger: Arc<dyn MessageLogger>) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    /// Sets the handlers receiving messages accepted by the application.
+    ///
+    /// Every received message still goes to the application first; if it is
+    /// accepted and the router has a handler for its type, the handler runs
+    /// and its rejection is answered like an application rejection.
+    #[must_use]
+    pub fn with_router(mut self, router: Arc<MessageRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Sets the token that requests a graceful shutdown when cancelled.
+    ///
+    /// # Arguments
+    /// * `token` - The shutdown token, e.g. a child of an engine-wide token
+    #[must_use]
+    pub fn with_shutdown_token(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    /// Sets the source of heartbeat timing and SendingTime (tag 52).
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a `MockClock` in tests
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the session identifier.
+    #[must_use]
+    pub const fn session_id(&self) -> &SessionId {
+        &self.session_id
+    }
+
+    /// Returns the session configuration.
+    #[must_use]
+    pub const fn config(&self) -> &SessionConfig {
+        &self.config
+    }
+
+    /// Returns the session sequence numbers.
+    #[must_use]
+    pub fn sequences(&self) -> &SequenceManager {
+        &self.sequences
+    }
+
+    /// Returns a handle to the session's live state.
+    ///
+    /// The handle can be cloned and polled from any thread while the session
+    /// runs.
+    #[must_use]
+    pub fn handle(&self) -> SessionHandle {
+        self.handle.clone()
+    }
+
+    /// Returns the session's connection and message counters.
+    #[must_use]
+    pub const fn metrics(&self) -> &Arc<SessionMetrics> {
+        &self.metrics
+    }
+
+    /// Returns the token that requests a graceful shutdown when cancelled.
+    #[must_use]
+    pub const fn shutdown_token(&self) -> &CancellationToken {
+        &self.shutdown
+    }
+
+    /// Logs the session out and waits for it to stop running.
+    ///
+    /// An active session sends Logout and waits up to the configured
+    /// `logout_timeout` for the counterparty's Logout before closing the
+    /// connection, then flushes the message store. Returns immediately if
+    /// the session is not running.
+    pub async fn shutdown(&self) {
+        self.shutdown.cancel();
+        let mut running = self.running.subscribe();
+        // The sender lives as long as `self`, so this cannot fail.
+        let _ = running.wait_for(|running| !running).await;
+    }
+
+    /// Marks the session as running and fires [`Application::on_create`].
+    ///
+    /// # Returns
+    /// A guard marking the session as stopped when dropped.
+    pub(crate) async fn start(&self) -> RunningGuard<'_> {
+        self.running.send_replace(true);
+        let running = RunningGuard(&self.running);
+        self.application.on_create(&self.session_id).await;
+        running
+    }
+
+    /// Marks the session as connected for an inbound connection.
+    ///
+    /// # Returns
+    /// A guard marking the session as connected until dropped, or `None` if
+    /// the session is already running.
+    pub(crate) fn claim(&self) -> Option<RunningGuard<'_>> {
+        self.running
+            .send_if_modified(|running| !std::mem::replace(running, true))
+            .then(|| RunningGuard(&self.running))
+    }
+
+    /// Runs the session over an inbound connection whose Logon has been read.
+    ///
+    /// Used by an [`Acceptor`](crate::acceptor::Acceptor) once the Logon's
+    /// CompIDs matched this session. Answers the Logon with the
+    /// counterparty's HeartBtInt, then runs the session until the connection
+    /// ends, like an initiated one.
+    ///
+    /// # Arguments
+    /// * `_running` - The guard returned by [`claim`](Self::claim)
+    /// * `framed` - The inbound connection
+    /// * `logon` - The counterparty's Logon frame
+    ///
+    /// # Errors
+    /// Returns `SessionError::LogonRejected` if the Logon is invalid or
+    /// rejected by the application, or another `SessionError` if the
+    /// connection fails before the session is established.
+    pub(crate) async fn serve(
+        &self,
+        _running: RunningGuard<'_>,
+        mut framed: Framed<TcpStream, FixCodec>,
+        logon: &[u8],
+    ) -> Result<(), SessionError> {
+        self.metrics.record_connection();
+        *framed.codec_mut() = self.codec();
+        let message = self.decode(logon).map_err(decode_error)?;
+        let request = Logon::from_raw(&message).map_err(|e| SessionError::LogonRejected {
+            reason: e.to_string(),
+        })?;
+        let heartbeat_interval = Duration::from_secs(request.heart_bt_int);
+        let mut connection = self.connection(framed, heartbeat_interval);
+
+        let reset = request.reset_seq_num || self.config.reset_on_logon;
+        if reset {
+            self.reset_sequences().await;
+        }
+        self.on_received(&mut connection, &message);
+        if let Some(tag) = self.routing_mismatch(&message) {
+            let reason = format!("unexpected routing field {tag} in logon");
+            let logout = Logout::new().with_text(reason.as_str()).encode();
+            self.send(&mut connection, self.frame(&logout)).await?;
+            return Err(SessionError::LogonRejected { reason });
+        }
+        match self.dispatch(&message).await {
+            Ok(responses) => {
+                let response = Logon::new(heartbeat_interval)
+                    .with_reset_seq_num(reset)
+                    .encode();
+                self.send(&mut connection, self.frame(&response)).await?;
+                self.check_sequence(&mut connection, &message).await?;
+                self.respond(&mut connection, responses).await?;
+            }
+            Err(reject) => {
+                self.send(&mut connection, self.frame(&reject)).await?;
+                return Err(SessionError::LogonRejected {
+                    reason: "logon rejected by application".to_string(),
+                });
+            }
+        }
+
+        self.run_logged_on(&mut connection).await;
+        self.flush_store().await;
+        Ok(())
+    }
+
+    /// Runs a logged-on session until it ends, firing the logon and logout
+    /// callbacks around it.
+    pub(crate) async fn run_logged_on(&self, connection: &mut Connection) {
+        self.handle.set_logged_on(true);
+        self.metrics.record_logon();
+        info!(session_id = %self.session_id, "logged on");
+        self.log_event("Logon completed");
+        self.application.on_logon(&self.session_id).await;
+
+        let result = self.run_session(connection).await;
+        self.handle.set_logged_on(false);
+        self.application.on_logout(&self.session_id).await;
+        match result {
+            Ok(()) => {
+                info!(session_id = %self.session_id, "session ended");
+                self.log_event("Session ended");
+            }
+            Err(e) => {
+                warn!(session_id = %self.session_id, error = %e, "session dropped");
+                self.log_event(&format!("Session dropped: {e}"));
+            }
+        }
+    }
+
+    /// Wraps a framed stream with fresh heartbeat and resend state.
+    pub(crate) fn connection(
+        &self,
+        framed: Framed<TcpStream, FixCodec>,
+        heartbeat_interval: Duration,
+    ) -> Connection {
+        Connection {
+            framed,
+            heartbeat: HeartbeatManager::new(heartbeat_interval, self.config.test_request_grace)
+                .with_clock(Arc::clone(&self.clock)),
+            resend: ResendTracker::new(self.config.resend_request_timeout)
+                .with_clock(Arc::clone(&self.clock)),
+        }
+    }
+
+    /// Returns a codec applying the configured size and validation limits.
+    pub(crate) fn codec(&self) -> FixCodec {
+        FixCodec::new()
+            .with_max_message_size(self.config.max_message_size)
+            .with_checksum_validation(self.config.validate_checksum)
+            .with_length_validation(self.config.validate_length)
+    }
+
+    /// Sends Logon and waits for the counterparty's Logon response.
+    pub(crate) async fn logon(&self, connection: &mut Connection) -> Result<(), SessionError> {
+        if self.config.reset_on_logon {
+            self.reset_sequences().await;
+        }
+
+        let logon = Logon::new(self.config.heartbeat_interval)
+            .with_reset_seq_num(self.config.reset_on_logon)
+            .encode();
+        self.send(connection, self.frame(&logon)).await?;
+
+        let deadline = Instant::now() + self.config.logon_timeout;
+        loop {
+            let frame = timeout_at(deadline, connection.framed.next())
+                .await
+                .map_err(|_| SessionError::LogonRejected {
+                    reason: "timed out waiting for logon response".to_string(),
+                })?
+                .ok_or_else(|| {
+                    SessionError::Connection("connection closed during logon".to_string())
+                })?
+                .map_err(connection_error)?;
+            let Some(message) = self.receive_frame(connection, &frame).await? else {
+                continue;
+            };
+            return self.on_logon_response(connection, &message).await;
+        }
+    }
+
+    /// Validates the counterparty's Logon response and delivers it to the
+    /// application.
+    async fn on_logon_response(
+        &self,
+        connection: &mut Connection,
+        message: &RawMessage<'_>,
+    ) -> Result<(), SessionError> {
+        if *message.msg_type() != MsgType::Logon {
+            return Err(SessionError::LogonRejected {
+                reason: message
+                    .get_field_str(58)
+                    .unwrap_or("unexpected logon response")
+                    .to_string(),
+            });
+        }
+        if let Some(tag) = self.routing_mismatch(message) {
+            return Err(SessionError::LogonRejected {
+                reason: format!("unexpected routing field {tag} in logon response"),
+            });
+        }
+        match self.dispatch(message).await {
+            Ok(responses) => self.respond(connection, responses).await,
+            Err(reject) => {
+                self.send(connection, self.frame(&reject)).await?;
+                Err(SessionError::LogonRejected {
+                    reason: "logon rejected by application".to_string(),
+                })
+            }
+        }
+    }
+
+    /// Processes messages and heartbeats until the connection ends.
+    ///
+    /// Returns `Ok` when the session ends with a Logout, the counterparty
+    /// closes the connection or a shutdown is requested.
+    async fn run_session(&self, connection: &mut Connection) -> Result<(), SessionError> {
+        let resolution = match self.config.heartbeat_interval {
+            Duration::ZERO => TIMER_RESOLUTION,
+            interval => TIMER_RESOLUTION.min(interval),
+        };
+        let mut timer = tokio::time::interval(resolution);
+
+        loop {
+            tokio::select! {
+                frame = connection.framed.next() => {
+                    let Some(frame) = frame else {
+                        return Ok(());
+                    };
+                    let frame = frame.map_err(connection_error)?;
+                    let Some(message) = self.receive_frame(connection, &frame).await? else {
+                        continue;
+                    };
+
+                    if let Some(tag) = self.routing_mismatch(&message) {
+                        let reject = build_session_reject(
+                            message.get_field_as::<u64>(34).unwrap_or(0),
+                            message.msg_type(),
+                            REJECT_REASON_COMP_ID_PROBLEM,
+                            Some(tag),
+                            Some("Unexpected routing field"),
+                        );
+                        self.send(connection, self.frame(&reject)).await?;
+                        continue;
+                    }
+
+                    match self.dispatch(&message).await {
+                        Ok(responses) => self.respond(connection, responses).await?,
+                        Err(reject) => self.send(connection, self.frame(&reject)).await?,
+                    }
+
+                    match message.msg_type() {
+                        MsgType::TestRequest => {
+                            let mut heartbeat = Heartbeat::new();
+                            if let Some(id) = message.get_field_str(112) {
+                                heartbeat = heartbeat.with_test_req_id(id);
+                            }
+                            self.send(connection, self.frame(&heartbeat.encode())).await?;
+                        }
+                        MsgType::ResendRequest => self.resend(connection, &message).await?,
+                        MsgType::Logout => {
+                            self.send(connection, self.frame(&Logout::new().encode())).await?;
+                            return Ok(());
+                        }
+                        _ => {}
+                    }
+                }
+                _ = self.shutdown.cancelled() => {
+                    return self.logout(connection).await;
+                }
+                _ = timer.tick() => {
+                    if let Some(range) = connection.resend.poll_timeout() {
+                        self.request_resend(connection, range).await?;
+                    }
+                    if connection.heartbeat.is_timed_out() {
+                        return Err(SessionError::HeartbeatTimeout {
+                            elapsed_ms: connection.heartbeat.time_since_last_received().as_millis()
+                                as u64,
+                        });
+                    }
+                    if connection.heartbeat.should_send_test_request() {
+                        let id = generate_test_req_id();
+                        let request = TestRequest::new(id.as_str()).encode();
+                        self.send(connection, self.frame(&request)).await?;
+                        connection.heartbeat.on_test_request_sent(id);
+                    } else if connection.heartbeat.should_send_heartbeat() {
+                        let heartbeat = Heartbeat::new().encode();
+                        self.send(connection, self.frame(&heartbeat)).await?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Answers a ResendRequest from the message store.
+    ///
+    /// Stored application messages are resent with their original MsgSeqNum
+    /// and the rest of the range is gap-filled, see [`build_resend`].
+    ///
+    /// # Errors
+    /// Returns `SessionError::ResendUnavailable` if the store fails, or
+    /// `SessionError::Connection` if a message cannot be sent.
+    async fn resend(
+        &self,
+        connection: &mut Connection,
+        message: &RawMessage<'_>,
+    ) -> Result<(), SessionError> {
+        let request = match ResendRequest::from_raw(message) {
+            Ok(request) => request,
+            Err(e) => {
+                warn!(session_id = %self.session_id, error = %e, "invalid resend request");
+                return Ok(());
+            }
+        };
+        let (begin, end) = (request.begin_seq_no, request.end_seq_no);
+        debug!(session_id = %self.session_id, begin, end, "resending");
+        let items = build_resend(
+            &*self.store,
+            begin,
+            end,
+            self.sequences.next_sender_seq().value(),
+            &*self.clock,
+        )
+        .await
+        .map_err(|e| {
+            warn!(session_id = %self.session_id, error = %e, "resend failed");
+            SessionError::ResendUnavailable { begin, end }
+        })?;
+        for item in items {
+            let message = match item {
+                ResendItem::Resend(message) => message,
+                ResendItem::GapFill {
+                    msg_seq_num,
+                    message,
+                } => self.frame_with_seq(&message, msg_seq_num),
+            };
+            self.transmit(connection, message, false).await?;
+        }
+        Ok(())
+    }
+
+    /// Sends Logout, waits for the counterparty's Logout and closes the
+    /// connection.
+    ///
+    /// Messages received before the Logout response are still delivered to
+    /// the application.
+    async fn logout(&self, connection: &mut Connection) -> Result<(), SessionError> {
+        self.send(connection, self.frame(&Logout::new().encode()))
+            .await?;
+
+        let acknowledged = timeout(self.config.logout_timeout, async {
+            while let Some(frame) = connection.framed.next().await {
+                let frame = frame.map_err(connection_error)?;
+                let Some(message) = self.receive_frame(connection, &frame).await? else {
+                    continue;
+                };
+                if *message.msg_type() == MsgType::Logout {
+                    return Ok(true);
+                }
+                let _ = self.dispatch(&message).await;
+            }
+            Ok::<_, SessionError>(false)
+        })
+        .await;
+        match acknowledged {
+            Ok(Ok(true)) => debug!(session_id = %self.session_id, "logout acknowledged"),
+            Ok(Ok(false)) => debug!(session_id = %self.session_id, "closed before logout ack"),
+            Ok(Err(e)) => warn!(session_id = %self.session_id, error = %e, "logout failed"),
+            Err(_) => warn!(session_id = %self.session_id, "timed out waiting for logout ack"),
+        }
+
+        SinkExt::<&[u8]>::close(&mut connection.framed)
+            .await
+            .map_err(connection_error)
+    }
+
+    /// Delivers a received message to the application and, once accepted,
+    /// to the router.
+    ///
+    /// # Returns
+    /// The messages the application queued in response, or the reject
+    /// message to send back instead.
+    async fn dispatch(&self, message: &RawMessage<'_>) -> Result<Vec<OwnedMessage>, OwnedMessage> {
+        let responder = Responder::new();
+        if let Some(reject) =
+            deliver(&*self.application, message, &self.session_id, &responder).await
+        {
+            return Err(reject);
+        }
+        if let Some(router) = self.router.as_deref()
+            && let Some(reject) = route(router, message, &self.session_id).await
+        {
+            return Err(reject);
+        }
+        Ok(responder.into_messages())
+    }
+
+    /// Frames and sends the messages queued in response to a received
+    /// message.
+    async fn respond(
+        &self,
+        connection: &mut Connection,
+        responses: Vec<OwnedMessage>,
+    ) -> Result<(), SessionError> {
+        for response in responses {
+            self.send(connection, self.frame(&response)).await?;
+        }
+        Ok(())
+    }
+
+    /// Passes a received frame to the raw hook and decodes it.
+    fn decode<'f>(&self, frame: &'f [u8]) -> Result<RawMessage<'f>, DecodeError> {
+        self.application.on_raw_in(&self.session_id, frame);
+        if let Some(logger) = &self.logger {
+            logger.on_incoming(&self.session_id, frame);
+        }
+        Decoder::new(frame)
+            .with_checksum_validation(self.config.validate_checksum)
+            .with_length_validation(self.config.validate_length)
+            .decode()
+    }
+
+    /// Decodes a received frame and records it as received.
+    ///
+    /// A message the codec delimited but whose BodyLength is wrong is
+    /// answered with a session-level Reject instead of ending the session.
+    ///
+    /// # Returns
+    /// The decoded message, or `None` if it was rejected.
+    ///
+    /// # Errors
+    /// Returns `SessionError::Connection` if the frame cannot be decoded or
+    /// the Reject cannot be sent.
+    async fn receive_frame<'f>(
+        &self,
+        connection: &mut Connection,
+        frame: &'f [u8],
+    ) -> Result<Option<RawMessage<'f>>, SessionError> {
+        match self.decode(frame) {
+            Ok(message) => {
+                self.on_received(connection, &message);
+                let in_sequence = self.check_sequence(connection, &message).await?;
+                Ok(in_sequence.then_some(message))
+            }
+            Err(DecodeError::IncorrectBodyLength { .. }) => {
+                let message = Decoder::new(frame)
+                    .with_checksum_validation(self.config.validate_checksum)
+                    .decode()
+                    .map_err(decode_error)?;
+                self.on_received(connection, &message);
+                if !self.check_sequence(connection, &message).await? {
+                    return Ok(None);
+                }
+                let reject = build_session_reject(
+                    message.get_field_as::<u64>(34).unwrap_or(0),
+                    message.msg_type(),
+                    REJECT_REASON_OTHER,
+                    None,
+                    Some("Incorrect BodyLength"),
+                );
+                self.send(connection, self.frame(&reject)).await?;
+                Ok(None)
+            }
+            Err(e) => Err(decode_error(e)),
+        }
+    }
+
+    /// Resets both sequence numbers to 1 and clears the sent messages, which
+    /// can no longer be resent under their sequence numbers.
+    pub(crate) async fn reset_sequences(&self) {
+        self.sequences.reset();
+        if let Err(e) = self.store.reset().await {
+            warn!(session_id = %self.session_id, error = %e, "message store reset failed");
+        }
+    }
+
+    /// Flushes the message store so that the messages sent so far can be
+    /// resent after a restart.
+    pub(crate) async fn flush_store(&self) {
+        if let Err(e) = self.store.flush().await {
+            warn!(session_id = %self.session_id, error = %e, "message store flush failed");
+        }
+    }
+
+    /// Checks the MsgSeqNum of a received message against the expected one.
+    ///
+    /// A message in sequence advances the expected sequence number, to the
+    /// NewSeqNo of a SequenceReset-GapFill. A message beyond it is held back
+    /// and requested again together with the missing messages before it,
+    /// through the connection's [`ResendTracker`]; a Logon or Logout is still
+    /// processed. A SequenceReset-Reset sets the expected sequence number
+    /// whatever its own MsgSeqNum. A message below it is ignored if it is a
+    /// possible duplicate (PossDupFlag Y); otherwise the session is logged
+    /// out with an error.
+    ///
+    /// # Returns
+    /// True if the message should be processed.
+    ///
+    /// # Errors
+    /// Returns `SessionError::SequenceTooLow` after sending the Logout for a
+    /// message below the expected sequence number, or
+    /// `SessionError::Connection` if the ResendRequest or Logout cannot be
+    /// sent.
+    async fn check_sequence(
+        &self,
+        connection: &mut Connection,
+        message: &RawMessage<'_>,
+    ) -> Result<bool, SessionError> {
+        let new_seq_num = message.get_field_as::<u64>(36).ok();
+        let is_sequence_reset = *message.msg_type() == MsgType::SequenceReset;
+        if is_sequence_reset && message.get_field_str(123) != Some("Y") {
+            if let Some(new_seq_num) = new_seq_num {
+                self.advance_target(connection, new_seq_num);
+            }
+            return Ok(true);
+        }
+
+        let received = message
+            .get_field_as::<u64>(34)
+            .unwrap_or_else(|_| self.sequences.next_target_seq().value());
+        match self.sequences.validate_incoming(received) {
+            SequenceResult::Ok => {
+                let next = match new_seq_num {
+                    Some(new_seq_num) if is_sequence_reset => new_seq_num.max(received + 1),
+                    _ => received + 1,
+                };
+                self.advance_target(connection, next);
+                Ok(true)
+            }
+            SequenceResult::Gap { expected, .. } => {
+                debug!(session_id = %self.session_id, expected, received, "sequence gap");
+                // The held-back message is requested along with the gap.
+                let gap = self.sequences.gap_range(received + 1);
+                if let Some(range) = gap.and_then(|gap| connection.resend.on_gap(gap)) {
+                    self.request_resend(connection, range).await?;
+                }
+                Ok(matches!(
+                    message.msg_type(),
+                    MsgType::Logon | MsgType::Logout
+                ))
+            }
+            SequenceResult::TooLow { .. } if message.get_field_str(43) == Some("Y") => Ok(false),
+            SequenceResult::TooLow { expected, .. } => {
+                warn!(
+                    session_id = %self.session_id,
+                    expected,
+                    received,
+                    "MsgSeqNum too low"
+                );
+                let text =
+                    format!("MsgSeqNum too low, expecting {expected} but received {received}");
+                let logout = Logout::new().with_text(text.as_str()).encode();
+                self.send(connection, self.frame(&logout)).await?;
+                Err(SessionError::SequenceTooLow { expected, received })
+            }
+        }
+    }
+
+    /// Moves the expected target sequence number forward.
+    fn advance_target(&self, connection: &mut Connection, next: u64) {
+        self.sequences.set_target_seq(next);
+        connection.resend.on_target_advanced(next);
+    }
+
+    /// Sends a ResendRequest for a range of sequence numbers, end exclusive.
+    async fn request_resend(
+        &self,
+        connection: &mut Connection,
+        range: Range<u64>,
+    ) -> Result<(), SessionError> {
+        debug!(
+            session_id = %self.session_id,
+            begin = range.start,
+            end = range.end,
+            "requesting resend"
+        );
+        let request = ResendRequest::for_range(range).encode();
+        self.send(connection, self.frame(&request)).await
+    }
+
+    /// Updates heartbeat and metrics state for a received message.
+    fn on_received(&self, connection: &mut Connection, message: &RawMessage<'_>) {
+        self.handle.record_received(self.clock.timestamp());
+        connection.heartbeat.on_message_received(
+            *message.msg_type() == MsgType::Heartbeat,
+            message.get_field_str(112),
+        );
+        self.metrics.record_received(
+            message.msg_type(),
+            message.get_field_as::<u64>(34).unwrap_or(0),
+            message.len(),
+        );
+    }
+
+    /// Writes a session event to the logger, if any.
+    pub(crate) fn log_event(&self, text: &str) {
+        if let Some(logger) = &self.logger {
+            logger.on_event(&self.session_id, text);
+        }
+    }
+
+    /// Returns the first configured routing field an inbound message lacks
+    /// or carries with a different value.
+    ///
+    /// The counterparty's SenderSubID (50) and SenderLocationID (142) must
+    /// match the configured target IDs, and its TargetSubID (57) and
+    /// TargetLocationID (143) the configured sender IDs. Unconfigured IDs are
+    /// not checked.
+    fn routing_mismatch(&self, message: &RawMessage<'_>) -> Option<u32> {
+        let expected = [
+            (50, &self.config.target_sub_id),
+            (142, &self.config.target_location_id),
+            (57, &self.config.sender_sub_id),
+            (143, &self.config.sender_location_id),
+        ];
+        expected.into_iter().find_map(|(tag, value)| {
+            let value = value.as_deref()?;
+            (message.get_field_str(tag) != Some(value)).then_some(tag)
+        })
+    }
+
+    /// Starts a message with the standard session header fields and the
+    /// next sender sequence number.
+    fn header(&self, msg_type: MsgType) -> MessageBuilder {
+        let seq_num = self.sequences.allocate_sender_seq().value();
+        self.header_with_seq(msg_type, seq_num)
+    }
+
+    /// Starts a message with the standard session header fields and the
+    /// given MsgSeqNum, leaving the live sender sequence untouched.
+    ///
+    /// Configured sub and location IDs follow SendingTime, keeping the
+    /// header order a strict decoder expects.
+    fn header_with_seq(&self, msg_type: MsgType, seq_num: u64) -> MessageBuilder {
+        let mut builder = MessageBuilder::new(self.config.begin_string.clone(), msg_type)
+            .field(49, self.config.sender_comp_id.as_str())
+            .field(56, self.config.target_comp_id.as_str())
+            .field(34, seq_num.to_string())
+            .field(52, self.clock.timestamp().format_millis().as_str());
+        let routing = [
+            (50, &self.config.sender_sub_id),
+            (142, &self.config.sender_location_id),
+            (57, &self.config.target_sub_id),
+            (143, &self.config.target_location_id),
+        ];
+        for (tag, value) in routing {
+            if let Some(value) = value {
+                builder = builder.field(tag, value.as_str());
+            }
+        }
+        builder
+    }
+
+    /// Adds the session header and trailer to a body-only message.
+    fn frame(&self, body: &OwnedMessage) -> OwnedMessage {
+        Self::fold_body(self.header(body.msg_type().clone()), body)
+    }
+
+    /// Adds the session header with the given MsgSeqNum and the trailer to a
+    /// body-only message, e.g. a SequenceReset-GapFill during a resend.
+    fn frame_with_seq(&self, body: &OwnedMessage, seq_num: u64) -> OwnedMessage {
+        Self::fold_body(self.header_with_seq(body.msg_type().clone(), seq_num), body)
+    }
+
+    /// Appends the fields of a body-only message to a header and builds it.
+    fn fold_body(header: MessageBuilder, body: &OwnedMessage) -> OwnedMessage {
+        body.fields()
+            .fold(header, |builder, (tag, value)| builder.field(tag, value))
+            .build()
+    }
+
+    /// Passes a message to the application, stores it for resending and
+    /// writes it to the connection.
+    async fn send(
+        &self,
+        connection: &mut Connection,
+        message: OwnedMessage,
+    ) -> Result<(), SessionError> {
+        self.transmit(connection, message, true).await
+    }
+
+    /// Passes a message to the application and writes it to the connection,
+    /// storing it first if `store` is set.
+    ///
+    /// Resent messages and GapFills are not stored again.
+    async fn transmit(
+        &self,
+        connection: &mut Connection,
+        mut message: OwnedMessage,
+        store: bool,
+    ) -> Result<(), SessionError> {
+        prepare_outbound(&*self.application, &mut message, &self.session_id)
+            .await
+            .map_err(|e| SessionError::Connection(e.to_string()))?;
+        let seq_num = message
+            .get_field_str(34)
+            .and_then(|seq_num| seq_num.parse().ok())
+            .unwrap_or(0);
+        if store {
+            self.store
+                .store(seq_num, message.as_bytes())
+                .await
+                .map_err(|e| SessionError::Connection(e.to_string()))?;
+        }
+        self.application
+            .on_raw_out(&self.session_id, message.as_bytes());
+        if let Some(logger) = &self.logger {
+            logger.on_outgoing(&self.session_id, message.as_bytes());
+        }
+        connection
+            .framed
+            .send(message.as_bytes())
+            .await
+            .map_err(connection_error)?;
+        connection.heartbeat.on_message_sent();
+        self.handle.record_sent(self.clock.timestamp());
+        self.metrics
+            .record_sent(message.msg_type(), seq_num, message.len());
+        Ok(())
+    }
+}
+
+/// Wraps a decode error as a session connection error.
+fn decode_error(error: DecodeError) -> SessionError {
+    SessionError::Connection(error.to_string())
+}
+
+/// Wraps a transport error as a session connection error.
+pub(crate) fn connection_error(error: impl std::fmt::Display) -> SessionError {
+    SessionError::Connection(error.to_string())
+}
@@ -0,0 +1,155 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Routing inbound connections to sessions by CompID.
+
+mod common;
+
+use common::{CountingApplication, receive};
+use futures::SinkExt;
+use ironfix_core::message::{MessageBuilder, MsgType};
+use ironfix_core::types::CompId;
+use ironfix_engine::{Acceptor, Initiator, SessionId};
+use ironfix_session::config::SessionConfig;
+use ironfix_transport::codec::FixCodec;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::codec::Framed;
+
+fn config(sender: &str, target: &str) -> SessionConfig {
+    SessionConfig::new(
+        CompId::new(sender).unwrap(),
+        CompId::new(target).unwrap(),
+        "FIX.4.4",
+    )
+}
+
+/// Sends a Logon from `sender` to SERVER and returns the response.
+async fn raw_logon(addr: &str, sender: &str) -> ironfix_core::message::OwnedMessage {
+    let stream = TcpStream::connect(addr).await.unwrap();
+    let mut framed = Framed::new(stream, FixCodec::new());
+    let logon = MessageBuilder::new("FIX.4.4", MsgType::Logon)
+        .field(49, sender)
+        .field(56, "SERVER")
+        .field(34, "1")
+        .field(52, "20260127-10:00:00.000")
+        .field(98, "0")
+        .field(108, "30")
+        .build();
+    framed.send(logon.as_bytes()).await.unwrap();
+    receive(&mut framed).await
+}
+
+#[tokio::test]
+async fn test_acceptor_routes_sessions_by_comp_id() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let server_app = CountingApplication::default();
+    let acceptor = Arc::new(
+        Acceptor::new(
+            Arc::new(server_app.clone()),
+            [config("SERVER", "CLIENT1"), config("SERVER", "CLIENT2")],
+        )
+        .unwrap(),
+    );
+    let server = tokio::spawn({
+        let acceptor = Arc::clone(&acceptor);
+        async move { acceptor.run(listener).await }
+    });
+
+    let client_app = Arc::new(CountingApplication::default());
+    let clients: Vec<_> = ["CLIENT1", "CLIENT2"]
+        .into_iter()
+        .map(|name| {
+            Arc::new(Initiator::new(
+                Arc::clone(&client_app),
+                config(name, "SERVER"),
+                addr.clone(),
+            ))
+        })
+        .collect();
+    let runners: Vec<_> = clients
+        .iter()
+        .map(|client| {
+            let client = Arc::clone(client);
+            tokio::spawn(async move { client.run().await })
+        })
+        .collect();
+
+    let first = SessionId::new("FIX.4.4", "SERVER", "CLIENT1");
+    let second = SessionId::new("FIX.4.4", "SERVER", "CLIENT2");
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !clients.iter().all(|client| client.handle().is_logged_on()) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    for session_id in [&first, &second] {
+        assert!(acceptor.session(session_id).unwrap().is_logged_on());
+        assert_eq!(acceptor.metrics(session_id).unwrap().logons(), 1);
+    }
+
+    // A Logon from an unconfigured counterparty is refused.
+    let response = raw_logon(&addr, "CLIENT3").await;
+    assert_eq!(*response.msg_type(), MsgType::Logout);
+    assert_eq!(response.get_field_str(58), Some("Unknown session"));
+    assert_eq!(response.get_field_str(56), Some("CLIENT3"));
+
+    // So is a second connection for a session that is already connected.
+    let response = raw_logon(&addr, "CLIENT1").await;
+    assert_eq!(*response.msg_type(), MsgType::Logout);
+    assert_eq!(
+        response.get_field_str(58),
+        Some("Session already connected")
+    );
+    assert_eq!(acceptor.metrics(&first).unwrap().connections(), 1);
+
+    // Logging out the first client leaves the second session connected.
+    clients[0].shutdown().await;
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while acceptor.session(&first).unwrap().is_logged_on() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert!(acceptor.session(&second).unwrap().is_logged_on());
+
+    tokio::time::timeout(Duration::from_secs(5), acceptor.shutdown())
+        .await
+        .unwrap();
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .unwrap()
+        .unwrap();
+    clients[1].shutdown().await;
+    for runner in runners {
+        assert!(runner.await.unwrap().is_ok());
+    }
+    assert_eq!(
+        server_app.logouts.load(std::sync::atomic::Ordering::SeqCst),
+        2
+    );
+}
+
+#[test]
+fn test_acceptor_rejects_invalid_config() {
+    let invalid = SessionConfig::new(
+        CompId::new("SERVER").unwrap(),
+        CompId::new("CLIENT").unwrap(),
+        "FIX4.4",
+    );
+    let result = Acceptor::new(
+        Arc::new(CountingApplication::default()),
+        [config("SERVER", "CLIENT1"), invalid],
+    );
+    assert!(matches!(
+        result,
+        Err(ironfix_core::error::SessionError::Configuration(_))
+    ));
+}
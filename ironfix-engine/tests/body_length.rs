@@ -0,0 +1,130 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Session-level rejection of messages with an incorrect BodyLength.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against, run_scripted};
+use futures::StreamExt;
+use ironfix_core::message::{MsgType, OwnedMessage};
+use ironfix_engine::EngineBuilder;
+use ironfix_tagvalue::checksum::calculate_checksum;
+
+/// Asserts that a message is a session-level Reject for an incorrect
+/// BodyLength of message `ref_seq_num`.
+fn assert_body_length_reject(reject: &OwnedMessage, ref_seq_num: &str) {
+    assert_eq!(*reject.msg_type(), MsgType::Reject);
+    assert_eq!(reject.get_field_str(45), Some(ref_seq_num));
+    assert_eq!(reject.get_field_str(58), Some("Incorrect BodyLength"));
+}
+
+/// Re-encodes a message with BodyLength offset by `delta` and a valid checksum.
+fn with_wrong_body_length(message: &[u8], delta: isize) -> Vec<u8> {
+    let text = std::str::from_utf8(message).unwrap();
+    let body_start = text.find("\x019=").unwrap() + 3;
+    let body_end = body_start + text[body_start..].find('\x01').unwrap();
+    let declared: isize = text[body_start..body_end].parse().unwrap();
+    let trailer = text.rfind("10=").unwrap();
+
+    let without_checksum = format!(
+        "{}{}{}",
+        &text[..body_start],
+        declared + delta,
+        &text[body_end..trailer]
+    );
+    let checksum = calculate_checksum(without_checksum.as_bytes());
+    format!("{without_checksum}10={checksum:03}\x01").into_bytes()
+}
+
+async fn assert_rejected(delta: isize) {
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config());
+    let (_, (reject, heartbeat)) = run_against(builder, async |peer| {
+        let news = peer.message(MsgType::News).field(148, "HEADLINE").build();
+        let bad = with_wrong_body_length(news.as_bytes(), delta);
+        peer.send(&bad).await;
+
+        // The session survives and answers a following TestRequest.
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "AFTER")
+            .build();
+        peer.send(request.as_bytes()).await;
+
+        (peer.receive().await, peer.receive().await)
+    })
+    .await;
+
+    assert_body_length_reject(&reject, "2");
+    assert_eq!(reject.get_field_str(372), Some("B"));
+    assert_eq!(*heartbeat.msg_type(), MsgType::Heartbeat);
+    assert_eq!(heartbeat.get_field_str(112), Some("AFTER"));
+}
+
+#[tokio::test]
+async fn test_body_length_too_short_is_rejected() {
+    assert_rejected(-4).await;
+}
+
+#[tokio::test]
+async fn test_body_length_too_long_is_rejected() {
+    assert_rejected(25).await;
+}
+
+#[tokio::test]
+async fn test_logon_response_with_wrong_body_length_is_rejected() {
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config());
+    run_scripted(builder, async |engine, peer| {
+        let logon = peer
+            .message(MsgType::Logon)
+            .field(98, "0")
+            .field(108, "30")
+            .build();
+        peer.send(&with_wrong_body_length(logon.as_bytes(), -3))
+            .await;
+        assert_body_length_reject(&peer.receive().await, "1");
+
+        // A valid Logon response still completes the logon.
+        peer.answer_logon(|logon| logon).await;
+        let request = peer.message(MsgType::TestRequest).field(112, "UP").build();
+        peer.send(request.as_bytes()).await;
+        assert_eq!(peer.receive().await.get_field_str(112), Some("UP"));
+
+        tokio::join!(engine.shutdown(), peer.acknowledge_logout());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_logout_ack_with_wrong_body_length_is_rejected() {
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config());
+    run_scripted(builder, async |engine, peer| {
+        peer.answer_logon(|logon| logon).await;
+        let request = peer.message(MsgType::TestRequest).field(112, "UP").build();
+        peer.send(request.as_bytes()).await;
+        peer.receive().await;
+
+        tokio::join!(engine.shutdown(), async {
+            let logout = peer.receive().await;
+            assert_eq!(*logout.msg_type(), MsgType::Logout);
+            let bad = peer.message(MsgType::Logout).build();
+            peer.send(&with_wrong_body_length(bad.as_bytes(), -2)).await;
+            assert_body_length_reject(&peer.receive().await, "3");
+
+            // The engine keeps waiting for a valid Logout before closing.
+            let response = peer.message(MsgType::Logout).build();
+            peer.send(response.as_bytes()).await;
+            assert!(peer.framed.next().await.is_none());
+        });
+    })
+    .await;
+}
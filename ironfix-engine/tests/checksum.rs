@@ -0,0 +1,99 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Honoring the session's checksum validation setting.
+
+mod common;
+
+use common::{CountingApplication, client_config, receive, run_against};
+use futures::SinkExt;
+use ironfix_core::message::{MessageBuilder, MsgType};
+use ironfix_core::types::CompId;
+use ironfix_engine::{Acceptor, EngineBuilder};
+use ironfix_session::config::SessionConfig;
+use ironfix_transport::codec::FixCodec;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::codec::Framed;
+
+/// Replaces a message's CheckSum (tag 10) with one that does not match.
+fn with_wrong_checksum(message: &[u8]) -> Vec<u8> {
+    let trailer = message.len() - 4;
+    let declared: u8 = std::str::from_utf8(&message[trailer..trailer + 3])
+        .unwrap()
+        .parse()
+        .unwrap();
+    let mut bad = message[..trailer].to_vec();
+    bad.extend_from_slice(format!("{:03}\x01", declared.wrapping_add(1)).as_bytes());
+    bad
+}
+
+#[tokio::test]
+async fn test_bad_checksum_processed_without_validation() {
+    let config = SessionConfig {
+        validate_checksum: false,
+        ..client_config()
+    };
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(config);
+    let (_, heartbeat) = run_against(builder, async |peer| {
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "BAD-SUM")
+            .build();
+        peer.send(&with_wrong_checksum(request.as_bytes())).await;
+        peer.receive().await
+    })
+    .await;
+
+    assert_eq!(*heartbeat.msg_type(), MsgType::Heartbeat);
+    assert_eq!(heartbeat.get_field_str(112), Some("BAD-SUM"));
+}
+
+#[tokio::test]
+async fn test_acceptor_logon_with_bad_checksum_without_validation() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let config = SessionConfig {
+        validate_checksum: false,
+        ..SessionConfig::new(
+            CompId::new("SERVER").unwrap(),
+            CompId::new("CLIENT").unwrap(),
+            "FIX.4.4",
+        )
+    };
+    let acceptor =
+        Arc::new(Acceptor::new(Arc::new(CountingApplication::default()), [config]).unwrap());
+    let server = tokio::spawn({
+        let acceptor = Arc::clone(&acceptor);
+        async move { acceptor.run(listener).await }
+    });
+
+    let stream = TcpStream::connect(addr).await.unwrap();
+    let mut framed = Framed::new(stream, FixCodec::new());
+    let logon = MessageBuilder::new("FIX.4.4", MsgType::Logon)
+        .field(49, "CLIENT")
+        .field(56, "SERVER")
+        .field(34, "1")
+        .field(52, "20260127-10:00:00.000")
+        .field(98, "0")
+        .field(108, "30")
+        .build();
+    framed
+        .send(with_wrong_checksum(logon.as_bytes()).as_slice())
+        .await
+        .unwrap();
+    let response = receive(&mut framed).await;
+    assert_eq!(*response.msg_type(), MsgType::Logon);
+
+    drop(framed);
+    tokio::time::timeout(Duration::from_secs(5), acceptor.shutdown())
+        .await
+        .unwrap();
+    server.await.unwrap();
+}
@@ -0,0 +1,256 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Shared helpers for engine integration tests.
+
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use ironfix_core::message::{MessageBuilder, MsgType, OwnedMessage, RawMessage};
+use ironfix_core::types::CompId;
+use ironfix_engine::application::{RejectReason, Responder, SessionId};
+use ironfix_engine::{Application, Engine, EngineBuilder};
+use ironfix_session::config::SessionConfig;
+use ironfix_tagvalue::Decoder;
+use ironfix_transport::codec::FixCodec;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::codec::Framed;
+
+/// Counts logon and logout callbacks and captures raw frames.
+#[derive(Debug, Default, Clone)]
+pub struct CountingApplication {
+    pub logons: Arc<AtomicU32>,
+    pub logouts: Arc<AtomicU32>,
+    pub raw_in: Arc<Mutex<Vec<Vec<u8>>>>,
+    pub raw_out: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+#[async_trait]
+impl Application for CountingApplication {
+    async fn on_create(&self, _session_id: &SessionId) {}
+
+    async fn on_logon(&self, _session_id: &SessionId) {
+        self.logons.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn on_logout(&self, _session_id: &SessionId) {
+        self.logouts.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn to_admin(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+    async fn from_admin(
+        &self,
+        _message: &RawMessage<'_>,
+        _session_id: &SessionId,
+        _responder: &Responder,
+    ) -> Result<(), RejectReason> {
+        Ok(())
+    }
+
+    async fn to_app(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+    async fn from_app(
+        &self,
+        _message: &RawMessage<'_>,
+        _session_id: &SessionId,
+        _responder: &Responder,
+    ) -> Result<(), RejectReason> {
+        Ok(())
+    }
+
+    fn on_raw_in(&self, _session_id: &SessionId, bytes: &[u8]) {
+        self.raw_in.lock().unwrap().push(bytes.to_vec());
+    }
+
+    fn on_raw_out(&self, _session_id: &SessionId, bytes: &[u8]) {
+        self.raw_out.lock().unwrap().push(bytes.to_vec());
+    }
+}
+
+/// Returns the CLIENT->SERVER session configuration used by the tests.
+pub fn client_config() -> SessionConfig {
+    SessionConfig::new(
+        CompId::new("CLIENT").unwrap(),
+        CompId::new("SERVER").unwrap(),
+        "FIX.4.4",
+    )
+}
+
+/// Builds a SERVER->CLIENT message with the standard header.
+pub fn server_message(msg_type: MsgType, seq_num: u64) -> MessageBuilder {
+    MessageBuilder::new("FIX.4.4", msg_type)
+        .field(49, "SERVER")
+        .field(56, "CLIENT")
+        .field(34, seq_num.to_string())
+        .field(52, "20260127-10:00:00.000")
+}
+
+/// Reads the next message from the connection.
+pub async fn receive(framed: &mut Framed<TcpStream, FixCodec>) -> OwnedMessage {
+    let frame = framed.next().await.unwrap().unwrap();
+    Decoder::new(&frame).decode().unwrap().to_owned()
+}
+
+/// Accepts a connection and answers its Logon.
+///
+/// # Returns
+/// The connection and the MsgSeqNum of the received Logon.
+pub async fn accept_logon(
+    listener: &TcpListener,
+    seq_num: u64,
+) -> (Framed<TcpStream, FixCodec>, u64) {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut framed = Framed::new(stream, FixCodec::new());
+
+    let logon = receive(&mut framed).await;
+    assert_eq!(*logon.msg_type(), MsgType::Logon);
+    let logon_seq_num = logon.get_field_str(34).unwrap().parse().unwrap();
+
+    let response = server_message(MsgType::Logon, seq_num)
+        .field(98, "0")
+        .field(108, "30")
+        .build();
+    framed.send(response.as_bytes()).await.unwrap();
+    (framed, logon_seq_num)
+}
+
+/// Scripted SERVER side of a session, numbering its messages from 1.
+pub struct Peer {
+    /// The accepted connection.
+    pub framed: Framed<TcpStream, FixCodec>,
+    /// The engine's Logon.
+    pub logon: OwnedMessage,
+    /// MsgSeqNum of the next message built.
+    next_seq: u64,
+}
+
+impl Peer {
+    /// Starts a SERVER->CLIENT message carrying the next MsgSeqNum.
+    pub fn message(&mut self, msg_type: MsgType) -> MessageBuilder {
+        let seq_num = self.next_seq;
+        self.next_seq += 1;
+        server_message(msg_type, seq_num)
+    }
+
+    /// Skips the next MsgSeqNum, as if the message carrying it was lost.
+    ///
+    /// # Returns
+    /// The skipped sequence number.
+    pub fn skip(&mut self) -> u64 {
+        self.next_seq += 1;
+        self.next_seq - 1
+    }
+
+    /// Writes raw message bytes to the engine.
+    pub async fn send(&mut self, message: &[u8]) {
+        self.framed.send(message).await.unwrap();
+    }
+
+    /// Reads the next message from the engine.
+    pub async fn receive(&mut self) -> OwnedMessage {
+        receive(&mut self.framed).await
+    }
+
+    /// Answers the engine's Logon.
+    ///
+    /// # Arguments
+    /// * `extra` - Adds fields to the response after the standard header
+    pub async fn answer_logon(&mut self, extra: impl FnOnce(MessageBuilder) -> MessageBuilder) {
+        let response = extra(self.message(MsgType::Logon))
+            .field(98, "0")
+            .field(108, "30")
+            .build();
+        self.send(response.as_bytes()).await;
+    }
+
+    /// Answers the engine's Logout and waits for it to close the connection.
+    pub async fn acknowledge_logout(&mut self) {
+        let logout = self.receive().await;
+        assert_eq!(*logout.msg_type(), MsgType::Logout);
+        let response = self.message(MsgType::Logout).build();
+        self.send(response.as_bytes()).await;
+        assert!(self.framed.next().await.is_none());
+    }
+}
+
+/// Runs a single-session engine against a scripted acceptor.
+///
+/// The acceptor answers the engine's Logon and runs `script`, which must
+/// leave the session logged on. The engine is then stopped with
+/// [`Engine::shutdown`], the acceptor acknowledges its Logout, and
+/// `Engine::run` must return `Ok`. Reconnecting is disabled, so a session
+/// that drops early fails the test instead of retrying.
+///
+/// # Returns
+/// The stopped engine and the script's output.
+pub async fn run_against<A: Application, T>(
+    builder: EngineBuilder<A>,
+    script: impl AsyncFnOnce(&mut Peer) -> T,
+) -> (Engine<A>, T) {
+    run_against_with_logon(builder, |logon| logon, script).await
+}
+
+/// Like [`run_against`], with `logon` adding fields to the acceptor's Logon
+/// response after the standard header.
+pub async fn run_against_with_logon<A: Application, T>(
+    builder: EngineBuilder<A>,
+    logon: impl FnOnce(MessageBuilder) -> MessageBuilder,
+    script: impl AsyncFnOnce(&mut Peer) -> T,
+) -> (Engine<A>, T) {
+    run_scripted(builder, async |engine, peer| {
+        peer.answer_logon(logon).await;
+        let output = script(peer).await;
+        tokio::join!(engine.shutdown(), peer.acknowledge_logout());
+        output
+    })
+    .await
+}
+
+/// Runs a single-session engine against an acceptor scripted from the
+/// engine's Logon on.
+///
+/// `script` answers the Logon and ends the session itself, e.g. with
+/// [`Engine::shutdown`] and [`Peer::acknowledge_logout`]; `Engine::run`
+/// must then return `Ok`. Reconnecting is disabled.
+///
+/// # Returns
+/// The stopped engine and the script's output.
+pub async fn run_scripted<A: Application, T>(
+    builder: EngineBuilder<A>,
+    script: impl AsyncFnOnce(&Engine<A>, &mut Peer) -> T,
+) -> (Engine<A>, T) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let engine = builder
+        .with_max_reconnect_attempts(0)
+        .build(listener.local_addr().unwrap().to_string())
+        .unwrap();
+
+    let acceptor = async {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut framed = Framed::new(stream, FixCodec::new());
+        let logon = receive(&mut framed).await;
+        assert_eq!(*logon.msg_type(), MsgType::Logon);
+
+        let mut peer = Peer {
+            framed,
+            logon,
+            next_seq: 1,
+        };
+        script(&engine, &mut peer).await
+    };
+    let (result, output) = tokio::time::timeout(Duration::from_secs(5), async {
+        tokio::join!(engine.run(), acceptor)
+    })
+    .await
+    .expect("scripted session did not finish");
+    assert_eq!(result, Ok(()));
+    (engine, output)
+}
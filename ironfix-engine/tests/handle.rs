@@ -0,0 +1,72 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Session handle state across a logon handshake and shutdown.
+
+mod common;
+
+use common::{CountingApplication, accept_logon, client_config, receive, server_message};
+use futures::{SinkExt, StreamExt};
+use ironfix_core::message::MsgType;
+use ironfix_engine::EngineBuilder;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn test_session_handle_reflects_logon() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let acceptor = tokio::spawn(async move {
+        let (mut framed, _) = accept_logon(&listener, 1).await;
+
+        let logout = receive(&mut framed).await;
+        assert_eq!(*logout.msg_type(), MsgType::Logout);
+        let response = server_message(MsgType::Logout, 2).build();
+        framed.send(response.as_bytes()).await.unwrap();
+        assert!(framed.next().await.is_none());
+    });
+
+    let engine = Arc::new(
+        EngineBuilder::new()
+            .with_application(CountingApplication::default())
+            .add_session(client_config())
+            .build(addr.to_string())
+            .unwrap(),
+    );
+    let session_id = engine.sessions().next().unwrap().clone();
+    let handle = engine.session(&session_id).unwrap();
+    assert!(!handle.is_logged_on());
+    assert_eq!(handle.last_sent(), None);
+    assert_eq!(handle.last_received(), None);
+    assert_eq!(handle.next_sender_seq().value(), 1);
+
+    let runner = tokio::spawn({
+        let engine = Arc::clone(&engine);
+        async move { engine.run().await }
+    });
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !handle.is_logged_on() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert!(handle.last_sent().is_some());
+    assert!(handle.last_received().is_some());
+    assert_eq!(handle.next_sender_seq().value(), 2);
+    assert_eq!(handle.next_target_seq().value(), 2);
+    assert_eq!(handle.session_id(), &session_id);
+
+    tokio::time::timeout(Duration::from_secs(5), engine.shutdown())
+        .await
+        .unwrap();
+    acceptor.await.unwrap();
+    assert!(runner.await.unwrap().is_ok());
+    assert!(!handle.is_logged_on());
+    assert_eq!(handle.next_sender_seq().value(), 3);
+}
@@ -0,0 +1,82 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! FIX message and event log files written by the engine.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against};
+use ironfix_core::message::MsgType;
+use ironfix_core::types::Timestamp;
+use ironfix_engine::application::SessionId;
+use ironfix_engine::{EngineBuilder, FileMessageLogger, MessageLogger};
+use ironfix_session::clock::MockClock;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_file_logger_records_sent_and_received_messages() {
+    let dir = std::env::temp_dir().join(format!("ironfix_engine_logging_{}", std::process::id()));
+    let clock = MockClock::new(Timestamp::from_nanos(1_769_508_000_000_000_000));
+    let logger = Arc::new(
+        FileMessageLogger::new(&dir)
+            .unwrap()
+            .with_clock(Arc::new(clock)),
+    );
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config())
+        .with_message_logger(logger.clone());
+    let (engine, (request, heartbeat)) = run_against(builder, async |peer| {
+        let request = peer.message(MsgType::TestRequest).field(112, "LOG").build();
+        peer.send(request.as_bytes()).await;
+        (request, peer.receive().await)
+    })
+    .await;
+    let session_id = engine.sessions().next().unwrap();
+    let received = String::from_utf8(request.as_bytes().to_vec()).unwrap();
+    let sent = String::from_utf8(heartbeat.as_bytes().to_vec()).unwrap();
+
+    let messages = std::fs::read_to_string(logger.messages_path(session_id)).unwrap();
+    let events = std::fs::read_to_string(logger.event_path(session_id)).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // Logon, TestRequest and Logout in each direction.
+    assert_eq!(messages.lines().count(), 6);
+    assert!(messages.contains(&format!(" : {received}\n")));
+    assert!(messages.contains(&format!(" : {sent}\n")));
+    assert!(events.contains(" : Logon completed\n"));
+    assert!(events.contains(" : Session ended\n"));
+    assert!(
+        messages
+            .lines()
+            .chain(events.lines())
+            .all(|line| line.starts_with("20260127-10:00:00.000 : "))
+    );
+}
+
+#[test]
+fn test_file_logger_keeps_hostile_comp_ids_inside_its_directory() {
+    let dir = std::env::temp_dir().join(format!("ironfix_engine_hostile_{}", std::process::id()));
+    let logger = FileMessageLogger::new(&dir).unwrap();
+    let session_id = SessionId::new("FIX.4.4", "../../etc/x", "TARGET").with_sender_sub_id("/tmp");
+
+    logger.on_event(&session_id, "hostile");
+    let messages_path = logger.messages_path(&session_id);
+    let event_path = logger.event_path(&session_id);
+    let entries: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(messages_path.parent(), Some(dir.as_path()));
+    assert_eq!(event_path.parent(), Some(dir.as_path()));
+    assert_eq!(
+        event_path.file_name().unwrap(),
+        "FIX.4.4-..%252F..%252Fetc%252Fx_%252Ftmp-TARGET.event.log"
+    );
+    assert!(entries.contains(&event_path.file_name().unwrap().to_owned()));
+}
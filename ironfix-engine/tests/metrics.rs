@@ -0,0 +1,44 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Session metrics collected by the engine during a message exchange.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against};
+use ironfix_core::message::MsgType;
+use ironfix_engine::EngineBuilder;
+
+#[tokio::test]
+async fn test_engine_metrics_count_exchange() {
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config());
+    let (engine, ()) = run_against(builder, async |peer| {
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "PING")
+            .build();
+        peer.send(request.as_bytes()).await;
+
+        let heartbeat = peer.receive().await;
+        assert_eq!(*heartbeat.msg_type(), MsgType::Heartbeat);
+        assert_eq!(heartbeat.get_field_str(112), Some("PING"));
+    })
+    .await;
+
+    // Logon, the TestRequest exchange and Logout in each direction.
+    let session_id = engine.sessions().next().unwrap();
+    let metrics = engine.metrics(session_id).unwrap().snapshot();
+    assert_eq!(metrics.connections, 1);
+    assert_eq!(metrics.logons, 1);
+    assert_eq!(metrics.messages_sent, 3);
+    assert_eq!(metrics.messages_received, 3);
+    assert_eq!(metrics.last_sent_seq_num, 3);
+    assert_eq!(metrics.last_received_seq_num, 3);
+    assert!(metrics.bytes_sent > 0);
+    assert!(metrics.bytes_received > 0);
+}
@@ -0,0 +1,36 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Raw wire-bytes callbacks on the engine's receive and send paths.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against};
+use ironfix_core::message::MsgType;
+use ironfix_engine::EngineBuilder;
+
+#[tokio::test]
+async fn test_raw_hooks_receive_exact_frames() {
+    let application = CountingApplication::default();
+    let builder = EngineBuilder::new()
+        .with_application(application.clone())
+        .add_session(client_config());
+    let (_, (request, heartbeat)) = run_against(builder, async |peer| {
+        let request = peer.message(MsgType::TestRequest).field(112, "RAW").build();
+        peer.send(request.as_bytes()).await;
+        (request, peer.receive().await)
+    })
+    .await;
+
+    // Logon, TestRequest and Logout in each direction.
+    let raw_in = application.raw_in.lock().unwrap();
+    assert_eq!(raw_in.len(), 3);
+    assert_eq!(raw_in[1], request.as_bytes());
+
+    let raw_out = application.raw_out.lock().unwrap();
+    assert_eq!(raw_out.len(), 3);
+    assert_eq!(raw_out[1], heartbeat.as_bytes());
+}
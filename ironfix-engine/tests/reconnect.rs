@@ -0,0 +1,54 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Initiator reconnect behaviour against a scripted acceptor.
+
+mod common;
+
+use common::{CountingApplication, accept_logon, client_config};
+use ironfix_engine::EngineBuilder;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// Accepts `connections` logons, closing each connection after answering,
+/// then stops listening. Returns the MsgSeqNum of each received Logon.
+async fn scripted_acceptor(listener: TcpListener, connections: u64) -> Vec<u64> {
+    let mut logon_seq_nums = Vec::new();
+    for seq_num in 1..=connections {
+        let (_framed, logon_seq_num) = accept_logon(&listener, seq_num).await;
+        logon_seq_nums.push(logon_seq_num);
+    }
+    logon_seq_nums
+}
+
+#[tokio::test]
+async fn test_initiator_reconnects_after_acceptor_closes() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let acceptor = tokio::spawn(scripted_acceptor(listener, 2));
+
+    let application = CountingApplication::default();
+    let initiator = EngineBuilder::new()
+        .with_application(application.clone())
+        .add_session(client_config())
+        .with_reconnect_interval(Duration::from_millis(10))
+        .with_max_reconnect_interval(Duration::from_millis(40))
+        .with_max_reconnect_attempts(2)
+        .build_initiator(addr.to_string())
+        .unwrap();
+
+    // Two sessions, then the acceptor is gone and both retries fail.
+    let result = tokio::time::timeout(Duration::from_secs(5), initiator.run())
+        .await
+        .unwrap();
+    assert!(result.is_err());
+
+    // Sequence numbers carry over to the second connection.
+    assert_eq!(acceptor.await.unwrap(), [1, 2]);
+    assert_eq!(application.logons.load(Ordering::SeqCst), 2);
+    assert_eq!(application.logouts.load(Ordering::SeqCst), 2);
+}
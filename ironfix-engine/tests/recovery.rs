@@ -0,0 +1,124 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Answering ResendRequests from the message store.
+
+mod common;
+
+use async_trait::async_trait;
+use common::{client_config, run_against};
+use ironfix_core::message::{MessageBuilder, MsgType, OwnedMessage, RawMessage};
+use ironfix_engine::application::{RejectReason, Responder, SessionId};
+use ironfix_engine::{Application, EngineBuilder};
+
+/// Echoes every News headline back to the counterparty.
+struct EchoApplication;
+
+#[async_trait]
+impl Application for EchoApplication {
+    async fn on_create(&self, _session_id: &SessionId) {}
+
+    async fn on_logon(&self, _session_id: &SessionId) {}
+
+    async fn on_logout(&self, _session_id: &SessionId) {}
+
+    async fn to_admin(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+    async fn from_admin(
+        &self,
+        _message: &RawMessage<'_>,
+        _session_id: &SessionId,
+        _responder: &Responder,
+    ) -> Result<(), RejectReason> {
+        Ok(())
+    }
+
+    async fn to_app(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+    async fn from_app(
+        &self,
+        message: &RawMessage<'_>,
+        _session_id: &SessionId,
+        responder: &Responder,
+    ) -> Result<(), RejectReason> {
+        let echo = MessageBuilder::new("FIX.4.4", MsgType::News)
+            .field(148, message.get_field_str(148).unwrap_or_default())
+            .build();
+        responder.send(echo);
+        Ok(())
+    }
+}
+
+/// Returns the number of MsgSeqNum fields in a message.
+fn seq_num_count(message: &OwnedMessage) -> usize {
+    message
+        .as_bytes()
+        .split(|&byte| byte == 0x01)
+        .filter(|field| field.starts_with(b"34="))
+        .count()
+}
+
+#[tokio::test]
+async fn test_resend_request_gap_fills_admin_message() {
+    let builder = EngineBuilder::new()
+        .with_application(EchoApplication)
+        .add_session(client_config());
+    let (_, (answer, heartbeat)) = run_against(builder, async |peer| {
+        // The engine sends News 2, Heartbeat 3 and News 4.
+        let first = peer.message(MsgType::News).field(148, "FIRST").build();
+        peer.send(first.as_bytes()).await;
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "MIDDLE")
+            .build();
+        peer.send(request.as_bytes()).await;
+        let second = peer.message(MsgType::News).field(148, "SECOND").build();
+        peer.send(second.as_bytes()).await;
+        for _ in 0..3 {
+            peer.receive().await;
+        }
+
+        let resend = peer
+            .message(MsgType::ResendRequest)
+            .field(7, "2")
+            .field(16, "4")
+            .build();
+        peer.send(resend.as_bytes()).await;
+        let answer = [
+            peer.receive().await,
+            peer.receive().await,
+            peer.receive().await,
+        ];
+
+        // Resending does not consume live sequence numbers.
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "AFTER")
+            .build();
+        peer.send(request.as_bytes()).await;
+        (answer, peer.receive().await)
+    })
+    .await;
+
+    let [first, gap_fill, second] = &answer;
+    for (resent, seq_num, headline) in [(first, "2", "FIRST"), (second, "4", "SECOND")] {
+        assert_eq!(*resent.msg_type(), MsgType::News);
+        assert_eq!(resent.get_field_str(34), Some(seq_num));
+        assert_eq!(resent.get_field_str(43), Some("Y"));
+        assert!(resent.get_field(122).is_some());
+        assert_eq!(resent.get_field_str(148), Some(headline));
+    }
+
+    assert_eq!(*gap_fill.msg_type(), MsgType::SequenceReset);
+    assert_eq!(gap_fill.get_field_str(34), Some("3"));
+    assert_eq!(seq_num_count(gap_fill), 1);
+    assert_eq!(gap_fill.get_field_str(123), Some("Y"));
+    assert_eq!(gap_fill.get_field_str(36), Some("4"));
+    assert_eq!(gap_fill.verify(), Ok(()));
+
+    assert_eq!(*heartbeat.msg_type(), MsgType::Heartbeat);
+    assert_eq!(heartbeat.get_field_str(34), Some("5"));
+}
@@ -0,0 +1,93 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Gap detection and ResendRequest on the receive path.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against};
+use ironfix_core::message::{MsgType, OwnedMessage};
+use ironfix_engine::EngineBuilder;
+use std::time::Duration;
+
+/// Asserts that a message is a ResendRequest for `begin..=end`.
+fn assert_resend_request(request: &OwnedMessage, begin: &str, end: &str) {
+    assert_eq!(*request.msg_type(), MsgType::ResendRequest);
+    assert_eq!(request.get_field_str(7), Some(begin));
+    assert_eq!(request.get_field_str(16), Some(end));
+}
+
+#[tokio::test]
+async fn test_gap_is_requested_once_and_filled() {
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config());
+    let (engine, heartbeat) = run_against(builder, async |peer| {
+        let lost = peer.skip();
+        let news = peer.message(MsgType::News).field(148, "HEADLINE").build();
+        peer.send(news.as_bytes()).await;
+
+        // The held-back News is requested along with the lost message.
+        assert_resend_request(&peer.receive().await, "2", "3");
+
+        let gap_fill = common::server_message(MsgType::SequenceReset, lost)
+            .field(43, "Y")
+            .field(123, "Y")
+            .field(36, "3")
+            .build();
+        peer.send(gap_fill.as_bytes()).await;
+        let resent = common::server_message(MsgType::News, 3)
+            .field(43, "Y")
+            .field(148, "HEADLINE")
+            .build();
+        peer.send(resent.as_bytes()).await;
+
+        // Back in sequence: the TestRequest is answered, not requested again.
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "AFTER")
+            .build();
+        peer.send(request.as_bytes()).await;
+        peer.receive().await
+    })
+    .await;
+
+    assert_eq!(*heartbeat.msg_type(), MsgType::Heartbeat);
+    assert_eq!(heartbeat.get_field_str(112), Some("AFTER"));
+    let session_id = engine.sessions().next().unwrap();
+    let metrics = engine.metrics(session_id).unwrap();
+    assert_eq!(metrics.resend_requests_sent(), 1);
+    let initiator = engine.initiator(session_id).unwrap();
+    assert_eq!(initiator.sequences().next_target_seq().value(), 6);
+}
+
+#[tokio::test]
+async fn test_unanswered_resend_request_is_repeated() {
+    let config = client_config().with_resend_request_timeout(Duration::ZERO);
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(config);
+    run_against(builder, async |peer| {
+        let lost = peer.skip();
+        let request = peer
+            .message(MsgType::TestRequest)
+            .field(112, "HELD")
+            .build();
+        peer.send(request.as_bytes()).await;
+
+        // The held-back TestRequest is not answered until the gap is filled.
+        assert_resend_request(&peer.receive().await, "2", "3");
+        assert_resend_request(&peer.receive().await, "2", "3");
+
+        let gap_fill = common::server_message(MsgType::SequenceReset, lost)
+            .field(43, "Y")
+            .field(123, "Y")
+            .field(36, "4")
+            .build();
+        peer.send(gap_fill.as_bytes()).await;
+    })
+    .await;
+}
@@ -0,0 +1,106 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Messages sent by application callbacks in response to received messages.
+
+mod common;
+
+use async_trait::async_trait;
+use common::{client_config, run_against};
+use ironfix_core::message::{FixMessage, MessageBuilder, MsgType, OwnedMessage, RawMessage};
+use ironfix_engine::application::{RejectReason, Responder, SessionId};
+use ironfix_engine::{Application, EngineBuilder};
+use ironfix_messages::{ExecType, ExecutionReport, OrdStatus, Side};
+use ironfix_tagvalue::Decoder;
+use rust_decimal::Decimal;
+
+/// Acknowledges every order with a new ExecutionReport.
+struct AckingApplication;
+
+#[async_trait]
+impl Application for AckingApplication {
+    async fn on_create(&self, _session_id: &SessionId) {}
+
+    async fn on_logon(&self, _session_id: &SessionId) {}
+
+    async fn on_logout(&self, _session_id: &SessionId) {}
+
+    async fn to_admin(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+    async fn from_admin(
+        &self,
+        _message: &RawMessage<'_>,
+        _session_id: &SessionId,
+        _responder: &Responder,
+    ) -> Result<(), RejectReason> {
+        Ok(())
+    }
+
+    async fn to_app(&self, _message: &mut OwnedMessage, _session_id: &SessionId) {}
+
+    async fn from_app(
+        &self,
+        message: &RawMessage<'_>,
+        _session_id: &SessionId,
+        responder: &Responder,
+    ) -> Result<(), RejectReason> {
+        let cl_ord_id = message.get_field_str(11).unwrap_or_default();
+        let report = MessageBuilder::new("FIX.4.4", MsgType::ExecutionReport)
+            .field(37, "EXCH-1")
+            .field(11, cl_ord_id)
+            .field(17, "EXEC-1")
+            .field(150, "0")
+            .field(39, "0")
+            .field(55, message.get_field_str(55).unwrap_or_default())
+            .field(54, "1")
+            .field(151, "100")
+            .field(14, "0")
+            .field(6, "0")
+            .build();
+        responder.send(report);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_from_app_response_reaches_peer() {
+    let builder = EngineBuilder::new()
+        .with_application(AckingApplication)
+        .add_session(client_config());
+    let (_, response) = run_against(builder, async |peer| {
+        let order = peer
+            .message(MsgType::NewOrderSingle)
+            .field(11, "ORD-1")
+            .field(55, "AAPL")
+            .field(54, "1")
+            .field(38, "100")
+            .field(40, "1")
+            .build();
+        peer.send(order.as_bytes()).await;
+        peer.receive().await
+    })
+    .await;
+
+    assert_eq!(response.get_field_str(49), Some("CLIENT"));
+    assert_eq!(response.get_field_str(56), Some("SERVER"));
+    assert_eq!(response.get_field_str(34), Some("2"));
+
+    let raw = Decoder::new(response.as_bytes()).decode().unwrap();
+    let report = ExecutionReport::from_raw(&raw).unwrap();
+    assert_eq!(
+        report,
+        ExecutionReport::new(
+            "EXCH-1",
+            "EXEC-1",
+            ExecType::New,
+            OrdStatus::New,
+            "AAPL",
+            Side::Buy
+        )
+        .with_cl_ord_id("ORD-1")
+        .with_quantities(Decimal::new(100, 0), Decimal::ZERO, Decimal::ZERO)
+    );
+}
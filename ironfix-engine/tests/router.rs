@@ -0,0 +1,79 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Message routing to typed handlers through the engine.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against};
+use ironfix_core::message::MsgType;
+use ironfix_engine::{EngineBuilder, MessageRouter};
+use ironfix_messages::{NewOrderSingle, OrdType, Side};
+use rust_decimal::Decimal;
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_router_decodes_and_dispatches_new_order_single() {
+    let orders = Arc::new(Mutex::new(Vec::new()));
+    let router = MessageRouter::new().on::<NewOrderSingle, _, _>({
+        let orders = Arc::clone(&orders);
+        move |order, _session_id| {
+            let orders = Arc::clone(&orders);
+            async move {
+                orders.lock().unwrap().push(order);
+                Ok(())
+            }
+        }
+    });
+    assert!(router.handles(&MsgType::NewOrderSingle));
+
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .with_router(router)
+        .add_session(client_config());
+    let (_, reject) = run_against(builder, async |peer| {
+        let order = peer
+            .message(MsgType::NewOrderSingle)
+            .field(11, "ORD-1")
+            .field(55, "AAPL")
+            .field(54, "1")
+            .field(38, "100")
+            .field(40, "2")
+            .field(44, "150.25")
+            .build();
+        peer.send(order.as_bytes()).await;
+
+        // OrdType is missing, so the order cannot be decoded.
+        let invalid = peer
+            .message(MsgType::NewOrderSingle)
+            .field(11, "ORD-2")
+            .field(55, "AAPL")
+            .field(54, "1")
+            .field(38, "100")
+            .build();
+        peer.send(invalid.as_bytes()).await;
+        peer.receive().await
+    })
+    .await;
+
+    let orders = orders.lock().unwrap();
+    assert_eq!(
+        *orders,
+        [NewOrderSingle::new(
+            "ORD-1",
+            "AAPL",
+            Side::Buy,
+            Decimal::new(100, 0),
+            OrdType::Limit
+        )
+        .with_price(Decimal::new(15025, 2))]
+    );
+
+    assert_eq!(*reject.msg_type(), MsgType::BusinessMessageReject);
+    assert_eq!(reject.get_field_str(45), Some("3"));
+    assert_eq!(reject.get_field_str(379), Some("ORD-2"));
+    assert_eq!(reject.get_field_str(380), Some("5"));
+}
@@ -0,0 +1,50 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Sub and location IDs on outbound headers and inbound validation.
+
+mod common;
+
+use common::{CountingApplication, client_config, run_against_with_logon};
+use ironfix_core::message::MsgType;
+use ironfix_engine::EngineBuilder;
+use ironfix_tagvalue::Decoder;
+
+#[tokio::test]
+async fn test_sender_sub_id_in_header_and_validated_inbound() {
+    let builder = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config().with_sender_sub_id("DESK1"));
+    let (_, (logon, reject)) = run_against_with_logon(
+        builder,
+        |logon| logon.field(57, "DESK1"),
+        async |peer| {
+            // A message without TargetSubID is rejected.
+            let request = peer
+                .message(MsgType::TestRequest)
+                .field(112, "NO-SUB")
+                .build();
+            peer.send(request.as_bytes()).await;
+            (peer.logon.clone(), peer.receive().await)
+        },
+    )
+    .await;
+
+    let tags: Vec<u32> = logon.fields().map(|(tag, _)| tag).take(8).collect();
+    assert_eq!(tags, [8, 9, 35, 49, 56, 34, 52, 50]);
+    assert_eq!(logon.get_field_str(50), Some("DESK1"));
+    let strict = Decoder::new(logon.as_bytes())
+        .strict(true)
+        .decode()
+        .unwrap();
+    assert_eq!(strict.get_field_str(50), Some("DESK1"));
+
+    assert_eq!(*reject.msg_type(), MsgType::Reject);
+    assert_eq!(reject.get_field_str(45), Some("2"));
+    assert_eq!(reject.get_field_str(371), Some("57"));
+    assert_eq!(reject.get_field_str(373), Some("9"));
+    assert_eq!(reject.get_field_str(50), Some("DESK1"));
+}
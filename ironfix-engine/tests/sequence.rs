@@ -0,0 +1,63 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Handling of a MsgSeqNum below the expected one.
+
+mod common;
+
+use common::{CountingApplication, accept_logon, client_config, receive, server_message};
+use futures::{SinkExt, StreamExt};
+use ironfix_core::message::MsgType;
+use ironfix_engine::EngineBuilder;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn test_seq_num_too_low_logs_out_and_disconnects() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let engine = EngineBuilder::new()
+        .with_application(CountingApplication::default())
+        .add_session(client_config())
+        .with_max_reconnect_attempts(0)
+        .build(listener.local_addr().unwrap().to_string())
+        .unwrap();
+
+    let acceptor = async {
+        let (mut framed, _) = accept_logon(&listener, 1).await;
+
+        // A possible duplicate below the expected MsgSeqNum is ignored.
+        let duplicate = server_message(MsgType::News, 1)
+            .field(43, "Y")
+            .field(148, "DUP")
+            .build();
+        framed.send(duplicate.as_bytes()).await.unwrap();
+        let request = server_message(MsgType::TestRequest, 2)
+            .field(112, "UP")
+            .build();
+        framed.send(request.as_bytes()).await.unwrap();
+        let heartbeat = receive(&mut framed).await;
+        assert_eq!(heartbeat.get_field_str(112), Some("UP"));
+
+        // Any other message below it ends the session.
+        let stale = server_message(MsgType::News, 2).field(148, "OLD").build();
+        framed.send(stale.as_bytes()).await.unwrap();
+        let logout = receive(&mut framed).await;
+        assert!(framed.next().await.is_none());
+        logout
+    };
+    let (result, logout) = tokio::time::timeout(Duration::from_secs(5), async {
+        tokio::join!(engine.run(), acceptor)
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(*logout.msg_type(), MsgType::Logout);
+    assert_eq!(
+        logout.get_field_str(58),
+        Some("MsgSeqNum too low, expecting 3 but received 2")
+    );
+    assert!(result.is_err());
+}
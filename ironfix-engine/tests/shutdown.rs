@@ -0,0 +1,120 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Graceful engine shutdown against a scripted acceptor.
+
+mod common;
+
+use async_trait::async_trait;
+use common::{CountingApplication, accept_logon, client_config, receive, server_message};
+use futures::{SinkExt, StreamExt};
+use ironfix_core::error::StoreError;
+use ironfix_core::message::{MsgType, OwnedMessage};
+use ironfix_engine::{Engine, EngineBuilder};
+use ironfix_store::{MemoryStore, MessageStore};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, SystemTime};
+use tokio::net::TcpListener;
+
+/// Memory store counting flushes.
+#[derive(Debug, Default)]
+struct FlushCountingStore {
+    inner: MemoryStore,
+    flushes: AtomicU32,
+}
+
+#[async_trait]
+impl MessageStore for FlushCountingStore {
+    async fn store(&self, seq_num: u64, message: &[u8]) -> Result<(), StoreError> {
+        self.inner.store(seq_num, message).await
+    }
+
+    async fn get_range(&self, begin: u64, end: u64) -> Result<Vec<OwnedMessage>, StoreError> {
+        self.inner.get_range(begin, end).await
+    }
+
+    fn next_sender_seq(&self) -> u64 {
+        self.inner.next_sender_seq()
+    }
+
+    fn next_target_seq(&self) -> u64 {
+        self.inner.next_target_seq()
+    }
+
+    async fn set_next_sender_seq(&self, seq: u64) -> Result<(), StoreError> {
+        self.inner.set_next_sender_seq(seq).await
+    }
+
+    async fn set_next_target_seq(&self, seq: u64) -> Result<(), StoreError> {
+        self.inner.set_next_target_seq(seq).await
+    }
+
+    async fn reset(&self) -> Result<(), StoreError> {
+        self.inner.reset().await
+    }
+
+    fn creation_time(&self) -> SystemTime {
+        self.inner.creation_time()
+    }
+
+    async fn flush(&self) -> Result<(), StoreError> {
+        self.flushes.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_engine_shutdown_logs_out_and_stops() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let acceptor = tokio::spawn(async move {
+        let (mut framed, _) = accept_logon(&listener, 1).await;
+
+        let logout = receive(&mut framed).await;
+        assert_eq!(*logout.msg_type(), MsgType::Logout);
+        let response = server_message(MsgType::Logout, 2).build();
+        framed.send(response.as_bytes()).await.unwrap();
+
+        // The initiator closes the connection once the logout is acknowledged.
+        assert!(framed.next().await.is_none());
+    });
+
+    let application = CountingApplication::default();
+    let store = Arc::new(FlushCountingStore::default());
+    let initiator = EngineBuilder::new()
+        .with_application(application.clone())
+        .add_session(client_config())
+        .build_initiator(addr.to_string())
+        .unwrap()
+        .with_message_store(Arc::clone(&store) as Arc<dyn MessageStore>);
+    let engine = Arc::new(Engine::new(vec![initiator]));
+    let session_id = engine.sessions().next().unwrap().clone();
+    let runner = tokio::spawn({
+        let engine = Arc::clone(&engine);
+        async move { engine.run().await }
+    });
+
+    let metrics = engine.metrics(&session_id).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while metrics.logons() == 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("session did not log on");
+
+    tokio::time::timeout(Duration::from_secs(5), engine.shutdown())
+        .await
+        .unwrap();
+    acceptor.await.unwrap();
+    assert!(runner.await.unwrap().is_ok());
+    assert_eq!(application.logouts.load(Ordering::SeqCst), 1);
+    assert_eq!(metrics.last_received_seq_num(), 2);
+    // The Logon and Logout were stored and the store flushed on stopping.
+    assert!(store.inner.contains(1) && store.inner.contains(2));
+    assert_eq!(store.flushes.load(Ordering::SeqCst), 1);
+}
@@ -6,7 +6,7 @@
 mod common;
 
 use common::{ExampleConfig, init_logging};
-use ironfix_fast::FastDecoder;
+use ironfix_fast::{FastDecoder, FastFrame, FastFrameReader};
 use tokio::io::AsyncReadExt;
 use tokio::net::TcpStream;
 use tracing::{error, info};
@@ -30,7 +30,7 @@ async fn main() -> anyhow::Result<()> {
     info!("Connected to FAST server");
 
     let mut buf = vec![0u8; 4096];
-    let mut data = Vec::new();
+    let mut reader = FastFrameReader::new();
 
     loop {
         match socket.read(&mut buf).await {
@@ -39,14 +39,17 @@ async fn main() -> anyhow::Result<()> {
                 break;
             }
             Ok(n) => {
-                data.extend_from_slice(&buf[..n]);
-
-                // Try to decode messages from buffer
-                while let Some(msg) = try_decode_message(&mut data) {
-                    info!(
-                        "Received: seq={} symbol={} price={:.2} size={}",
-                        msg.seq_num, msg.symbol, msg.price, msg.size
-                    );
+                reader.extend(&buf[..n]);
+
+                // Decode every complete frame in the buffer
+                while let Some(frame) = reader.next_frame()? {
+                    match decode_market_data(&frame) {
+                        Ok(msg) => info!(
+                            "Received: seq={} symbol={} price={:.2} size={}",
+                            msg.seq_num, msg.symbol, msg.price, msg.size
+                        ),
+                        Err(e) => error!("Failed to decode message: {}", e),
+                    }
                 }
             }
             Err(e) => {
@@ -63,7 +66,7 @@ async fn main() -> anyhow::Result<()> {
 #[derive(Debug)]
 #[allow(dead_code)]
 struct MarketDataMessage {
-    template_id: u64,
+    template_id: u32,
     seq_num: u64,
     timestamp: String,
     symbol: String,
@@ -71,67 +74,33 @@ struct MarketDataMessage {
     size: u64,
 }
 
-/// Try to decode a FAST message from the buffer.
-/// Returns None if not enough data is available.
-fn try_decode_message(data: &mut Vec<u8>) -> Option<MarketDataMessage> {
-    if data.is_empty() {
-        return None;
-    }
-
+/// Decode a FAST-encoded market data message from its frame.
+fn decode_market_data(frame: &FastFrame) -> Result<MarketDataMessage, &'static str> {
+    let data = frame.body();
     let mut offset = 0;
-    let _original_len = data.len();
-
-    // Try to decode the message
-    let result = decode_market_data(data, &mut offset);
-
-    match result {
-        Ok(msg) => {
-            // Remove consumed bytes from buffer
-            data.drain(..offset);
-            Some(msg)
-        }
-        Err(_) => {
-            // Not enough data or invalid message
-            // If we consumed some bytes but failed, we might have a partial message
-            // Keep the data for next read
-            None
-        }
-    }
-}
-
-/// Decode a FAST-encoded market data message.
-fn decode_market_data(data: &[u8], offset: &mut usize) -> Result<MarketDataMessage, &'static str> {
-    if data.len() < 7 {
-        return Err("Not enough data");
-    }
-
-    // Decode presence map
-    let _pmap = FastDecoder::decode_uint(data, offset).map_err(|_| "Failed to decode pmap")?;
-
-    // Template ID
-    let template_id =
-        FastDecoder::decode_uint(data, offset).map_err(|_| "Failed to decode template_id")?;
 
     // Sequence number
-    let seq_num = FastDecoder::decode_uint(data, offset).map_err(|_| "Failed to decode seq_num")?;
+    let seq_num =
+        FastDecoder::decode_uint(data, &mut offset).map_err(|_| "Failed to decode seq_num")?;
 
     // Timestamp
     let timestamp =
-        FastDecoder::decode_ascii(data, offset).map_err(|_| "Failed to decode timestamp")?;
+        FastDecoder::decode_ascii(data, &mut offset).map_err(|_| "Failed to decode timestamp")?;
 
     // Symbol
-    let symbol = FastDecoder::decode_ascii(data, offset).map_err(|_| "Failed to decode symbol")?;
+    let symbol =
+        FastDecoder::decode_ascii(data, &mut offset).map_err(|_| "Failed to decode symbol")?;
 
     // Price (scaled by 100)
     let scaled_price =
-        FastDecoder::decode_uint(data, offset).map_err(|_| "Failed to decode price")?;
+        FastDecoder::decode_uint(data, &mut offset).map_err(|_| "Failed to decode price")?;
     let price = scaled_price as f64 / 100.0;
 
     // Size
-    let size = FastDecoder::decode_uint(data, offset).map_err(|_| "Failed to decode size")?;
+    let size = FastDecoder::decode_uint(data, &mut offset).map_err(|_| "Failed to decode size")?;
 
     Ok(MarketDataMessage {
-        template_id,
+        template_id: frame.template_id(),
         seq_num,
         timestamp,
         symbol,
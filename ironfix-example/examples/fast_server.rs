@@ -6,7 +6,8 @@
 mod common;
 
 use common::{ExampleConfig, format_timestamp, init_logging};
-use ironfix_fast::FastEncoder;
+use ironfix_fast::pmap::PresenceMapBuilder;
+use ironfix_fast::{FastEncoder, FastFrame};
 use tokio::io::AsyncWriteExt;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::time::{Duration, interval};
@@ -100,11 +101,10 @@ async fn handle_client(mut socket: TcpStream) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Build a FAST-encoded market data message.
+/// Build a framed FAST market data message.
 ///
 /// Message structure:
-/// - Presence map (1 byte with stop bit)
-/// - Template ID (uint32)
+/// - Block length, presence map and template ID (see [`FastFrame`])
 /// - Sequence number (uint64)
 /// - Timestamp (string)
 /// - Symbol (string)
@@ -113,14 +113,6 @@ async fn handle_client(mut socket: TcpStream) -> anyhow::Result<()> {
 fn build_market_data(seq_num: u64, symbol: &str, price: f64, size: u64) -> Vec<u8> {
     let mut encoder = FastEncoder::new();
 
-    // Presence map: all fields present (6 bits set + stop bit)
-    // Bits: template_id, seq_num, timestamp, symbol, price, size
-    let pmap_byte: u8 = 0b1111_1100 | 0x80; // 6 bits set + stop bit
-    encoder.encode_uint(pmap_byte as u64);
-
-    // Template ID = 1 (Market Data)
-    encoder.encode_uint(1);
-
     // Sequence number
     encoder.encode_uint(seq_num);
 
@@ -137,5 +129,12 @@ fn build_market_data(seq_num: u64, symbol: &str, price: f64, size: u64) -> Vec<u
     // Size
     encoder.encode_uint(size);
 
-    encoder.finish()
+    // Presence map: all fields present
+    // Bits: seq_num, timestamp, symbol, price, size
+    let pmap = (0..5)
+        .fold(PresenceMapBuilder::new(), |pmap, _| pmap.bit(true))
+        .build();
+
+    // Template ID = 1 (Market Data)
+    FastFrame::new(pmap, 1, encoder.finish()).encode()
 }
@@ -1,7 +1,7 @@
 //! FIX 4.0 Client Example
 
 use bytes::BytesMut;
-use ironfix_core::{MsgType, Side};
+use ironfix_core::{HandlInst, MsgType, Side};
 use ironfix_tagvalue::{Decoder, Encoder};
 use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -149,7 +149,7 @@ fn build_order(
     e.put_str(34, &seq.to_string());
     e.put_str(52, &format_timestamp());
     e.put_str(11, id);
-    e.put_str(21, "1");
+    e.put_char(21, HandlInst::AutomatedPrivate.as_char());
     e.put_str(55, sym);
     e.put_char(54, side.as_char());
     e.put_str(60, &format_timestamp());
@@ -2,6 +2,7 @@
 
 use bytes::BytesMut;
 use ironfix_core::MsgType;
+use ironfix_engine::SessionId;
 use ironfix_tagvalue::{Decoder, Encoder};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -61,7 +62,7 @@ async fn handle(
     cfg: ExampleConfig,
 ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let mut buf = BytesMut::with_capacity(4096);
-    let key = format!("{}:{}", cfg.target_comp_id, cfg.sender_comp_id);
+    let key = SessionId::new(FIX_VERSION, &cfg.target_comp_id, &cfg.sender_comp_id).key();
     state.lock().await.insert(key.clone(), Session::new());
 
     loop {
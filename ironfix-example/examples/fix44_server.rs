@@ -10,6 +10,7 @@ use tokio::sync::Mutex;
 use tracing::{error, info, warn};
 
 use ironfix_core::MsgType;
+use ironfix_engine::SessionId;
 use ironfix_tagvalue::{Decoder, Encoder};
 
 mod common;
@@ -58,7 +59,7 @@ async fn handle(
     cfg: ExampleConfig,
 ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let mut buf = BytesMut::with_capacity(4096);
-    let key = format!("{}:{}", cfg.target_comp_id, cfg.sender_comp_id);
+    let key = SessionId::new(FIX_VERSION, &cfg.target_comp_id, &cfg.sender_comp_id).key();
     state.lock().await.insert(key.clone(), Session::new());
 
     loop {
@@ -16,6 +16,7 @@ use tokio::sync::{Mutex, mpsc};
 use tracing::{error, info, warn};
 
 use ironfix_core::MsgType;
+use ironfix_engine::SessionId;
 use ironfix_tagvalue::{Decoder, Encoder};
 
 mod common;
@@ -102,7 +103,7 @@ async fn handle_connection(
     sessions: Arc<Mutex<HashMap<String, Session>>>,
     cfg: ExampleConfig,
 ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let session_id = format!("{}:{}", cfg.target_comp_id, cfg.sender_comp_id);
+    let session_id = SessionId::new(FIX_VERSION, &cfg.target_comp_id, &cfg.sender_comp_id).key();
 
     // Create response channel for this connection
     let (response_tx, mut response_rx) = mpsc::channel::<OutgoingMessage>(100);
@@ -1,6 +1,8 @@
 //! FIX 5.0 SP2 Server Example (FIXT.1.1 Transport)
 use bytes::BytesMut;
 use ironfix_core::MsgType;
+use ironfix_dictionary::Version;
+use ironfix_engine::SessionId;
 use ironfix_tagvalue::{Decoder, Encoder};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -12,7 +14,7 @@ mod common;
 use common::{ExampleConfig, format_timestamp, init_logging, try_decode_message};
 
 const FIX_VERSION: &str = "FIXT.1.1";
-const APPL_VER_ID: &str = "9"; // FIX 5.0 SP2
+const DEFAULT_APP_VERSION: Version = Version::Fix50Sp2;
 const DEFAULT_PORT: u16 = 9882;
 
 #[tokio::main]
@@ -49,8 +51,9 @@ async fn handle(
     cfg: ExampleConfig,
 ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let mut buf = BytesMut::with_capacity(4096);
-    let key = format!("{}:{}", cfg.target_comp_id, cfg.sender_comp_id);
+    let key = SessionId::new(FIX_VERSION, &cfg.target_comp_id, &cfg.sender_comp_id).key();
     state.lock().await.insert(key.clone(), 1);
+    let mut app_version = DEFAULT_APP_VERSION;
     loop {
         if sock.read_buf(&mut buf).await? == 0 {
             break;
@@ -61,15 +64,20 @@ async fn handle(
             if let Ok(raw) = dec.decode() {
                 let resp = match raw.msg_type() {
                     MsgType::Logon => {
-                        info!("Logon");
-                        Some(build_logon(&cfg))
+                        app_version =
+                            DEFAULT_APP_VERSION.resolve_application(raw.get_field_str(1137));
+                        info!("Logon (application version {:?})", app_version);
+                        Some(build_logon(&cfg, app_version))
                     }
                     MsgType::TestRequest => Some(build_hb(&cfg, raw.get_field_str(112))),
                     MsgType::Logout => {
                         sock.write_all(&build_logout(&cfg)).await?;
                         return Ok(());
                     }
-                    MsgType::NewOrderSingle => Some(build_exec(&cfg, &raw)),
+                    MsgType::NewOrderSingle => {
+                        let version = app_version.resolve_application(raw.get_field_str(1128));
+                        Some(build_exec(&cfg, &raw, version))
+                    }
                     _ => {
                         warn!("Unhandled: {:?}", raw.msg_type());
                         None
@@ -85,7 +93,7 @@ async fn handle(
     Ok(())
 }
 
-fn build_logon(c: &ExampleConfig) -> Vec<u8> {
+fn build_logon(c: &ExampleConfig, version: Version) -> Vec<u8> {
     let mut e = Encoder::new(FIX_VERSION);
     e.put_str(35, "A");
     e.put_str(49, &c.sender_comp_id);
@@ -94,7 +102,9 @@ fn build_logon(c: &ExampleConfig) -> Vec<u8> {
     e.put_str(52, &format_timestamp());
     e.put_str(98, "0");
     e.put_str(108, &c.heartbeat_interval.to_string());
-    e.put_str(1137, APPL_VER_ID);
+    if let Some(id) = version.appl_ver_id() {
+        e.put_str(1137, id);
+    }
     e.finish().to_vec()
 }
 
@@ -121,7 +131,11 @@ fn build_logout(c: &ExampleConfig) -> Vec<u8> {
     e.finish().to_vec()
 }
 
-fn build_exec(c: &ExampleConfig, raw: &ironfix_tagvalue::RawMessage<'_>) -> Vec<u8> {
+fn build_exec(
+    c: &ExampleConfig,
+    raw: &ironfix_tagvalue::RawMessage<'_>,
+    version: Version,
+) -> Vec<u8> {
     let clid = raw.get_field_str(11).unwrap_or("0");
     let mut e = Encoder::new(FIX_VERSION);
     e.put_str(35, "8");
@@ -129,7 +143,9 @@ fn build_exec(c: &ExampleConfig, raw: &ironfix_tagvalue::RawMessage<'_>) -> Vec<
     e.put_str(56, &c.target_comp_id);
     e.put_str(34, "1");
     e.put_str(52, &format_timestamp());
-    e.put_str(1128, APPL_VER_ID);
+    if let Some(id) = version.appl_ver_id() {
+        e.put_str(1128, id);
+    }
     e.put_str(37, &format!("O{}", clid));
     e.put_str(11, clid);
     e.put_str(17, &format!("E{}", clid));
@@ -34,7 +34,7 @@
 //!         CompId::new("TARGET").unwrap(),
 //!         "FIX.4.4",
 //!     ))
-//!     .build();
+//!     .build("127.0.0.1:9876")?;
 //! ```
 //!
 //! ## Crate Organization
@@ -8,11 +8,18 @@
 //!
 //! This module provides decoding of FAST-encoded messages using stop-bit
 //! encoding and presence maps.
+//!
+//! For diagnosing template mismatches the decoder can keep a trace of every
+//! named field it decodes; see [`FastDecoder::with_trace`].
 
 use crate::error::FastError;
-use crate::operators::DictionaryValue;
+use crate::operators::{DictionaryValue, Operator, apply_delta, apply_tail};
 use crate::pmap::PresenceMap;
 use std::collections::HashMap;
+use std::ops::Range;
+
+/// Default maximum length of a string or byte vector field (64 KiB).
+pub const DEFAULT_MAX_FIELD_LEN: usize = 64 * 1024;
 
 /// FAST protocol decoder.
 #[derive(Debug)]
@@ -23,6 +30,23 @@ pub struct FastDecoder {
     template_dicts: HashMap<u32, HashMap<String, DictionaryValue>>,
     /// Last used template ID.
     last_template_id: Option<u32>,
+    /// Maximum length of a string or byte vector field.
+    max_field_len: usize,
+    /// Decoded field trace, present only while tracing is enabled.
+    trace: Option<Vec<FieldTrace>>,
+}
+
+/// One decoded field recorded by a tracing [`FastDecoder`].
+#[derive(Debug, Clone)]
+pub struct FieldTrace {
+    /// The field name (the dictionary key for operator fields).
+    pub field_name: String,
+    /// The operator the field was decoded with.
+    pub operator: Operator,
+    /// The decoded value.
+    pub value: DictionaryValue,
+    /// The input bytes consumed by the field; empty if it was absent.
+    pub range: Range<usize>,
 }
 
 impl FastDecoder {
@@ -33,14 +57,98 @@ impl FastDecoder {
             global_dict: HashMap::new(),
             template_dicts: HashMap::new(),
             last_template_id: None,
+            max_field_len: DEFAULT_MAX_FIELD_LEN,
+            trace: None,
+        }
+    }
+
+    /// Enables or disables the decoded field trace.
+    ///
+    /// While enabled, the operator decoding methods record a [`FieldTrace`]
+    /// for every field they decode. Fields decoded with the associated
+    /// `decode_*` functions carry no name, so callers walking a template
+    /// record them with [`record_field`](Self::record_field).
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to record decoded fields
+    #[must_use]
+    pub fn with_trace(mut self, enabled: bool) -> Self {
+        self.trace = enabled.then(Vec::new);
+        self
+    }
+
+    /// Returns true if the decoded field trace is enabled.
+    #[must_use]
+    pub const fn is_tracing(&self) -> bool {
+        self.trace.is_some()
+    }
+
+    /// Returns the fields recorded since the trace was last taken.
+    ///
+    /// Empty when tracing is disabled.
+    #[must_use]
+    pub fn trace(&self) -> &[FieldTrace] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Takes the recorded fields, leaving the trace empty but enabled.
+    pub fn take_trace(&mut self) -> Vec<FieldTrace> {
+        self.trace.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Records a decoded field in the trace, if tracing is enabled.
+    ///
+    /// # Arguments
+    /// * `field_name` - The template field name
+    /// * `operator` - The operator the field was decoded with
+    /// * `value` - The decoded value
+    /// * `range` - The input bytes consumed by the field
+    pub fn record_field(
+        &mut self,
+        field_name: &str,
+        operator: Operator,
+        value: DictionaryValue,
+        range: Range<usize>,
+    ) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(FieldTrace {
+                field_name: field_name.to_string(),
+                operator,
+                value,
+                range,
+            });
         }
     }
 
-    /// Resets the decoder state.
+    /// Sets the maximum length of string and byte vector fields.
+    ///
+    /// Guards against unbounded allocation from a malicious or corrupt
+    /// stream. Applies to the operator decoding methods; the associated
+    /// `decode_*` functions use [`DEFAULT_MAX_FIELD_LEN`] unless called through
+    /// their `_with_limit` variants.
+    ///
+    /// # Arguments
+    /// * `max_field_len` - The maximum field length in bytes
+    #[must_use]
+    pub const fn with_max_field_len(mut self, max_field_len: usize) -> Self {
+        self.max_field_len = max_field_len;
+        self
+    }
+
+    /// Returns the maximum length of string and byte vector fields.
+    #[must_use]
+    pub const fn max_field_len(&self) -> usize {
+        self.max_field_len
+    }
+
+    /// Resets the decoder state, including any recorded trace.
     pub fn reset(&mut self) {
         self.global_dict.clear();
         self.template_dicts.clear();
         self.last_template_id = None;
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
     }
 
     /// Decodes an unsigned integer using stop-bit encoding.
@@ -91,7 +199,8 @@ impl FastDecoder {
     /// The decoded signed integer.
     ///
     /// # Errors
-    /// Returns `FastError::UnexpectedEof` if data is incomplete.
+    /// Returns `FastError::UnexpectedEof` if data is incomplete, or
+    /// `FastError::IntegerOverflow` if the value does not fit in an `i64`.
     pub fn decode_int(data: &[u8], offset: &mut usize) -> Result<i64, FastError> {
         if *offset >= data.len() {
             return Err(FastError::UnexpectedEof);
@@ -110,6 +219,11 @@ impl FastDecoder {
             let byte = data[*offset];
             *offset += 1;
 
+            // Check for overflow
+            if !((i64::MIN >> 7)..=(i64::MAX >> 7)).contains(&result) {
+                return Err(FastError::IntegerOverflow);
+            }
+
             result = (result << 7) | (byte & 0x7F) as i64;
 
             if byte & 0x80 != 0 {
@@ -122,6 +236,9 @@ impl FastDecoder {
 
     /// Decodes an ASCII string using stop-bit encoding.
     ///
+    /// A lone stop-bit byte (`0x80`) is the empty string. Strings longer than
+    /// [`DEFAULT_MAX_FIELD_LEN`] are rejected.
+    ///
     /// # Arguments
     /// * `data` - The input bytes
     /// * `offset` - Current position (will be updated)
@@ -132,12 +249,36 @@ impl FastDecoder {
     /// # Errors
     /// Returns `FastError::UnexpectedEof` if data is incomplete.
     pub fn decode_ascii(data: &[u8], offset: &mut usize) -> Result<String, FastError> {
+        Self::decode_ascii_with_limit(data, offset, DEFAULT_MAX_FIELD_LEN)
+    }
+
+    /// Decodes an ASCII string, rejecting strings longer than `max_len`.
+    ///
+    /// # Arguments
+    /// * `data` - The input bytes
+    /// * `offset` - Current position (will be updated)
+    /// * `max_len` - The maximum string length in bytes
+    ///
+    /// # Errors
+    /// Returns `FastError::UnexpectedEof` if data is incomplete, or
+    /// `FastError::FieldTooLarge` once more than `max_len` bytes are read.
+    pub fn decode_ascii_with_limit(
+        data: &[u8],
+        offset: &mut usize,
+        max_len: usize,
+    ) -> Result<String, FastError> {
         let mut result = Vec::new();
 
         loop {
             if *offset >= data.len() {
                 return Err(FastError::UnexpectedEof);
             }
+            if result.len() == max_len {
+                return Err(FastError::FieldTooLarge {
+                    len: max_len + 1,
+                    max: max_len,
+                });
+            }
 
             let byte = data[*offset];
             *offset += 1;
@@ -151,11 +292,17 @@ impl FastDecoder {
             }
         }
 
+        if result == [0] {
+            result.clear();
+        }
+
         String::from_utf8(result).map_err(|_| FastError::InvalidString)
     }
 
     /// Decodes a byte vector.
     ///
+    /// Byte vectors longer than [`DEFAULT_MAX_FIELD_LEN`] are rejected.
+    ///
     /// # Arguments
     /// * `data` - The input bytes
     /// * `offset` - Current position (will be updated)
@@ -166,7 +313,32 @@ impl FastDecoder {
     /// # Errors
     /// Returns `FastError::UnexpectedEof` if data is incomplete.
     pub fn decode_bytes(data: &[u8], offset: &mut usize) -> Result<Vec<u8>, FastError> {
-        let length = Self::decode_uint(data, offset)? as usize;
+        Self::decode_bytes_with_limit(data, offset, DEFAULT_MAX_FIELD_LEN)
+    }
+
+    /// Decodes a byte vector, rejecting lengths greater than `max_len`.
+    ///
+    /// # Arguments
+    /// * `data` - The input bytes
+    /// * `offset` - Current position (will be updated)
+    /// * `max_len` - The maximum length in bytes
+    ///
+    /// # Errors
+    /// Returns `FastError::FieldTooLarge` if the declared length exceeds
+    /// `max_len`, or `FastError::UnexpectedEof` if data is incomplete.
+    pub fn decode_bytes_with_limit(
+        data: &[u8],
+        offset: &mut usize,
+        max_len: usize,
+    ) -> Result<Vec<u8>, FastError> {
+        let length = Self::decode_uint(data, offset)?;
+        let length = usize::try_from(length)
+            .ok()
+            .filter(|&length| length <= max_len)
+            .ok_or(FastError::FieldTooLarge {
+                len: usize::try_from(length).unwrap_or(usize::MAX),
+                max: max_len,
+            })?;
 
         if *offset + length > data.len() {
             return Err(FastError::UnexpectedEof);
@@ -193,6 +365,184 @@ impl FastDecoder {
         PresenceMap::decode(data, offset)
     }
 
+    /// Decodes an ASCII string field with the Tail operator.
+    ///
+    /// When present, the tail read from the stream replaces the end of the
+    /// global dictionary value; when absent, the dictionary value is reused.
+    /// The dictionary is updated with the result.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `present` - The field's presence map bit
+    /// * `data` - The input bytes
+    /// * `offset` - Current position (will be updated)
+    ///
+    /// # Errors
+    /// Returns `FastError::DictionaryEntryNotFound` if the field is absent and
+    /// the dictionary has no value for it, or a decoding error from the stream.
+    pub fn decode_ascii_tail(
+        &mut self,
+        key: &str,
+        present: bool,
+        data: &[u8],
+        offset: &mut usize,
+    ) -> Result<String, FastError> {
+        let start = *offset;
+        let tail = if present {
+            let tail = Self::decode_ascii_with_limit(data, offset, self.max_field_len)
+                .map_err(|e| e.at_offset(*offset))?;
+            Some(tail.into_bytes())
+        } else {
+            None
+        };
+        let value = self.decode_tail(key, tail)?;
+        let value = String::from_utf8(value).map_err(|_| FastError::InvalidString)?;
+        self.set_global(key, DictionaryValue::String(value.clone()));
+        self.trace_field(key, Operator::Tail, start..*offset, || {
+            DictionaryValue::String(value.clone())
+        });
+        Ok(value)
+    }
+
+    /// Decodes a byte vector field with the Tail operator.
+    ///
+    /// Behaves like [`decode_ascii_tail`](Self::decode_ascii_tail) with a
+    /// length-prefixed tail.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `present` - The field's presence map bit
+    /// * `data` - The input bytes
+    /// * `offset` - Current position (will be updated)
+    ///
+    /// # Errors
+    /// Returns `FastError::DictionaryEntryNotFound` if the field is absent and
+    /// the dictionary has no value for it, or a decoding error from the stream.
+    pub fn decode_bytes_tail(
+        &mut self,
+        key: &str,
+        present: bool,
+        data: &[u8],
+        offset: &mut usize,
+    ) -> Result<Vec<u8>, FastError> {
+        let start = *offset;
+        let tail = if present {
+            let tail = Self::decode_bytes_with_limit(data, offset, self.max_field_len)
+                .map_err(|e| e.at_offset(*offset))?;
+            Some(tail)
+        } else {
+            None
+        };
+        let value = self.decode_tail(key, tail)?;
+        self.set_global(key, DictionaryValue::Bytes(value.clone()));
+        self.trace_field(key, Operator::Tail, start..*offset, || {
+            DictionaryValue::Bytes(value.clone())
+        });
+        Ok(value)
+    }
+
+    /// Decodes an ASCII string field with the Delta operator.
+    ///
+    /// Reads the subtraction length and the string to add, applies them to the
+    /// global dictionary value and stores the result back in the dictionary.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `data` - The input bytes
+    /// * `offset` - Current position (will be updated)
+    ///
+    /// # Errors
+    /// Returns `FastError::InvalidOperator` if the subtraction length exceeds
+    /// the dictionary value, or a decoding error from the stream.
+    pub fn decode_ascii_delta(
+        &mut self,
+        key: &str,
+        data: &[u8],
+        offset: &mut usize,
+    ) -> Result<String, FastError> {
+        let start = *offset;
+        let subtraction = Self::decode_int(data, offset).map_err(|e| e.at_offset(*offset))?;
+        let diff = Self::decode_ascii_with_limit(data, offset, self.max_field_len)
+            .map_err(|e| e.at_offset(*offset))?;
+        let value = self.decode_delta(key, subtraction, diff.as_bytes())?;
+        let value = String::from_utf8(value).map_err(|_| FastError::InvalidString)?;
+        self.set_global(key, DictionaryValue::String(value.clone()));
+        self.trace_field(key, Operator::Delta, start..*offset, || {
+            DictionaryValue::String(value.clone())
+        });
+        Ok(value)
+    }
+
+    /// Decodes a byte vector field with the Delta operator.
+    ///
+    /// Behaves like [`decode_ascii_delta`](Self::decode_ascii_delta) with
+    /// length-prefixed bytes to add.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `data` - The input bytes
+    /// * `offset` - Current position (will be updated)
+    ///
+    /// # Errors
+    /// Returns `FastError::InvalidOperator` if the subtraction length exceeds
+    /// the dictionary value, or a decoding error from the stream.
+    pub fn decode_bytes_delta(
+        &mut self,
+        key: &str,
+        data: &[u8],
+        offset: &mut usize,
+    ) -> Result<Vec<u8>, FastError> {
+        let start = *offset;
+        let subtraction = Self::decode_int(data, offset).map_err(|e| e.at_offset(*offset))?;
+        let diff = Self::decode_bytes_with_limit(data, offset, self.max_field_len)
+            .map_err(|e| e.at_offset(*offset))?;
+        let value = self.decode_delta(key, subtraction, &diff)?;
+        self.set_global(key, DictionaryValue::Bytes(value.clone()));
+        self.trace_field(key, Operator::Delta, start..*offset, || {
+            DictionaryValue::Bytes(value.clone())
+        });
+        Ok(value)
+    }
+
+    /// Records a field decoded by an operator method, building the value
+    /// only when tracing is enabled.
+    fn trace_field(
+        &mut self,
+        key: &str,
+        operator: Operator,
+        range: Range<usize>,
+        value: impl FnOnce() -> DictionaryValue,
+    ) {
+        if self.trace.is_some() {
+            self.record_field(key, operator, value(), range);
+        }
+    }
+
+    /// Applies a received delta to the dictionary value for `key`.
+    fn decode_delta(&self, key: &str, subtraction: i64, diff: &[u8]) -> Result<Vec<u8>, FastError> {
+        let base = self
+            .global_dict
+            .get(key)
+            .and_then(DictionaryValue::as_bytes)
+            .unwrap_or_default();
+        apply_delta(base, subtraction, diff)
+    }
+
+    /// Combines a received tail, if any, with the dictionary value for `key`.
+    fn decode_tail(&self, key: &str, tail: Option<Vec<u8>>) -> Result<Vec<u8>, FastError> {
+        let base = self
+            .global_dict
+            .get(key)
+            .and_then(DictionaryValue::as_bytes);
+        match (tail, base) {
+            (Some(tail), base) => Ok(apply_tail(base.unwrap_or_default(), &tail)),
+            (None, Some(base)) => Ok(base.to_vec()),
+            (None, None) => Err(FastError::DictionaryEntryNotFound {
+                key: key.to_string(),
+            }),
+        }
+    }
+
     /// Gets a value from the global dictionary.
     #[must_use]
     pub fn get_global(&self, key: &str) -> Option<&DictionaryValue> {
@@ -246,6 +596,7 @@ impl Default for FastDecoder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::encoder::FastEncoder;
 
     #[test]
     fn test_decode_uint_single_byte() {
@@ -293,6 +644,67 @@ mod tests {
         assert_eq!(result, -1);
     }
 
+    #[test]
+    fn test_int_round_trip_boundaries() {
+        let values = [
+            0,
+            63,
+            64,
+            65,
+            -1,
+            -64,
+            -65,
+            -8192,
+            -8193,
+            8191,
+            8192,
+            i64::MAX,
+            i64::MIN,
+        ];
+        for value in values {
+            let mut encoder = FastEncoder::new();
+            encoder.encode_int(value);
+            let bytes = encoder.finish();
+
+            let mut offset = 0;
+            assert_eq!(
+                FastDecoder::decode_int(&bytes, &mut offset).unwrap(),
+                value,
+                "value {value} encoded as {bytes:02X?}"
+            );
+            assert_eq!(offset, bytes.len());
+        }
+    }
+
+    #[test]
+    fn test_encode_int_boundary_bytes() {
+        let encode = |value| {
+            let mut encoder = FastEncoder::new();
+            encoder.encode_int(value);
+            encoder.finish()
+        };
+        assert_eq!(encode(63), [0xBF]);
+        assert_eq!(encode(64), [0x00, 0xC0]);
+        assert_eq!(encode(-64), [0xC0]);
+        assert_eq!(encode(-65), [0x7F, 0xBF]);
+        assert_eq!(encode(-8192), [0x40, 0x80]);
+        assert_eq!(encode(i64::MAX).len(), 10);
+        assert_eq!(encode(i64::MIN).len(), 10);
+    }
+
+    #[test]
+    fn test_decode_int_overflow() {
+        // Eleven groups carry more than 64 significant bits.
+        let mut data = [0x7F; 11];
+        data[0] = 0x3F;
+        data[10] = 0xFF;
+        let mut offset = 0;
+        assert_eq!(
+            FastDecoder::decode_int(&data, &mut offset),
+            Err(FastError::IntegerOverflow)
+        );
+    }
+
     #[test]
     fn test_decode_ascii() {
         let data = [b'H', b'i', b'!' | 0x80]; // "Hi!"
@@ -301,6 +713,208 @@ mod tests {
         assert_eq!(result, "Hi!");
     }
 
+    #[test]
+    fn test_decode_ascii_empty() {
+        let data = [0x80, 0x00, 0x80];
+        let mut offset = 0;
+        assert_eq!(FastDecoder::decode_ascii(&data, &mut offset).unwrap(), "");
+        assert_eq!(
+            FastDecoder::decode_ascii(&data, &mut offset).unwrap(),
+            "\0\0"
+        );
+    }
+
+    #[test]
+    fn test_decode_ascii_limit() {
+        let mut data = vec![b'A'; 4];
+        data.push(b'B' | 0x80);
+
+        let mut offset = 0;
+        assert_eq!(
+            FastDecoder::decode_ascii_with_limit(&data, &mut offset, 5).unwrap(),
+            "AAAAB"
+        );
+
+        let mut offset = 0;
+        assert_eq!(
+            FastDecoder::decode_ascii_with_limit(&data, &mut offset, 4),
+            Err(FastError::FieldTooLarge { len: 5, max: 4 })
+        );
+    }
+
+    #[test]
+    fn test_decode_bytes_limit() {
+        let mut encoder = FastEncoder::new();
+        encoder.encode_bytes(&[7; 5]);
+        let data = encoder.finish();
+
+        let mut offset = 0;
+        let bytes = FastDecoder::decode_bytes_with_limit(&data, &mut offset, 5).unwrap();
+        assert_eq!(bytes, vec![7; 5]);
+
+        let mut offset = 0;
+        assert_eq!(
+            FastDecoder::decode_bytes_with_limit(&data, &mut offset, 4),
+            Err(FastError::FieldTooLarge { len: 5, max: 4 })
+        );
+    }
+
+    #[test]
+    fn test_decode_bytes_default_limit() {
+        // Declares a length one over the default limit without the payload.
+        let mut encoder = FastEncoder::new();
+        encoder.encode_uint(DEFAULT_MAX_FIELD_LEN as u64 + 1);
+        let data = encoder.finish();
+
+        let mut offset = 0;
+        assert_eq!(
+            FastDecoder::decode_bytes(&data, &mut offset),
+            Err(FastError::FieldTooLarge {
+                len: DEFAULT_MAX_FIELD_LEN + 1,
+                max: DEFAULT_MAX_FIELD_LEN
+            })
+        );
+    }
+
+    #[test]
+    fn test_operator_decoding_uses_max_field_len() {
+        let mut decoder = FastDecoder::new().with_max_field_len(3);
+        assert_eq!(decoder.max_field_len(), 3);
+
+        let data = [b'A', b'B', b'C', b'D' | 0x80];
+        let mut offset = 0;
+        assert_eq!(
+            decoder.decode_ascii_tail("symbol", true, &data, &mut offset),
+            Err(FastError::FieldTooLarge { len: 4, max: 3 }.at_offset(3))
+        );
+    }
+
+    #[test]
+    fn test_delta_round_trip() {
+        let mut encoder = FastEncoder::new();
+        let values = ["AAPL", "AAPLX", "MSFT", "XMSFT", "MSFT"];
+        for value in values {
+            encoder.encode_ascii_delta("symbol", value);
+        }
+        let bytes = encoder.finish();
+
+        let mut decoder = FastDecoder::new();
+        let mut offset = 0;
+        for value in values {
+            let decoded = decoder
+                .decode_ascii_delta("symbol", &bytes, &mut offset)
+                .unwrap();
+            assert_eq!(decoded, value);
+        }
+        assert_eq!(offset, bytes.len());
+        assert_eq!(decoder.get_global("symbol").unwrap().as_str(), Some("MSFT"));
+    }
+
+    #[test]
+    fn test_delta_encoding() {
+        let mut encoder = FastEncoder::new();
+        encoder.set_global("symbol", DictionaryValue::String("AAPL".to_string()));
+
+        // Back-append: remove nothing, append "X".
+        encoder.encode_ascii_delta("symbol", "AAPLX");
+        assert_eq!(encoder.as_bytes(), &[0x80, b'X' | 0x80]);
+
+        // Full replacement: remove all five, append "MSFT".
+        encoder.clear();
+        encoder.encode_ascii_delta("symbol", "MSFT");
+        assert_eq!(encoder.as_bytes(), &[0x85, b'M', b'S', b'F', b'T' | 0x80]);
+    }
+
+    #[test]
+    fn test_bytes_delta_round_trip() {
+        let mut encoder = FastEncoder::new();
+        encoder.encode_bytes_delta("data", &[1, 2, 3]);
+        encoder.encode_bytes_delta("data", &[0, 1, 2, 3]);
+        let bytes = encoder.finish();
+
+        let mut decoder = FastDecoder::new();
+        let mut offset = 0;
+        let first = decoder.decode_bytes_delta("data", &bytes, &mut offset);
+        let second = decoder.decode_bytes_delta("data", &bytes, &mut offset);
+        assert_eq!(first.unwrap(), vec![1, 2, 3]);
+        assert_eq!(second.unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_tail_round_trip_shared_prefix() {
+        let mut encoder = FastEncoder::new();
+        let mut pmap = Vec::new();
+        for value in ["ESH6", "ESM6", "ESM6", "ESU6C"] {
+            pmap.push(encoder.encode_ascii_tail("symbol", value).unwrap());
+        }
+        assert_eq!(pmap, [true, true, false, true]);
+
+        let bytes = encoder.finish();
+        // Only "M6" is sent for the second value.
+        assert_eq!(&bytes[4..6], &[b'M', b'6' | 0x80]);
+
+        let mut decoder = FastDecoder::new();
+        let mut offset = 0;
+        let decoded: Vec<String> = pmap
+            .iter()
+            .map(|&present| {
+                decoder
+                    .decode_ascii_tail("symbol", present, &bytes, &mut offset)
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(decoded, ["ESH6", "ESM6", "ESM6", "ESU6C"]);
+        assert_eq!(offset, bytes.len());
+    }
+
+    #[test]
+    fn test_bytes_tail_round_trip() {
+        let mut encoder = FastEncoder::new();
+        assert!(encoder.encode_bytes_tail("data", &[1, 2, 3, 4]).unwrap());
+        assert!(encoder.encode_bytes_tail("data", &[1, 2, 9, 9]).unwrap());
+        let bytes = encoder.finish();
+        assert_eq!(&bytes[5..], &[0x82, 9, 9]);
+
+        let mut decoder = FastDecoder::new();
+        let mut offset = 0;
+        let first = decoder.decode_bytes_tail("data", true, &bytes, &mut offset);
+        let second = decoder.decode_bytes_tail("data", true, &bytes, &mut offset);
+        assert_eq!(first.unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(second.unwrap(), vec![1, 2, 9, 9]);
+    }
+
+    #[test]
+    fn test_tail_shorter_value() {
+        let mut encoder = FastEncoder::new();
+        encoder.encode_ascii_tail("symbol", "ABCD").unwrap();
+        assert!(matches!(
+            encoder.encode_ascii_tail("symbol", "AB"),
+            Err(FastError::InvalidOperator(_))
+        ));
+
+        // A received tail shorter than the stored value replaces its end.
+        let mut decoder = FastDecoder::new();
+        decoder.set_global("symbol", DictionaryValue::String("ABCD".to_string()));
+        let data = [b'X' | 0x80];
+        let mut offset = 0;
+        let value = decoder
+            .decode_ascii_tail("symbol", true, &data, &mut offset)
+            .unwrap();
+        assert_eq!(value, "ABCX");
+    }
+
+    #[test]
+    fn test_tail_absent_without_dictionary_value() {
+        let mut decoder = FastDecoder::new();
+        let mut offset = 0;
+        assert_eq!(
+            decoder.decode_ascii_tail("symbol", false, &[], &mut offset),
+            Err(FastError::DictionaryEntryNotFound {
+                key: "symbol".to_string()
+            })
+        );
+    }
+
     #[test]
     fn test_decoder_dictionary() {
         let mut decoder = FastDecoder::new();
@@ -314,4 +928,68 @@ mod tests {
             Some(100)
         );
     }
+
+    #[test]
+    fn test_trace_records_field_names_and_ranges() {
+        let mut encoder = FastEncoder::new();
+        encoder.encode_uint(7);
+        assert!(encoder.encode_ascii_tail("Symbol", "ESH6").unwrap());
+        encoder.encode_ascii_delta("Text", "hi");
+        let bytes = encoder.finish();
+
+        let mut decoder = FastDecoder::new().with_trace(true);
+        let mut offset = 0;
+        let seq = FastDecoder::decode_uint(&bytes, &mut offset).unwrap();
+        decoder.record_field(
+            "MsgSeqNum",
+            Operator::None,
+            DictionaryValue::UInt(seq),
+            0..offset,
+        );
+        decoder
+            .decode_ascii_tail("Symbol", true, &bytes, &mut offset)
+            .unwrap();
+        decoder
+            .decode_ascii_delta("Text", &bytes, &mut offset)
+            .unwrap();
+        decoder
+            .decode_ascii_tail("Symbol", false, &bytes, &mut offset)
+            .unwrap();
+
+        let trace = decoder.take_trace();
+        let summary: Vec<_> = trace
+            .iter()
+            .map(|f| (f.field_name.as_str(), f.operator, f.range.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("MsgSeqNum", Operator::None, 0..1),
+                ("Symbol", Operator::Tail, 1..5),
+                ("Text", Operator::Delta, 5..8),
+                ("Symbol", Operator::Tail, 8..8),
+            ]
+        );
+        assert_eq!(trace[0].value.as_u64(), Some(7));
+        assert_eq!(trace[1].value.as_str(), Some("ESH6"));
+        assert_eq!(trace[2].value.as_str(), Some("hi"));
+        assert!(decoder.is_tracing());
+        assert!(decoder.trace().is_empty());
+    }
+
+    #[test]
+    fn test_trace_disabled_by_default() {
+        let mut encoder = FastEncoder::new();
+        encoder.encode_ascii_delta("Text", "hi");
+        let bytes = encoder.finish();
+
+        let mut decoder = FastDecoder::new();
+        let mut offset = 0;
+        decoder
+            .decode_ascii_delta("Text", &bytes, &mut offset)
+            .unwrap();
+        decoder.record_field("Other", Operator::None, DictionaryValue::Empty, 0..0);
+        assert!(!decoder.is_tracing());
+        assert!(decoder.trace().is_empty());
+    }
 }
@@ -8,7 +8,8 @@
 //!
 //! This module provides encoding of values using FAST stop-bit encoding.
 
-use crate::operators::DictionaryValue;
+use crate::error::FastError;
+use crate::operators::{DictionaryValue, delta_diff, tail_diff};
 use std::collections::HashMap;
 
 /// FAST protocol encoder.
@@ -86,35 +87,30 @@ impl FastEncoder {
             return;
         }
 
-        let mut bytes = Vec::new();
+        // Emit 7-bit groups, least significant first, until the remaining
+        // bits are pure sign extension of the last group's sign bit (0x40).
+        // An i64 needs at most 10 groups.
+        let mut bytes = [0u8; 10];
+        let mut len = 0;
         let mut v = value;
-        let negative = value < 0;
 
         loop {
-            bytes.push((v & 0x7F) as u8);
+            let byte = (v & 0x7F) as u8;
             v >>= 7;
+            bytes[len] = byte;
+            len += 1;
 
-            if (negative && v == -1 && (bytes.last().unwrap() & 0x40) != 0)
-                || (!negative && v == 0 && (bytes.last().unwrap() & 0x40) == 0)
-            {
-                break;
-            }
-
-            if v == 0 && !negative {
-                break;
-            }
-            if v == -1 && negative {
+            let sign_bit = byte & 0x40 != 0;
+            if (v == 0 && !sign_bit) || (v == -1 && sign_bit) {
                 break;
             }
         }
 
+        let bytes = &mut bytes[..len];
         bytes.reverse();
+        bytes[len - 1] |= 0x80;
 
-        if let Some(last) = bytes.last_mut() {
-            *last |= 0x80;
-        }
-
-        self.buffer.extend(bytes);
+        self.buffer.extend_from_slice(bytes);
     }
 
     /// Encodes an ASCII string using stop-bit encoding.
@@ -122,8 +118,11 @@ impl FastEncoder {
     /// # Arguments
     /// * `value` - The string to encode
     pub fn encode_ascii(&mut self, value: &str) {
-        let bytes = value.as_bytes();
+        self.encode_ascii_bytes(value.as_bytes());
+    }
 
+    /// Encodes ASCII bytes using stop-bit encoding.
+    fn encode_ascii_bytes(&mut self, bytes: &[u8]) {
         if bytes.is_empty() {
             self.buffer.push(0x80);
             return;
@@ -158,6 +157,108 @@ impl FastEncoder {
         }
     }
 
+    /// Encodes an ASCII string field with the Tail operator.
+    ///
+    /// Only the differing tail relative to the global dictionary value is
+    /// written; nothing is written if the value is unchanged. The dictionary
+    /// is updated with the new value.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `value` - The string to encode
+    ///
+    /// # Returns
+    /// Whether the field was written, i.e. its presence map bit.
+    ///
+    /// # Errors
+    /// Returns `FastError::InvalidOperator` if the value is shorter than the
+    /// dictionary value.
+    pub fn encode_ascii_tail(&mut self, key: &str, value: &str) -> Result<bool, FastError> {
+        let present = self.encode_tail(key, value.as_bytes(), Self::encode_ascii_bytes)?;
+        self.set_global(key, DictionaryValue::String(value.to_string()));
+        Ok(present)
+    }
+
+    /// Encodes a byte vector field with the Tail operator.
+    ///
+    /// Behaves like [`encode_ascii_tail`](Self::encode_ascii_tail), writing
+    /// the tail with a length prefix.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `value` - The bytes to encode
+    ///
+    /// # Returns
+    /// Whether the field was written, i.e. its presence map bit.
+    ///
+    /// # Errors
+    /// Returns `FastError::InvalidOperator` if the value is shorter than the
+    /// dictionary value.
+    pub fn encode_bytes_tail(&mut self, key: &str, value: &[u8]) -> Result<bool, FastError> {
+        let present = self.encode_tail(key, value, Self::encode_bytes)?;
+        self.set_global(key, DictionaryValue::Bytes(value.to_vec()));
+        Ok(present)
+    }
+
+    /// Encodes an ASCII string field with the Delta operator.
+    ///
+    /// Writes the subtraction length and the string to add relative to the
+    /// global dictionary value, which is then updated with the new value.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `value` - The string to encode
+    pub fn encode_ascii_delta(&mut self, key: &str, value: &str) {
+        self.encode_delta(key, value.as_bytes(), Self::encode_ascii_bytes);
+        self.set_global(key, DictionaryValue::String(value.to_string()));
+    }
+
+    /// Encodes a byte vector field with the Delta operator.
+    ///
+    /// Behaves like [`encode_ascii_delta`](Self::encode_ascii_delta), writing
+    /// the bytes to add with a length prefix.
+    ///
+    /// # Arguments
+    /// * `key` - The dictionary key of the field
+    /// * `value` - The bytes to encode
+    pub fn encode_bytes_delta(&mut self, key: &str, value: &[u8]) {
+        self.encode_delta(key, value, Self::encode_bytes);
+        self.set_global(key, DictionaryValue::Bytes(value.to_vec()));
+    }
+
+    /// Writes the delta of `value` relative to the dictionary value for `key`.
+    fn encode_delta(&mut self, key: &str, value: &[u8], write: fn(&mut Self, &[u8])) {
+        let base = self
+            .global_dict
+            .get(key)
+            .and_then(DictionaryValue::as_bytes)
+            .unwrap_or_default();
+        let (subtraction, diff) = delta_diff(base, value);
+        self.encode_int(subtraction);
+        write(self, diff);
+    }
+
+    /// Writes the tail of `value` relative to the dictionary value for `key`.
+    fn encode_tail(
+        &mut self,
+        key: &str,
+        value: &[u8],
+        write: fn(&mut Self, &[u8]),
+    ) -> Result<bool, FastError> {
+        let base = self
+            .global_dict
+            .get(key)
+            .and_then(DictionaryValue::as_bytes)
+            .unwrap_or_default();
+        match tail_diff(base, value)? {
+            Some(tail) => {
+                write(self, tail);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Returns the encoded bytes.
     #[must_use]
     pub fn finish(self) -> Vec<u8> {
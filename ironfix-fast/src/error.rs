@@ -15,6 +15,10 @@ pub enum FastError {
     #[error("unexpected end of input")]
     UnexpectedEof,
 
+    /// Not enough data buffered for a complete frame.
+    #[error("incomplete frame")]
+    Incomplete,
+
     /// Unknown template ID.
     #[error("unknown template id: {0}")]
     UnknownTemplate(u32),
@@ -23,10 +27,35 @@ pub enum FastError {
     #[error("invalid presence map")]
     InvalidPresenceMap,
 
+    /// More presence map bits read than the map contains.
+    #[error("presence map exhausted after {bits} bits")]
+    PresenceMapExhausted {
+        /// Number of bits in the presence map.
+        bits: usize,
+    },
+
     /// Integer overflow during decoding.
     #[error("integer overflow")]
     IntegerOverflow,
 
+    /// Field value longer than the decoder allows.
+    #[error("field too large: {len} bytes exceeds limit of {max}")]
+    FieldTooLarge {
+        /// Length of the field, or the bytes read before giving up.
+        len: usize,
+        /// Maximum allowed length.
+        max: usize,
+    },
+
+    /// Frame block longer than the reader allows.
+    #[error("frame too large: {len} bytes exceeds limit of {max}")]
+    FrameTooLarge {
+        /// Declared block length of the frame.
+        len: u64,
+        /// Maximum allowed block length.
+        max: usize,
+    },
+
     /// Invalid string encoding.
     #[error("invalid string encoding")]
     InvalidString,
@@ -66,4 +95,130 @@ pub enum FastError {
         /// Actual length.
         actual: u32,
     },
+
+    /// Error annotated with the byte offset where decoding stopped.
+    #[error("{source} at byte offset {offset}")]
+    AtOffset {
+        /// Byte offset into the decoded input.
+        offset: usize,
+        /// The underlying error.
+        #[source]
+        source: Box<FastError>,
+    },
+
+    /// Error raised while decoding a message of a given template.
+    #[error("template {template_id}: {source}")]
+    InTemplate {
+        /// Template ID of the message being decoded.
+        template_id: u32,
+        /// The underlying error.
+        #[source]
+        source: Box<FastError>,
+    },
+}
+
+impl FastError {
+    /// Annotates the error with the byte offset where decoding stopped.
+    ///
+    /// # Arguments
+    /// * `offset` - Byte offset into the decoded input
+    #[must_use]
+    pub fn at_offset(self, offset: usize) -> Self {
+        Self::AtOffset {
+            offset,
+            source: Box::new(self),
+        }
+    }
+
+    /// Annotates the error with the template being decoded.
+    ///
+    /// # Arguments
+    /// * `template_id` - Template ID of the message
+    #[must_use]
+    pub fn in_template(self, template_id: u32) -> Self {
+        Self::InTemplate {
+            template_id,
+            source: Box::new(self),
+        }
+    }
+
+    /// Returns the byte offset recorded on this error, if any.
+    #[must_use]
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::AtOffset { offset, .. } => Some(*offset),
+            Self::InTemplate { source, .. } => source.offset(),
+            _ => None,
+        }
+    }
+
+    /// Returns the template ID recorded on this error, if any.
+    #[must_use]
+    pub fn template_id(&self) -> Option<u32> {
+        match self {
+            Self::InTemplate { template_id, .. } => Some(*template_id),
+            Self::UnknownTemplate(template_id) => Some(*template_id),
+            Self::AtOffset { source, .. } => source.template_id(),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error without offset or template context.
+    #[must_use]
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::AtOffset { source, .. } | Self::InTemplate { source, .. } => source.root(),
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::FastDecoder;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_error_display_includes_offset() {
+        let data = [b'A', b'B', b'C'];
+        let mut offset = 0;
+        let err = FastDecoder::decode_ascii(&data, &mut offset)
+            .map_err(|e| e.at_offset(offset))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "unexpected end of input at byte offset 3");
+        assert_eq!(err.offset(), Some(3));
+        assert_eq!(err.root(), &FastError::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_error_source_chain() {
+        let err = FastError::UnexpectedEof.at_offset(7).in_template(42);
+
+        assert_eq!(
+            err.to_string(),
+            "template 42: unexpected end of input at byte offset 7"
+        );
+        assert_eq!(err.template_id(), Some(42));
+        assert_eq!(err.offset(), Some(7));
+
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.to_string(),
+            "unexpected end of input at byte offset 7"
+        );
+        let root = source.source().unwrap();
+        assert_eq!(root.to_string(), "unexpected end of input");
+        assert!(root.source().is_none());
+    }
+
+    #[test]
+    fn test_error_without_context() {
+        let err = FastError::PresenceMapExhausted { bits: 7 };
+        assert_eq!(err.to_string(), "presence map exhausted after 7 bits");
+        assert_eq!(err.offset(), None);
+        assert_eq!(err.template_id(), None);
+        assert_eq!(FastError::UnknownTemplate(9).template_id(), Some(9));
+    }
 }
@@ -0,0 +1,334 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! FAST message framing for byte streams.
+//!
+//! FAST messages carry no delimiter, so a stream reader cannot tell where one
+//! message ends without decoding it against its template. A frame prefixes
+//! each message with its block length (stop-bit encoded), followed by the
+//! presence map and template ID, so complete messages can be split off a
+//! growing buffer before the body is decoded.
+
+use crate::decoder::FastDecoder;
+use crate::encoder::FastEncoder;
+use crate::error::FastError;
+use crate::pmap::PresenceMap;
+
+/// Default maximum block length of a frame (1 MiB).
+pub const DEFAULT_MAX_FRAME_LEN: usize = 1024 * 1024;
+
+/// A single framed FAST message.
+#[derive(Debug, Clone)]
+pub struct FastFrame {
+    /// Presence map of the message.
+    pmap: PresenceMap,
+    /// Template ID of the message.
+    template_id: u32,
+    /// Encoded message body following the template ID.
+    body: Vec<u8>,
+}
+
+impl FastFrame {
+    /// Creates a new frame.
+    ///
+    /// # Arguments
+    /// * `pmap` - The message presence map
+    /// * `template_id` - The message template ID
+    /// * `body` - The encoded fields following the template ID
+    #[must_use]
+    pub fn new(pmap: PresenceMap, template_id: u32, body: Vec<u8>) -> Self {
+        Self {
+            pmap,
+            template_id,
+            body,
+        }
+    }
+
+    /// Returns the presence map.
+    #[must_use]
+    pub fn pmap(&self) -> &PresenceMap {
+        &self.pmap
+    }
+
+    /// Returns a mutable reference to the presence map, for reading bits.
+    #[must_use]
+    pub fn pmap_mut(&mut self) -> &mut PresenceMap {
+        &mut self.pmap
+    }
+
+    /// Returns the template ID.
+    #[must_use]
+    pub const fn template_id(&self) -> u32 {
+        self.template_id
+    }
+
+    /// Returns the encoded message body.
+    #[must_use]
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Encodes the frame, including its block length prefix.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut block = FastEncoder::with_capacity(self.body.len() + 8);
+        block.encode_uint(u64::from(self.template_id));
+        let pmap = self.pmap.encode();
+        let block_len = pmap.len() + block.len() + self.body.len();
+
+        let mut frame = FastEncoder::with_capacity(block_len + 4);
+        frame.encode_uint(block_len as u64);
+        let mut bytes = frame.finish();
+        bytes.extend_from_slice(&pmap);
+        bytes.extend_from_slice(block.as_bytes());
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+
+    /// Decodes one frame from the start of `data`.
+    ///
+    /// Frames with a block longer than [`DEFAULT_MAX_FRAME_LEN`] are
+    /// rejected.
+    ///
+    /// # Arguments
+    /// * `data` - The buffered stream bytes
+    ///
+    /// # Returns
+    /// The frame and the number of bytes it occupied.
+    ///
+    /// # Errors
+    /// Returns `FastError::Incomplete` if `data` does not yet hold a complete
+    /// frame, or another error if the frame header is malformed.
+    pub fn decode(data: &[u8]) -> Result<(Self, usize), FastError> {
+        Self::decode_with_limit(data, DEFAULT_MAX_FRAME_LEN)
+    }
+
+    /// Decodes one frame from the start of `data`, rejecting a block longer
+    /// than `max_len`.
+    ///
+    /// The block length is checked as soon as its prefix is read, so an
+    /// oversized frame is rejected before its block is buffered.
+    ///
+    /// # Arguments
+    /// * `data` - The buffered stream bytes
+    /// * `max_len` - The maximum block length in bytes
+    ///
+    /// # Returns
+    /// The frame and the number of bytes it occupied.
+    ///
+    /// # Errors
+    /// Returns `FastError::FrameTooLarge` if the declared block length
+    /// exceeds `max_len`, `FastError::Incomplete` if `data` does not yet hold
+    /// a complete frame, or another error if the frame header is malformed.
+    pub fn decode_with_limit(data: &[u8], max_len: usize) -> Result<(Self, usize), FastError> {
+        let mut offset = 0;
+        let block_len = match FastDecoder::decode_uint(data, &mut offset) {
+            Err(FastError::UnexpectedEof) => return Err(FastError::Incomplete),
+            result => result?,
+        };
+        let end = usize::try_from(block_len)
+            .ok()
+            .filter(|len| *len <= max_len)
+            .ok_or(FastError::FrameTooLarge {
+                len: block_len,
+                max: max_len,
+            })?
+            .checked_add(offset)
+            .ok_or(FastError::IntegerOverflow)?;
+        if data.len() < end {
+            return Err(FastError::Incomplete);
+        }
+
+        let block = &data[..end];
+        let pmap = PresenceMap::decode(block, &mut offset).map_err(|e| e.at_offset(offset))?;
+        let template_id = FastDecoder::decode_uint(block, &mut offset)
+            .and_then(|id| u32::try_from(id).map_err(|_| FastError::IntegerOverflow))
+            .map_err(|e| e.at_offset(offset))?;
+        let body = block[offset..].to_vec();
+
+        Ok((Self::new(pmap, template_id, body), end))
+    }
+}
+
+/// Splits framed FAST messages off a growing stream buffer.
+#[derive(Debug)]
+pub struct FastFrameReader {
+    /// Bytes received but not yet consumed as frames.
+    buffer: Vec<u8>,
+    /// Maximum block length of a frame.
+    max_frame_len: usize,
+}
+
+impl FastFrameReader {
+    /// Creates an empty reader.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_frame_len: DEFAULT_MAX_FRAME_LEN,
+        }
+    }
+
+    /// Sets the maximum block length of a frame.
+    ///
+    /// Guards against unbounded buffering when a peer declares a huge block
+    /// length. Defaults to [`DEFAULT_MAX_FRAME_LEN`].
+    ///
+    /// # Arguments
+    /// * `max_frame_len` - The maximum block length in bytes
+    #[must_use]
+    pub const fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+
+    /// Returns the maximum block length of a frame.
+    #[must_use]
+    pub const fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Appends bytes received from the stream.
+    ///
+    /// # Arguments
+    /// * `data` - The received bytes
+    pub fn extend(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Returns the number of buffered bytes not yet consumed.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Extracts the next complete frame.
+    ///
+    /// # Returns
+    /// `None` if more data is needed.
+    ///
+    /// # Errors
+    /// Returns `FastError::FrameTooLarge` if the next frame's block exceeds
+    /// [`max_frame_len`](Self::max_frame_len), or another error if the
+    /// buffered frame header is malformed. The stream cannot be resumed
+    /// after an error.
+    pub fn next_frame(&mut self) -> Result<Option<FastFrame>, FastError> {
+        match FastFrame::decode_with_limit(&self.buffer, self.max_frame_len) {
+            Ok((frame, consumed)) => {
+                self.buffer.drain(..consumed);
+                Ok(Some(frame))
+            }
+            Err(FastError::Incomplete) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for FastFrameReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pmap::PresenceMapBuilder;
+
+    fn market_data_frame(seq_num: u64, symbol: &str) -> Vec<u8> {
+        let mut body = FastEncoder::new();
+        body.encode_uint(seq_num);
+        body.encode_ascii(symbol);
+        let pmap = PresenceMapBuilder::new().bit(true).bit(false).build();
+        FastFrame::new(pmap, 1, body.finish()).encode()
+    }
+
+    #[test]
+    fn test_frame_round_trip() {
+        let bytes = market_data_frame(7, "AAPL");
+        let (frame, consumed) = FastFrame::decode(&bytes).unwrap();
+
+        assert_eq!(consumed, bytes.len());
+        assert_eq!(frame.template_id(), 1);
+        assert!(frame.pmap().bit(0));
+        assert!(!frame.pmap().bit(1));
+
+        let mut offset = 0;
+        assert_eq!(FastDecoder::decode_uint(frame.body(), &mut offset), Ok(7));
+        assert_eq!(
+            FastDecoder::decode_ascii(frame.body(), &mut offset).unwrap(),
+            "AAPL"
+        );
+        assert_eq!(offset, frame.body().len());
+    }
+
+    #[test]
+    fn test_frame_decode_incomplete() {
+        let bytes = market_data_frame(7, "AAPL");
+        for len in 0..bytes.len() {
+            assert!(matches!(
+                FastFrame::decode(&bytes[..len]),
+                Err(FastError::Incomplete)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_frame_decode_truncated_header() {
+        // Block of two bytes holding a pmap without a stop bit.
+        let bytes = [0x82, 0x01, 0x02];
+        assert_eq!(
+            FastFrame::decode(&bytes).unwrap_err(),
+            FastError::UnexpectedEof.at_offset(3)
+        );
+    }
+
+    #[test]
+    fn test_reader_rejects_oversized_frame() {
+        let mut prefix = FastEncoder::new();
+        prefix.encode_uint(u64::MAX);
+        let mut reader = FastFrameReader::new();
+        reader.extend(&prefix.finish());
+        assert_eq!(
+            reader.next_frame().unwrap_err(),
+            FastError::FrameTooLarge {
+                len: u64::MAX,
+                max: DEFAULT_MAX_FRAME_LEN
+            }
+        );
+
+        let bytes = market_data_frame(7, "AAPL");
+        let mut reader = FastFrameReader::new().with_max_frame_len(4);
+        reader.extend(&bytes[..1]);
+        assert!(matches!(
+            reader.next_frame(),
+            Err(FastError::FrameTooLarge { max: 4, .. })
+        ));
+        assert!(FastFrame::decode_with_limit(&bytes, bytes.len()).is_ok());
+    }
+
+    #[test]
+    fn test_reader_two_concatenated_frames() {
+        let mut stream = market_data_frame(1, "AAPL");
+        stream.extend(market_data_frame(2, "MSFT"));
+
+        let mut reader = FastFrameReader::new();
+        reader.extend(&stream[..3]);
+        assert!(reader.next_frame().unwrap().is_none());
+        reader.extend(&stream[3..]);
+
+        let mut decoded = Vec::new();
+        while let Some(frame) = reader.next_frame().unwrap() {
+            let mut offset = 0;
+            let seq_num = FastDecoder::decode_uint(frame.body(), &mut offset).unwrap();
+            let symbol = FastDecoder::decode_ascii(frame.body(), &mut offset).unwrap();
+            decoded.push((seq_num, symbol));
+        }
+
+        assert_eq!(decoded, [(1, "AAPL".to_string()), (2, "MSFT".to_string())]);
+        assert_eq!(reader.buffered(), 0);
+    }
+}
@@ -18,14 +18,17 @@
 //! - **Presence maps**: Track which optional fields are present
 //! - **Field operators**: Copy, Delta, Increment, Tail, etc.
 //! - **Template support**: Message structure definitions
+//! - **Framing**: Block-length prefixed messages for stream transports
 
 pub mod decoder;
 pub mod encoder;
 pub mod error;
+pub mod frame;
 pub mod operators;
 pub mod pmap;
 
-pub use decoder::FastDecoder;
+pub use decoder::{FastDecoder, FieldTrace};
 pub use encoder::FastEncoder;
 pub use error::FastError;
+pub use frame::{FastFrame, FastFrameReader};
 pub use pmap::PresenceMap;
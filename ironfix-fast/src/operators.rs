@@ -9,7 +9,10 @@
 //! Operators define how field values are encoded and decoded relative to
 //! previous values in the dictionary.
 
+use crate::error::FastError;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// FAST field operator types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -131,6 +134,166 @@ impl DictionaryValue {
             _ => None,
         }
     }
+
+    /// Returns the value as a `(mantissa, exponent)` pair, if applicable.
+    #[must_use]
+    pub const fn as_decimal(&self) -> Option<(i64, i32)> {
+        match self {
+            Self::Decimal(mantissa, exponent) => Some((*mantissa, *exponent)),
+            _ => None,
+        }
+    }
+
+    /// Converts a decimal value to a [`Decimal`].
+    ///
+    /// # Returns
+    /// `None` if the value is not a decimal or `mantissa * 10^exponent` is
+    /// outside the range of [`Decimal`].
+    #[must_use]
+    pub fn to_rust_decimal(&self) -> Option<Decimal> {
+        let (mantissa, exponent) = self.as_decimal()?;
+        if exponent <= 0 {
+            Decimal::try_new(mantissa, exponent.unsigned_abs()).ok()
+        } else {
+            (0..exponent).try_fold(Decimal::from(mantissa), |value, _| {
+                value.checked_mul(Decimal::TEN)
+            })
+        }
+    }
+
+    /// Returns the raw bytes of a string or byte vector value.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::String(s) => Some(s.as_bytes()),
+            Self::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DictionaryValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Undefined => write!(f, "<undefined>"),
+            Self::Empty => write!(f, "<empty>"),
+            Self::Int(v) => write!(f, "{}", v),
+            Self::UInt(v) => write!(f, "{}", v),
+            Self::String(s) => write!(f, "{:?}", s),
+            Self::Bytes(bytes) => {
+                write!(f, "0x")?;
+                for b in bytes {
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+            Self::Decimal(mantissa, exponent) => match self.to_rust_decimal() {
+                Some(decimal) => write!(f, "{}", decimal),
+                None => write!(f, "{}e{}", mantissa, exponent),
+            },
+        }
+    }
+}
+
+/// Computes the tail to transmit for `value` relative to `base`.
+///
+/// # Returns
+/// `None` if the value equals the base and can be omitted from the stream.
+/// A value longer than the base is sent whole.
+///
+/// # Errors
+/// Returns `FastError::InvalidOperator` if the value is shorter than the base,
+/// since a tail can only replace characters, never remove them.
+pub(crate) fn tail_diff<'v>(base: &[u8], value: &'v [u8]) -> Result<Option<&'v [u8]>, FastError> {
+    if value == base {
+        return Ok(None);
+    }
+    if value.len() < base.len() {
+        return Err(FastError::InvalidOperator(format!(
+            "tail cannot shorten a {}-byte value to {} bytes",
+            base.len(),
+            value.len()
+        )));
+    }
+    if value.len() > base.len() {
+        return Ok(Some(value));
+    }
+
+    let prefix = base.iter().zip(value).take_while(|(a, b)| a == b).count();
+    Ok(Some(&value[prefix..]))
+}
+
+/// Combines a received tail with the base value.
+///
+/// The tail replaces the same number of trailing bytes of the base; a tail
+/// longer than the base replaces it entirely.
+pub(crate) fn apply_tail(base: &[u8], tail: &[u8]) -> Vec<u8> {
+    let keep = base.len().saturating_sub(tail.len());
+    let mut value = Vec::with_capacity(keep + tail.len());
+    value.extend_from_slice(&base[..keep]);
+    value.extend_from_slice(tail);
+    value
+}
+
+/// Computes the string delta transforming `base` into `value`.
+///
+/// The delta is a subtraction length and the bytes to add. A non-negative
+/// length removes bytes from the back of the base and appends; a negative
+/// length `-(n + 1)` removes `n` bytes from the front and prepends. The
+/// shorter of the two forms is chosen, preferring the back.
+pub(crate) fn delta_diff<'v>(base: &[u8], value: &'v [u8]) -> (i64, &'v [u8]) {
+    let prefix = base.iter().zip(value).take_while(|(a, b)| a == b).count();
+    let suffix = base
+        .iter()
+        .rev()
+        .zip(value.iter().rev())
+        .take(base.len().min(value.len()) - prefix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    if value.len() - suffix < value.len() - prefix {
+        let removed = (base.len() - suffix) as i64;
+        (-(removed + 1), &value[..value.len() - suffix])
+    } else {
+        ((base.len() - prefix) as i64, &value[prefix..])
+    }
+}
+
+/// Applies a received string delta to the base value.
+///
+/// # Errors
+/// Returns `FastError::InvalidOperator` if the subtraction length exceeds the
+/// base length.
+pub(crate) fn apply_delta(
+    base: &[u8],
+    subtraction: i64,
+    diff: &[u8],
+) -> Result<Vec<u8>, FastError> {
+    let (front, removed) = if subtraction < 0 {
+        (true, subtraction.unsigned_abs() - 1)
+    } else {
+        (false, subtraction.unsigned_abs())
+    };
+    let removed = usize::try_from(removed)
+        .ok()
+        .filter(|&removed| removed <= base.len())
+        .ok_or_else(|| {
+            FastError::InvalidOperator(format!(
+                "delta removes {} bytes from a {}-byte value",
+                removed,
+                base.len()
+            ))
+        })?;
+
+    let mut value = Vec::with_capacity(base.len() - removed + diff.len());
+    if front {
+        value.extend_from_slice(diff);
+        value.extend_from_slice(&base[removed..]);
+    } else {
+        value.extend_from_slice(&base[..base.len() - removed]);
+        value.extend_from_slice(diff);
+    }
+    Ok(value)
 }
 
 #[cfg(test)]
@@ -166,5 +329,90 @@ mod tests {
 
         let str_val = DictionaryValue::String("test".to_string());
         assert_eq!(str_val.as_str(), Some("test"));
+        assert_eq!(str_val.as_bytes(), Some(&b"test"[..]));
+        assert_eq!(int_val.as_bytes(), None);
+    }
+
+    #[test]
+    fn test_dictionary_value_decimal() {
+        let price = DictionaryValue::Decimal(12345, -2);
+        assert_eq!(price.as_decimal(), Some((12345, -2)));
+        assert_eq!(price.to_rust_decimal(), Some(Decimal::new(12345, 2)));
+
+        let scaled = DictionaryValue::Decimal(15, 3);
+        assert_eq!(scaled.to_rust_decimal(), Some(Decimal::from(15000)));
+
+        assert_eq!(DictionaryValue::Decimal(1, -40).to_rust_decimal(), None);
+        assert_eq!(
+            DictionaryValue::Decimal(i64::MAX, 20).to_rust_decimal(),
+            None
+        );
+        assert_eq!(DictionaryValue::Int(1).as_decimal(), None);
+        assert_eq!(DictionaryValue::Int(1).to_rust_decimal(), None);
+    }
+
+    #[test]
+    fn test_dictionary_value_display() {
+        assert_eq!(DictionaryValue::Undefined.to_string(), "<undefined>");
+        assert_eq!(DictionaryValue::Empty.to_string(), "<empty>");
+        assert_eq!(DictionaryValue::Int(-7).to_string(), "-7");
+        assert_eq!(DictionaryValue::UInt(42).to_string(), "42");
+        assert_eq!(
+            DictionaryValue::String("AAPL".to_string()).to_string(),
+            "\"AAPL\""
+        );
+        assert_eq!(
+            DictionaryValue::Bytes(vec![0x01, 0xab]).to_string(),
+            "0x01ab"
+        );
+        assert_eq!(DictionaryValue::Decimal(12345, -2).to_string(), "123.45");
+        assert_eq!(DictionaryValue::Decimal(1, -40).to_string(), "1e-40");
+    }
+
+    #[test]
+    fn test_tail_diff() {
+        assert_eq!(tail_diff(b"ABCD", b"ABCD").unwrap(), None);
+        assert_eq!(tail_diff(b"ABCD", b"ABXY").unwrap(), Some(&b"XY"[..]));
+        assert_eq!(tail_diff(b"ABCD", b"ABCDE").unwrap(), Some(&b"ABCDE"[..]));
+        assert_eq!(tail_diff(b"", b"AB").unwrap(), Some(&b"AB"[..]));
+        assert!(matches!(
+            tail_diff(b"ABCD", b"AB"),
+            Err(FastError::InvalidOperator(_))
+        ));
+    }
+
+    #[test]
+    fn test_apply_tail() {
+        assert_eq!(apply_tail(b"ABCD", b"XY"), b"ABXY");
+        assert_eq!(apply_tail(b"ABCD", b"WXYZ"), b"WXYZ");
+        assert_eq!(apply_tail(b"AB", b"WXYZ"), b"WXYZ");
+        assert_eq!(apply_tail(b"", b"XY"), b"XY");
+    }
+
+    #[test]
+    fn test_delta_diff() {
+        assert_eq!(delta_diff(b"AAPL", b"AAPLX"), (0, &b"X"[..]));
+        assert_eq!(delta_diff(b"AAPL", b"MSFT"), (4, &b"MSFT"[..]));
+        assert_eq!(delta_diff(b"AAPL", b"AAP"), (1, &b""[..]));
+        assert_eq!(delta_diff(b"AAPL", b"XAAPL"), (-1, &b"X"[..]));
+        assert_eq!(delta_diff(b"ABCD", b"ZCD"), (-3, &b"Z"[..]));
+        assert_eq!(delta_diff(b"AAPL", b"AAPL"), (0, &b""[..]));
+        assert_eq!(delta_diff(b"", b"AB"), (0, &b"AB"[..]));
+    }
+
+    #[test]
+    fn test_apply_delta() {
+        assert_eq!(apply_delta(b"AAPL", 0, b"X").unwrap(), b"AAPLX");
+        assert_eq!(apply_delta(b"AAPL", 4, b"MSFT").unwrap(), b"MSFT");
+        assert_eq!(apply_delta(b"AAPL", -1, b"X").unwrap(), b"XAAPL");
+        assert_eq!(apply_delta(b"ABCD", -3, b"Z").unwrap(), b"ZCD");
+        assert!(matches!(
+            apply_delta(b"AB", 3, b""),
+            Err(FastError::InvalidOperator(_))
+        ));
+        assert!(matches!(
+            apply_delta(b"AB", -4, b""),
+            Err(FastError::InvalidOperator(_))
+        ));
     }
 }
@@ -16,6 +16,19 @@ use crate::error::FastError;
 ///
 /// The presence map tracks which optional fields are present in a message.
 /// Bits are consumed in order as fields are decoded.
+///
+/// # Length
+///
+/// Bits past the end of the map read as unset, so maps that differ only in
+/// trailing unset bits describe the same fields. The wire form carries bits
+/// in 7-bit groups: [`encode`](Self::encode) pads the last group with unset
+/// bits and [`decode`](Self::decode) always yields a multiple of 7 bits, so a
+/// round trip rounds [`len`](Self::len) up to the next multiple of 7 (at
+/// least 7) while preserving every bit. [`from_bits`](Self::from_bits),
+/// [`with_capacity`](Self::with_capacity) and the builder keep the length
+/// given, [`set_bit`](Self::set_bit) only grows it, and
+/// [`normalize`](Self::normalize) trims it to the canonical form without
+/// trailing unset bits.
 #[derive(Debug, Clone)]
 pub struct PresenceMap {
     /// The raw bits of the presence map.
@@ -40,8 +53,47 @@ impl PresenceMap {
         Self { bits, position: 0 }
     }
 
+    /// Creates a presence map of `bits` bits, all unset.
+    ///
+    /// # Arguments
+    /// * `bits` - The number of bits in the map
+    #[must_use]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self::from_bits(vec![false; bits])
+    }
+
+    /// Sets the bit at the specified position.
+    ///
+    /// The map grows with unset bits if `index` is past the end.
+    ///
+    /// # Arguments
+    /// * `index` - The bit position (0-indexed)
+    /// * `present` - Whether the corresponding field is present
+    pub fn set_bit(&mut self, index: usize, present: bool) {
+        if index >= self.bits.len() {
+            self.bits.resize(index + 1, false);
+        }
+        self.bits[index] = present;
+    }
+
+    /// Trims trailing unset bits, leaving the canonical form of the map.
+    ///
+    /// Maps describing the same fields have equal bits once normalized,
+    /// whatever their encoded padding. The read position is kept.
+    pub fn normalize(&mut self) {
+        let len = self
+            .bits
+            .iter()
+            .rposition(|&bit| bit)
+            .map_or(0, |last| last + 1);
+        self.bits.truncate(len);
+    }
+
     /// Decodes a presence map from a byte slice.
     ///
+    /// The result holds 7 bits per byte read, including any padding bits of
+    /// the last byte.
+    ///
     /// # Arguments
     /// * `data` - The input bytes
     /// * `offset` - Current position in the data (will be updated)
@@ -92,6 +144,31 @@ impl PresenceMap {
         }
     }
 
+    /// Returns the next bit from the presence map, failing when exhausted.
+    ///
+    /// Unlike [`next_bit`](Self::next_bit), reading past the end is reported
+    /// so template decoders can detect an under-sized presence map.
+    ///
+    /// # Errors
+    /// Returns `FastError::PresenceMapExhausted` if every bit has been read.
+    #[inline]
+    pub fn try_next_bit(&mut self) -> Result<bool, FastError> {
+        if self.position < self.bits.len() {
+            Ok(self.next_bit())
+        } else {
+            Err(FastError::PresenceMapExhausted {
+                bits: self.bits.len(),
+            })
+        }
+    }
+
+    /// Returns the number of bits not yet consumed.
+    #[inline]
+    #[must_use]
+    pub fn remaining_bits(&self) -> usize {
+        self.bits.len().saturating_sub(self.position)
+    }
+
     /// Returns the bit at the specified position without consuming it.
     ///
     /// # Arguments
@@ -126,6 +203,9 @@ impl PresenceMap {
 
     /// Encodes the presence map to bytes.
     ///
+    /// Bits are packed 7 per byte; the last byte is padded with unset bits and
+    /// carries the stop bit. An empty map encodes as a single `0x80`.
+    ///
     /// # Returns
     /// The encoded bytes with stop-bit encoding.
     #[must_use]
@@ -232,6 +312,27 @@ mod tests {
         assert!(!pmap.next_bit()); // Exhausted
     }
 
+    #[test]
+    fn test_presence_map_try_next_bit_detects_exhaustion() {
+        let data = [0b1101_0101];
+        let mut offset = 0;
+        let mut pmap = PresenceMap::decode(&data, &mut offset).unwrap();
+
+        assert_eq!(pmap.remaining_bits(), 7);
+        for _ in 0..7 {
+            assert!(pmap.try_next_bit().is_ok());
+        }
+        assert_eq!(pmap.remaining_bits(), 0);
+        assert_eq!(
+            pmap.try_next_bit(),
+            Err(FastError::PresenceMapExhausted { bits: 7 })
+        );
+
+        pmap.reset();
+        assert_eq!(pmap.remaining_bits(), 7);
+        assert_eq!(pmap.try_next_bit(), Ok(true));
+    }
+
     #[test]
     fn test_presence_map_encode() {
         let pmap = PresenceMap::from_bits(vec![true, true, false, false, false, false, false]);
@@ -241,6 +342,69 @@ mod tests {
         assert_eq!(encoded[0], 0b1110_0000);
     }
 
+    #[test]
+    fn test_presence_map_set_bit() {
+        let mut pmap = PresenceMap::with_capacity(10);
+        assert_eq!(pmap.len(), 10);
+        assert_eq!(pmap.encode(), vec![0b0000_0000, 0b1000_0000]);
+
+        pmap.set_bit(0, true);
+        pmap.set_bit(3, true);
+        pmap.set_bit(8, true);
+        assert!(pmap.bit(3));
+        assert!(!pmap.bit(4));
+        assert_eq!(pmap.encode(), vec![0b0100_1000, 0b1010_0000]);
+
+        pmap.set_bit(3, false);
+        assert_eq!(pmap.encode(), vec![0b0100_0000, 0b1010_0000]);
+    }
+
+    #[test]
+    fn test_presence_map_set_bit_grows() {
+        let mut pmap = PresenceMap::new();
+        pmap.set_bit(2, true);
+
+        assert_eq!(pmap.len(), 3);
+        assert_eq!(pmap.encode(), vec![0b1001_0000]);
+    }
+
+    #[test]
+    fn test_presence_map_normalize() {
+        let mut pmap = PresenceMap::from_bits(vec![false, true, false, false]);
+        pmap.normalize();
+        assert_eq!(pmap.len(), 2);
+        assert!(pmap.bit(1));
+
+        let mut empty = PresenceMap::with_capacity(9);
+        empty.normalize();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_presence_map_round_trip_preserves_bits() {
+        // Every bit pattern of every length up to three stop-bit bytes.
+        for len in 1usize..20 {
+            for pattern in 0u32..1 << len {
+                let bits: Vec<bool> = (0..len).map(|i| pattern >> i & 1 == 1).collect();
+                let mut original = PresenceMap::from_bits(bits);
+
+                let encoded = original.encode();
+                let mut offset = 0;
+                let mut decoded = PresenceMap::decode(&encoded, &mut offset).unwrap();
+                assert_eq!(offset, encoded.len());
+                assert_eq!(decoded.len(), len.div_ceil(7) * 7);
+
+                for _ in 0..decoded.len() + 1 {
+                    assert_eq!(decoded.next_bit(), original.next_bit());
+                }
+
+                original.normalize();
+                decoded.normalize();
+                assert_eq!(decoded.bits, original.bits);
+            }
+        }
+    }
+
     #[test]
     fn test_presence_map_builder() {
         let pmap = PresenceMapBuilder::new()
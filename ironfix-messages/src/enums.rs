@@ -0,0 +1,427 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Enumerated field values.
+//!
+//! Each enumeration is represented by its single-character FIX code and
+//! implements [`FixField`] for decoding from and encoding to the wire.
+//! [`Side`](ironfix_core::types::Side) is provided by `ironfix-core`.
+
+use ironfix_core::error::DecodeError;
+use ironfix_core::field::FixField;
+use std::fmt;
+
+/// Order type enumeration (tag 40).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum OrdType {
+    /// Market order.
+    Market = b'1',
+    /// Limit order.
+    Limit = b'2',
+    /// Stop order.
+    Stop = b'3',
+    /// Stop limit order.
+    StopLimit = b'4',
+    /// Market on close.
+    MarketOnClose = b'5',
+    /// With or without.
+    WithOrWithout = b'6',
+    /// Limit or better.
+    LimitOrBetter = b'7',
+    /// Limit with or without.
+    LimitWithOrWithout = b'8',
+    /// On basis.
+    OnBasis = b'9',
+    /// Previously quoted.
+    PreviouslyQuoted = b'D',
+    /// Previously indicated.
+    PreviouslyIndicated = b'E',
+    /// Forex swap.
+    ForexSwap = b'G',
+    /// Funari (limit day order with unexecuted portion handled as market on close).
+    Funari = b'I',
+    /// Market if touched.
+    MarketIfTouched = b'J',
+    /// Market with leftover as limit.
+    MarketWithLeftOverAsLimit = b'K',
+    /// Previous fund valuation point.
+    PreviousFundValuationPoint = b'L',
+    /// Next fund valuation point.
+    NextFundValuationPoint = b'M',
+    /// Pegged.
+    Pegged = b'P',
+}
+
+impl OrdType {
+    /// Creates a value from its FIX character code.
+    ///
+    /// # Arguments
+    /// * `c` - The character code
+    ///
+    /// # Returns
+    /// `None` if the character is not a known value.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '1' => Some(Self::Market),
+            '2' => Some(Self::Limit),
+            '3' => Some(Self::Stop),
+            '4' => Some(Self::StopLimit),
+            '5' => Some(Self::MarketOnClose),
+            '6' => Some(Self::WithOrWithout),
+            '7' => Some(Self::LimitOrBetter),
+            '8' => Some(Self::LimitWithOrWithout),
+            '9' => Some(Self::OnBasis),
+            'D' => Some(Self::PreviouslyQuoted),
+            'E' => Some(Self::PreviouslyIndicated),
+            'G' => Some(Self::ForexSwap),
+            'I' => Some(Self::Funari),
+            'J' => Some(Self::MarketIfTouched),
+            'K' => Some(Self::MarketWithLeftOverAsLimit),
+            'L' => Some(Self::PreviousFundValuationPoint),
+            'M' => Some(Self::NextFundValuationPoint),
+            'P' => Some(Self::Pegged),
+            _ => None,
+        }
+    }
+
+    /// Returns the FIX character code of this value.
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        self as u8 as char
+    }
+}
+
+impl fmt::Display for OrdType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl FixField for OrdType {
+    const TAG: u32 = 40;
+    type Value = Self;
+
+    fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+        decode_char(Self::TAG, bytes, Self::from_char)
+    }
+
+    fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+        buf.push(*value as u8);
+    }
+}
+
+/// Time in force enumeration (tag 59).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum TimeInForce {
+    /// Day.
+    Day = b'0',
+    /// Good till cancel.
+    GoodTillCancel = b'1',
+    /// At the opening.
+    AtTheOpening = b'2',
+    /// Immediate or cancel.
+    ImmediateOrCancel = b'3',
+    /// Fill or kill.
+    FillOrKill = b'4',
+    /// Good till crossing.
+    GoodTillCrossing = b'5',
+    /// Good till date.
+    GoodTillDate = b'6',
+    /// At the close.
+    AtTheClose = b'7',
+}
+
+impl TimeInForce {
+    /// Creates a value from its FIX character code.
+    ///
+    /// # Arguments
+    /// * `c` - The character code
+    ///
+    /// # Returns
+    /// `None` if the character is not a known value.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '0' => Some(Self::Day),
+            '1' => Some(Self::GoodTillCancel),
+            '2' => Some(Self::AtTheOpening),
+            '3' => Some(Self::ImmediateOrCancel),
+            '4' => Some(Self::FillOrKill),
+            '5' => Some(Self::GoodTillCrossing),
+            '6' => Some(Self::GoodTillDate),
+            '7' => Some(Self::AtTheClose),
+            _ => None,
+        }
+    }
+
+    /// Returns the FIX character code of this value.
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        self as u8 as char
+    }
+}
+
+impl fmt::Display for TimeInForce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl FixField for TimeInForce {
+    const TAG: u32 = 59;
+    type Value = Self;
+
+    fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+        decode_char(Self::TAG, bytes, Self::from_char)
+    }
+
+    fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+        buf.push(*value as u8);
+    }
+}
+
+/// Execution type enumeration (tag 150).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ExecType {
+    /// New.
+    New = b'0',
+    /// Partial fill (FIX 4.2 and earlier).
+    PartialFill = b'1',
+    /// Fill (FIX 4.2 and earlier).
+    Fill = b'2',
+    /// Done for day.
+    DoneForDay = b'3',
+    /// Canceled.
+    Canceled = b'4',
+    /// Replaced.
+    Replaced = b'5',
+    /// Pending cancel.
+    PendingCancel = b'6',
+    /// Stopped.
+    Stopped = b'7',
+    /// Rejected.
+    Rejected = b'8',
+    /// Suspended.
+    Suspended = b'9',
+    /// Pending new.
+    PendingNew = b'A',
+    /// Calculated.
+    Calculated = b'B',
+    /// Expired.
+    Expired = b'C',
+    /// Restated.
+    Restated = b'D',
+    /// Pending replace.
+    PendingReplace = b'E',
+    /// Trade (partial fill or fill).
+    Trade = b'F',
+    /// Trade correct.
+    TradeCorrect = b'G',
+    /// Trade cancel.
+    TradeCancel = b'H',
+    /// Order status.
+    OrderStatus = b'I',
+}
+
+impl ExecType {
+    /// Creates a value from its FIX character code.
+    ///
+    /// # Arguments
+    /// * `c` - The character code
+    ///
+    /// # Returns
+    /// `None` if the character is not a known value.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '0' => Some(Self::New),
+            '1' => Some(Self::PartialFill),
+            '2' => Some(Self::Fill),
+            '3' => Some(Self::DoneForDay),
+            '4' => Some(Self::Canceled),
+            '5' => Some(Self::Replaced),
+            '6' => Some(Self::PendingCancel),
+            '7' => Some(Self::Stopped),
+            '8' => Some(Self::Rejected),
+            '9' => Some(Self::Suspended),
+            'A' => Some(Self::PendingNew),
+            'B' => Some(Self::Calculated),
+            'C' => Some(Self::Expired),
+            'D' => Some(Self::Restated),
+            'E' => Some(Self::PendingReplace),
+            'F' => Some(Self::Trade),
+            'G' => Some(Self::TradeCorrect),
+            'H' => Some(Self::TradeCancel),
+            'I' => Some(Self::OrderStatus),
+            _ => None,
+        }
+    }
+
+    /// Returns the FIX character code of this value.
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        self as u8 as char
+    }
+}
+
+impl fmt::Display for ExecType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl FixField for ExecType {
+    const TAG: u32 = 150;
+    type Value = Self;
+
+    fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+        decode_char(Self::TAG, bytes, Self::from_char)
+    }
+
+    fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+        buf.push(*value as u8);
+    }
+}
+
+/// Order status enumeration (tag 39).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum OrdStatus {
+    /// New.
+    New = b'0',
+    /// Partially filled.
+    PartiallyFilled = b'1',
+    /// Filled.
+    Filled = b'2',
+    /// Done for day.
+    DoneForDay = b'3',
+    /// Canceled.
+    Canceled = b'4',
+    /// Replaced (FIX 4.2 and earlier).
+    Replaced = b'5',
+    /// Pending cancel.
+    PendingCancel = b'6',
+    /// Stopped.
+    Stopped = b'7',
+    /// Rejected.
+    Rejected = b'8',
+    /// Suspended.
+    Suspended = b'9',
+    /// Pending new.
+    PendingNew = b'A',
+    /// Calculated.
+    Calculated = b'B',
+    /// Expired.
+    Expired = b'C',
+    /// Accepted for bidding.
+    AcceptedForBidding = b'D',
+    /// Pending replace.
+    PendingReplace = b'E',
+}
+
+impl OrdStatus {
+    /// Creates a value from its FIX character code.
+    ///
+    /// # Arguments
+    /// * `c` - The character code
+    ///
+    /// # Returns
+    /// `None` if the character is not a known value.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '0' => Some(Self::New),
+            '1' => Some(Self::PartiallyFilled),
+            '2' => Some(Self::Filled),
+            '3' => Some(Self::DoneForDay),
+            '4' => Some(Self::Canceled),
+            '5' => Some(Self::Replaced),
+            '6' => Some(Self::PendingCancel),
+            '7' => Some(Self::Stopped),
+            '8' => Some(Self::Rejected),
+            '9' => Some(Self::Suspended),
+            'A' => Some(Self::PendingNew),
+            'B' => Some(Self::Calculated),
+            'C' => Some(Self::Expired),
+            'D' => Some(Self::AcceptedForBidding),
+            'E' => Some(Self::PendingReplace),
+            _ => None,
+        }
+    }
+
+    /// Returns the FIX character code of this value.
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        self as u8 as char
+    }
+}
+
+impl fmt::Display for OrdStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl FixField for OrdStatus {
+    const TAG: u32 = 39;
+    type Value = Self;
+
+    fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+        decode_char(Self::TAG, bytes, Self::from_char)
+    }
+
+    fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+        buf.push(*value as u8);
+    }
+}
+
+/// Decodes a single-character enumerated value.
+fn decode_char<T>(
+    tag: u32,
+    bytes: &[u8],
+    from_char: impl FnOnce(char) -> Option<T>,
+) -> Result<T, DecodeError> {
+    match bytes {
+        [b] => from_char(*b as char),
+        _ => None,
+    }
+    .ok_or_else(|| DecodeError::InvalidFieldValue {
+        tag,
+        reason: format!("unknown value '{}'", String::from_utf8_lossy(bytes)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enum_codes() {
+        assert_eq!(OrdType::Limit.as_char(), '2');
+        assert_eq!(OrdType::from_char('P'), Some(OrdType::Pegged));
+        assert_eq!(
+            TimeInForce::from_char('3'),
+            Some(TimeInForce::ImmediateOrCancel)
+        );
+        assert_eq!(ExecType::Trade.to_string(), "F");
+        assert_eq!(OrdStatus::from_char('Z'), None);
+    }
+
+    #[test]
+    fn test_enum_fix_field() {
+        assert_eq!(OrdStatus::decode(b"2").unwrap(), OrdStatus::Filled);
+        assert!(OrdType::decode(b"Z").is_err());
+        assert!(ExecType::decode(b"FF").is_err());
+
+        let mut buf = Vec::new();
+        TimeInForce::encode(&TimeInForce::GoodTillCancel, &mut buf);
+        assert_eq!(buf, b"1");
+    }
+}
@@ -0,0 +1,209 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Execution messages.
+//!
+//! This module provides [`ExecutionReport`] (MsgType=8).
+
+use crate::enums::{ExecType, OrdStatus};
+use crate::fields;
+use crate::wire::{
+    expect_msg_type, optional_decimal, optional_string, put_decimal, put_field,
+    put_optional_decimal, put_optional_str, put_side, required,
+};
+use ironfix_core::error::{DecodeError, EncodeError};
+use ironfix_core::message::{FixMessage, RawMessage};
+use ironfix_core::types::Side;
+use rust_decimal::Decimal;
+
+/// ExecutionReport message (MsgType=8).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionReport {
+    /// OrderID (tag 37).
+    pub order_id: String,
+    /// ClOrdID (tag 11).
+    pub cl_ord_id: Option<String>,
+    /// OrigClOrdID (tag 41).
+    pub orig_cl_ord_id: Option<String>,
+    /// ExecID (tag 17).
+    pub exec_id: String,
+    /// ExecType (tag 150).
+    pub exec_type: ExecType,
+    /// OrdStatus (tag 39).
+    pub ord_status: OrdStatus,
+    /// Symbol (tag 55).
+    pub symbol: String,
+    /// Side (tag 54).
+    pub side: Side,
+    /// OrderQty (tag 38).
+    pub order_qty: Option<Decimal>,
+    /// Price (tag 44).
+    pub price: Option<Decimal>,
+    /// LastQty (tag 32).
+    pub last_qty: Option<Decimal>,
+    /// LastPx (tag 31).
+    pub last_px: Option<Decimal>,
+    /// LeavesQty (tag 151).
+    pub leaves_qty: Decimal,
+    /// CumQty (tag 14).
+    pub cum_qty: Decimal,
+    /// AvgPx (tag 6).
+    pub avg_px: Decimal,
+    /// TransactTime (tag 60), as a UTCTimestamp string.
+    pub transact_time: Option<String>,
+    /// Text (tag 58).
+    pub text: Option<String>,
+}
+
+impl ExecutionReport {
+    /// Creates a report from its required identifying fields.
+    ///
+    /// LeavesQty, CumQty and AvgPx start at zero; see
+    /// [`with_quantities`](Self::with_quantities).
+    ///
+    /// # Arguments
+    /// * `order_id` - Counterparty-assigned order ID
+    /// * `exec_id` - Execution ID
+    /// * `exec_type` - Execution type
+    /// * `ord_status` - Order status after this execution
+    /// * `symbol` - Instrument symbol
+    /// * `side` - Order side
+    #[must_use]
+    pub fn new(
+        order_id: impl Into<String>,
+        exec_id: impl Into<String>,
+        exec_type: ExecType,
+        ord_status: OrdStatus,
+        symbol: impl Into<String>,
+        side: Side,
+    ) -> Self {
+        Self {
+            order_id: order_id.into(),
+            cl_ord_id: None,
+            orig_cl_ord_id: None,
+            exec_id: exec_id.into(),
+            exec_type,
+            ord_status,
+            symbol: symbol.into(),
+            side,
+            order_qty: None,
+            price: None,
+            last_qty: None,
+            last_px: None,
+            leaves_qty: Decimal::ZERO,
+            cum_qty: Decimal::ZERO,
+            avg_px: Decimal::ZERO,
+            transact_time: None,
+            text: None,
+        }
+    }
+
+    /// Sets the client order ID.
+    #[must_use]
+    pub fn with_cl_ord_id(mut self, cl_ord_id: impl Into<String>) -> Self {
+        self.cl_ord_id = Some(cl_ord_id.into());
+        self
+    }
+
+    /// Sets the original client order ID.
+    #[must_use]
+    pub fn with_orig_cl_ord_id(mut self, orig_cl_ord_id: impl Into<String>) -> Self {
+        self.orig_cl_ord_id = Some(orig_cl_ord_id.into());
+        self
+    }
+
+    /// Sets the order quantity and price.
+    #[must_use]
+    pub const fn with_order(mut self, order_qty: Decimal, price: Option<Decimal>) -> Self {
+        self.order_qty = Some(order_qty);
+        self.price = price;
+        self
+    }
+
+    /// Sets the quantity and price of the last fill.
+    #[must_use]
+    pub const fn with_last_fill(mut self, last_qty: Decimal, last_px: Decimal) -> Self {
+        self.last_qty = Some(last_qty);
+        self.last_px = Some(last_px);
+        self
+    }
+
+    /// Sets the order's open quantity, executed quantity and average price.
+    #[must_use]
+    pub const fn with_quantities(
+        mut self,
+        leaves_qty: Decimal,
+        cum_qty: Decimal,
+        avg_px: Decimal,
+    ) -> Self {
+        self.leaves_qty = leaves_qty;
+        self.cum_qty = cum_qty;
+        self.avg_px = avg_px;
+        self
+    }
+
+    /// Sets the transaction time.
+    #[must_use]
+    pub fn with_transact_time(mut self, transact_time: impl Into<String>) -> Self {
+        self.transact_time = Some(transact_time.into());
+        self
+    }
+
+    /// Sets the free-format text.
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+}
+
+impl FixMessage for ExecutionReport {
+    const MSG_TYPE: &'static str = "8";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        expect_msg_type(raw, Self::MSG_TYPE)?;
+        Ok(Self {
+            order_id: required(raw, fields::ORDER_ID)?.to_string()?,
+            cl_ord_id: optional_string(raw, fields::CL_ORD_ID)?,
+            orig_cl_ord_id: optional_string(raw, fields::ORIG_CL_ORD_ID)?,
+            exec_id: required(raw, fields::EXEC_ID)?.to_string()?,
+            exec_type: required(raw, fields::EXEC_TYPE)?.as_enum::<ExecType>()?,
+            ord_status: required(raw, fields::ORD_STATUS)?.as_enum::<OrdStatus>()?,
+            symbol: required(raw, fields::SYMBOL)?.to_string()?,
+            side: required(raw, fields::SIDE)?.parse()?,
+            order_qty: optional_decimal(raw, fields::ORDER_QTY)?,
+            price: optional_decimal(raw, fields::PRICE)?,
+            last_qty: optional_decimal(raw, fields::LAST_QTY)?,
+            last_px: optional_decimal(raw, fields::LAST_PX)?,
+            leaves_qty: required(raw, fields::LEAVES_QTY)?.as_decimal()?,
+            cum_qty: required(raw, fields::CUM_QTY)?.as_decimal()?,
+            avg_px: required(raw, fields::AVG_PX)?.as_decimal()?,
+            transact_time: optional_string(raw, fields::TRANSACT_TIME)?,
+            text: optional_string(raw, fields::TEXT)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_field(buf, fields::ORDER_ID, self.order_id.as_bytes());
+        put_optional_str(buf, fields::CL_ORD_ID, self.cl_ord_id.as_ref());
+        put_optional_str(buf, fields::ORIG_CL_ORD_ID, self.orig_cl_ord_id.as_ref());
+        put_field(buf, fields::EXEC_ID, self.exec_id.as_bytes());
+        put_field(buf, fields::EXEC_TYPE, &[self.exec_type as u8]);
+        put_field(buf, fields::ORD_STATUS, &[self.ord_status as u8]);
+        put_field(buf, fields::SYMBOL, self.symbol.as_bytes());
+        put_side(buf, fields::SIDE, self.side);
+        put_optional_decimal(buf, fields::ORDER_QTY, self.order_qty.as_ref());
+        put_optional_decimal(buf, fields::PRICE, self.price.as_ref());
+        put_optional_decimal(buf, fields::LAST_QTY, self.last_qty.as_ref());
+        put_optional_decimal(buf, fields::LAST_PX, self.last_px.as_ref());
+        put_decimal(buf, fields::LEAVES_QTY, &self.leaves_qty);
+        put_decimal(buf, fields::CUM_QTY, &self.cum_qty);
+        put_decimal(buf, fields::AVG_PX, &self.avg_px);
+        put_optional_str(buf, fields::TRANSACT_TIME, self.transact_time.as_ref());
+        put_optional_str(buf, fields::TEXT, self.text.as_ref());
+        Ok(())
+    }
+}
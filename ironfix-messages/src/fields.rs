@@ -0,0 +1,50 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Field tag constants used by the message types.
+
+/// Account (tag 1).
+pub const ACCOUNT: u32 = 1;
+/// AvgPx (tag 6).
+pub const AVG_PX: u32 = 6;
+/// ClOrdID (tag 11).
+pub const CL_ORD_ID: u32 = 11;
+/// CumQty (tag 14).
+pub const CUM_QTY: u32 = 14;
+/// ExecID (tag 17).
+pub const EXEC_ID: u32 = 17;
+/// LastPx (tag 31).
+pub const LAST_PX: u32 = 31;
+/// LastQty (tag 32).
+pub const LAST_QTY: u32 = 32;
+/// OrderID (tag 37).
+pub const ORDER_ID: u32 = 37;
+/// OrderQty (tag 38).
+pub const ORDER_QTY: u32 = 38;
+/// OrdStatus (tag 39).
+pub const ORD_STATUS: u32 = 39;
+/// OrdType (tag 40).
+pub const ORD_TYPE: u32 = 40;
+/// OrigClOrdID (tag 41).
+pub const ORIG_CL_ORD_ID: u32 = 41;
+/// Price (tag 44).
+pub const PRICE: u32 = 44;
+/// Side (tag 54).
+pub const SIDE: u32 = 54;
+/// Symbol (tag 55).
+pub const SYMBOL: u32 = 55;
+/// Text (tag 58).
+pub const TEXT: u32 = 58;
+/// TimeInForce (tag 59).
+pub const TIME_IN_FORCE: u32 = 59;
+/// TransactTime (tag 60).
+pub const TRANSACT_TIME: u32 = 60;
+/// StopPx (tag 99).
+pub const STOP_PX: u32 = 99;
+/// ExecType (tag 150).
+pub const EXEC_TYPE: u32 = 150;
+/// LeavesQty (tag 151).
+pub const LEAVES_QTY: u32 = 151;
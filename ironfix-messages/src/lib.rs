@@ -0,0 +1,31 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! # IronFix Messages
+//!
+//! Hand-written typed FIX messages for the IronFix FIX protocol engine.
+//!
+//! This crate provides ready-to-use message types for the most common order
+//! flow, without running the code generation pipeline:
+//! - **NewOrderSingle** (MsgType=D)
+//! - **OrderCancelRequest** (MsgType=F)
+//! - **ExecutionReport** (MsgType=8)
+//!
+//! Each type implements [`FixMessage`](ironfix_core::message::FixMessage),
+//! decoding from a [`RawMessage`](ironfix_core::message::RawMessage) and
+//! encoding its body fields, with typed enums and `Decimal` prices and
+//! quantities.
+
+pub mod enums;
+pub mod execution;
+pub mod fields;
+pub mod order;
+mod wire;
+
+pub use enums::{ExecType, OrdStatus, OrdType, TimeInForce};
+pub use execution::ExecutionReport;
+pub use ironfix_core::types::Side;
+pub use order::{NewOrderSingle, OrderCancelRequest};
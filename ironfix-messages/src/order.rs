@@ -0,0 +1,271 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Order entry messages.
+//!
+//! This module provides [`NewOrderSingle`] (MsgType=D) and
+//! [`OrderCancelRequest`] (MsgType=F).
+
+use crate::enums::{OrdType, TimeInForce};
+use crate::fields;
+use crate::wire::{
+    expect_msg_type, optional_decimal, optional_string, put_decimal, put_field,
+    put_optional_decimal, put_optional_str, put_side, required,
+};
+use ironfix_core::error::{DecodeError, EncodeError};
+use ironfix_core::message::{FixMessage, RawMessage};
+use ironfix_core::types::Side;
+use rust_decimal::Decimal;
+
+/// NewOrderSingle message (MsgType=D).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrderSingle {
+    /// ClOrdID (tag 11).
+    pub cl_ord_id: String,
+    /// Account (tag 1).
+    pub account: Option<String>,
+    /// Symbol (tag 55).
+    pub symbol: String,
+    /// Side (tag 54).
+    pub side: Side,
+    /// TransactTime (tag 60), as a UTCTimestamp string.
+    pub transact_time: Option<String>,
+    /// OrderQty (tag 38).
+    pub order_qty: Decimal,
+    /// OrdType (tag 40).
+    pub ord_type: OrdType,
+    /// Price (tag 44).
+    pub price: Option<Decimal>,
+    /// StopPx (tag 99).
+    pub stop_px: Option<Decimal>,
+    /// TimeInForce (tag 59).
+    pub time_in_force: Option<TimeInForce>,
+    /// Text (tag 58).
+    pub text: Option<String>,
+}
+
+impl NewOrderSingle {
+    /// Creates an order from its required fields.
+    ///
+    /// # Arguments
+    /// * `cl_ord_id` - Client order ID
+    /// * `symbol` - Instrument symbol
+    /// * `side` - Order side
+    /// * `order_qty` - Order quantity
+    /// * `ord_type` - Order type
+    #[must_use]
+    pub fn new(
+        cl_ord_id: impl Into<String>,
+        symbol: impl Into<String>,
+        side: Side,
+        order_qty: Decimal,
+        ord_type: OrdType,
+    ) -> Self {
+        Self {
+            cl_ord_id: cl_ord_id.into(),
+            account: None,
+            symbol: symbol.into(),
+            side,
+            transact_time: None,
+            order_qty,
+            ord_type,
+            price: None,
+            stop_px: None,
+            time_in_force: None,
+            text: None,
+        }
+    }
+
+    /// Sets the account.
+    #[must_use]
+    pub fn with_account(mut self, account: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Sets the transaction time.
+    #[must_use]
+    pub fn with_transact_time(mut self, transact_time: impl Into<String>) -> Self {
+        self.transact_time = Some(transact_time.into());
+        self
+    }
+
+    /// Sets the limit price.
+    #[must_use]
+    pub const fn with_price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Sets the stop price.
+    #[must_use]
+    pub const fn with_stop_px(mut self, stop_px: Decimal) -> Self {
+        self.stop_px = Some(stop_px);
+        self
+    }
+
+    /// Sets the time in force.
+    #[must_use]
+    pub const fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Sets the free-format text.
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+}
+
+impl FixMessage for NewOrderSingle {
+    const MSG_TYPE: &'static str = "D";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        expect_msg_type(raw, Self::MSG_TYPE)?;
+        Ok(Self {
+            cl_ord_id: required(raw, fields::CL_ORD_ID)?.to_string()?,
+            account: optional_string(raw, fields::ACCOUNT)?,
+            symbol: required(raw, fields::SYMBOL)?.to_string()?,
+            side: required(raw, fields::SIDE)?.parse()?,
+            transact_time: optional_string(raw, fields::TRANSACT_TIME)?,
+            order_qty: required(raw, fields::ORDER_QTY)?.as_decimal()?,
+            ord_type: required(raw, fields::ORD_TYPE)?.as_enum::<OrdType>()?,
+            price: optional_decimal(raw, fields::PRICE)?,
+            stop_px: optional_decimal(raw, fields::STOP_PX)?,
+            time_in_force: raw
+                .get_field(fields::TIME_IN_FORCE)
+                .map(|f| f.as_enum::<TimeInForce>())
+                .transpose()?,
+            text: optional_string(raw, fields::TEXT)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_optional_str(buf, fields::ACCOUNT, self.account.as_ref());
+        put_field(buf, fields::CL_ORD_ID, self.cl_ord_id.as_bytes());
+        put_field(buf, fields::SYMBOL, self.symbol.as_bytes());
+        put_side(buf, fields::SIDE, self.side);
+        put_optional_str(buf, fields::TRANSACT_TIME, self.transact_time.as_ref());
+        put_decimal(buf, fields::ORDER_QTY, &self.order_qty);
+        put_field(buf, fields::ORD_TYPE, &[self.ord_type as u8]);
+        put_optional_decimal(buf, fields::PRICE, self.price.as_ref());
+        put_optional_decimal(buf, fields::STOP_PX, self.stop_px.as_ref());
+        if let Some(time_in_force) = self.time_in_force {
+            put_field(buf, fields::TIME_IN_FORCE, &[time_in_force as u8]);
+        }
+        put_optional_str(buf, fields::TEXT, self.text.as_ref());
+        Ok(())
+    }
+}
+
+/// OrderCancelRequest message (MsgType=F).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderCancelRequest {
+    /// OrigClOrdID (tag 41).
+    pub orig_cl_ord_id: String,
+    /// OrderID (tag 37).
+    pub order_id: Option<String>,
+    /// ClOrdID (tag 11).
+    pub cl_ord_id: String,
+    /// Symbol (tag 55).
+    pub symbol: String,
+    /// Side (tag 54).
+    pub side: Side,
+    /// TransactTime (tag 60), as a UTCTimestamp string.
+    pub transact_time: Option<String>,
+    /// OrderQty (tag 38).
+    pub order_qty: Option<Decimal>,
+    /// Text (tag 58).
+    pub text: Option<String>,
+}
+
+impl OrderCancelRequest {
+    /// Creates a cancel request from its required fields.
+    ///
+    /// # Arguments
+    /// * `orig_cl_ord_id` - ClOrdID of the order to cancel
+    /// * `cl_ord_id` - Client ID of this cancel request
+    /// * `symbol` - Instrument symbol
+    /// * `side` - Side of the order to cancel
+    #[must_use]
+    pub fn new(
+        orig_cl_ord_id: impl Into<String>,
+        cl_ord_id: impl Into<String>,
+        symbol: impl Into<String>,
+        side: Side,
+    ) -> Self {
+        Self {
+            orig_cl_ord_id: orig_cl_ord_id.into(),
+            order_id: None,
+            cl_ord_id: cl_ord_id.into(),
+            symbol: symbol.into(),
+            side,
+            transact_time: None,
+            order_qty: None,
+            text: None,
+        }
+    }
+
+    /// Sets the counterparty-assigned order ID.
+    #[must_use]
+    pub fn with_order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    /// Sets the transaction time.
+    #[must_use]
+    pub fn with_transact_time(mut self, transact_time: impl Into<String>) -> Self {
+        self.transact_time = Some(transact_time.into());
+        self
+    }
+
+    /// Sets the order quantity.
+    #[must_use]
+    pub const fn with_order_qty(mut self, order_qty: Decimal) -> Self {
+        self.order_qty = Some(order_qty);
+        self
+    }
+
+    /// Sets the free-format text.
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+}
+
+impl FixMessage for OrderCancelRequest {
+    const MSG_TYPE: &'static str = "F";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        expect_msg_type(raw, Self::MSG_TYPE)?;
+        Ok(Self {
+            orig_cl_ord_id: required(raw, fields::ORIG_CL_ORD_ID)?.to_string()?,
+            order_id: optional_string(raw, fields::ORDER_ID)?,
+            cl_ord_id: required(raw, fields::CL_ORD_ID)?.to_string()?,
+            symbol: required(raw, fields::SYMBOL)?.to_string()?,
+            side: required(raw, fields::SIDE)?.parse()?,
+            transact_time: optional_string(raw, fields::TRANSACT_TIME)?,
+            order_qty: optional_decimal(raw, fields::ORDER_QTY)?,
+            text: optional_string(raw, fields::TEXT)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_field(buf, fields::ORIG_CL_ORD_ID, self.orig_cl_ord_id.as_bytes());
+        put_optional_str(buf, fields::ORDER_ID, self.order_id.as_ref());
+        put_field(buf, fields::CL_ORD_ID, self.cl_ord_id.as_bytes());
+        put_field(buf, fields::SYMBOL, self.symbol.as_bytes());
+        put_side(buf, fields::SIDE, self.side);
+        put_optional_str(buf, fields::TRANSACT_TIME, self.transact_time.as_ref());
+        put_optional_decimal(buf, fields::ORDER_QTY, self.order_qty.as_ref());
+        put_optional_str(buf, fields::TEXT, self.text.as_ref());
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Shared decoding and encoding helpers.
+
+use ironfix_core::error::DecodeError;
+use ironfix_core::field::FieldRef;
+use ironfix_core::message::RawMessage;
+use ironfix_core::types::Side;
+use rust_decimal::Decimal;
+
+/// Fails unless the raw message has the expected MsgType.
+pub(crate) fn expect_msg_type(raw: &RawMessage<'_>, msg_type: &str) -> Result<(), DecodeError> {
+    if raw.msg_type().as_str() == msg_type {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidMsgType(
+            raw.msg_type().as_str().to_string(),
+        ))
+    }
+}
+
+/// Looks up a required field, failing if it is absent.
+pub(crate) fn required<'a>(raw: &RawMessage<'a>, tag: u32) -> Result<FieldRef<'a>, DecodeError> {
+    raw.get_field(tag)
+        .copied()
+        .ok_or(DecodeError::MissingRequiredField { tag })
+}
+
+/// Reads an optional string field.
+pub(crate) fn optional_string(
+    raw: &RawMessage<'_>,
+    tag: u32,
+) -> Result<Option<String>, DecodeError> {
+    raw.get_field(tag).map(FieldRef::to_string).transpose()
+}
+
+/// Reads an optional decimal field.
+pub(crate) fn optional_decimal(
+    raw: &RawMessage<'_>,
+    tag: u32,
+) -> Result<Option<Decimal>, DecodeError> {
+    raw.get_field(tag).map(FieldRef::as_decimal).transpose()
+}
+
+/// Appends a `tag=value<SOH>` field to the buffer.
+pub(crate) fn put_field(buf: &mut Vec<u8>, tag: u32, value: &[u8]) {
+    buf.extend_from_slice(tag.to_string().as_bytes());
+    buf.push(b'=');
+    buf.extend_from_slice(value);
+    buf.push(0x01);
+}
+
+/// Appends a decimal field.
+pub(crate) fn put_decimal(buf: &mut Vec<u8>, tag: u32, value: &Decimal) {
+    put_field(buf, tag, value.to_string().as_bytes());
+}
+
+/// Appends a Side (tag 54) field.
+pub(crate) fn put_side(buf: &mut Vec<u8>, tag: u32, side: Side) {
+    put_field(buf, tag, &[side as u8]);
+}
+
+/// Appends the field if the value is present.
+pub(crate) fn put_optional_str(buf: &mut Vec<u8>, tag: u32, value: Option<&String>) {
+    if let Some(value) = value {
+        put_field(buf, tag, value.as_bytes());
+    }
+}
+
+/// Appends the decimal field if the value is present.
+pub(crate) fn put_optional_decimal(buf: &mut Vec<u8>, tag: u32, value: Option<&Decimal>) {
+    if let Some(value) = value {
+        put_decimal(buf, tag, value);
+    }
+}
@@ -0,0 +1,153 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Encode/decode round trips for the typed messages.
+
+use ironfix_core::error::DecodeError;
+use ironfix_core::message::FixMessage;
+use ironfix_messages::{
+    ExecType, ExecutionReport, NewOrderSingle, OrdStatus, OrdType, OrderCancelRequest, Side,
+    TimeInForce,
+};
+use ironfix_tagvalue::{Decoder, Encoder};
+use rust_decimal::Decimal;
+
+/// Wraps an encoded message body in a complete FIX message.
+fn frame<M: FixMessage>(message: &M) -> Vec<u8> {
+    let mut body = Vec::new();
+    message.encode(&mut body).unwrap();
+
+    let mut encoder = Encoder::new("FIX.4.4");
+    encoder.put_str(35, M::MSG_TYPE);
+    let mut decoder = Decoder::new(&body);
+    while let Some(field) = decoder.next_field() {
+        encoder.put_raw(field.tag, field.value);
+    }
+    encoder.finish().to_vec()
+}
+
+/// Encodes, frames and decodes a message.
+fn round_trip<M: FixMessage>(message: &M) -> M {
+    let bytes = frame(message);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    M::from_raw(&raw).unwrap()
+}
+
+#[test]
+fn test_new_order_single_round_trip() {
+    let order = NewOrderSingle::new(
+        "ORDER1",
+        "AAPL",
+        Side::Buy,
+        Decimal::new(100, 0),
+        OrdType::Limit,
+    )
+    .with_account("ACCT-1")
+    .with_price(Decimal::new(15025, 2))
+    .with_time_in_force(TimeInForce::ImmediateOrCancel)
+    .with_transact_time("20260127-10:15:30.123");
+
+    let bytes = frame(&order);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    assert_eq!(raw.get_field_str(54), Some("1"));
+    assert_eq!(raw.get_field_str(40), Some("2"));
+    assert_eq!(raw.get_field_str(44), Some("150.25"));
+    assert_eq!(raw.get_field_str(59), Some("3"));
+
+    assert_eq!(NewOrderSingle::from_raw(&raw).unwrap(), order);
+}
+
+#[test]
+fn test_new_order_single_minimal_round_trip() {
+    let order = NewOrderSingle::new(
+        "ORDER2",
+        "MSFT",
+        Side::SellShort,
+        Decimal::new(50, 0),
+        OrdType::Market,
+    );
+
+    let decoded = round_trip(&order);
+    assert_eq!(decoded, order);
+    assert_eq!(decoded.price, None);
+    assert_eq!(decoded.time_in_force, None);
+}
+
+#[test]
+fn test_order_cancel_request_round_trip() {
+    let cancel = OrderCancelRequest::new("ORDER1", "CANCEL1", "AAPL", Side::Buy)
+        .with_order_id("EX-42")
+        .with_order_qty(Decimal::new(100, 0))
+        .with_text("changed my mind");
+
+    assert_eq!(OrderCancelRequest::MSG_TYPE, "F");
+    assert_eq!(round_trip(&cancel), cancel);
+}
+
+#[test]
+fn test_execution_report_round_trip() {
+    let report = ExecutionReport::new(
+        "EX-42",
+        "EXEC-7",
+        ExecType::Trade,
+        OrdStatus::PartiallyFilled,
+        "AAPL",
+        Side::Buy,
+    )
+    .with_cl_ord_id("ORDER1")
+    .with_order(Decimal::new(100, 0), Some(Decimal::new(15025, 2)))
+    .with_last_fill(Decimal::new(40, 0), Decimal::new(15020, 2))
+    .with_quantities(
+        Decimal::new(60, 0),
+        Decimal::new(40, 0),
+        Decimal::new(15020, 2),
+    );
+
+    let bytes = frame(&report);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+    assert_eq!(raw.get_field_str(150), Some("F"));
+    assert_eq!(raw.get_field_str(39), Some("1"));
+    assert_eq!(raw.get_field_str(151), Some("60"));
+
+    assert_eq!(ExecutionReport::from_raw(&raw).unwrap(), report);
+}
+
+#[test]
+fn test_missing_required_field() {
+    let bytes = {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_str(11, "ORDER1");
+        encoder.put_str(55, "AAPL");
+        encoder.put_str(54, "1");
+        encoder.put_str(38, "100");
+        encoder.finish().to_vec()
+    };
+    let raw = Decoder::new(&bytes).decode().unwrap();
+
+    assert!(matches!(
+        NewOrderSingle::from_raw(&raw),
+        Err(DecodeError::MissingRequiredField { tag: 40 })
+    ));
+}
+
+#[test]
+fn test_wrong_msg_type() {
+    let order = NewOrderSingle::new(
+        "ORDER1",
+        "AAPL",
+        Side::Buy,
+        Decimal::new(1, 0),
+        OrdType::Market,
+    );
+    let bytes = frame(&order);
+    let raw = Decoder::new(&bytes).decode().unwrap();
+
+    assert!(matches!(
+        ExecutionReport::from_raw(&raw),
+        Err(DecodeError::InvalidMsgType(_))
+    ));
+}
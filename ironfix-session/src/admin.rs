@@ -0,0 +1,446 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Typed session-level (admin) messages.
+//!
+//! This module provides builders for the admin messages a session sends:
+//! - [`Logon`]: EncryptMethod, HeartBtInt and ResetSeqNumFlag
+//! - [`Logout`]: Optional Text
+//! - [`Heartbeat`]: Optional TestReqID answering a TestRequest
+//! - [`TestRequest`]: TestReqID to be echoed by a Heartbeat
+//! - [`ResendRequest`]: BeginSeqNo and EndSeqNo of the messages to resend
+//!
+//! [`encode`](Logon::encode) returns a body-only message holding MsgType
+//! and the message's own fields; the session runtime adds the standard
+//! header and trailer before sending.
+
+use bytes::Bytes;
+use ironfix_core::error::{DecodeError, EncodeError};
+use ironfix_core::field::FieldRef;
+use ironfix_core::message::{FixMessage, MsgType, OwnedMessage, RawMessage};
+use std::ops::Range;
+use std::time::Duration;
+
+/// Logon (MsgType=A) message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logon {
+    /// EncryptMethod (tag 98); 0 means none.
+    pub encrypt_method: u32,
+    /// HeartBtInt (tag 108) in seconds; 0 disables heartbeats.
+    pub heart_bt_int: u64,
+    /// ResetSeqNumFlag (tag 141); written only when set.
+    pub reset_seq_num: bool,
+}
+
+impl Logon {
+    /// Creates a Logon without encryption or sequence reset.
+    ///
+    /// # Arguments
+    /// * `heartbeat_interval` - The heartbeat interval, sent in whole seconds
+    #[must_use]
+    pub const fn new(heartbeat_interval: Duration) -> Self {
+        Self {
+            encrypt_method: 0,
+            heart_bt_int: heartbeat_interval.as_secs(),
+            reset_seq_num: false,
+        }
+    }
+
+    /// Sets EncryptMethod (tag 98).
+    #[must_use]
+    pub const fn with_encrypt_method(mut self, encrypt_method: u32) -> Self {
+        self.encrypt_method = encrypt_method;
+        self
+    }
+
+    /// Sets ResetSeqNumFlag (tag 141).
+    #[must_use]
+    pub const fn with_reset_seq_num(mut self, reset: bool) -> Self {
+        self.reset_seq_num = reset;
+        self
+    }
+
+    /// Returns the heartbeat interval.
+    #[must_use]
+    pub const fn heartbeat_interval(&self) -> Duration {
+        Duration::from_secs(self.heart_bt_int)
+    }
+
+    /// Builds the body-only message.
+    #[must_use]
+    pub fn encode(&self) -> OwnedMessage {
+        body_message(MsgType::Logon, &self.fields())
+    }
+
+    /// Returns the body fields in wire order.
+    fn fields(&self) -> Vec<(u32, Vec<u8>)> {
+        let mut fields = vec![
+            (98, self.encrypt_method.to_string().into_bytes()),
+            (108, self.heart_bt_int.to_string().into_bytes()),
+        ];
+        if self.reset_seq_num {
+            fields.push((141, b"Y".to_vec()));
+        }
+        fields
+    }
+}
+
+impl FixMessage for Logon {
+    const MSG_TYPE: &'static str = "A";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        let encrypt_method = required(raw, 98)?.as_u64()?;
+        Ok(Self {
+            encrypt_method: u32::try_from(encrypt_method).map_err(|_| {
+                DecodeError::InvalidFieldValue {
+                    tag: 98,
+                    reason: format!("encrypt method {} out of range", encrypt_method),
+                }
+            })?,
+            heart_bt_int: required(raw, 108)?.as_u64()?,
+            reset_seq_num: raw
+                .get_field(141)
+                .map(FieldRef::as_bool)
+                .transpose()?
+                .unwrap_or(false),
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_fields(buf, &self.fields());
+        Ok(())
+    }
+}
+
+/// Logout (MsgType=5) message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Logout {
+    /// Text (tag 58) explaining the logout.
+    pub text: Option<String>,
+}
+
+impl Logout {
+    /// Creates a Logout without text.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { text: None }
+    }
+
+    /// Sets Text (tag 58).
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Builds the body-only message.
+    #[must_use]
+    pub fn encode(&self) -> OwnedMessage {
+        body_message(MsgType::Logout, &self.fields())
+    }
+
+    /// Returns the body fields in wire order.
+    fn fields(&self) -> Vec<(u32, Vec<u8>)> {
+        optional(58, self.text.as_deref())
+    }
+}
+
+impl FixMessage for Logout {
+    const MSG_TYPE: &'static str = "5";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            text: raw.get_field(58).map(FieldRef::to_string).transpose()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_fields(buf, &self.fields());
+        Ok(())
+    }
+}
+
+/// Heartbeat (MsgType=0) message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Heartbeat {
+    /// TestReqID (tag 112) of the TestRequest being answered.
+    pub test_req_id: Option<String>,
+}
+
+impl Heartbeat {
+    /// Creates an unsolicited Heartbeat.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { test_req_id: None }
+    }
+
+    /// Sets TestReqID (tag 112) to answer a TestRequest.
+    #[must_use]
+    pub fn with_test_req_id(mut self, test_req_id: impl Into<String>) -> Self {
+        self.test_req_id = Some(test_req_id.into());
+        self
+    }
+
+    /// Builds the body-only message.
+    #[must_use]
+    pub fn encode(&self) -> OwnedMessage {
+        body_message(MsgType::Heartbeat, &self.fields())
+    }
+
+    /// Returns the body fields in wire order.
+    fn fields(&self) -> Vec<(u32, Vec<u8>)> {
+        optional(112, self.test_req_id.as_deref())
+    }
+}
+
+impl FixMessage for Heartbeat {
+    const MSG_TYPE: &'static str = "0";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            test_req_id: raw.get_field(112).map(FieldRef::to_string).transpose()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_fields(buf, &self.fields());
+        Ok(())
+    }
+}
+
+/// TestRequest (MsgType=1) message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRequest {
+    /// TestReqID (tag 112) the counterparty must echo in its Heartbeat.
+    pub test_req_id: String,
+}
+
+impl TestRequest {
+    /// Creates a TestRequest.
+    ///
+    /// # Arguments
+    /// * `test_req_id` - The TestReqID (tag 112)
+    #[must_use]
+    pub fn new(test_req_id: impl Into<String>) -> Self {
+        Self {
+            test_req_id: test_req_id.into(),
+        }
+    }
+
+    /// Builds the body-only message.
+    #[must_use]
+    pub fn encode(&self) -> OwnedMessage {
+        body_message(MsgType::TestRequest, &self.fields())
+    }
+
+    /// Returns the body fields in wire order.
+    fn fields(&self) -> Vec<(u32, Vec<u8>)> {
+        optional(112, Some(&self.test_req_id))
+    }
+}
+
+impl FixMessage for TestRequest {
+    const MSG_TYPE: &'static str = "1";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            test_req_id: required(raw, 112)?.to_string()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_fields(buf, &self.fields());
+        Ok(())
+    }
+}
+
+/// ResendRequest (MsgType=2) message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResendRequest {
+    /// BeginSeqNo (tag 7), the first message to resend.
+    pub begin_seq_no: u64,
+    /// EndSeqNo (tag 16), the last message to resend; 0 means all later
+    /// messages.
+    pub end_seq_no: u64,
+}
+
+impl ResendRequest {
+    /// Creates a ResendRequest.
+    ///
+    /// # Arguments
+    /// * `begin_seq_no` - The BeginSeqNo (tag 7)
+    /// * `end_seq_no` - The EndSeqNo (tag 16), inclusive
+    #[must_use]
+    pub const fn new(begin_seq_no: u64, end_seq_no: u64) -> Self {
+        Self {
+            begin_seq_no,
+            end_seq_no,
+        }
+    }
+
+    /// Creates a ResendRequest for a non-empty range of sequence numbers.
+    ///
+    /// # Arguments
+    /// * `range` - The sequence numbers to resend, end exclusive, as returned
+    ///   by [`ResendTracker`](crate::resend::ResendTracker)
+    #[must_use]
+    pub const fn for_range(range: Range<u64>) -> Self {
+        Self::new(range.start, range.end.saturating_sub(1))
+    }
+
+    /// Builds the body-only message.
+    #[must_use]
+    pub fn encode(&self) -> OwnedMessage {
+        body_message(MsgType::ResendRequest, &self.fields())
+    }
+
+    /// Returns the body fields in wire order.
+    fn fields(&self) -> Vec<(u32, Vec<u8>)> {
+        vec![
+            (7, self.begin_seq_no.to_string().into_bytes()),
+            (16, self.end_seq_no.to_string().into_bytes()),
+        ]
+    }
+}
+
+impl FixMessage for ResendRequest {
+    const MSG_TYPE: &'static str = "2";
+
+    fn from_raw(raw: &RawMessage<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            begin_seq_no: required(raw, 7)?.as_u64()?,
+            end_seq_no: required(raw, 16)?.as_u64()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), EncodeError> {
+        put_fields(buf, &self.fields());
+        Ok(())
+    }
+}
+
+/// Returns a required field of a raw message.
+fn required<'r, 'a>(raw: &'r RawMessage<'a>, tag: u32) -> Result<&'r FieldRef<'a>, DecodeError> {
+    raw.get_field(tag)
+        .ok_or(DecodeError::MissingRequiredField { tag })
+}
+
+/// Returns a single-field list, or an empty one if the value is absent.
+fn optional(tag: u32, value: Option<&str>) -> Vec<(u32, Vec<u8>)> {
+    value
+        .map(|value| (tag, value.as_bytes().to_vec()))
+        .into_iter()
+        .collect()
+}
+
+/// Builds a body-only message holding MsgType and the given fields.
+fn body_message(msg_type: MsgType, fields: &[(u32, Vec<u8>)]) -> OwnedMessage {
+    let mut message = OwnedMessage::new(Bytes::new(), msg_type.clone(), Vec::new());
+    message.set_field(35, msg_type.as_str().as_bytes());
+    for (tag, value) in fields {
+        message.set_field(*tag, value);
+    }
+    message
+}
+
+/// Appends each field as `tag=value` followed by SOH.
+fn put_fields(buf: &mut Vec<u8>, fields: &[(u32, Vec<u8>)]) {
+    for (tag, value) in fields {
+        buf.extend_from_slice(tag.to_string().as_bytes());
+        buf.push(b'=');
+        buf.extend_from_slice(value);
+        buf.push(0x01);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ironfix_core::message::MessageBuilder;
+    use ironfix_tagvalue::Decoder;
+
+    /// Frames a body-only message the way the session runtime does.
+    fn frame(body: &OwnedMessage) -> OwnedMessage {
+        body.fields()
+            .fold(
+                MessageBuilder::new("FIX.4.4", body.msg_type().clone())
+                    .field(49, "SENDER")
+                    .field(56, "TARGET")
+                    .field(34, "1"),
+                |builder, (tag, value)| builder.field(tag, value),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_logon_decodes_with_admin_fields() {
+        let logon = Logon::new(Duration::from_secs(30)).with_reset_seq_num(true);
+        let message = frame(&logon.encode());
+
+        let mut decoder = Decoder::new(message.as_bytes());
+        let raw = decoder.decode().unwrap();
+        assert_eq!(*raw.msg_type(), MsgType::Logon);
+        assert_eq!(raw.get_field_str(98), Some("0"));
+        assert_eq!(raw.get_field_str(108), Some("30"));
+        assert_eq!(raw.get_field_str(141), Some("Y"));
+        assert_eq!(Logon::from_raw(&raw).unwrap(), logon);
+        assert_eq!(logon.heartbeat_interval(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_logon_omits_unset_reset_flag() {
+        let body = Logon::new(Duration::from_secs(10))
+            .with_encrypt_method(0)
+            .encode();
+        assert_eq!(body.get_field_str(35), Some("A"));
+        assert_eq!(body.get_field(141), None);
+
+        let message = frame(&body);
+        let raw = Decoder::new(message.as_bytes()).decode().unwrap();
+        assert!(!Logon::from_raw(&raw).unwrap().reset_seq_num);
+    }
+
+    #[test]
+    fn test_heartbeat_logout_and_test_request() {
+        let heartbeat = frame(&Heartbeat::new().with_test_req_id("TR-1").encode());
+        let raw = Decoder::new(heartbeat.as_bytes()).decode().unwrap();
+        assert_eq!(*raw.msg_type(), MsgType::Heartbeat);
+        assert_eq!(
+            Heartbeat::from_raw(&raw).unwrap().test_req_id.as_deref(),
+            Some("TR-1")
+        );
+        assert_eq!(Heartbeat::new().encode().get_field(112), None);
+
+        let logout = frame(&Logout::new().with_text("bye").encode());
+        let raw = Decoder::new(logout.as_bytes()).decode().unwrap();
+        assert_eq!(Logout::from_raw(&raw).unwrap().text.as_deref(), Some("bye"));
+
+        let request = frame(&TestRequest::new("TR-2").encode());
+        let raw = Decoder::new(request.as_bytes()).decode().unwrap();
+        assert_eq!(*raw.msg_type(), MsgType::TestRequest);
+        assert_eq!(TestRequest::from_raw(&raw).unwrap().test_req_id, "TR-2");
+    }
+
+    #[test]
+    fn test_resend_request_for_range_is_inclusive() {
+        let request = ResendRequest::for_range(5..10);
+        assert_eq!(request, ResendRequest::new(5, 9));
+
+        let message = frame(&request.encode());
+        let raw = Decoder::new(message.as_bytes()).decode().unwrap();
+        assert_eq!(*raw.msg_type(), MsgType::ResendRequest);
+        assert_eq!(raw.get_field_str(7), Some("5"));
+        assert_eq!(raw.get_field_str(16), Some("9"));
+        assert_eq!(ResendRequest::from_raw(&raw).unwrap(), request);
+    }
+
+    #[test]
+    fn test_fix_message_encode_writes_body_fields() {
+        let mut buf = Vec::new();
+        FixMessage::encode(&Logon::new(Duration::from_secs(5)), &mut buf).unwrap();
+        assert_eq!(buf, b"98=0\x01108=5\x01");
+    }
+}
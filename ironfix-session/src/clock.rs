@@ -0,0 +1,128 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Time sources for session logic.
+//!
+//! This module provides:
+//! - [`Clock`]: the source of monotonic and wall-clock time used by sessions
+//! - [`SystemClock`]: the real clock
+//! - [`MockClock`]: a manually advanced clock for deterministic tests
+
+use ironfix_core::types::Timestamp;
+use parking_lot::Mutex;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Source of time for heartbeats, timeouts and SendingTime.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current monotonic time, used to measure intervals.
+    fn now(&self) -> Instant;
+
+    /// Returns the current UTC wall-clock time, used for SendingTime.
+    fn timestamp(&self) -> Timestamp;
+}
+
+/// The real system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    #[inline]
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// A clock that only moves when advanced.
+///
+/// Clones share the same time, so a test can keep one clone and hand another
+/// to the component under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    /// Current monotonic and wall-clock time.
+    inner: Arc<Mutex<(Instant, Timestamp)>>,
+}
+
+impl MockClock {
+    /// Creates a mock clock reading the given wall-clock time.
+    ///
+    /// # Arguments
+    /// * `timestamp` - The initial UTC time
+    #[must_use]
+    pub fn new(timestamp: Timestamp) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new((Instant::now(), timestamp))),
+        }
+    }
+
+    /// Moves both the monotonic and the wall-clock time forward.
+    ///
+    /// # Arguments
+    /// * `duration` - How far to advance
+    pub fn advance(&self, duration: Duration) {
+        let mut inner = self.inner.lock();
+        inner.0 += duration;
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        inner.1 = Timestamp::from_nanos(inner.1.as_nanos().saturating_add(nanos));
+    }
+
+    /// Sets the wall-clock time without moving the monotonic time.
+    ///
+    /// # Arguments
+    /// * `timestamp` - The new UTC time
+    pub fn set_timestamp(&self, timestamp: Timestamp) {
+        self.inner.lock().1 = timestamp;
+    }
+}
+
+impl Default for MockClock {
+    /// Creates a mock clock starting at the Unix epoch.
+    fn default() -> Self {
+        Self::new(Timestamp::from_nanos(0))
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.inner.lock().0
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.lock().1
+    }
+}
+
+/// Returns the shared real clock used when none is configured.
+#[must_use]
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_advance() {
+        let clock = MockClock::new(Timestamp::from_nanos(1_000));
+        let shared = clock.clone();
+        let start = clock.now();
+
+        shared.advance(Duration::from_millis(5));
+        assert_eq!(clock.now() - start, Duration::from_millis(5));
+        assert_eq!(clock.timestamp().as_nanos(), 5_001_000);
+
+        clock.set_timestamp(Timestamp::from_nanos(42));
+        assert_eq!(shared.timestamp().as_nanos(), 42);
+        assert_eq!(shared.now() - start, Duration::from_millis(5));
+    }
+}
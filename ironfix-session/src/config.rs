@@ -8,7 +8,11 @@
 //!
 //! This module provides configuration options for FIX sessions.
 
+use crate::heartbeat::DEFAULT_TEST_REQUEST_GRACE;
+use crate::resend::DEFAULT_RESEND_REQUEST_TIMEOUT;
+use ironfix_core::error::SessionError;
 use ironfix_core::types::CompId;
+use ironfix_dictionary::Version;
 use std::time::Duration;
 
 /// Configuration for a FIX session.
@@ -22,6 +26,9 @@ pub struct SessionConfig {
     pub begin_string: String,
     /// Heartbeat interval in seconds.
     pub heartbeat_interval: Duration,
+    /// Silence allowed after the heartbeat interval before a TestRequest,
+    /// as a multiple of the interval.
+    pub test_request_grace: f64,
     /// Whether to reset sequence numbers on logon.
     pub reset_on_logon: bool,
     /// Whether to reset sequence numbers on logout.
@@ -34,6 +41,8 @@ pub struct SessionConfig {
     pub logon_timeout: Duration,
     /// Logout timeout duration.
     pub logout_timeout: Duration,
+    /// Time to wait for resent messages before repeating a ResendRequest.
+    pub resend_request_timeout: Duration,
     /// Whether to validate incoming message checksums.
     pub validate_checksum: bool,
     /// Whether to validate incoming message length.
@@ -66,12 +75,14 @@ impl SessionConfig {
             target_comp_id,
             begin_string: begin_string.into(),
             heartbeat_interval: Duration::from_secs(30),
+            test_request_grace: DEFAULT_TEST_REQUEST_GRACE,
             reset_on_logon: false,
             reset_on_logout: false,
             reset_on_disconnect: false,
             max_message_size: 1024 * 1024, // 1MB
             logon_timeout: Duration::from_secs(10),
             logout_timeout: Duration::from_secs(10),
+            resend_request_timeout: DEFAULT_RESEND_REQUEST_TIMEOUT,
             validate_checksum: true,
             validate_length: true,
             sender_sub_id: None,
@@ -88,6 +99,17 @@ impl SessionConfig {
         self
     }
 
+    /// Sets the TestRequest grace as a multiple of the heartbeat interval.
+    ///
+    /// # Arguments
+    /// * `grace` - The multiplier; `1.0` sends a TestRequest after twice the
+    ///   interval of silence
+    #[must_use]
+    pub fn with_test_request_grace(mut self, grace: f64) -> Self {
+        self.test_request_grace = grace;
+        self
+    }
+
     /// Sets whether to reset sequence numbers on logon.
     #[must_use]
     pub const fn with_reset_on_logon(mut self, reset: bool) -> Self {
@@ -109,6 +131,14 @@ impl SessionConfig {
         self
     }
 
+    /// Sets the time to wait for resent messages before repeating a
+    /// ResendRequest.
+    #[must_use]
+    pub fn with_resend_request_timeout(mut self, timeout: Duration) -> Self {
+        self.resend_request_timeout = timeout;
+        self
+    }
+
     /// Sets the sender sub ID.
     #[must_use]
     pub fn with_sender_sub_id(mut self, sub_id: impl Into<String>) -> Self {
@@ -123,11 +153,52 @@ impl SessionConfig {
         self
     }
 
+    /// Sets the sender location ID.
+    #[must_use]
+    pub fn with_sender_location_id(mut self, location_id: impl Into<String>) -> Self {
+        self.sender_location_id = Some(location_id.into());
+        self
+    }
+
+    /// Sets the target location ID.
+    #[must_use]
+    pub fn with_target_location_id(mut self, location_id: impl Into<String>) -> Self {
+        self.target_location_id = Some(location_id.into());
+        self
+    }
+
     /// Returns the heartbeat interval in seconds.
     #[must_use]
     pub fn heartbeat_interval_secs(&self) -> u64 {
         self.heartbeat_interval.as_secs()
     }
+
+    /// Returns the FIX version named by the BeginString.
+    ///
+    /// # Errors
+    /// Returns `SessionError::Configuration` if the BeginString is not a
+    /// known FIX version, e.g. `"FIX4.4"`.
+    pub fn version(&self) -> Result<Version, SessionError> {
+        Version::from_begin_string(&self.begin_string).ok_or_else(|| {
+            SessionError::Configuration(format!("unknown begin string {:?}", self.begin_string))
+        })
+    }
+
+    /// Checks that the configuration can be used to run a session.
+    ///
+    /// # Errors
+    /// Returns `SessionError::Configuration` if the BeginString is not a
+    /// known FIX version or the TestRequest grace is negative or not finite.
+    pub fn validate(&self) -> Result<(), SessionError> {
+        self.version()?;
+        if !self.test_request_grace.is_finite() || self.test_request_grace < 0.0 {
+            return Err(SessionError::Configuration(format!(
+                "invalid test request grace {}",
+                self.test_request_grace
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// Builder for session configuration.
@@ -183,12 +254,45 @@ impl SessionConfigBuilder {
         self
     }
 
-    /// Builds the configuration.
+    /// Builds and validates the configuration.
+    ///
+    /// # Errors
+    /// Returns `SessionError::Configuration` if a required field is not set
+    /// or the configuration fails [`SessionConfig::validate`].
+    pub fn try_build(self) -> Result<SessionConfig, SessionError> {
+        if self.sender_comp_id.is_none() {
+            return Err(SessionError::Configuration(
+                "sender_comp_id is required".to_string(),
+            ));
+        }
+        if self.target_comp_id.is_none() {
+            return Err(SessionError::Configuration(
+                "target_comp_id is required".to_string(),
+            ));
+        }
+        let config = self.assemble();
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Builds the configuration without validating it.
     ///
     /// # Panics
     /// Panics if required fields are not set.
     #[must_use]
+    #[deprecated(
+        since = "0.1.6",
+        note = "use `try_build`, which also validates the configuration"
+    )]
     pub fn build(self) -> SessionConfig {
+        self.assemble()
+    }
+
+    /// Assembles the configuration from the fields set so far.
+    ///
+    /// # Panics
+    /// Panics if required fields are not set.
+    fn assemble(self) -> SessionConfig {
         let sender = self.sender_comp_id.expect("sender_comp_id is required");
         let target = self.target_comp_id.expect("target_comp_id is required");
         let begin_string = self.begin_string.unwrap_or_else(|| "FIX.4.4".to_string());
@@ -231,10 +335,66 @@ mod tests {
             .begin_string("FIX.4.2")
             .heartbeat_interval(Duration::from_secs(60))
             .reset_on_logon(true)
-            .build();
+            .try_build()
+            .unwrap();
 
         assert_eq!(config.begin_string, "FIX.4.2");
         assert_eq!(config.heartbeat_interval, Duration::from_secs(60));
         assert!(config.reset_on_logon);
     }
+
+    fn config_for(begin_string: &str) -> Result<SessionConfig, SessionError> {
+        SessionConfigBuilder::new()
+            .sender_comp_id(CompId::new("SENDER").unwrap())
+            .target_comp_id(CompId::new("TARGET").unwrap())
+            .begin_string(begin_string)
+            .try_build()
+    }
+
+    #[test]
+    fn test_session_config_version() {
+        let config = config_for("FIX.4.4").unwrap();
+        assert_eq!(config.version(), Ok(Version::Fix44));
+
+        let config = config_for("FIXT.1.1").unwrap();
+        assert_eq!(config.version(), Ok(Version::Fixt11));
+    }
+
+    #[test]
+    fn test_session_config_rejects_unknown_version() {
+        assert_eq!(
+            config_for("FIX4.4").unwrap_err(),
+            SessionError::Configuration("unknown begin string \"FIX4.4\"".to_string())
+        );
+
+        let mut config = config_for("FIX.4.4").unwrap();
+        config.begin_string = "FIX.9.9".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_session_config_rejects_invalid_test_request_grace() {
+        let config = config_for("FIX.4.4").unwrap();
+        assert!(
+            config
+                .clone()
+                .with_test_request_grace(0.0)
+                .validate()
+                .is_ok()
+        );
+        for grace in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                config.clone().with_test_request_grace(grace).validate(),
+                Err(SessionError::Configuration(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_session_config_try_build_requires_comp_ids() {
+        let result = SessionConfigBuilder::new()
+            .sender_comp_id(CompId::new("SENDER").unwrap())
+            .try_build();
+        assert!(matches!(result, Err(SessionError::Configuration(_))));
+    }
 }
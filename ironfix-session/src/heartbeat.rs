@@ -11,99 +11,141 @@
 //! - Sending TestRequest when no messages received
 //! - Detecting heartbeat timeouts
 
+use crate::clock::{Clock, system_clock};
+use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+/// Default TestRequest grace, as a multiple of the heartbeat interval.
+///
+/// With the default, a TestRequest is sent after twice the heartbeat interval
+/// without any received message.
+pub const DEFAULT_TEST_REQUEST_GRACE: f64 = 1.0;
+
+/// Maximum number of unacknowledged TestRequests tracked at once.
+///
+/// When exceeded, the oldest request is forgotten.
+pub const MAX_PENDING_TEST_REQUESTS: usize = 8;
+
 /// Manages heartbeat timing for a FIX session.
+///
+/// A zero interval, negotiated as HeartBtInt=0 on Logon, disables heartbeats:
+/// no Heartbeat or TestRequest is ever due and the session never times out.
 #[derive(Debug)]
 pub struct HeartbeatManager {
     /// Heartbeat interval.
     interval: Duration,
+    /// Silence allowed after the interval before sending a TestRequest.
+    test_request_grace: Duration,
     /// Time of last message sent.
     last_sent: Instant,
     /// Time of last message received.
     last_received: Instant,
-    /// Pending TestRequest ID, if any.
-    test_request_pending: Option<String>,
-    /// Time when TestRequest was sent.
-    test_request_sent_at: Option<Instant>,
+    /// Unacknowledged TestRequest IDs with their send times, oldest first.
+    pending_test_requests: VecDeque<(String, Instant)>,
+    /// Source of time.
+    clock: Arc<dyn Clock>,
 }
 
 impl HeartbeatManager {
     /// Creates a new heartbeat manager with the specified interval.
     ///
     /// # Arguments
-    /// * `interval` - The heartbeat interval
+    /// * `interval` - The heartbeat interval; zero disables heartbeats
+    /// * `test_request_grace` - Silence allowed after the interval before a
+    ///   TestRequest, as a multiple of the interval; negative and NaN values
+    ///   count as zero and the result saturates at `Duration::MAX`. See
+    ///   [`DEFAULT_TEST_REQUEST_GRACE`].
     #[must_use]
-    pub fn new(interval: Duration) -> Self {
-        let now = Instant::now();
+    pub fn new(interval: Duration, test_request_grace: f64) -> Self {
+        let clock = system_clock();
+        let now = clock.now();
         Self {
             interval,
+            test_request_grace: scale(interval, test_request_grace),
             last_sent: now,
             last_received: now,
-            test_request_pending: None,
-            test_request_sent_at: None,
+            pending_test_requests: VecDeque::new(),
+            clock,
         }
     }
 
+    /// Sets the source of time and resets the manager state to it.
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a [`MockClock`](crate::clock::MockClock)
+    ///   in tests
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self.reset();
+        self
+    }
+
     /// Records that a message was sent.
     #[inline]
     pub fn on_message_sent(&mut self) {
-        self.last_sent = Instant::now();
+        self.last_sent = self.clock.now();
     }
 
     /// Records that a message was received.
     ///
-    /// If a TestRequest was pending and a Heartbeat with matching ID is received,
-    /// the pending request is cleared.
+    /// A Heartbeat whose TestReqID matches a pending TestRequest acknowledges
+    /// that request and every older one, since the counterparty has answered
+    /// a later request.
     ///
     /// # Arguments
     /// * `is_heartbeat` - Whether the received message is a Heartbeat
     /// * `test_req_id` - The TestReqID from the Heartbeat, if present
     pub fn on_message_received(&mut self, is_heartbeat: bool, test_req_id: Option<&str>) {
-        self.last_received = Instant::now();
+        self.last_received = self.clock.now();
 
         if is_heartbeat
-            && let (Some(pending), Some(received)) = (&self.test_request_pending, test_req_id)
-            && pending == received
+            && let Some(received) = test_req_id
+            && let Some(index) = self
+                .pending_test_requests
+                .iter()
+                .position(|(pending, _)| pending == received)
         {
-            self.test_request_pending = None;
-            self.test_request_sent_at = None;
+            self.pending_test_requests.drain(..=index);
         }
     }
 
     /// Checks if a heartbeat should be sent.
     ///
     /// A heartbeat should be sent if no message has been sent within the interval.
+    /// Always false when heartbeats are disabled.
     #[must_use]
     pub fn should_send_heartbeat(&self) -> bool {
-        self.last_sent.elapsed() >= self.interval
+        self.is_enabled() && self.elapsed_since(self.last_sent) >= self.interval
     }
 
     /// Checks if a TestRequest should be sent.
     ///
     /// A TestRequest should be sent if no message has been received within
     /// the interval plus a grace period, and no TestRequest is already pending.
+    /// Always false when heartbeats are disabled.
     #[must_use]
     pub fn should_send_test_request(&self) -> bool {
-        if self.test_request_pending.is_some() {
+        if !self.is_enabled() || !self.pending_test_requests.is_empty() {
             return false;
         }
 
-        let grace = Duration::from_secs(1);
-        self.last_received.elapsed() >= self.interval + grace
+        self.elapsed_since(self.last_received)
+            >= self.interval.saturating_add(self.test_request_grace)
     }
 
     /// Checks if the session has timed out.
     ///
-    /// A timeout occurs if a TestRequest was sent but no response was received
-    /// within the interval.
+    /// A timeout occurs if the oldest unacknowledged TestRequest was sent
+    /// more than the interval ago. Always false when heartbeats are disabled.
     #[must_use]
     pub fn is_timed_out(&self) -> bool {
-        if let Some(sent_at) = self.test_request_sent_at {
-            sent_at.elapsed() >= self.interval
-        } else {
-            false
-        }
+        self.is_enabled()
+            && self
+                .pending_test_requests
+                .front()
+                .is_some_and(|(_, sent_at)| self.elapsed_since(*sent_at) >= self.interval)
     }
 
     /// Records that a TestRequest was sent.
@@ -111,27 +153,35 @@ impl HeartbeatManager {
     /// # Arguments
     /// * `test_req_id` - The TestReqID that was sent
     pub fn on_test_request_sent(&mut self, test_req_id: String) {
-        self.test_request_pending = Some(test_req_id);
-        self.test_request_sent_at = Some(Instant::now());
-        self.last_sent = Instant::now();
+        let now = self.clock.now();
+        if self.pending_test_requests.len() == MAX_PENDING_TEST_REQUESTS {
+            self.pending_test_requests.pop_front();
+        }
+        self.pending_test_requests.push_back((test_req_id, now));
+        self.last_sent = now;
     }
 
-    /// Returns the pending TestRequest ID, if any.
+    /// Returns the most recent unacknowledged TestRequest ID, if any.
     #[must_use]
     pub fn pending_test_request(&self) -> Option<&str> {
-        self.test_request_pending.as_deref()
+        self.pending_test_requests.back().map(|(id, _)| id.as_str())
+    }
+
+    /// Returns the unacknowledged TestRequest IDs, oldest first.
+    pub fn pending_test_requests(&self) -> impl Iterator<Item = &str> {
+        self.pending_test_requests.iter().map(|(id, _)| id.as_str())
     }
 
     /// Returns the time since the last message was received.
     #[must_use]
     pub fn time_since_last_received(&self) -> Duration {
-        self.last_received.elapsed()
+        self.elapsed_since(self.last_received)
     }
 
     /// Returns the time since the last message was sent.
     #[must_use]
     pub fn time_since_last_sent(&self) -> Duration {
-        self.last_sent.elapsed()
+        self.elapsed_since(self.last_sent)
     }
 
     /// Returns the heartbeat interval.
@@ -140,16 +190,44 @@ impl HeartbeatManager {
         self.interval
     }
 
+    /// Returns the silence allowed after the interval before a TestRequest.
+    #[must_use]
+    pub const fn test_request_grace(&self) -> Duration {
+        self.test_request_grace
+    }
+
+    /// Returns true unless the interval is zero, which disables heartbeats.
+    #[inline]
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        !self.interval.is_zero()
+    }
+
     /// Resets the manager state.
     pub fn reset(&mut self) {
-        let now = Instant::now();
+        let now = self.clock.now();
         self.last_sent = now;
         self.last_received = now;
-        self.test_request_pending = None;
-        self.test_request_sent_at = None;
+        self.pending_test_requests.clear();
+    }
+
+    /// Returns the clock time elapsed since `instant`.
+    fn elapsed_since(&self, instant: Instant) -> Duration {
+        self.clock.now().saturating_duration_since(instant)
     }
 }
 
+/// Multiplies a duration by a factor without panicking.
+///
+/// Negative and NaN factors give zero; results too large for a `Duration`
+/// saturate at `Duration::MAX`.
+fn scale(duration: Duration, factor: f64) -> Duration {
+    if duration.is_zero() || factor.is_nan() || factor <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
 /// Generates a unique TestReqID.
 ///
 /// Uses the current timestamp in nanoseconds.
@@ -168,28 +246,35 @@ pub fn generate_test_req_id() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::thread::sleep;
+    use crate::clock::MockClock;
+
+    /// Returns a manager driven by a mock clock, and the clock.
+    fn mock_manager(interval: Duration, grace: f64) -> (HeartbeatManager, MockClock) {
+        let clock = MockClock::default();
+        let mgr = HeartbeatManager::new(interval, grace).with_clock(Arc::new(clock.clone()));
+        (mgr, clock)
+    }
 
     #[test]
     fn test_heartbeat_manager_new() {
-        let mgr = HeartbeatManager::new(Duration::from_secs(30));
+        let mgr = HeartbeatManager::new(Duration::from_secs(30), DEFAULT_TEST_REQUEST_GRACE);
         assert_eq!(mgr.interval(), Duration::from_secs(30));
         assert!(mgr.pending_test_request().is_none());
     }
 
     #[test]
     fn test_should_send_heartbeat() {
-        let mgr = HeartbeatManager::new(Duration::from_millis(10));
+        let (mgr, clock) = mock_manager(Duration::from_millis(10), DEFAULT_TEST_REQUEST_GRACE);
         assert!(!mgr.should_send_heartbeat());
 
-        sleep(Duration::from_millis(15));
+        clock.advance(Duration::from_millis(10));
         assert!(mgr.should_send_heartbeat());
     }
 
     #[test]
     fn test_on_message_sent() {
-        let mut mgr = HeartbeatManager::new(Duration::from_millis(10));
-        sleep(Duration::from_millis(15));
+        let (mut mgr, clock) = mock_manager(Duration::from_millis(10), DEFAULT_TEST_REQUEST_GRACE);
+        clock.advance(Duration::from_millis(10));
         assert!(mgr.should_send_heartbeat());
 
         mgr.on_message_sent();
@@ -198,7 +283,7 @@ mod tests {
 
     #[test]
     fn test_test_request_pending() {
-        let mut mgr = HeartbeatManager::new(Duration::from_secs(30));
+        let mut mgr = HeartbeatManager::new(Duration::from_secs(30), DEFAULT_TEST_REQUEST_GRACE);
 
         mgr.on_test_request_sent("TEST123".to_string());
         assert_eq!(mgr.pending_test_request(), Some("TEST123"));
@@ -207,6 +292,143 @@ mod tests {
         assert!(mgr.pending_test_request().is_none());
     }
 
+    #[test]
+    fn test_acknowledging_newer_test_request_clears_older() {
+        let (mut mgr, clock) = mock_manager(Duration::from_millis(20), DEFAULT_TEST_REQUEST_GRACE);
+        mgr.on_test_request_sent("TEST1".to_string());
+        mgr.on_test_request_sent("TEST2".to_string());
+        assert_eq!(mgr.pending_test_request(), Some("TEST2"));
+        assert_eq!(
+            mgr.pending_test_requests().collect::<Vec<_>>(),
+            ["TEST1", "TEST2"]
+        );
+
+        mgr.on_message_received(true, Some("TEST2"));
+        assert!(mgr.pending_test_request().is_none());
+
+        clock.advance(Duration::from_millis(20));
+        assert!(!mgr.is_timed_out());
+    }
+
+    #[test]
+    fn test_timeout_tracks_oldest_unacknowledged_request() {
+        let (mut mgr, clock) = mock_manager(Duration::from_millis(20), DEFAULT_TEST_REQUEST_GRACE);
+        mgr.on_test_request_sent("TEST1".to_string());
+        clock.advance(Duration::from_millis(15));
+        mgr.on_test_request_sent("TEST2".to_string());
+
+        // Acknowledging the first leaves the second, sent later, pending.
+        mgr.on_message_received(true, Some("TEST1"));
+        assert_eq!(mgr.pending_test_requests().collect::<Vec<_>>(), ["TEST2"]);
+        clock.advance(Duration::from_millis(10));
+        assert!(!mgr.is_timed_out());
+
+        clock.advance(Duration::from_millis(10));
+        assert!(mgr.is_timed_out());
+
+        // A Heartbeat with an unknown TestReqID acknowledges nothing.
+        mgr.on_message_received(true, Some("OTHER"));
+        assert_eq!(mgr.pending_test_request(), Some("TEST2"));
+    }
+
+    #[test]
+    fn test_pending_test_requests_are_bounded() {
+        let mut mgr = HeartbeatManager::new(Duration::from_secs(30), DEFAULT_TEST_REQUEST_GRACE);
+        for i in 0..=MAX_PENDING_TEST_REQUESTS {
+            mgr.on_test_request_sent(format!("TEST{i}"));
+        }
+        assert_eq!(
+            mgr.pending_test_requests().count(),
+            MAX_PENDING_TEST_REQUESTS
+        );
+        assert_eq!(mgr.pending_test_requests().next(), Some("TEST1"));
+    }
+
+    #[test]
+    fn test_test_request_after_configured_grace() {
+        let (mgr, clock) = mock_manager(Duration::from_millis(10), DEFAULT_TEST_REQUEST_GRACE);
+        assert_eq!(mgr.test_request_grace(), Duration::from_millis(10));
+        clock.advance(Duration::from_millis(19));
+        assert!(!mgr.should_send_test_request());
+        clock.advance(Duration::from_millis(1));
+        assert!(mgr.should_send_test_request());
+
+        let (mgr, clock) = mock_manager(Duration::from_millis(10), 0.5);
+        assert_eq!(mgr.test_request_grace(), Duration::from_millis(5));
+        clock.advance(Duration::from_millis(14));
+        assert!(!mgr.should_send_test_request());
+        clock.advance(Duration::from_millis(1));
+        assert!(mgr.should_send_test_request());
+
+        let mgr = HeartbeatManager::new(Duration::from_millis(10), -1.0);
+        assert_eq!(mgr.test_request_grace(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_out_of_range_grace_does_not_panic() {
+        let interval = Duration::from_secs(30);
+        assert_eq!(
+            HeartbeatManager::new(interval, f64::NAN).test_request_grace(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            HeartbeatManager::new(interval, f64::INFINITY).test_request_grace(),
+            Duration::MAX
+        );
+        let mgr = HeartbeatManager::new(interval, 1e300);
+        assert_eq!(mgr.test_request_grace(), Duration::MAX);
+        assert!(!mgr.should_send_test_request());
+        assert_eq!(
+            HeartbeatManager::new(Duration::ZERO, f64::INFINITY).test_request_grace(),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_zero_interval_disables_heartbeats() {
+        let (mut mgr, clock) = mock_manager(Duration::ZERO, DEFAULT_TEST_REQUEST_GRACE);
+        assert!(!mgr.is_enabled());
+        clock.advance(Duration::from_secs(60));
+        assert!(!mgr.should_send_heartbeat());
+        assert!(!mgr.should_send_test_request());
+        assert!(!mgr.is_timed_out());
+
+        // Even an explicitly sent TestRequest never times out.
+        mgr.on_test_request_sent("TEST1".to_string());
+        clock.advance(Duration::from_secs(60));
+        assert!(!mgr.is_timed_out());
+        assert!(!mgr.should_send_heartbeat());
+    }
+
+    #[test]
+    fn test_mock_clock_drives_heartbeat_and_timeout() {
+        let clock = MockClock::default();
+        let mut mgr = HeartbeatManager::new(Duration::from_secs(30), DEFAULT_TEST_REQUEST_GRACE)
+            .with_clock(Arc::new(clock.clone()));
+
+        clock.advance(Duration::from_secs(29));
+        assert!(!mgr.should_send_heartbeat());
+        clock.advance(Duration::from_secs(1));
+        assert!(mgr.should_send_heartbeat());
+        assert!(!mgr.should_send_test_request());
+        mgr.on_message_sent();
+        assert_eq!(mgr.time_since_last_sent(), Duration::ZERO);
+
+        clock.advance(Duration::from_secs(30));
+        assert!(mgr.should_send_test_request());
+        assert_eq!(mgr.time_since_last_received(), Duration::from_secs(60));
+
+        mgr.on_test_request_sent("TEST1".to_string());
+        clock.advance(Duration::from_secs(29));
+        assert!(!mgr.is_timed_out());
+        clock.advance(Duration::from_secs(1));
+        assert!(mgr.is_timed_out());
+
+        mgr.on_message_received(true, Some("TEST1"));
+        assert!(!mgr.is_timed_out());
+        assert!(!mgr.should_send_test_request());
+    }
+
     #[test]
     fn test_generate_test_req_id() {
         let id1 = generate_test_req_id();
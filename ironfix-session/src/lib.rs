@@ -12,17 +12,27 @@
 //! - **State machine**: Typestate-based session FSM with compile-time state checks
 //! - **Sequence management**: Atomic sequence number handling
 //! - **Heartbeat handling**: Heartbeat/TestRequest logic
-//! - **Recovery**: Gap fill and ResendRequest processing
+//! - **Clock**: Pluggable time source, with a mock for deterministic tests
+//! - **Recovery**: Gap fill and ResendRequest processing, with duplicate
+//!   request suppression and re-request on timeout
 //! - **Configuration**: Session configuration options
+//! - **Admin messages**: Typed Logon, Logout, Heartbeat and TestRequest builders
 
+pub mod admin;
+pub mod clock;
 pub mod config;
 pub mod heartbeat;
+pub mod resend;
 pub mod sequence;
 pub mod state;
 
+pub use admin::{Heartbeat, Logon, Logout, ResendRequest, TestRequest};
+pub use clock::{Clock, MockClock, SystemClock};
 pub use config::SessionConfig;
 pub use heartbeat::HeartbeatManager;
-pub use sequence::SequenceManager;
+pub use resend::ResendTracker;
+pub use sequence::{NoopSeqPersister, SeqPersister, SequenceManager};
 pub use state::{
-    Active, Connecting, Disconnected, LogonSent, LogoutPending, Resending, SessionState,
+    Active, AnySession, Connecting, Disconnected, Error, LogonSent, LogoutPending, Resending,
+    SessionState, SessionStatus, TransitionObserver,
 };
@@ -0,0 +1,247 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! ResendRequest throttling.
+//!
+//! This module tracks the ResendRequest a session is waiting on, so that:
+//! - A gap inside the outstanding range does not trigger a duplicate request
+//! - A gap beyond it only requests the newly missing messages
+//! - A request that goes unanswered is re-sent after a timeout
+
+use crate::clock::{Clock, system_clock};
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Default time to wait for resent messages before requesting them again.
+pub const DEFAULT_RESEND_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outstanding ResendRequest.
+#[derive(Debug, Clone)]
+struct PendingResend {
+    /// Sequence numbers still awaited, end exclusive.
+    range: Range<u64>,
+    /// Time of the last request or of the last progress through the range.
+    requested_at: Instant,
+}
+
+/// Tracks the outstanding ResendRequest of a FIX session.
+///
+/// Feed every detected gap to [`on_gap`](Self::on_gap) and every advance of
+/// the expected target sequence number to
+/// [`on_target_advanced`](Self::on_target_advanced); poll
+/// [`poll_timeout`](Self::poll_timeout) from the session timer. Whenever one
+/// of these returns a range, send a ResendRequest for it.
+#[derive(Debug)]
+pub struct ResendTracker {
+    /// Time to wait for progress before requesting again.
+    timeout: Duration,
+    /// The request being waited on, if any.
+    pending: Option<PendingResend>,
+    /// Source of time.
+    clock: Arc<dyn Clock>,
+}
+
+impl ResendTracker {
+    /// Creates a tracker with no outstanding request.
+    ///
+    /// # Arguments
+    /// * `timeout` - Time to wait for resent messages before requesting them
+    ///   again. See [`DEFAULT_RESEND_REQUEST_TIMEOUT`].
+    #[must_use]
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            pending: None,
+            clock: system_clock(),
+        }
+    }
+
+    /// Sets the source of time.
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a [`MockClock`](crate::clock::MockClock)
+    ///   in tests
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the re-request timeout.
+    #[must_use]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the sequence numbers still awaited, end exclusive.
+    #[must_use]
+    pub fn pending(&self) -> Option<Range<u64>> {
+        self.pending.as_ref().map(|pending| pending.range.clone())
+    }
+
+    /// Returns true while a ResendRequest is outstanding.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Records a detected gap.
+    ///
+    /// # Arguments
+    /// * `gap` - The missing sequence numbers, end exclusive, as returned by
+    ///   [`SequenceManager::gap_range`](crate::sequence::SequenceManager::gap_range)
+    ///
+    /// # Returns
+    /// The range to request, or `None` if the gap is already covered by the
+    /// outstanding request. A gap reaching past the outstanding request
+    /// returns only the part not yet requested.
+    pub fn on_gap(&mut self, gap: Range<u64>) -> Option<Range<u64>> {
+        if gap.is_empty() {
+            return None;
+        }
+        let now = self.clock.now();
+        match &mut self.pending {
+            Some(pending) if gap.end <= pending.range.end => None,
+            Some(pending) => {
+                let request = pending.range.end.max(gap.start)..gap.end;
+                pending.range.end = gap.end;
+                pending.requested_at = now;
+                Some(request)
+            }
+            None => {
+                self.pending = Some(PendingResend {
+                    range: gap.clone(),
+                    requested_at: now,
+                });
+                Some(gap)
+            }
+        }
+    }
+
+    /// Records that the expected target sequence number advanced.
+    ///
+    /// Progress through the outstanding range restarts the timeout; reaching
+    /// its end completes the request.
+    ///
+    /// # Arguments
+    /// * `next_target` - The new next expected target sequence number
+    pub fn on_target_advanced(&mut self, next_target: u64) {
+        let Some(pending) = &mut self.pending else {
+            return;
+        };
+        if next_target >= pending.range.end {
+            self.pending = None;
+        } else if next_target > pending.range.start {
+            pending.range.start = next_target;
+            pending.requested_at = self.clock.now();
+        }
+    }
+
+    /// Checks whether the outstanding request has timed out.
+    ///
+    /// # Returns
+    /// The sequence numbers still awaited if nothing arrived within the
+    /// timeout, in which case the timeout restarts; `None` otherwise.
+    pub fn poll_timeout(&mut self) -> Option<Range<u64>> {
+        let pending = self.pending.as_mut()?;
+        let now = self.clock.now();
+        if now.saturating_duration_since(pending.requested_at) < self.timeout {
+            return None;
+        }
+        pending.requested_at = now;
+        Some(pending.range.clone())
+    }
+
+    /// Forgets the outstanding request, e.g. after a sequence reset.
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+}
+
+impl Default for ResendTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_RESEND_REQUEST_TIMEOUT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+
+    #[test]
+    fn test_duplicate_gap_is_suppressed() {
+        let mut tracker = ResendTracker::default();
+        assert_eq!(tracker.on_gap(5..10), Some(5..10));
+
+        // The peer keeps sending new messages while the resend is pending.
+        assert_eq!(tracker.on_gap(5..10), None);
+        assert_eq!(tracker.on_gap(5..8), None);
+        assert_eq!(tracker.pending(), Some(5..10));
+        assert_eq!(tracker.poll_timeout(), None);
+    }
+
+    #[test]
+    fn test_gap_beyond_pending_requests_only_new_part() {
+        let mut tracker = ResendTracker::default();
+        tracker.on_gap(5..10);
+        assert_eq!(tracker.on_gap(5..14), Some(10..14));
+        assert_eq!(tracker.pending(), Some(5..14));
+    }
+
+    #[test]
+    fn test_rerequest_after_timeout() {
+        let clock = MockClock::default();
+        let mut tracker =
+            ResendTracker::new(Duration::from_millis(10)).with_clock(Arc::new(clock.clone()));
+        tracker.on_gap(5..10);
+        assert_eq!(tracker.poll_timeout(), None);
+
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(tracker.poll_timeout(), Some(5..10));
+        // The timeout restarts after re-requesting.
+        assert_eq!(tracker.poll_timeout(), None);
+
+        tracker.on_target_advanced(7);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(tracker.poll_timeout(), Some(7..10));
+    }
+
+    #[test]
+    fn test_rerequest_with_mock_clock() {
+        let clock = MockClock::default();
+        let mut tracker =
+            ResendTracker::new(Duration::from_secs(10)).with_clock(Arc::new(clock.clone()));
+        tracker.on_gap(5..10);
+
+        clock.advance(Duration::from_secs(9));
+        assert_eq!(tracker.poll_timeout(), None);
+        // Progress restarts the timeout.
+        tracker.on_target_advanced(6);
+        clock.advance(Duration::from_secs(9));
+        assert_eq!(tracker.poll_timeout(), None);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(tracker.poll_timeout(), Some(6..10));
+    }
+
+    #[test]
+    fn test_progress_completes_request() {
+        let mut tracker = ResendTracker::new(Duration::ZERO);
+        tracker.on_gap(5..10);
+        tracker.on_target_advanced(6);
+        assert_eq!(tracker.pending(), Some(6..10));
+
+        tracker.on_target_advanced(10);
+        assert!(!tracker.is_pending());
+        assert_eq!(tracker.poll_timeout(), None);
+        assert_eq!(tracker.on_gap(12..15), Some(12..15));
+
+        tracker.reset();
+        assert_eq!(tracker.pending(), None);
+        assert_eq!(tracker.on_gap(3..3), None);
+    }
+}
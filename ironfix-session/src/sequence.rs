@@ -6,34 +6,89 @@
 
 //! Sequence number management.
 //!
-//! This module provides atomic sequence number management for FIX sessions.
+//! This module provides atomic sequence number management for FIX sessions,
+//! with an optional [`SeqPersister`] hook so sequence numbers can survive a
+//! restart.
 
 use ironfix_core::types::SeqNum;
+use parking_lot::Mutex;
+use std::fmt;
+use std::ops::Range;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+/// Receives every change to a session's sequence numbers.
+///
+/// Called synchronously after each advance, while that direction's
+/// sequence number is locked, so calls for one direction never overlap and
+/// arrive in order. Implementations should be fast; a durable
+/// implementation typically writes to a store that is flushed elsewhere.
+/// Implementations should persist the last value written rather than the
+/// highest, since a reset or SequenceReset can move a sequence number back.
+pub trait SeqPersister: Send + Sync {
+    /// Called when the next sender sequence number changes.
+    ///
+    /// # Arguments
+    /// * `next` - The new next sender sequence number
+    fn persist_sender_seq(&self, next: u64);
+
+    /// Called when the next target sequence number changes.
+    ///
+    /// # Arguments
+    /// * `next` - The new next target sequence number
+    fn persist_target_seq(&self, next: u64);
+}
+
+/// Persister that discards all updates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopSeqPersister;
+
+impl SeqPersister for NoopSeqPersister {
+    #[inline]
+    fn persist_sender_seq(&self, _next: u64) {}
+
+    #[inline]
+    fn persist_target_seq(&self, _next: u64) {}
+}
+
 /// Manages sequence numbers for a FIX session.
 ///
-/// Uses atomic operations for thread-safe access without locks.
-#[derive(Debug)]
+/// Reads are lock-free atomic loads. With a [`SeqPersister`], every advance
+/// is written through to it under a per-direction lock, so concurrent
+/// advances reach the persister in the order they were made; without one,
+/// advances are lock-free as well.
 pub struct SequenceManager {
     /// Next outgoing sequence number.
     next_sender_seq: AtomicU64,
     /// Next expected incoming sequence number.
     next_target_seq: AtomicU64,
+    /// Serializes sender advances with their persistence.
+    sender_lock: Mutex<()>,
+    /// Serializes target advances with their persistence.
+    target_lock: Mutex<()>,
+    /// Receives sequence number changes, if persisted.
+    persister: Option<Box<dyn SeqPersister>>,
+}
+
+impl fmt::Debug for SequenceManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SequenceManager")
+            .field("next_sender_seq", &self.next_sender_seq)
+            .field("next_target_seq", &self.next_target_seq)
+            .finish_non_exhaustive()
+    }
 }
 
 impl SequenceManager {
     /// Creates a new sequence manager with sequence numbers starting at 1.
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            next_sender_seq: AtomicU64::new(1),
-            next_target_seq: AtomicU64::new(1),
-        }
+        Self::with_initial(1, 1)
     }
 
     /// Creates a new sequence manager with specified starting values.
     ///
+    /// Changes are not persisted, so advancing takes no lock.
+    ///
     /// # Arguments
     /// * `sender_seq` - Initial sender sequence number
     /// * `target_seq` - Initial target sequence number
@@ -42,6 +97,31 @@ impl SequenceManager {
         Self {
             next_sender_seq: AtomicU64::new(sender_seq),
             next_target_seq: AtomicU64::new(target_seq),
+            sender_lock: Mutex::new(()),
+            target_lock: Mutex::new(()),
+            persister: None,
+        }
+    }
+
+    /// Creates a new sequence manager that writes every change through to a
+    /// persister.
+    ///
+    /// The initial values are not persisted; they are usually the values
+    /// just recovered from the same store.
+    ///
+    /// # Arguments
+    /// * `sender_seq` - Initial sender sequence number
+    /// * `target_seq` - Initial target sequence number
+    /// * `persister` - Receives every subsequent change
+    #[must_use]
+    pub fn with_persister(
+        sender_seq: u64,
+        target_seq: u64,
+        persister: impl SeqPersister + 'static,
+    ) -> Self {
+        Self {
+            persister: Some(Box::new(persister)),
+            ..Self::with_initial(sender_seq, target_seq)
         }
     }
 
@@ -65,7 +145,13 @@ impl SequenceManager {
     /// value before the increment.
     #[inline]
     pub fn allocate_sender_seq(&self) -> SeqNum {
-        SeqNum::new(self.next_sender_seq.fetch_add(1, Ordering::SeqCst))
+        let Some(persister) = &self.persister else {
+            return SeqNum::new(self.next_sender_seq.fetch_add(1, Ordering::SeqCst));
+        };
+        let _guard = self.sender_lock.lock();
+        let seq = self.next_sender_seq.fetch_add(1, Ordering::SeqCst);
+        persister.persist_sender_seq(seq + 1);
+        SeqNum::new(seq)
     }
 
     /// Increments the target sequence number.
@@ -73,7 +159,13 @@ impl SequenceManager {
     /// Call this after successfully processing an incoming message.
     #[inline]
     pub fn increment_target_seq(&self) {
-        self.next_target_seq.fetch_add(1, Ordering::SeqCst);
+        let Some(persister) = &self.persister else {
+            self.next_target_seq.fetch_add(1, Ordering::SeqCst);
+            return;
+        };
+        let _guard = self.target_lock.lock();
+        let seq = self.next_target_seq.fetch_add(1, Ordering::SeqCst);
+        persister.persist_target_seq(seq + 1);
     }
 
     /// Sets the next sender sequence number.
@@ -82,7 +174,13 @@ impl SequenceManager {
     /// * `seq` - The new sequence number
     #[inline]
     pub fn set_sender_seq(&self, seq: u64) {
+        let Some(persister) = &self.persister else {
+            self.next_sender_seq.store(seq, Ordering::SeqCst);
+            return;
+        };
+        let _guard = self.sender_lock.lock();
         self.next_sender_seq.store(seq, Ordering::SeqCst);
+        persister.persist_sender_seq(seq);
     }
 
     /// Sets the next target sequence number.
@@ -91,14 +189,20 @@ impl SequenceManager {
     /// * `seq` - The new sequence number
     #[inline]
     pub fn set_target_seq(&self, seq: u64) {
+        let Some(persister) = &self.persister else {
+            self.next_target_seq.store(seq, Ordering::SeqCst);
+            return;
+        };
+        let _guard = self.target_lock.lock();
         self.next_target_seq.store(seq, Ordering::SeqCst);
+        persister.persist_target_seq(seq);
     }
 
     /// Resets both sequence numbers to 1.
     #[inline]
     pub fn reset(&self) {
-        self.next_sender_seq.store(1, Ordering::SeqCst);
-        self.next_target_seq.store(1, Ordering::SeqCst);
+        self.set_sender_seq(1);
+        self.set_target_seq(1);
     }
 
     /// Validates an incoming sequence number.
@@ -122,6 +226,23 @@ impl SequenceManager {
             SequenceResult::Gap { expected, received }
         }
     }
+
+    /// Returns the range of sequence numbers missing before `received`.
+    ///
+    /// This is the range to request in a ResendRequest: from the expected
+    /// sequence number up to, but excluding, the received one.
+    ///
+    /// # Arguments
+    /// * `received` - The received sequence number
+    ///
+    /// # Returns
+    /// `Some(expected..received)` if `received` is ahead of the expected
+    /// sequence number, `None` otherwise.
+    #[must_use]
+    pub fn gap_range(&self, received: u64) -> Option<Range<u64>> {
+        let expected = self.next_target_seq.load(Ordering::SeqCst);
+        (received > expected).then_some(expected..received)
+    }
 }
 
 impl Default for SequenceManager {
@@ -174,6 +295,22 @@ impl SequenceResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, Default)]
+    struct RecordingPersister {
+        updates: Arc<Mutex<Vec<(&'static str, u64)>>>,
+    }
+
+    impl SeqPersister for RecordingPersister {
+        fn persist_sender_seq(&self, next: u64) {
+            self.updates.lock().unwrap().push(("sender", next));
+        }
+
+        fn persist_target_seq(&self, next: u64) {
+            self.updates.lock().unwrap().push(("target", next));
+        }
+    }
 
     #[test]
     fn test_sequence_manager_new() {
@@ -218,6 +355,88 @@ mod tests {
         assert!(mgr.validate_incoming(10).is_gap());
     }
 
+    #[test]
+    fn test_persister_observes_each_advance() {
+        let persister = RecordingPersister::default();
+        let mgr = SequenceManager::with_persister(5, 10, persister.clone());
+        assert!(persister.updates.lock().unwrap().is_empty());
+
+        assert_eq!(mgr.allocate_sender_seq().value(), 5);
+        mgr.increment_target_seq();
+        mgr.set_sender_seq(20);
+        mgr.set_target_seq(30);
+        mgr.reset();
+
+        assert_eq!(
+            *persister.updates.lock().unwrap(),
+            vec![
+                ("sender", 6),
+                ("target", 11),
+                ("sender", 20),
+                ("target", 30),
+                ("sender", 1),
+                ("target", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_concurrent_advances_persist_final_value() {
+        #[derive(Debug, Default)]
+        struct LastValue {
+            sender: AtomicU64,
+            target: AtomicU64,
+        }
+
+        impl SeqPersister for Arc<LastValue> {
+            fn persist_sender_seq(&self, next: u64) {
+                self.sender.store(next, Ordering::SeqCst);
+            }
+
+            fn persist_target_seq(&self, next: u64) {
+                self.target.store(next, Ordering::SeqCst);
+            }
+        }
+
+        let persisted = Arc::new(LastValue::default());
+        let mgr = Arc::new(SequenceManager::with_persister(
+            1,
+            1,
+            Arc::clone(&persisted),
+        ));
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let mgr = Arc::clone(&mgr);
+                std::thread::spawn(move || {
+                    for _ in 0..1_000 {
+                        mgr.allocate_sender_seq();
+                        mgr.increment_target_seq();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(mgr.next_sender_seq().value(), 8_001);
+        assert_eq!(mgr.next_target_seq().value(), 8_001);
+        assert_eq!(persisted.sender.load(Ordering::SeqCst), 8_001);
+        assert_eq!(persisted.target.load(Ordering::SeqCst), 8_001);
+    }
+
+    #[test]
+    fn test_gap_range() {
+        let mgr = SequenceManager::with_initial(1, 5);
+
+        assert_eq!(mgr.gap_range(4), None);
+        assert_eq!(mgr.gap_range(5), None);
+        assert_eq!(mgr.gap_range(6), Some(5..6));
+        assert_eq!(mgr.gap_range(1_000), Some(5..1_000));
+        assert_eq!(mgr.gap_range(1_000).map(|r| r.count()), Some(995));
+    }
+
     #[test]
     fn test_reset() {
         let mgr = SequenceManager::with_initial(100, 200);
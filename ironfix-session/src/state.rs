@@ -8,12 +8,71 @@
 //!
 //! This module implements a compile-time checked state machine for FIX sessions.
 //! State transitions are enforced by the type system, preventing invalid operations.
+//!
+//! For runtime inspection, each state maps to a [`SessionStatus`], and sessions in
+//! different states can be stored together as an [`AnySession`].
 
-use std::marker::PhantomData;
+use crate::clock::{Clock, system_clock};
+use ironfix_core::error::SessionError;
+use std::fmt;
+use std::sync::Arc;
 use std::time::Instant;
+use tracing::trace;
+
+/// Runtime representation of a session state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SessionStatus {
+    /// No connection established.
+    Disconnected,
+    /// TCP connection in progress.
+    Connecting,
+    /// Logon message sent, awaiting response.
+    LogonSent,
+    /// Session is fully established.
+    Active,
+    /// Processing a resend request.
+    Resending,
+    /// Logout sent, awaiting confirmation.
+    LogoutPending,
+    /// Session terminated abnormally.
+    Error,
+}
+
+impl SessionStatus {
+    /// Returns the status name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disconnected => "Disconnected",
+            Self::Connecting => "Connecting",
+            Self::LogonSent => "LogonSent",
+            Self::Active => "Active",
+            Self::Resending => "Resending",
+            Self::LogoutPending => "LogoutPending",
+            Self::Error => "Error",
+        }
+    }
+
+    /// Returns whether a session in this status may be reconnected automatically.
+    ///
+    /// Sessions that terminated abnormally require intervention before reconnecting.
+    #[must_use]
+    pub const fn allows_reconnect(&self) -> bool {
+        !matches!(self, Self::Error)
+    }
+}
+
+impl fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Marker trait for session states.
-pub trait SessionState: private::Sealed {}
+pub trait SessionState: private::Sealed {
+    /// Runtime status corresponding to this state.
+    const STATUS: SessionStatus;
+}
 
 mod private {
     pub trait Sealed {}
@@ -24,14 +83,18 @@ mod private {
 pub struct Disconnected;
 
 impl private::Sealed for Disconnected {}
-impl SessionState for Disconnected {}
+impl SessionState for Disconnected {
+    const STATUS: SessionStatus = SessionStatus::Disconnected;
+}
 
 /// Connecting state - TCP connection in progress.
 #[derive(Debug, Clone, Copy)]
 pub struct Connecting;
 
 impl private::Sealed for Connecting {}
-impl SessionState for Connecting {}
+impl SessionState for Connecting {
+    const STATUS: SessionStatus = SessionStatus::Connecting;
+}
 
 /// LogonSent state - Logon message sent, awaiting response.
 #[derive(Debug, Clone)]
@@ -41,14 +104,18 @@ pub struct LogonSent {
 }
 
 impl private::Sealed for LogonSent {}
-impl SessionState for LogonSent {}
+impl SessionState for LogonSent {
+    const STATUS: SessionStatus = SessionStatus::LogonSent;
+}
 
 /// Active state - session is fully established.
 #[derive(Debug, Clone, Copy)]
 pub struct Active;
 
 impl private::Sealed for Active {}
-impl SessionState for Active {}
+impl SessionState for Active {
+    const STATUS: SessionStatus = SessionStatus::Active;
+}
 
 /// Resending state - processing a resend request.
 #[derive(Debug, Clone)]
@@ -60,7 +127,9 @@ pub struct Resending {
 }
 
 impl private::Sealed for Resending {}
-impl SessionState for Resending {}
+impl SessionState for Resending {
+    const STATUS: SessionStatus = SessionStatus::Resending;
+}
 
 /// LogoutPending state - Logout sent, awaiting confirmation.
 #[derive(Debug, Clone)]
@@ -70,17 +139,51 @@ pub struct LogoutPending {
 }
 
 impl private::Sealed for LogoutPending {}
-impl SessionState for LogoutPending {}
+impl SessionState for LogoutPending {
+    const STATUS: SessionStatus = SessionStatus::LogoutPending;
+}
+
+/// Error state - session terminated abnormally.
+///
+/// Terminal state: unlike [`Disconnected`], it offers no transition back to
+/// connecting, so the session is not reconnected automatically.
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// Fatal error that terminated the session.
+    pub reason: SessionError,
+}
+
+impl private::Sealed for Error {}
+impl SessionState for Error {
+    const STATUS: SessionStatus = SessionStatus::Error;
+}
+
+/// Callback invoked on every session state transition with the previous and
+/// new status.
+pub type TransitionObserver = Box<dyn Fn(SessionStatus, SessionStatus) + Send + Sync>;
 
 /// Session wrapper with typestate for compile-time state checking.
 ///
 /// The type parameter `S` represents the current session state.
-#[derive(Debug)]
 pub struct Session<S: SessionState> {
     /// Session identifier.
     pub session_id: String,
-    /// Phantom data for the state type.
-    _state: PhantomData<S>,
+    /// Optional callback fired on state transitions.
+    observer: Option<TransitionObserver>,
+    /// Source of the times recorded by timed states.
+    clock: Arc<dyn Clock>,
+    /// Current state data.
+    state: S,
+}
+
+impl<S: SessionState> fmt::Debug for Session<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("session_id", &self.session_id)
+            .field("status", &S::STATUS)
+            .field("has_observer", &self.observer.is_some())
+            .finish()
+    }
 }
 
 impl<S: SessionState> Session<S> {
@@ -89,6 +192,65 @@ impl<S: SessionState> Session<S> {
     pub fn session_id(&self) -> &str {
         &self.session_id
     }
+
+    /// Returns the runtime status of this session.
+    #[must_use]
+    pub const fn status(&self) -> SessionStatus {
+        S::STATUS
+    }
+
+    /// Sets a callback invoked on every state transition.
+    ///
+    /// The observer is carried across transitions and receives the previous
+    /// and new status.
+    ///
+    /// # Arguments
+    /// * `observer` - Callback taking `(from, to)` statuses
+    #[must_use]
+    pub fn with_observer(
+        mut self,
+        observer: impl Fn(SessionStatus, SessionStatus) + Send + Sync + 'static,
+    ) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Sets the clock used to timestamp Logon and Logout.
+    ///
+    /// The clock is carried across transitions.
+    ///
+    /// # Arguments
+    /// * `clock` - The clock, e.g. a `MockClock` in tests
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the current state data.
+    #[must_use]
+    pub const fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Moves the session into state `T`, notifying the observer.
+    fn transition<T: SessionState>(self, state: T) -> Session<T> {
+        if let Some(observer) = &self.observer {
+            observer(S::STATUS, T::STATUS);
+        }
+        trace!(
+            session_id = %self.session_id,
+            from = %S::STATUS,
+            to = %T::STATUS,
+            "session state transition"
+        );
+        Session {
+            session_id: self.session_id,
+            observer: self.observer,
+            clock: self.clock,
+            state,
+        }
+    }
 }
 
 impl Session<Disconnected> {
@@ -100,17 +262,16 @@ impl Session<Disconnected> {
     pub fn new(session_id: impl Into<String>) -> Self {
         Self {
             session_id: session_id.into(),
-            _state: PhantomData,
+            observer: None,
+            clock: system_clock(),
+            state: Disconnected,
         }
     }
 
     /// Transitions to the Connecting state.
     #[must_use]
     pub fn connect(self) -> Session<Connecting> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Connecting)
     }
 }
 
@@ -118,19 +279,14 @@ impl Session<Connecting> {
     /// Transitions to the LogonSent state after sending Logon.
     #[must_use]
     pub fn send_logon(self) -> Session<LogonSent> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        let sent_at = self.clock.now();
+        self.transition(LogonSent { sent_at })
     }
 
     /// Transitions back to Disconnected on connection failure.
     #[must_use]
     pub fn disconnect(self) -> Session<Disconnected> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Disconnected)
     }
 }
 
@@ -138,19 +294,13 @@ impl Session<LogonSent> {
     /// Transitions to Active state on successful Logon acknowledgement.
     #[must_use]
     pub fn on_logon_ack(self) -> Session<Active> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Active)
     }
 
     /// Transitions to Disconnected on Logon rejection or timeout.
     #[must_use]
     pub fn on_logon_reject(self) -> Session<Disconnected> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Disconnected)
     }
 }
 
@@ -161,29 +311,30 @@ impl Session<Active> {
     /// * `begin_seq` - Begin sequence number of the gap
     /// * `end_seq` - End sequence number of the gap
     #[must_use]
-    pub fn start_resend(self, _begin_seq: u64, _end_seq: u64) -> Session<Resending> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+    pub fn start_resend(self, begin_seq: u64, end_seq: u64) -> Session<Resending> {
+        self.transition(Resending { begin_seq, end_seq })
     }
 
     /// Transitions to LogoutPending state.
     #[must_use]
     pub fn initiate_logout(self) -> Session<LogoutPending> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        let sent_at = self.clock.now();
+        self.transition(LogoutPending { sent_at })
     }
 
     /// Transitions to Disconnected on unexpected disconnect.
     #[must_use]
     pub fn disconnect(self) -> Session<Disconnected> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Disconnected)
+    }
+
+    /// Transitions to the Error state on a fatal session error.
+    ///
+    /// # Arguments
+    /// * `reason` - The error that terminated the session
+    #[must_use]
+    pub fn on_fatal_error(self, reason: SessionError) -> Session<Error> {
+        self.transition(Error { reason })
     }
 }
 
@@ -191,19 +342,22 @@ impl Session<Resending> {
     /// Transitions back to Active when resend is complete.
     #[must_use]
     pub fn resend_complete(self) -> Session<Active> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Active)
     }
 
     /// Transitions to Disconnected on error.
     #[must_use]
     pub fn disconnect(self) -> Session<Disconnected> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Disconnected)
+    }
+
+    /// Transitions to the Error state on a fatal session error.
+    ///
+    /// # Arguments
+    /// * `reason` - The error that terminated the session
+    #[must_use]
+    pub fn on_fatal_error(self, reason: SessionError) -> Session<Error> {
+        self.transition(Error { reason })
     }
 }
 
@@ -211,20 +365,116 @@ impl Session<LogoutPending> {
     /// Transitions to Disconnected on Logout acknowledgement or timeout.
     #[must_use]
     pub fn on_logout_ack(self) -> Session<Disconnected> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
-        }
+        self.transition(Disconnected)
     }
 
     /// Transitions to Disconnected on timeout.
     #[must_use]
     pub fn on_timeout(self) -> Session<Disconnected> {
-        Session {
-            session_id: self.session_id,
-            _state: PhantomData,
+        self.transition(Disconnected)
+    }
+}
+
+impl Session<Error> {
+    /// Returns the error that terminated the session.
+    #[must_use]
+    pub const fn reason(&self) -> &SessionError {
+        &self.state.reason
+    }
+}
+
+/// Session in any state, with the state checked at runtime.
+///
+/// Allows sessions in different states to be stored together, e.g. in a
+/// `HashMap` keyed by session identifier.
+#[derive(Debug)]
+pub enum AnySession {
+    /// Session in the Disconnected state.
+    Disconnected(Session<Disconnected>),
+    /// Session in the Connecting state.
+    Connecting(Session<Connecting>),
+    /// Session in the LogonSent state.
+    LogonSent(Session<LogonSent>),
+    /// Session in the Active state.
+    Active(Session<Active>),
+    /// Session in the Resending state.
+    Resending(Session<Resending>),
+    /// Session in the LogoutPending state.
+    LogoutPending(Session<LogoutPending>),
+    /// Session in the Error state.
+    Error(Session<Error>),
+}
+
+impl AnySession {
+    /// Returns the session identifier.
+    #[must_use]
+    pub fn session_id(&self) -> &str {
+        match self {
+            Self::Disconnected(session) => session.session_id(),
+            Self::Connecting(session) => session.session_id(),
+            Self::LogonSent(session) => session.session_id(),
+            Self::Active(session) => session.session_id(),
+            Self::Resending(session) => session.session_id(),
+            Self::LogoutPending(session) => session.session_id(),
+            Self::Error(session) => session.session_id(),
         }
     }
+
+    /// Returns the runtime status of the session.
+    #[must_use]
+    pub const fn status(&self) -> SessionStatus {
+        match self {
+            Self::Disconnected(session) => session.status(),
+            Self::Connecting(session) => session.status(),
+            Self::LogonSent(session) => session.status(),
+            Self::Active(session) => session.status(),
+            Self::Resending(session) => session.status(),
+            Self::LogoutPending(session) => session.status(),
+            Self::Error(session) => session.status(),
+        }
+    }
+}
+
+impl From<Session<Disconnected>> for AnySession {
+    fn from(session: Session<Disconnected>) -> Self {
+        Self::Disconnected(session)
+    }
+}
+
+impl From<Session<Connecting>> for AnySession {
+    fn from(session: Session<Connecting>) -> Self {
+        Self::Connecting(session)
+    }
+}
+
+impl From<Session<LogonSent>> for AnySession {
+    fn from(session: Session<LogonSent>) -> Self {
+        Self::LogonSent(session)
+    }
+}
+
+impl From<Session<Active>> for AnySession {
+    fn from(session: Session<Active>) -> Self {
+        Self::Active(session)
+    }
+}
+
+impl From<Session<Resending>> for AnySession {
+    fn from(session: Session<Resending>) -> Self {
+        Self::Resending(session)
+    }
+}
+
+impl From<Session<LogoutPending>> for AnySession {
+    fn from(session: Session<LogoutPending>) -> Self {
+        Self::LogoutPending(session)
+    }
+}
+
+impl From<Session<Error>> for AnySession {
+    fn from(session: Session<Error>) -> Self {
+        Self::Error(session)
+    }
 }
 
 #[cfg(test)]
@@ -245,6 +495,23 @@ mod tests {
         let _session = session.on_logout_ack();
     }
 
+    #[test]
+    fn test_session_uses_clock() {
+        use crate::clock::MockClock;
+        use std::time::Duration;
+
+        let clock = MockClock::default();
+        let session = Session::<Disconnected>::new("TEST")
+            .with_clock(Arc::new(clock.clone()))
+            .connect()
+            .send_logon();
+        assert_eq!(session.state().sent_at, clock.now());
+
+        clock.advance(Duration::from_secs(30));
+        let session = session.on_logon_ack().initiate_logout();
+        assert_eq!(session.state().sent_at, clock.now());
+    }
+
     #[test]
     fn test_resend_flow() {
         let session = Session::<Disconnected>::new("TEST");
@@ -255,4 +522,142 @@ mod tests {
         let session = session.start_resend(1, 5);
         let _session = session.resend_complete();
     }
+
+    #[test]
+    fn test_runtime_status() {
+        let session = Session::<Disconnected>::new("TEST");
+        assert_eq!(session.status(), SessionStatus::Disconnected);
+
+        let session = session.connect();
+        assert_eq!(session.status(), SessionStatus::Connecting);
+
+        let session = session.send_logon();
+        assert_eq!(session.status(), SessionStatus::LogonSent);
+
+        let session = session.on_logon_ack();
+        assert_eq!(session.status(), SessionStatus::Active);
+
+        let session = session.start_resend(1, 5);
+        assert_eq!(session.status(), SessionStatus::Resending);
+
+        let session = session.resend_complete().initiate_logout();
+        assert_eq!(session.status(), SessionStatus::LogoutPending);
+
+        let session = session.on_logout_ack();
+        assert_eq!(session.status(), SessionStatus::Disconnected);
+        assert_eq!(SessionStatus::LogoutPending.to_string(), "LogoutPending");
+    }
+
+    #[test]
+    fn test_any_session() {
+        use std::collections::HashMap;
+
+        let mut sessions: HashMap<String, AnySession> = HashMap::new();
+        let idle = Session::<Disconnected>::new("IDLE");
+        let active = Session::<Disconnected>::new("LIVE")
+            .connect()
+            .send_logon()
+            .on_logon_ack();
+
+        sessions.insert(idle.session_id().to_string(), idle.into());
+        sessions.insert(active.session_id().to_string(), active.into());
+
+        assert_eq!(sessions["IDLE"].status(), SessionStatus::Disconnected);
+        assert_eq!(sessions["LIVE"].status(), SessionStatus::Active);
+        assert_eq!(sessions["LIVE"].session_id(), "LIVE");
+
+        let Some(AnySession::Active(session)) = sessions.remove("LIVE") else {
+            panic!("expected active session");
+        };
+        let session: AnySession = session.initiate_logout().into();
+        assert_eq!(session.status(), SessionStatus::LogoutPending);
+    }
+
+    #[test]
+    fn test_transition_observer() {
+        use std::sync::{Arc, Mutex};
+
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&transitions);
+
+        let session = Session::<Disconnected>::new("TEST")
+            .with_observer(move |from, to| recorded.lock().unwrap().push((from, to)));
+        let _session = session
+            .connect()
+            .send_logon()
+            .on_logon_ack()
+            .initiate_logout()
+            .on_logout_ack();
+
+        assert_eq!(
+            *transitions.lock().unwrap(),
+            vec![
+                (SessionStatus::Disconnected, SessionStatus::Connecting),
+                (SessionStatus::Connecting, SessionStatus::LogonSent),
+                (SessionStatus::LogonSent, SessionStatus::Active),
+                (SessionStatus::Active, SessionStatus::LogoutPending),
+                (SessionStatus::LogoutPending, SessionStatus::Disconnected),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_state_data() {
+        let session = Session::<Disconnected>::new("TEST").connect().send_logon();
+        assert!(session.state().sent_at.elapsed().as_secs() < 1);
+
+        let session = session.on_logon_ack().start_resend(3, 7);
+        assert_eq!(session.state().begin_seq, 3);
+        assert_eq!(session.state().end_seq, 7);
+    }
+
+    #[test]
+    fn test_fatal_error_from_active() {
+        let reason = SessionError::SequenceTooLow {
+            expected: 10,
+            received: 5,
+        };
+        let session = Session::<Disconnected>::new("TEST")
+            .connect()
+            .send_logon()
+            .on_logon_ack()
+            .on_fatal_error(reason.clone());
+
+        assert_eq!(session.status(), SessionStatus::Error);
+        assert_eq!(session.reason(), &reason);
+        assert!(!session.status().allows_reconnect());
+
+        let session: AnySession = session.into();
+        assert_eq!(session.status(), SessionStatus::Error);
+    }
+
+    #[test]
+    fn test_fatal_error_from_resending() {
+        let session = Session::<Disconnected>::new("TEST")
+            .connect()
+            .send_logon()
+            .on_logon_ack()
+            .start_resend(1, 5)
+            .on_fatal_error(SessionError::HeartbeatTimeout { elapsed_ms: 60_000 });
+
+        assert_eq!(session.status(), SessionStatus::Error);
+        assert!(matches!(
+            session.reason(),
+            SessionError::HeartbeatTimeout { .. }
+        ));
+    }
+
+    #[test]
+    fn test_clean_disconnect_allows_reconnect() {
+        let session = Session::<Disconnected>::new("TEST")
+            .connect()
+            .send_logon()
+            .on_logon_ack()
+            .disconnect();
+        assert_eq!(session.status(), SessionStatus::Disconnected);
+        assert!(session.status().allows_reconnect());
+
+        let session = session.connect();
+        assert_eq!(session.status(), SessionStatus::Connecting);
+    }
 }
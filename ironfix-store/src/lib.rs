@@ -12,9 +12,14 @@
 //! - **MessageStore trait**: Abstract interface for message storage
 //! - **MemoryStore**: In-memory message store for testing and simple use cases
 //! - **FileStore**: File-based persistent message store
+//! - **SqliteStore**: SQLite-backed persistent message store (`sqlite` feature)
 
 pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod traits;
 
-pub use memory::MemoryStore;
+pub use memory::{DEFAULT_SESSION_ID, MemoryStore};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStore;
 pub use traits::MessageStore;
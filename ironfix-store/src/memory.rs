@@ -7,45 +7,72 @@
 //! In-memory message store implementation.
 //!
 //! This module provides a simple in-memory message store suitable for
-//! testing and applications that don't require persistence.
+//! testing and applications that don't require persistence. One store can
+//! hold messages for several sessions through [`MemoryStore::scoped`].
 
 use crate::traits::MessageStore;
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt};
 use ironfix_core::error::StoreError;
 use ironfix_core::message::{MsgType, OwnedMessage};
 use parking_lot::RwLock;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::SystemTime;
 
-/// In-memory message store.
-///
-/// Stores messages in a `BTreeMap` for efficient range queries.
-/// Not persistent - all data is lost when the process exits.
+/// Session identifier used by stores created without an explicit session.
+pub const DEFAULT_SESSION_ID: &str = "default";
+
+/// Messages and sequence numbers of a single session.
 #[derive(Debug)]
-pub struct MemoryStore {
+struct Partition {
     /// Stored messages indexed by sequence number.
     messages: RwLock<BTreeMap<u64, Bytes>>,
     /// Next sender sequence number.
     next_sender_seq: AtomicU64,
     /// Next expected target sequence number.
     next_target_seq: AtomicU64,
-    /// Store creation time.
+    /// Session creation time.
     creation_time: SystemTime,
 }
 
-impl MemoryStore {
-    /// Creates a new empty memory store.
-    #[must_use]
-    pub fn new() -> Self {
+impl Partition {
+    fn new(sender_seq: u64, target_seq: u64) -> Self {
         Self {
             messages: RwLock::new(BTreeMap::new()),
-            next_sender_seq: AtomicU64::new(1),
-            next_target_seq: AtomicU64::new(1),
+            next_sender_seq: AtomicU64::new(sender_seq),
+            next_target_seq: AtomicU64::new(target_seq),
             creation_time: SystemTime::now(),
         }
     }
+}
+
+/// In-memory message store.
+///
+/// Stores messages in a `BTreeMap` for efficient range queries.
+/// Not persistent - all data is lost when the process exits.
+///
+/// Storage is partitioned by session. Each store handle operates on one
+/// session; [`scoped`](Self::scoped) returns a handle to another session
+/// sharing the same underlying storage.
+#[derive(Debug)]
+pub struct MemoryStore {
+    /// Session this handle operates on.
+    session_id: String,
+    /// Partition of the current session.
+    partition: Arc<Partition>,
+    /// All partitions, indexed by session identifier.
+    partitions: Arc<RwLock<HashMap<String, Arc<Partition>>>>,
+}
+
+impl MemoryStore {
+    /// Creates a new empty memory store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_initial_seqs(1, 1)
+    }
 
     /// Creates a new memory store with initial sequence numbers.
     ///
@@ -54,24 +81,56 @@ impl MemoryStore {
     /// * `target_seq` - Initial target sequence number
     #[must_use]
     pub fn with_initial_seqs(sender_seq: u64, target_seq: u64) -> Self {
+        let partition = Arc::new(Partition::new(sender_seq, target_seq));
+        let partitions = HashMap::from([(DEFAULT_SESSION_ID.to_string(), Arc::clone(&partition))]);
+
         Self {
-            messages: RwLock::new(BTreeMap::new()),
-            next_sender_seq: AtomicU64::new(sender_seq),
-            next_target_seq: AtomicU64::new(target_seq),
-            creation_time: SystemTime::now(),
+            session_id: DEFAULT_SESSION_ID.to_string(),
+            partition,
+            partitions: Arc::new(RwLock::new(partitions)),
         }
     }
 
-    /// Returns the number of stored messages.
+    /// Returns a handle to the given session's messages in this store.
+    ///
+    /// The returned store shares storage with `self`. A new session starts
+    /// with sequence numbers at 1.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session to operate on
+    #[must_use]
+    pub fn scoped(&self, session_id: impl Into<String>) -> Self {
+        let session_id = session_id.into();
+        let partition = Arc::clone(
+            self.partitions
+                .write()
+                .entry(session_id.clone())
+                .or_insert_with(|| Arc::new(Partition::new(1, 1))),
+        );
+
+        Self {
+            session_id,
+            partition,
+            partitions: Arc::clone(&self.partitions),
+        }
+    }
+
+    /// Returns the session this store handle operates on.
+    #[must_use]
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Returns the number of stored messages for this session.
     #[must_use]
     pub fn message_count(&self) -> usize {
-        self.messages.read().len()
+        self.partition.messages.read().len()
     }
 
-    /// Checks if a message with the given sequence number exists.
+    /// Checks if a message with the given sequence number exists for this session.
     #[must_use]
     pub fn contains(&self, seq_num: u64) -> bool {
-        self.messages.read().contains_key(&seq_num)
+        self.partition.messages.read().contains_key(&seq_num)
     }
 }
 
@@ -84,55 +143,101 @@ impl Default for MemoryStore {
 #[async_trait]
 impl MessageStore for MemoryStore {
     async fn store(&self, seq_num: u64, message: &[u8]) -> Result<(), StoreError> {
-        let mut messages = self.messages.write();
+        let mut messages = self.partition.messages.write();
         messages.insert(seq_num, Bytes::copy_from_slice(message));
         Ok(())
     }
 
     async fn get_range(&self, begin: u64, end: u64) -> Result<Vec<OwnedMessage>, StoreError> {
-        let messages = self.messages.read();
+        let messages = self.partition.messages.read();
         let end = if end == 0 { u64::MAX } else { end };
 
-        let result: Vec<OwnedMessage> = messages
-            .range(begin..=end)
-            .map(|(_, bytes)| OwnedMessage::new(bytes.clone(), MsgType::default(), vec![]))
-            .collect();
+        let result: Vec<OwnedMessage> = if begin <= end {
+            messages
+                .range(begin..=end)
+                .map(|(_, bytes)| OwnedMessage::new(bytes.clone(), MsgType::default(), vec![]))
+                .collect()
+        } else {
+            Vec::new()
+        };
 
-        if result.is_empty() && begin <= end {
+        if result.is_empty() {
             return Err(StoreError::RangeNotAvailable {
-                range: begin..end + 1,
+                range: begin..end.saturating_add(1),
             });
         }
 
         Ok(result)
     }
 
+    fn stream_range(
+        &self,
+        begin: u64,
+        end: u64,
+    ) -> BoxStream<'_, Result<OwnedMessage, StoreError>> {
+        let end = if end == 0 { u64::MAX } else { end };
+
+        // Look up one message per step so the lock is never held across polls
+        // and the range is never collected.
+        stream::unfold(Some((begin, false)), move |state| async move {
+            let (cursor, yielded) = state?;
+            let next = if cursor <= end {
+                self.partition
+                    .messages
+                    .read()
+                    .range(cursor..=end)
+                    .next()
+                    .map(|(seq, bytes)| (*seq, bytes.clone()))
+            } else {
+                None
+            };
+
+            match next {
+                Some((seq, bytes)) => {
+                    let message = OwnedMessage::new(bytes, MsgType::default(), vec![]);
+                    let state = seq.checked_add(1).map(|cursor| (cursor, true));
+                    Some((Ok(message), state))
+                }
+                None if !yielded => Some((
+                    Err(StoreError::RangeNotAvailable {
+                        range: begin..end.saturating_add(1),
+                    }),
+                    None,
+                )),
+                None => None,
+            }
+        })
+        .boxed()
+    }
+
     fn next_sender_seq(&self) -> u64 {
-        self.next_sender_seq.load(Ordering::SeqCst)
+        self.partition.next_sender_seq.load(Ordering::SeqCst)
     }
 
     fn next_target_seq(&self) -> u64 {
-        self.next_target_seq.load(Ordering::SeqCst)
+        self.partition.next_target_seq.load(Ordering::SeqCst)
     }
 
-    fn set_next_sender_seq(&self, seq: u64) {
-        self.next_sender_seq.store(seq, Ordering::SeqCst);
+    async fn set_next_sender_seq(&self, seq: u64) -> Result<(), StoreError> {
+        self.partition.next_sender_seq.store(seq, Ordering::SeqCst);
+        Ok(())
     }
 
-    fn set_next_target_seq(&self, seq: u64) {
-        self.next_target_seq.store(seq, Ordering::SeqCst);
+    async fn set_next_target_seq(&self, seq: u64) -> Result<(), StoreError> {
+        self.partition.next_target_seq.store(seq, Ordering::SeqCst);
+        Ok(())
     }
 
     async fn reset(&self) -> Result<(), StoreError> {
-        let mut messages = self.messages.write();
+        let mut messages = self.partition.messages.write();
         messages.clear();
-        self.next_sender_seq.store(1, Ordering::SeqCst);
-        self.next_target_seq.store(1, Ordering::SeqCst);
+        self.partition.next_sender_seq.store(1, Ordering::SeqCst);
+        self.partition.next_target_seq.store(1, Ordering::SeqCst);
         Ok(())
     }
 
     fn creation_time(&self) -> SystemTime {
-        self.creation_time
+        self.partition.creation_time
     }
 }
 
@@ -183,8 +288,8 @@ mod tests {
     async fn test_memory_store_sequence_numbers() {
         let store = MemoryStore::new();
 
-        store.set_next_sender_seq(10);
-        store.set_next_target_seq(20);
+        store.set_next_sender_seq(10).await.unwrap();
+        store.set_next_target_seq(20).await.unwrap();
 
         assert_eq!(store.next_sender_seq(), 10);
         assert_eq!(store.next_target_seq(), 20);
@@ -195,8 +300,8 @@ mod tests {
         let store = MemoryStore::new();
 
         store.store(1, b"msg1").await.unwrap();
-        store.set_next_sender_seq(10);
-        store.set_next_target_seq(20);
+        store.set_next_sender_seq(10).await.unwrap();
+        store.set_next_target_seq(20).await.unwrap();
 
         store.reset().await.unwrap();
 
@@ -204,4 +309,80 @@ mod tests {
         assert_eq!(store.next_sender_seq(), 1);
         assert_eq!(store.next_target_seq(), 1);
     }
+
+    #[tokio::test]
+    async fn test_memory_store_stream_range() {
+        use futures::TryStreamExt;
+
+        let store = MemoryStore::new();
+
+        store.store(1, b"msg1").await.unwrap();
+        store.store(2, b"msg2").await.unwrap();
+        store.store(3, b"msg3").await.unwrap();
+        store.store(5, b"msg5").await.unwrap();
+
+        let streamed: Vec<_> = store.stream_range(2, 5).try_collect().await.unwrap();
+        let expected = store.get_range(2, 5).await.unwrap();
+        assert_eq!(streamed.len(), 3);
+        for (streamed, expected) in streamed.iter().zip(&expected) {
+            assert_eq!(streamed.as_bytes(), expected.as_bytes());
+        }
+
+        let all: Vec<_> = store.stream_range(1, 0).try_collect().await.unwrap();
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[3].as_bytes(), b"msg5");
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_stream_range_empty() {
+        let store = MemoryStore::new();
+        store.store(1, b"msg1").await.unwrap();
+
+        let results: Vec<_> = store.stream_range(10, 20).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(StoreError::RangeNotAvailable { .. })
+        ));
+
+        let results: Vec<_> = store.stream_range(5, 3).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            store.get_range(5, 3).await,
+            Err(StoreError::RangeNotAvailable { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_scoped_isolation() {
+        let store = MemoryStore::new();
+        let first = store.scoped("FIRST");
+        let second = store.scoped("SECOND");
+
+        first.store(1, b"first1").await.unwrap();
+        first.store(2, b"first2").await.unwrap();
+        second.store(1, b"second1").await.unwrap();
+        first.set_next_sender_seq(3).await.unwrap();
+        second.set_next_sender_seq(2).await.unwrap();
+
+        assert_eq!(store.session_id(), DEFAULT_SESSION_ID);
+        assert_eq!(store.message_count(), 0);
+        assert_eq!(first.message_count(), 2);
+        assert_eq!(second.message_count(), 1);
+        assert_eq!(first.next_sender_seq(), 3);
+        assert_eq!(second.next_sender_seq(), 2);
+        assert_eq!(store.next_sender_seq(), 1);
+
+        let range = second.get_range(1, 0).await.unwrap();
+        assert_eq!(range.len(), 1);
+        assert_eq!(range[0].as_bytes(), b"second1");
+
+        second.reset().await.unwrap();
+        assert_eq!(second.message_count(), 0);
+        assert_eq!(first.message_count(), 2);
+
+        let again = store.scoped("FIRST");
+        assert_eq!(again.message_count(), 2);
+        assert_eq!(again.next_sender_seq(), 3);
+    }
 }
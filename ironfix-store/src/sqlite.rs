@@ -0,0 +1,478 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! SQLite-backed message store implementation.
+//!
+//! This module provides a persistent message store using SQLite. Messages are
+//! kept in a table keyed on `(session_id, seq_num)`, so several sessions can
+//! share one database file. Sequence counters live in a separate table.
+//!
+//! Requires the `sqlite` feature.
+
+use crate::traits::MessageStore;
+use async_trait::async_trait;
+use bytes::Bytes;
+use ironfix_core::error::StoreError;
+use ironfix_core::message::{MsgType, OwnedMessage};
+use parking_lot::Mutex;
+use rusqlite::{Connection, OptionalExtension, params};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS messages (
+        session_id TEXT NOT NULL,
+        seq_num INTEGER NOT NULL,
+        message BLOB NOT NULL,
+        PRIMARY KEY (session_id, seq_num)
+    );
+    CREATE TABLE IF NOT EXISTS sequences (
+        session_id TEXT PRIMARY KEY,
+        next_sender_seq INTEGER NOT NULL,
+        next_target_seq INTEGER NOT NULL,
+        creation_time INTEGER NOT NULL
+    );
+";
+
+/// SQLite-backed message store.
+///
+/// Sequence numbers are cached in memory and written through to the database;
+/// the cache is only updated once the write succeeds. Queries made from the
+/// async methods run on Tokio's blocking thread pool.
+///
+/// Sequence numbers above `i64::MAX` cannot be stored and are rejected.
+#[derive(Debug)]
+pub struct SqliteStore {
+    /// Database connection, shared with scoped stores.
+    conn: Arc<Mutex<Connection>>,
+    /// Session whose messages this store holds.
+    session_id: String,
+    /// Next sender sequence number.
+    next_sender_seq: AtomicU64,
+    /// Next expected target sequence number.
+    next_target_seq: AtomicU64,
+    /// Session creation time.
+    creation_time: SystemTime,
+}
+
+impl SqliteStore {
+    /// Opens or creates a store in the database file at `path`.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the SQLite database file
+    /// * `session_id` - Session whose messages this store holds
+    ///
+    /// # Errors
+    /// Returns `StoreError::Io` if the database cannot be opened or initialized.
+    pub fn open(path: impl AsRef<Path>, session_id: impl Into<String>) -> Result<Self, StoreError> {
+        let conn = Connection::open(path).map_err(io_error)?;
+        Self::with_connection(conn, session_id)
+    }
+
+    /// Creates a store backed by a private in-memory database.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session whose messages this store holds
+    ///
+    /// # Errors
+    /// Returns `StoreError::Io` if the database cannot be initialized.
+    pub fn open_in_memory(session_id: impl Into<String>) -> Result<Self, StoreError> {
+        let conn = Connection::open_in_memory().map_err(io_error)?;
+        Self::with_connection(conn, session_id)
+    }
+
+    /// Creates a store using an existing connection.
+    ///
+    /// Creates the tables if needed and loads the session's sequence numbers,
+    /// initializing them to 1 for a new session.
+    ///
+    /// # Arguments
+    /// * `conn` - The database connection
+    /// * `session_id` - Session whose messages this store holds
+    ///
+    /// # Errors
+    /// Returns `StoreError::Io` if the database cannot be initialized.
+    pub fn with_connection(
+        conn: Connection,
+        session_id: impl Into<String>,
+    ) -> Result<Self, StoreError> {
+        conn.execute_batch(SCHEMA).map_err(io_error)?;
+        Self::with_shared(Arc::new(Mutex::new(conn)), session_id.into())
+    }
+
+    /// Returns a store for another session in the same database.
+    ///
+    /// The returned store shares the connection with `self`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session to operate on
+    ///
+    /// # Errors
+    /// Returns `StoreError::Io` if the session cannot be initialized.
+    pub fn scoped(&self, session_id: impl Into<String>) -> Result<Self, StoreError> {
+        Self::with_shared(Arc::clone(&self.conn), session_id.into())
+    }
+
+    /// Creates a store for a session on an initialized shared connection.
+    fn with_shared(conn: Arc<Mutex<Connection>>, session_id: String) -> Result<Self, StoreError> {
+        let now = i64::try_from(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_micros(),
+        )
+        .unwrap_or(i64::MAX);
+
+        let (sender_seq, target_seq, created) = {
+            let conn = conn.lock();
+            conn.execute(
+                "INSERT OR IGNORE INTO sequences
+                    (session_id, next_sender_seq, next_target_seq, creation_time)
+                 VALUES (?1, 1, 1, ?2)",
+                params![session_id, now],
+            )
+            .map_err(io_error)?;
+            load_sequences(&conn, &session_id)?
+        };
+
+        Ok(Self {
+            conn,
+            session_id,
+            next_sender_seq: AtomicU64::new(sender_seq),
+            next_target_seq: AtomicU64::new(target_seq),
+            creation_time: created,
+        })
+    }
+
+    /// Returns the session identifier.
+    #[must_use]
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Returns the number of stored messages for this session.
+    ///
+    /// # Errors
+    /// Returns `StoreError::Io` if the query fails.
+    pub fn message_count(&self) -> Result<usize, StoreError> {
+        let count: i64 = self
+            .conn
+            .lock()
+            .query_row(
+                "SELECT COUNT(*) FROM messages WHERE session_id = ?1",
+                params![self.session_id],
+                |row| row.get(0),
+            )
+            .map_err(io_error)?;
+        Ok(count as usize)
+    }
+
+    /// Runs a database operation on the blocking thread pool.
+    async fn blocking<T, F>(&self, f: F) -> Result<T, StoreError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection, &str) -> Result<T, StoreError> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        let session_id = self.session_id.clone();
+        tokio::task::spawn_blocking(move || f(&mut conn.lock(), &session_id))
+            .await
+            .map_err(|e| StoreError::Io(e.to_string()))?
+    }
+
+    /// Persists a sequence counter.
+    async fn persist_seq(&self, column: &'static str, seq: u64) -> Result<(), StoreError> {
+        let value = to_sql(seq)?;
+        self.blocking(move |conn, session_id| {
+            let sql = format!("UPDATE sequences SET {column} = ?1 WHERE session_id = ?2");
+            conn.execute(&sql, params![value, session_id])
+                .map_err(io_error)?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl MessageStore for SqliteStore {
+    async fn store(&self, seq_num: u64, message: &[u8]) -> Result<(), StoreError> {
+        let store_failed = move |reason: String| StoreError::StoreFailed { seq_num, reason };
+        let seq = i64::try_from(seq_num)
+            .map_err(|_| store_failed("sequence number out of range".to_string()))?;
+        let message = message.to_vec();
+        self.blocking(move |conn, session_id| {
+            conn.execute(
+                "INSERT OR REPLACE INTO messages (session_id, seq_num, message)
+                 VALUES (?1, ?2, ?3)",
+                params![session_id, seq, message],
+            )
+            .map_err(|e| store_failed(e.to_string()))?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_range(&self, begin: u64, end: u64) -> Result<Vec<OwnedMessage>, StoreError> {
+        let end = if end == 0 { u64::MAX } else { end };
+        let retrieve_failed = move |e: rusqlite::Error| StoreError::RetrieveFailed {
+            seq_num: begin,
+            reason: e.to_string(),
+        };
+
+        // Stored sequence numbers never exceed `i64::MAX`, so clamping keeps
+        // the range's meaning.
+        let (low, high) = (
+            i64::try_from(begin).unwrap_or(i64::MAX),
+            i64::try_from(end).unwrap_or(i64::MAX),
+        );
+        let result = self
+            .blocking(move |conn, session_id| {
+                let mut stmt = conn
+                    .prepare_cached(
+                        "SELECT message FROM messages
+                         WHERE session_id = ?1 AND seq_num BETWEEN ?2 AND ?3
+                         ORDER BY seq_num",
+                    )
+                    .map_err(retrieve_failed)?;
+                stmt.query_map(params![session_id, low, high], |row| {
+                    row.get::<_, Vec<u8>>(0)
+                })
+                .map_err(retrieve_failed)?
+                .map(|bytes| {
+                    bytes
+                        .map(|bytes| {
+                            OwnedMessage::new(Bytes::from(bytes), MsgType::default(), vec![])
+                        })
+                        .map_err(retrieve_failed)
+                })
+                .collect::<Result<Vec<_>, _>>()
+            })
+            .await?;
+
+        if result.is_empty() {
+            return Err(StoreError::RangeNotAvailable {
+                range: begin..end.saturating_add(1),
+            });
+        }
+
+        Ok(result)
+    }
+
+    fn next_sender_seq(&self) -> u64 {
+        self.next_sender_seq.load(Ordering::SeqCst)
+    }
+
+    fn next_target_seq(&self) -> u64 {
+        self.next_target_seq.load(Ordering::SeqCst)
+    }
+
+    async fn set_next_sender_seq(&self, seq: u64) -> Result<(), StoreError> {
+        self.persist_seq("next_sender_seq", seq).await?;
+        self.next_sender_seq.store(seq, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn set_next_target_seq(&self, seq: u64) -> Result<(), StoreError> {
+        self.persist_seq("next_target_seq", seq).await?;
+        self.next_target_seq.store(seq, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn reset(&self) -> Result<(), StoreError> {
+        self.blocking(|conn, session_id| {
+            let tx = conn.transaction().map_err(io_error)?;
+            tx.execute(
+                "DELETE FROM messages WHERE session_id = ?1",
+                params![session_id],
+            )
+            .map_err(io_error)?;
+            tx.execute(
+                "UPDATE sequences SET next_sender_seq = 1, next_target_seq = 1
+                 WHERE session_id = ?1",
+                params![session_id],
+            )
+            .map_err(io_error)?;
+            tx.commit().map_err(io_error)
+        })
+        .await?;
+
+        self.next_sender_seq.store(1, Ordering::SeqCst);
+        self.next_target_seq.store(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn creation_time(&self) -> SystemTime {
+        self.creation_time
+    }
+
+    async fn refresh(&self) -> Result<(), StoreError> {
+        let (sender_seq, target_seq, _) = self
+            .blocking(|conn, session_id| load_sequences(conn, session_id))
+            .await?;
+        self.next_sender_seq.store(sender_seq, Ordering::SeqCst);
+        self.next_target_seq.store(target_seq, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+/// Reads the sequence counters and creation time for a session.
+fn load_sequences(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<(u64, u64, SystemTime), StoreError> {
+    let row = conn
+        .query_row(
+            "SELECT next_sender_seq, next_target_seq, creation_time
+             FROM sequences WHERE session_id = ?1",
+            params![session_id],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(io_error)?;
+
+    let (sender_seq, target_seq, created) = row.ok_or_else(|| StoreError::Corrupted {
+        reason: format!("missing sequence row for session {session_id}"),
+    })?;
+
+    let corrupted = |column: &str| StoreError::Corrupted {
+        reason: format!("negative {column} for session {session_id}"),
+    };
+    Ok((
+        u64::try_from(sender_seq).map_err(|_| corrupted("next_sender_seq"))?,
+        u64::try_from(target_seq).map_err(|_| corrupted("next_target_seq"))?,
+        UNIX_EPOCH + Duration::from_micros(u64::try_from(created).unwrap_or_default()),
+    ))
+}
+
+/// Converts a sequence number to its database representation.
+fn to_sql(seq: u64) -> Result<i64, StoreError> {
+    i64::try_from(seq).map_err(|_| StoreError::Io(format!("sequence number {seq} out of range")))
+}
+
+/// Converts a database error into a store error.
+fn io_error(e: rusqlite::Error) -> StoreError {
+    StoreError::Io(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sqlite_store_new() {
+        let store = SqliteStore::open_in_memory("SESSION").unwrap();
+        assert_eq!(store.session_id(), "SESSION");
+        assert_eq!(store.next_sender_seq(), 1);
+        assert_eq!(store.next_target_seq(), 1);
+        assert_eq!(store.message_count().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_get_range() {
+        let store = SqliteStore::open_in_memory("SESSION").unwrap();
+
+        store.store(1, b"msg1").await.unwrap();
+        store.store(2, b"msg2").await.unwrap();
+        store.store(3, b"msg3").await.unwrap();
+        store.store(5, b"msg5").await.unwrap();
+        assert_eq!(store.message_count().unwrap(), 4);
+
+        let range = store.get_range(2, 5).await.unwrap();
+        let bodies: Vec<_> = range.iter().map(OwnedMessage::as_bytes).collect();
+        assert_eq!(bodies, [b"msg2", b"msg3", b"msg5"]);
+
+        let all = store.get_range(1, 0).await.unwrap();
+        assert_eq!(all.len(), 4);
+
+        assert!(matches!(
+            store.get_range(10, 20).await,
+            Err(StoreError::RangeNotAvailable { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_sequence_numbers() {
+        let store = SqliteStore::open_in_memory("SESSION").unwrap();
+
+        store.set_next_sender_seq(10).await.unwrap();
+        store.set_next_target_seq(20).await.unwrap();
+        store.refresh().await.unwrap();
+
+        assert_eq!(store.next_sender_seq(), 10);
+        assert_eq!(store.next_target_seq(), 20);
+
+        assert!(store.set_next_sender_seq(u64::MAX).await.is_err());
+        assert!(store.store(u64::MAX, b"msg").await.is_err());
+        store.refresh().await.unwrap();
+        assert_eq!(store.next_sender_seq(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_persist_failure() {
+        let store = SqliteStore::open_in_memory("SESSION").unwrap();
+        store
+            .conn
+            .lock()
+            .execute_batch("DROP TABLE sequences")
+            .unwrap();
+
+        assert!(matches!(
+            store.set_next_sender_seq(5).await,
+            Err(StoreError::Io(_))
+        ));
+        assert_eq!(store.next_sender_seq(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_reset() {
+        let store = SqliteStore::open_in_memory("SESSION").unwrap();
+
+        store.store(1, b"msg1").await.unwrap();
+        store.set_next_sender_seq(10).await.unwrap();
+        store.set_next_target_seq(20).await.unwrap();
+
+        store.reset().await.unwrap();
+        store.refresh().await.unwrap();
+
+        assert_eq!(store.message_count().unwrap(), 0);
+        assert_eq!(store.next_sender_seq(), 1);
+        assert_eq!(store.next_target_seq(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_scoped_isolation() {
+        let first = SqliteStore::open_in_memory("FIRST").unwrap();
+        let second = first.scoped("SECOND").unwrap();
+
+        first.store(1, b"first1").await.unwrap();
+        first.store(2, b"first2").await.unwrap();
+        second.store(1, b"second1").await.unwrap();
+        first.set_next_sender_seq(3).await.unwrap();
+
+        assert_eq!(first.message_count().unwrap(), 2);
+        assert_eq!(second.message_count().unwrap(), 1);
+        assert_eq!(second.next_sender_seq(), 1);
+
+        let range = second.get_range(1, 0).await.unwrap();
+        assert_eq!(range.len(), 1);
+        assert_eq!(range[0].as_bytes(), b"second1");
+
+        second.reset().await.unwrap();
+        assert_eq!(first.message_count().unwrap(), 2);
+
+        let again = second.scoped("FIRST").unwrap();
+        assert_eq!(again.message_count().unwrap(), 2);
+        assert_eq!(again.next_sender_seq(), 3);
+    }
+}
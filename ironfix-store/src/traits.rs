@@ -9,6 +9,7 @@
 //! This module defines the abstract interface for message storage implementations.
 
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use ironfix_core::error::StoreError;
 use ironfix_core::message::OwnedMessage;
 
@@ -30,6 +31,9 @@ pub trait MessageStore: Send + Sync {
 
     /// Retrieves messages for a resend request.
     ///
+    /// Sequence numbers with no stored message are skipped, so the result
+    /// may have gaps.
+    ///
     /// # Arguments
     /// * `begin` - Begin sequence number (inclusive)
     /// * `end` - End sequence number (inclusive, or 0 for infinity)
@@ -38,9 +42,38 @@ pub trait MessageStore: Send + Sync {
     /// A vector of messages in the requested range.
     ///
     /// # Errors
-    /// Returns `StoreError` if messages cannot be retrieved.
+    /// Returns `StoreError::RangeNotAvailable` if no message in the range is
+    /// stored, including when `begin` is past `end`, or another `StoreError`
+    /// if messages cannot be retrieved.
     async fn get_range(&self, begin: u64, end: u64) -> Result<Vec<OwnedMessage>, StoreError>;
 
+    /// Streams messages for a resend request.
+    ///
+    /// Unlike [`get_range`](Self::get_range), implementations may yield messages
+    /// one at a time so large ranges can be replayed without buffering them all.
+    /// Missing sequence numbers are skipped as by `get_range`, and a range
+    /// holding no stored message yields a single
+    /// `StoreError::RangeNotAvailable`. The default implementation streams the
+    /// result of `get_range`.
+    ///
+    /// # Arguments
+    /// * `begin` - Begin sequence number (inclusive)
+    /// * `end` - End sequence number (inclusive, or 0 for infinity)
+    ///
+    /// # Returns
+    /// A stream of messages in the requested range, or of a single error if
+    /// they cannot be retrieved.
+    fn stream_range(
+        &self,
+        begin: u64,
+        end: u64,
+    ) -> BoxStream<'_, Result<OwnedMessage, StoreError>> {
+        stream::once(self.get_range(begin, end))
+            .map_ok(|messages| stream::iter(messages.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
     /// Returns the next sender sequence number.
     fn next_sender_seq(&self) -> u64;
 
@@ -51,13 +84,19 @@ pub trait MessageStore: Send + Sync {
     ///
     /// # Arguments
     /// * `seq` - The new sequence number
-    fn set_next_sender_seq(&self, seq: u64);
+    ///
+    /// # Errors
+    /// Returns `StoreError` if the sequence number cannot be persisted.
+    async fn set_next_sender_seq(&self, seq: u64) -> Result<(), StoreError>;
 
     /// Sets the next expected target sequence number.
     ///
     /// # Arguments
     /// * `seq` - The new sequence number
-    fn set_next_target_seq(&self, seq: u64);
+    ///
+    /// # Errors
+    /// Returns `StoreError` if the sequence number cannot be persisted.
+    async fn set_next_target_seq(&self, seq: u64) -> Result<(), StoreError>;
 
     /// Resets the store, clearing all messages and resetting sequence numbers.
     ///
@@ -75,6 +114,18 @@ pub trait MessageStore: Send + Sync {
     async fn refresh(&self) -> Result<(), StoreError> {
         Ok(())
     }
+
+    /// Writes buffered messages and sequence numbers to persistent storage.
+    ///
+    /// Called by the session runtime when a session stops. The default
+    /// implementation does nothing, which suits stores that write through on
+    /// every call, such as the memory and SQLite stores.
+    ///
+    /// # Errors
+    /// Returns `StoreError` if the flush fails.
+    async fn flush(&self) -> Result<(), StoreError> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -89,8 +140,21 @@ mod tests {
             Ok(())
         }
 
-        async fn get_range(&self, _begin: u64, _end: u64) -> Result<Vec<OwnedMessage>, StoreError> {
-            Ok(vec![])
+        async fn get_range(&self, begin: u64, end: u64) -> Result<Vec<OwnedMessage>, StoreError> {
+            if begin > end {
+                return Err(StoreError::RangeNotAvailable {
+                    range: begin..end + 1,
+                });
+            }
+            Ok((begin..=end)
+                .map(|seq| {
+                    OwnedMessage::new(
+                        bytes::Bytes::from(seq.to_string()),
+                        Default::default(),
+                        vec![],
+                    )
+                })
+                .collect())
         }
 
         fn next_sender_seq(&self) -> u64 {
@@ -101,9 +165,13 @@ mod tests {
             1
         }
 
-        fn set_next_sender_seq(&self, _seq: u64) {}
+        async fn set_next_sender_seq(&self, _seq: u64) -> Result<(), StoreError> {
+            Ok(())
+        }
 
-        fn set_next_target_seq(&self, _seq: u64) {}
+        async fn set_next_target_seq(&self, _seq: u64) -> Result<(), StoreError> {
+            Ok(())
+        }
 
         async fn reset(&self) -> Result<(), StoreError> {
             Ok(())
@@ -122,4 +190,16 @@ mod tests {
         assert!(store.store(1, b"test").await.is_ok());
         assert!(store.reset().await.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_default_stream_range() {
+        let store = MockStore;
+        let messages: Vec<_> = store.stream_range(3, 5).try_collect().await.unwrap();
+        let bodies: Vec<_> = messages.iter().map(OwnedMessage::as_bytes).collect();
+        assert_eq!(bodies, [b"3", b"4", b"5"]);
+
+        let results: Vec<_> = store.stream_range(5, 3).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
 }
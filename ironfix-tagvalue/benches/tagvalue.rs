@@ -0,0 +1,86 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 27/1/26
+******************************************************************************/
+
+//! Tag=value decode, encode and checksum benchmarks.
+//!
+//! Run with `cargo bench -p ironfix-tagvalue`. The `decode` group compares
+//! the allocating [`Decoder::decode`] with [`Decoder::parse_reusing`] into a
+//! caller-owned [`RawMessageBuf`].
+
+use bytes::BytesMut;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use ironfix_core::message::MsgType;
+use ironfix_tagvalue::{Decoder, Encoder, RawMessageBuf, calculate_checksum};
+use std::hint::black_box;
+
+/// FIX 4.4 NewOrderSingle as produced by [`encode_order`].
+const NEW_ORDER_SINGLE: &[u8] = b"8=FIX.4.4\x019=137\x0135=D\x0149=SENDER\x0156=TARGET\x01\
+34=12345\x0152=20260127-10:00:00.000\x0111=ORDER-0001\x0155=IBM\x0154=1\x01\
+60=20260127-10:00:00.000\x0138=100\x0140=2\x0144=123.45\x0159=0\x0110=045\x01";
+
+/// Encodes the NewOrderSingle fixture.
+fn encode_order() -> BytesMut {
+    let mut encoder = Encoder::with_capacity("FIX.4.4", 256);
+    encoder.put_str(35, MsgType::NewOrderSingle.as_str());
+    encoder.put_str(49, "SENDER");
+    encoder.put_str(56, "TARGET");
+    encoder.put_uint(34, 12_345);
+    encoder.put_str(52, "20260127-10:00:00.000");
+    encoder.put_str(11, "ORDER-0001");
+    encoder.put_str(55, "IBM");
+    encoder.put_char(54, '1');
+    encoder.put_str(60, "20260127-10:00:00.000");
+    encoder.put_uint(38, 100);
+    encoder.put_char(40, '2');
+    encoder.put_str(44, "123.45");
+    encoder.put_char(59, '0');
+    encoder.finish()
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    group.throughput(Throughput::Bytes(NEW_ORDER_SINGLE.len() as u64));
+    group.bench_function("decode", |b| {
+        b.iter(|| {
+            let message = Decoder::new(black_box(NEW_ORDER_SINGLE)).decode().unwrap();
+            black_box(message.field_count())
+        });
+    });
+    let mut buf = RawMessageBuf::with_capacity(32);
+    group.bench_function("parse_reusing", |b| {
+        b.iter(|| {
+            let view = Decoder::new(black_box(NEW_ORDER_SINGLE))
+                .parse_reusing(&mut buf)
+                .unwrap();
+            black_box(view.field_count())
+        });
+    });
+    group.finish();
+}
+
+fn bench_encode(c: &mut Criterion) {
+    assert_eq!(&encode_order()[..], NEW_ORDER_SINGLE);
+
+    let mut group = c.benchmark_group("encode");
+    group.throughput(Throughput::Bytes(NEW_ORDER_SINGLE.len() as u64));
+    group.bench_function("finish", |b| b.iter(encode_order));
+    group.finish();
+}
+
+fn bench_checksum(c: &mut Criterion) {
+    let trailer = NEW_ORDER_SINGLE.len() - b"10=045\x01".len();
+    let covered = &NEW_ORDER_SINGLE[..trailer];
+
+    let mut group = c.benchmark_group("checksum");
+    group.throughput(Throughput::Bytes(covered.len() as u64));
+    group.bench_function("calculate_checksum", |b| {
+        b.iter(|| calculate_checksum(black_box(covered)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_decode, bench_encode, bench_checksum);
+criterion_main!(benches);
@@ -9,6 +9,58 @@
 //! The FIX checksum is the sum of all bytes in the message (excluding the
 //! checksum field itself) modulo 256, formatted as a 3-digit zero-padded string.
 
+/// Incremental FIX checksum accumulator.
+///
+/// Allows the checksum to be computed over a message delivered in chunks,
+/// e.g. while a streaming parser is framing the message.
+///
+/// # Example
+/// ```
+/// use ironfix_tagvalue::checksum::Checksum;
+///
+/// let mut checksum = Checksum::new();
+/// checksum.update(b"8=FIX.4.4\x01");
+/// checksum.update(b"9=5\x0135=0\x01");
+/// let value = checksum.finalize();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Checksum {
+    /// Running sum of all bytes seen so far, modulo 256.
+    sum: u8,
+}
+
+impl Checksum {
+    /// Creates a new accumulator with a zero sum.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { sum: 0 }
+    }
+
+    /// Adds the given bytes to the running checksum.
+    ///
+    /// # Arguments
+    /// * `bytes` - The next chunk of message bytes
+    #[inline]
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.sum = self.sum.wrapping_add(calculate_checksum_wide(bytes));
+    }
+
+    /// Returns the checksum of all bytes seen so far without consuming the accumulator.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> u8 {
+        self.sum
+    }
+
+    /// Consumes the accumulator and returns the final checksum value.
+    #[inline]
+    #[must_use]
+    pub const fn finalize(self) -> u8 {
+        self.sum
+    }
+}
+
 /// Calculates the FIX checksum for the given data.
 ///
 /// The checksum is the sum of all bytes modulo 256.
@@ -29,16 +81,44 @@
 #[inline]
 #[must_use]
 pub fn calculate_checksum(data: &[u8]) -> u8 {
-    calculate_checksum_portable(data)
+    let mut checksum = Checksum::new();
+    checksum.update(data);
+    checksum.finalize()
 }
 
-/// Portable checksum calculation without SIMD.
+/// Number of independent accumulator lanes used by [`calculate_checksum_wide`].
+const CHECKSUM_LANES: usize = 16;
+
+/// Wide-accumulator checksum calculation.
+///
+/// Sums the input into several independent `u64` lanes so the compiler can
+/// auto-vectorize the inner loop, then reduces the lanes and the remainder.
+/// A `u64` lane cannot overflow for any realistic buffer size.
 #[inline]
-fn calculate_checksum_portable(data: &[u8]) -> u8 {
-    let sum: u32 = data.iter().map(|&b| b as u32).sum();
+fn calculate_checksum_wide(data: &[u8]) -> u8 {
+    let mut lanes = [0u64; CHECKSUM_LANES];
+    let mut chunks = data.chunks_exact(CHECKSUM_LANES);
+
+    for chunk in &mut chunks {
+        for (lane, &b) in lanes.iter_mut().zip(chunk) {
+            *lane += b as u64;
+        }
+    }
+
+    let mut sum: u64 = lanes.iter().sum();
+    for &b in chunks.remainder() {
+        sum += b as u64;
+    }
+
     (sum % 256) as u8
 }
 
+/// Portable byte-at-a-time checksum calculation, used as a reference.
+#[cfg(test)]
+fn calculate_checksum_portable(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |acc, &b| acc.wrapping_add(b))
+}
+
 /// Formats a checksum value as a 3-digit zero-padded string.
 ///
 /// # Arguments
@@ -103,6 +183,71 @@ mod tests {
         assert_eq!(calculate_checksum(&data), expected);
     }
 
+    #[test]
+    fn test_incremental_matches_one_shot() {
+        let data = b"8=FIX.4.4\x019=49\x0135=D\x0149=SENDER\x0156=TARGET\x0134=1\x0111=ORDER1\x01";
+        let expected = calculate_checksum(data);
+
+        for chunk_size in 1..data.len() {
+            let mut checksum = Checksum::new();
+            for chunk in data.chunks(chunk_size) {
+                checksum.update(chunk);
+            }
+            assert_eq!(checksum.finalize(), expected);
+        }
+    }
+
+    #[test]
+    fn test_incremental_empty() {
+        let mut checksum = Checksum::new();
+        checksum.update(b"");
+        assert_eq!(checksum.value(), 0);
+        assert_eq!(checksum.finalize(), 0);
+    }
+
+    /// Simple xorshift generator so the property test needs no extra dependencies.
+    fn xorshift(state: &mut u64) -> u64 {
+        let mut x = *state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        *state = x;
+        x
+    }
+
+    #[test]
+    fn test_wide_matches_portable_random() {
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..256 {
+            let len = (xorshift(&mut state) % 4096) as usize;
+            let data: Vec<u8> = (0..len).map(|_| xorshift(&mut state) as u8).collect();
+            assert_eq!(
+                calculate_checksum(&data),
+                calculate_checksum_portable(&data)
+            );
+        }
+    }
+
+    #[test]
+    fn test_wide_matches_portable_edge_sizes() {
+        for len in [0, 1, 15, 16, 17, 31, 32, 33, 255, 256, 257] {
+            let data: Vec<u8> = (0..len).map(|i| (i * 31 + 7) as u8).collect();
+            assert_eq!(
+                calculate_checksum(&data),
+                calculate_checksum_portable(&data)
+            );
+        }
+    }
+
+    #[test]
+    fn test_wide_matches_portable_large() {
+        let data = vec![0xFFu8; 70 * 1024 + 3];
+        assert_eq!(
+            calculate_checksum(&data),
+            calculate_checksum_portable(&data)
+        );
+    }
+
     #[test]
     fn test_format_checksum() {
         assert_eq!(format_checksum(0), *b"000");
@@ -11,6 +11,10 @@
 //! references to the original buffer.
 
 use crate::checksum::{calculate_checksum, parse_checksum};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::Range;
 use ironfix_core::error::DecodeError;
 use ironfix_core::field::FieldRef;
 use ironfix_core::message::{MsgType, RawMessage};
@@ -23,11 +27,54 @@ pub const SOH: u8 = 0x01;
 /// Equals sign delimiter between tag and value.
 pub const EQUALS: u8 = b'=';
 
+/// Default maximum number of fields in a decoded message.
+pub const DEFAULT_MAX_FIELDS: usize = 10_000;
+
+/// Standard length and data field pairs: (length tag, data tag).
+///
+/// A data field's value is binary and may contain the delimiter, so it is
+/// read as exactly as many bytes as the preceding length field declares.
+/// Covers Signature (93/89), SecureData (90/91), RawData (95/96), XmlData
+/// (212/213) and the encoded text fields of FIX 4.2 to 5.0.
+pub const DATA_FIELDS: [(u32, u32); 15] = [
+    (90, 91),
+    (93, 89),
+    (95, 96),
+    (212, 213),
+    (348, 349),
+    (350, 351),
+    (352, 353),
+    (354, 355),
+    (356, 357),
+    (358, 359),
+    (360, 361),
+    (362, 363),
+    (364, 365),
+    (445, 446),
+    (618, 619),
+];
+
+/// Standard header tags that must directly follow MsgType, in order, when
+/// decoding in strict mode: SenderCompID, TargetCompID, MsgSeqNum, SendingTime.
+const STRICT_HEADER_TAGS: [u32; 4] = [49, 56, 34, 52];
+
+/// Framing of a decoded message.
+struct Frame {
+    /// Offset of the first byte of the message in the decoder input.
+    start: usize,
+    /// Range of the BeginString value, relative to the message start.
+    begin_string: Range<usize>,
+    /// Range of the message body, relative to the message start.
+    body: Range<usize>,
+    /// The parsed message type.
+    msg_type: MsgType,
+}
+
 /// Zero-copy FIX message decoder.
 ///
 /// The decoder parses FIX messages from a byte buffer, extracting fields
 /// as references to the original data without copying.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Decoder<'a> {
     /// Input buffer.
     input: &'a [u8],
@@ -35,6 +82,19 @@ pub struct Decoder<'a> {
     offset: usize,
     /// Whether to validate checksums.
     validate_checksum: bool,
+    /// Byte terminating each field.
+    delimiter: u8,
+    /// Whether to validate standard header field ordering.
+    strict: bool,
+    /// Whether to check BodyLength against the actual body size.
+    validate_length: bool,
+    /// Maximum number of fields accepted per message.
+    max_fields: usize,
+    /// Declared and computed checksum of the last decoded message.
+    last_checksum: Option<(u8, u8)>,
+    /// Data tag and length announced by the field just parsed, if it was
+    /// the length field of a data field.
+    data_length: Option<(u32, usize)>,
 }
 
 impl<'a> Decoder<'a> {
@@ -49,6 +109,12 @@ impl<'a> Decoder<'a> {
             input,
             offset: 0,
             validate_checksum: true,
+            delimiter: SOH,
+            strict: false,
+            validate_length: false,
+            max_fields: DEFAULT_MAX_FIELDS,
+            last_checksum: None,
+            data_length: None,
         }
     }
 
@@ -63,31 +129,318 @@ impl<'a> Decoder<'a> {
         self
     }
 
+    /// Sets the byte terminating each field.
+    ///
+    /// Defaults to [`SOH`]. A visible delimiter such as `b'|'` is useful for
+    /// debugging and for interop with tools that log messages that way.
+    /// Checksums are validated over the bytes as received.
+    ///
+    /// # Arguments
+    /// * `delimiter` - The field delimiter byte
+    #[inline]
+    #[must_use]
+    pub const fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether to validate standard header field ordering.
+    ///
+    /// The decoder always requires BeginString, BodyLength and MsgType as the
+    /// first three fields. In strict mode, SenderCompID (49), TargetCompID (56),
+    /// MsgSeqNum (34) and SendingTime (52) must also follow MsgType, in that
+    /// order. Disabled by default.
+    ///
+    /// # Arguments
+    /// * `strict` - Whether to validate header ordering
+    #[inline]
+    #[must_use]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets whether to check BodyLength against the actual body size.
+    ///
+    /// When enabled, a message whose BodyLength does not equal the number of
+    /// bytes between the BodyLength field and the CheckSum field is rejected
+    /// with `DecodeError::IncorrectBodyLength`. Disabled by default.
+    ///
+    /// # Arguments
+    /// * `validate` - Whether to validate BodyLength
+    #[inline]
+    #[must_use]
+    pub const fn with_length_validation(mut self, validate: bool) -> Self {
+        self.validate_length = validate;
+        self
+    }
+
+    /// Sets the maximum number of fields accepted per message.
+    ///
+    /// Guards against a peer sending huge numbers of tiny fields, complementing
+    /// the transport's message size limit. CheckSum is not counted. Defaults
+    /// to [`DEFAULT_MAX_FIELDS`].
+    ///
+    /// # Arguments
+    /// * `max_fields` - The maximum number of fields
+    #[inline]
+    #[must_use]
+    pub const fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields;
+        self
+    }
+
+    /// Returns the maximum number of fields accepted per message.
+    #[inline]
+    #[must_use]
+    pub const fn max_fields(&self) -> usize {
+        self.max_fields
+    }
+
+    /// Returns the checksum of the last message decoded, as
+    /// `(declared, computed)`.
+    ///
+    /// Recorded whether or not checksum validation is enabled, and also when
+    /// decoding fails with `DecodeError::ChecksumMismatch`, so tooling can
+    /// report or repair a bad CheckSum.
+    ///
+    /// # Returns
+    /// `None` before the first decode, or if the last message had no
+    /// parsable CheckSum field.
+    #[inline]
+    #[must_use]
+    pub const fn last_checksum(&self) -> Option<(u8, u8)> {
+        self.last_checksum
+    }
+
     /// Decodes a complete FIX message from the buffer.
     ///
     /// # Returns
     /// A `RawMessage` containing zero-copy references to the parsed fields.
     ///
     /// # Errors
-    /// Returns `DecodeError` if the message is malformed or incomplete.
+    /// Returns `DecodeError::TooManyFields` if the message has more than
+    /// [`max_fields`](Self::max_fields) fields, or another `DecodeError` if it
+    /// is malformed or incomplete.
     pub fn decode(&mut self) -> Result<RawMessage<'a>, DecodeError> {
-        let start_offset = self.offset;
+        let mut fields: SmallVec<[FieldRef<'a>; 32]> = SmallVec::new();
+        let frame = self.decode_fields(|field, _| {
+            fields.push(field);
+            Ok(())
+        })?;
+
+        // Validate standard header ordering if strict
+        if self.strict {
+            validate_header_order(fields[3..].iter().map(|field| field.tag))?;
+        }
+
+        Ok(RawMessage::new(
+            &self.input[frame.start..self.offset],
+            frame.begin_string,
+            frame.body,
+            frame.msg_type,
+            fields,
+        ))
+    }
+
+    /// Decodes a complete FIX message into a caller-provided slice.
+    ///
+    /// Unlike [`decode`](Self::decode), this never allocates: fields are
+    /// written to `fields` in message order, starting with BeginString,
+    /// BodyLength and MsgType. The CheckSum field is not stored.
+    ///
+    /// # Arguments
+    /// * `fields` - Destination for the parsed fields
+    ///
+    /// # Returns
+    /// The number of fields written.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::TooManyFields` if the message does not fit in
+    /// `fields` or exceeds [`max_fields`](Self::max_fields), or another
+    /// `DecodeError` if it is malformed or incomplete.
+    pub fn decode_into(&mut self, fields: &mut [FieldRef<'a>]) -> Result<usize, DecodeError> {
+        let max = fields.len();
+        let mut count = 0;
+        self.decode_fields(|field, _| {
+            let slot = fields
+                .get_mut(count)
+                .ok_or(DecodeError::TooManyFields { max })?;
+            *slot = field;
+            count += 1;
+            Ok(())
+        })?;
+
+        if self.strict {
+            validate_header_order(fields[3..count].iter().map(|field| field.tag))?;
+        }
+
+        Ok(count)
+    }
+
+    /// Decodes every `tag=value` pair from the current position to the end of
+    /// the buffer, without requiring or validating the framing fields.
+    ///
+    /// Intended for offline analysis of captured logs, which may store only
+    /// the message body or fragments with the configured delimiter. The final
+    /// field need not be terminated by the delimiter. BeginString, BodyLength
+    /// and CheckSum are kept as ordinary fields if present, and neither the
+    /// body length, the checksum nor the header order is checked.
+    ///
+    /// # Returns
+    /// A `RawMessage` over the remaining input, whose body spans all of it.
+    /// The message type is taken from MsgType (tag 35) and is an empty
+    /// `MsgType::Custom` if that field is absent.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidTag`, annotated with the field's byte
+    /// offset, if a tag is not a valid number, `DecodeError::Incomplete` if
+    /// trailing bytes hold no `=`, or
+    /// `DecodeError::TooManyFields` if there are more than
+    /// [`max_fields`](Self::max_fields) fields.
+    pub fn decode_fields_only(&mut self) -> Result<RawMessage<'a>, DecodeError> {
+        let start = self.offset;
+        let mut fields: SmallVec<[FieldRef<'a>; 32]> = SmallVec::new();
+        let mut begin_string = 0..0;
+
+        while !self.is_empty() {
+            let field_start = self.offset;
+            let field = match self.try_next_field()? {
+                // The value ends just before the delimiter preceding the offset.
+                Some(field) => (field, self.offset - 1),
+                None => {
+                    // Accept a final field without a trailing delimiter.
+                    let remaining = self.remaining();
+                    let eq_pos = memchr(EQUALS, remaining).ok_or(DecodeError::Incomplete)?;
+                    let tag = parse_tag(&remaining[..eq_pos]).ok_or_else(|| {
+                        DecodeError::InvalidTag(
+                            String::from_utf8_lossy(&remaining[..eq_pos]).into_owned(),
+                        )
+                        .at_offset(field_start)
+                    })?;
+                    self.offset = self.input.len();
+                    (FieldRef::new(tag, &remaining[eq_pos + 1..]), self.offset)
+                }
+            };
+            let (field, value_end) = field;
+            if fields.len() == self.max_fields {
+                return Err(DecodeError::TooManyFields {
+                    max: self.max_fields,
+                });
+            }
+            if field.tag == 8 && fields.is_empty() {
+                let value_end = value_end - start;
+                begin_string = value_end - field.value.len()..value_end;
+            }
+            fields.push(field);
+        }
+
+        let msg_type = fields
+            .iter()
+            .find(|field| field.tag == 35)
+            .and_then(|field| field.as_str().ok())
+            .map_or_else(
+                || MsgType::Custom(String::new()),
+                |value| value.parse().unwrap(),
+            );
+
+        Ok(RawMessage::new(
+            &self.input[start..],
+            begin_string,
+            0..self.input.len() - start,
+            msg_type,
+            fields,
+        ))
+    }
+
+    /// Decodes a complete FIX message into a caller-owned, reusable buffer.
+    ///
+    /// Like [`decode`](Self::decode), but the parsed fields are stored in
+    /// `buf`, whose allocation is kept across calls, so decoding a stream of
+    /// messages with one buffer does not allocate once it has grown to the
+    /// largest message. The CheckSum field is not stored.
+    ///
+    /// # Arguments
+    /// * `buf` - The buffer receiving the parsed fields; previous contents
+    ///   are discarded
+    ///
+    /// # Returns
+    /// A view of the message reading its fields from `buf`.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::TooManyFields` if the message has more than
+    /// [`max_fields`](Self::max_fields) fields, or another `DecodeError` if it
+    /// is malformed or incomplete.
+    pub fn parse_reusing<'m>(
+        &mut self,
+        buf: &'m mut RawMessageBuf,
+    ) -> Result<MessageView<'m, 'a>, DecodeError> {
+        buf.fields.clear();
+        let fields = &mut buf.fields;
+        let frame = self.decode_fields(|field, range| {
+            fields.push((field.tag, range));
+            Ok(())
+        })?;
+
+        if self.strict {
+            validate_header_order(buf.fields[3..].iter().map(|(tag, _)| *tag))?;
+        }
+
+        buf.begin_string = frame.begin_string;
+        buf.body = frame.body;
+        buf.msg_type = frame.msg_type;
+        Ok(MessageView {
+            buffer: &self.input[frame.start..self.offset],
+            parsed: buf,
+        })
+    }
+
+    /// Decodes one message, handing each field except CheckSum to `push`
+    /// along with the range of its value within the message.
+    ///
+    /// Validates the framing fields, the field count and, if enabled, the
+    /// body length and checksum. Strict header validation is left to the
+    /// caller.
+    ///
+    /// Offsets are taken from the parse position rather than derived from
+    /// field pointers, and a declared BodyLength is never trusted to lie
+    /// within the input, so malformed input yields an error, not a panic.
+    ///
+    /// # Errors
+    /// Returns `DecodeError` if the message is malformed or incomplete, or
+    /// the first error returned by `push`.
+    fn decode_fields(
+        &mut self,
+        mut push: impl FnMut(FieldRef<'a>, Range<usize>) -> Result<(), DecodeError>,
+    ) -> Result<Frame, DecodeError> {
+        let start = self.offset;
+        self.last_checksum = None;
+        self.data_length = None;
+        let max = self.max_fields;
+        let mut count = 0;
+        let mut push = |field, range| {
+            if count == max {
+                return Err(DecodeError::TooManyFields { max });
+            }
+            count += 1;
+            push(field, range)
+        };
 
         // Parse BeginString (tag 8)
-        let begin_string_field = self.next_field().ok_or(DecodeError::Incomplete)?;
+        let begin_string_field = self.try_next_field()?.ok_or(DecodeError::Incomplete)?;
         if begin_string_field.tag != 8 {
             return Err(DecodeError::InvalidBeginString);
         }
-        let begin_string_start =
-            begin_string_field.value.as_ptr() as usize - self.input.as_ptr() as usize;
-        let begin_string_end = begin_string_start + begin_string_field.value.len();
-        let begin_string = begin_string_start..begin_string_end;
+        let begin_string = self.value_range(start, &begin_string_field);
 
         // Parse BodyLength (tag 9)
-        let body_length_field = self.next_field().ok_or(DecodeError::MissingBodyLength)?;
+        let body_length_field = self
+            .try_next_field()?
+            .ok_or(DecodeError::MissingBodyLength)?;
         if body_length_field.tag != 9 {
             return Err(DecodeError::MissingBodyLength);
         }
+        let body_length_range = self.value_range(start, &body_length_field);
         let body_length: usize = body_length_field
             .as_str()?
             .parse()
@@ -97,42 +450,66 @@ impl<'a> Decoder<'a> {
         let body_start = self.offset;
 
         // Parse MsgType (tag 35) - should be first field in body
-        let msg_type_field = self.next_field().ok_or(DecodeError::MissingMsgType)?;
+        let msg_type_field = self.try_next_field()?.ok_or(DecodeError::MissingMsgType)?;
         if msg_type_field.tag != 35 {
             return Err(DecodeError::MissingMsgType);
         }
+        let msg_type_range = self.value_range(start, &msg_type_field);
         let msg_type: MsgType = msg_type_field.as_str()?.parse().unwrap();
 
-        // Collect all fields
-        let mut fields: SmallVec<[FieldRef<'a>; 32]> = SmallVec::new();
-        fields.push(begin_string_field);
-        fields.push(body_length_field);
-        fields.push(msg_type_field);
+        push(begin_string_field, begin_string.clone())?;
+        push(body_length_field, body_length_range)?;
+        push(msg_type_field, msg_type_range)?;
 
         // Parse remaining fields until checksum
-        let mut checksum_field: Option<FieldRef<'a>> = None;
-        while let Some(field) = self.next_field() {
+        let mut checksum_field: Option<(usize, FieldRef<'a>)> = None;
+        let mut field_start = self.offset;
+        while let Some(field) = self.try_next_field()? {
             if field.tag == 10 {
-                checksum_field = Some(field);
+                checksum_field = Some((field_start, field));
                 break;
             }
-            fields.push(field);
+            push(field, self.value_range(start, &field))?;
+            field_start = self.offset;
+        }
+
+        // Validate body length if enabled
+        if self.validate_length {
+            let (checksum_start, _) = checksum_field.ok_or(DecodeError::Incomplete)?;
+            let actual = checksum_start
+                .checked_sub(body_start)
+                .ok_or(DecodeError::Incomplete)?;
+            if actual != body_length {
+                return Err(DecodeError::IncorrectBodyLength {
+                    declared: body_length,
+                    actual,
+                });
+            }
+        }
+
+        // Record the checksum of everything before the checksum field
+        if let Some((checksum_start, checksum_ref)) = checksum_field
+            && let Some(declared) = parse_checksum(checksum_ref.value)
+            && let Some(covered) = self.input.get(start..checksum_start)
+        {
+            self.last_checksum = Some((declared, calculate_checksum(covered)));
         }
 
         // Validate checksum if enabled
         if self.validate_checksum {
-            let checksum_ref = checksum_field.ok_or(DecodeError::Incomplete)?;
-            let declared = parse_checksum(checksum_ref.value).ok_or_else(|| {
-                DecodeError::InvalidFieldValue {
+            let (_, checksum_ref) = checksum_field.ok_or(DecodeError::Incomplete)?;
+            if parse_checksum(checksum_ref.value).is_none() {
+                return Err(DecodeError::InvalidFieldValue {
                     tag: 10,
                     reason: "invalid checksum format".to_string(),
-                }
-            })?;
-
-            // Calculate checksum of everything before the checksum field
-            let checksum_start =
-                checksum_ref.value.as_ptr() as usize - self.input.as_ptr() as usize - 3; // "10="
-            let calculated = calculate_checksum(&self.input[start_offset..checksum_start]);
+                });
+            }
+            let (declared, calculated) =
+                self.last_checksum
+                    .ok_or_else(|| DecodeError::InvalidFieldValue {
+                        tag: 10,
+                        reason: "checksum field out of bounds".to_string(),
+                    })?;
 
             if calculated != declared {
                 return Err(DecodeError::ChecksumMismatch {
@@ -142,16 +519,28 @@ impl<'a> Decoder<'a> {
             }
         }
 
-        let body_end = body_start + body_length;
-        let body = body_start..body_end;
+        // Without length validation the declared BodyLength may be anything;
+        // clamp the body to the bytes actually parsed.
+        let body_limit = checksum_field.map_or(self.offset, |(checksum_start, _)| checksum_start);
+        let body_end = body_start.saturating_add(body_length).min(body_limit);
 
-        Ok(RawMessage::new(
-            &self.input[start_offset..self.offset],
+        Ok(Frame {
+            start,
             begin_string,
-            body,
+            body: body_start - start..body_end - start,
             msg_type,
-            fields,
-        ))
+        })
+    }
+
+    /// Returns the range, relative to the message starting at `start`, of
+    /// the value of the field just parsed.
+    ///
+    /// The value ends just before the delimiter that precedes the current
+    /// offset.
+    #[inline]
+    fn value_range(&self, start: usize, field: &FieldRef<'_>) -> Range<usize> {
+        let end = self.offset - 1 - start;
+        end - field.value.len()..end
     }
 
     /// Parses the next field from the buffer.
@@ -160,27 +549,124 @@ impl<'a> Decoder<'a> {
     /// The next field, or `None` if the buffer is exhausted.
     #[inline]
     pub fn next_field(&mut self) -> Option<FieldRef<'a>> {
+        self.try_next_field().ok().flatten()
+    }
+
+    /// Returns an iterator decoding consecutive messages from the current
+    /// position.
+    ///
+    /// Each complete message is decoded with the decoder's validation
+    /// settings. Iteration stops cleanly at the end of the buffer or at a
+    /// trailing partial message, leaving the decoder at its start; the
+    /// number of bytes consumed is then [`offset`](Self::offset), or
+    /// [`Messages::consumed`] while iterating. A message that fails
+    /// validation is yielded as an error and skipped, while a malformed tag
+    /// ends the iteration since the next message cannot be located.
+    #[inline]
+    pub fn decode_all(&mut self) -> Messages<'_, 'a> {
+        Messages {
+            decoder: self,
+            done: false,
+        }
+    }
+
+    /// Returns the offset just past the next CheckSum field, without
+    /// advancing the decoder.
+    ///
+    /// # Returns
+    /// `Ok(None)` if the buffer ends before a CheckSum field.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidTag` if a malformed tag comes first.
+    fn frame_end(&self) -> Result<Option<usize>, DecodeError> {
+        let mut probe = self.clone();
+        while let Some(field) = probe.try_next_field()? {
+            if field.tag == 10 {
+                return Ok(Some(probe.offset));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns an iterator over the fields from the current position.
+    ///
+    /// The iterator works on its own copy of the position, so the decoder is
+    /// not advanced. Iteration stops at the end of the buffer, at an
+    /// incomplete field or at a malformed tag, like
+    /// [`next_field`](Self::next_field).
+    #[inline]
+    #[must_use]
+    pub fn fields(&self) -> Fields<'a> {
+        Fields {
+            decoder: self.clone(),
+        }
+    }
+
+    /// Parses the next field, distinguishing malformed input from missing data.
+    ///
+    /// # Returns
+    /// `None` if the buffer holds no further complete field.
+    ///
+    /// A data field directly following its length field (see
+    /// [`DATA_FIELDS`]) is read as exactly the declared number of bytes, so
+    /// its value may contain the delimiter.
+    ///
+    /// # Errors
+    /// Returns `DecodeError::InvalidTag`, annotated with the field's byte
+    /// offset, if the tag is not a valid number, or
+    /// `DecodeError::InvalidFieldValue`, likewise annotated, if a data field
+    /// is not terminated right after its declared length.
+    fn try_next_field(&mut self) -> Result<Option<FieldRef<'a>>, DecodeError> {
         if self.offset >= self.input.len() {
-            return None;
+            return Ok(None);
         }
 
         let remaining = &self.input[self.offset..];
 
         // Find '=' delimiter using SIMD-accelerated search
-        let eq_pos = memchr(EQUALS, remaining)?;
+        let Some(eq_pos) = memchr(EQUALS, remaining) else {
+            return Ok(None);
+        };
         let tag_bytes = &remaining[..eq_pos];
 
         // Parse tag number
-        let tag = parse_tag(tag_bytes)?;
+        let tag = parse_tag(tag_bytes).ok_or_else(|| {
+            DecodeError::InvalidTag(String::from_utf8_lossy(tag_bytes).into_owned())
+                .at_offset(self.offset)
+        })?;
 
-        // Find SOH delimiter
+        // Find field delimiter (SOH by default)
         let value_start = eq_pos + 1;
-        let soh_pos = memchr(SOH, &remaining[value_start..])?;
-        let value = &remaining[value_start..value_start + soh_pos];
+        let value_len = match self.data_length {
+            Some((data_tag, len)) if data_tag == tag => {
+                let Some(&terminator) = value_start
+                    .checked_add(len)
+                    .and_then(|end| remaining.get(end))
+                else {
+                    return Ok(None);
+                };
+                if terminator != self.delimiter {
+                    return Err(DecodeError::InvalidFieldValue {
+                        tag,
+                        reason: "data field longer than its declared length".to_string(),
+                    }
+                    .at_offset(self.offset));
+                }
+                len
+            }
+            _ => {
+                let Some(soh_pos) = memchr(self.delimiter, &remaining[value_start..]) else {
+                    return Ok(None);
+                };
+                soh_pos
+            }
+        };
+        let value = &remaining[value_start..value_start + value_len];
 
-        self.offset += value_start + soh_pos + 1;
+        self.offset += value_start + value_len + 1;
+        self.data_length = data_length(tag, value);
 
-        Some(FieldRef::new(tag, value))
+        Ok(Some(FieldRef::new(tag, value)))
     }
 
     /// Returns the current offset in the buffer.
@@ -208,9 +694,246 @@ impl<'a> Decoder<'a> {
     #[inline]
     pub fn reset(&mut self) {
         self.offset = 0;
+        self.data_length = None;
+    }
+}
+
+/// Iterator over the fields of a buffer.
+///
+/// Created by [`Decoder::fields`].
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    /// Decoder positioned at the next field.
+    decoder: Decoder<'a>,
+}
+
+impl Fields<'_> {
+    /// Returns the byte offset of the next field in the buffer.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.decoder.offset
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = FieldRef<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = self.decoder.next_field();
+        if field.is_none() {
+            // Stay exhausted after a malformed or incomplete field.
+            self.decoder.offset = self.decoder.input.len();
+        }
+        field
+    }
+}
+
+impl FusedIterator for Fields<'_> {}
+
+/// Iterator over the complete messages of a buffer.
+///
+/// Created by [`Decoder::decode_all`].
+#[derive(Debug)]
+pub struct Messages<'d, 'a> {
+    /// Decoder positioned at the next message.
+    decoder: &'d mut Decoder<'a>,
+    /// Whether iteration has ended.
+    done: bool,
+}
+
+impl Messages<'_, '_> {
+    /// Returns the number of bytes consumed by the messages yielded so far.
+    #[inline]
+    #[must_use]
+    pub const fn consumed(&self) -> usize {
+        self.decoder.offset
+    }
+}
+
+impl<'a> Iterator for Messages<'_, 'a> {
+    type Item = Result<RawMessage<'a>, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.decoder.is_empty() {
+            return None;
+        }
+
+        let end = match self.decoder.frame_end() {
+            Ok(Some(end)) => end,
+            Ok(None) => {
+                // A partial message: leave it for the next read.
+                self.done = true;
+                return None;
+            }
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        let result = self.decoder.decode();
+        if result.is_err() {
+            // Resume at the next message.
+            self.decoder.offset = end;
+        }
+        Some(result)
+    }
+}
+
+impl FusedIterator for Messages<'_, '_> {}
+
+/// Caller-owned field storage for [`Decoder::parse_reusing`].
+///
+/// Stores field offsets rather than borrowed fields, so one buffer can be
+/// reused for messages from different input buffers.
+#[derive(Debug, Clone, Default)]
+pub struct RawMessageBuf {
+    /// Parsed fields: tag and value range within the message.
+    fields: Vec<(u32, Range<usize>)>,
+    /// Range of the BeginString value.
+    begin_string: Range<usize>,
+    /// Range of the message body.
+    body: Range<usize>,
+    /// The parsed message type.
+    msg_type: MsgType,
+}
+
+impl RawMessageBuf {
+    /// Creates an empty buffer that allocates on first use.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty buffer with room for `fields` fields.
+    #[must_use]
+    pub fn with_capacity(fields: usize) -> Self {
+        Self {
+            fields: Vec::with_capacity(fields),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of fields the buffer holds without reallocating.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.fields.capacity()
     }
 }
 
+/// A message decoded by [`Decoder::parse_reusing`].
+///
+/// Field values borrow the input; the field offsets live in the
+/// [`RawMessageBuf`].
+#[derive(Debug, Clone, Copy)]
+pub struct MessageView<'m, 'a> {
+    /// The complete message bytes.
+    buffer: &'a [u8],
+    /// The parsed fields.
+    parsed: &'m RawMessageBuf,
+}
+
+impl<'a> MessageView<'_, 'a> {
+    /// Returns the complete message bytes.
+    #[inline]
+    #[must_use]
+    pub const fn buffer(&self) -> &'a [u8] {
+        self.buffer
+    }
+
+    /// Returns the BeginString value (e.g., "FIX.4.4").
+    #[must_use]
+    pub fn begin_string(&self) -> &'a str {
+        core::str::from_utf8(&self.buffer[self.parsed.begin_string.clone()]).unwrap_or("")
+    }
+
+    /// Returns the message type.
+    #[inline]
+    #[must_use]
+    pub fn msg_type(&self) -> &MsgType {
+        &self.parsed.msg_type
+    }
+
+    /// Returns the message body range.
+    #[inline]
+    #[must_use]
+    pub fn body_range(&self) -> Range<usize> {
+        self.parsed.body.clone()
+    }
+
+    /// Returns the number of fields in the message.
+    #[inline]
+    #[must_use]
+    pub fn field_count(&self) -> usize {
+        self.parsed.fields.len()
+    }
+
+    /// Returns an iterator over all fields, in message order.
+    pub fn fields(&self) -> impl Iterator<Item = FieldRef<'a>> + '_ {
+        let buffer = self.buffer;
+        self.parsed
+            .fields
+            .iter()
+            .map(move |(tag, range)| FieldRef::new(*tag, &buffer[range.clone()]))
+    }
+
+    /// Gets a field by tag number.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    ///
+    /// # Returns
+    /// The first field with the given tag, or `None` if not found.
+    #[must_use]
+    pub fn get_field(&self, tag: u32) -> Option<FieldRef<'a>> {
+        self.fields().find(|field| field.tag == tag)
+    }
+
+    /// Gets a field value as a string.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    ///
+    /// # Returns
+    /// The field value as a string, or `None` if not found or invalid UTF-8.
+    #[must_use]
+    pub fn get_field_str(&self, tag: u32) -> Option<&'a str> {
+        self.get_field(tag).and_then(|field| field.as_str().ok())
+    }
+}
+
+/// Checks that the fields after MsgType start with [`STRICT_HEADER_TAGS`].
+///
+/// # Errors
+/// Returns `DecodeError::HeaderOutOfOrder` on the first mismatching position,
+/// or `DecodeError::MissingRequiredField` if the message ends early.
+fn validate_header_order(tags: impl IntoIterator<Item = u32>) -> Result<(), DecodeError> {
+    let mut tags = tags.into_iter();
+    for &expected in &STRICT_HEADER_TAGS {
+        let found = tags
+            .next()
+            .ok_or(DecodeError::MissingRequiredField { tag: expected })?;
+        if found != expected {
+            return Err(DecodeError::HeaderOutOfOrder { expected, found });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the data tag and length announced by a length field.
+///
+/// # Returns
+/// `None` unless `tag` is the length tag of a [`DATA_FIELDS`] pair and
+/// `value` a valid length.
+fn data_length(tag: u32, value: &[u8]) -> Option<(u32, usize)> {
+    let (_, data_tag) = DATA_FIELDS.iter().find(|(len_tag, _)| *len_tag == tag)?;
+    let len = core::str::from_utf8(value).ok()?.parse().ok()?;
+    Some((*data_tag, len))
+}
+
 /// Parses a tag number from ASCII bytes.
 ///
 /// # Arguments
@@ -269,6 +992,45 @@ mod tests {
         assert!(decoder.next_field().is_none());
     }
 
+    #[test]
+    fn test_fields_iterator_matches_next_field() {
+        let input = b"8=FIX.4.4\x019=12\x0135=0\x0149=A\x0156=B\x01";
+        let decoder = Decoder::new(input);
+
+        let mut fields = decoder.fields();
+        assert_eq!(fields.offset(), 0);
+        let collected: Vec<(u32, &[u8])> = fields.by_ref().map(|f| (f.tag, f.value)).collect();
+        assert_eq!(fields.offset(), input.len());
+        assert_eq!(decoder.offset(), 0);
+
+        let mut manual = Vec::new();
+        let mut decoder = Decoder::new(input);
+        while let Some(field) = decoder.next_field() {
+            manual.push((field.tag, field.value));
+        }
+        assert_eq!(collected, manual);
+
+        let target = Decoder::new(input).fields().find(|f| f.tag == 56);
+        assert_eq!(target.map(|f| f.value), Some(&b"B"[..]));
+        assert_eq!(
+            Decoder::new(input).fields().filter(|f| f.tag > 9).count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_fields_iterator_starts_at_decoder_position() {
+        let input = b"8=FIX.4.4\x019=5\x0135=0\x01x=1\x01";
+        let mut decoder = Decoder::new(input);
+        decoder.next_field();
+
+        let mut fields = decoder.fields();
+        assert_eq!(fields.offset(), 10);
+        let tags: Vec<u32> = fields.by_ref().map(|f| f.tag).collect();
+        assert_eq!(tags, [9, 35]);
+        assert!(fields.next().is_none());
+    }
+
     #[test]
     fn test_decoder_empty() {
         let mut decoder = Decoder::new(b"");
@@ -282,4 +1044,556 @@ mod tests {
         let mut decoder = Decoder::new(input);
         assert!(decoder.next_field().is_none());
     }
+
+    /// Appends `10=NNN` and the delimiter to `prefix`, computing the checksum.
+    fn with_checksum(prefix: &[u8], delimiter: u8) -> Vec<u8> {
+        let mut message = prefix.to_vec();
+        let checksum = calculate_checksum(prefix);
+        message.extend_from_slice(format!("10={:03}", checksum).as_bytes());
+        message.push(delimiter);
+        message
+    }
+
+    #[test]
+    fn test_decode_data_fields_with_embedded_soh() {
+        let body = b"35=D\x0190=5\x0191=ab\x01cd\x0155=IBM\x0193=3\x0189=\x01=\x01\x01";
+        let mut prefix = format!("8=FIX.4.4\x019={}\x01", body.len()).into_bytes();
+        prefix.extend_from_slice(body);
+        let input = with_checksum(&prefix, SOH);
+
+        let msg = Decoder::new(&input)
+            .with_length_validation(true)
+            .decode()
+            .unwrap();
+        assert_eq!(msg.secure_data(), Some(&b"ab\x01cd"[..]));
+        assert_eq!(msg.get_field(91).unwrap().value, b"ab\x01cd");
+        assert_eq!(msg.get_field_str(55), Some("IBM"));
+        assert_eq!(msg.signature(), Some(&b"\x01=\x01"[..]));
+        assert_eq!(
+            msg.fields().map(|f| f.tag).collect::<Vec<_>>(),
+            [8, 9, 35, 90, 91, 55, 93, 89]
+        );
+    }
+
+    #[test]
+    fn test_decode_data_field_longer_than_declared() {
+        let body = b"35=D\x0190=2\x0191=abc\x01";
+        let mut prefix = format!("8=FIX.4.4\x019={}\x01", body.len()).into_bytes();
+        prefix.extend_from_slice(body);
+        let input = with_checksum(&prefix, SOH);
+
+        let err = Decoder::new(&input).decode().unwrap_err();
+        assert_eq!(err.offset(), Some(prefix.len() - 7));
+        assert!(matches!(
+            err,
+            DecodeError::AtOffset { ref source, .. }
+                if matches!(**source, DecodeError::InvalidFieldValue { tag: 91, .. })
+        ));
+
+        // Data fields are only length-delimited right after their length field.
+        let body = b"35=D\x0191=ab\x0190=2\x01";
+        let mut prefix = format!("8=FIX.4.4\x019={}\x01", body.len()).into_bytes();
+        prefix.extend_from_slice(body);
+        let input = with_checksum(&prefix, SOH);
+        let msg = Decoder::new(&input).decode().unwrap();
+        assert_eq!(msg.get_field_str(91), Some("ab"));
+    }
+
+    #[test]
+    fn test_decode_pipe_delimited() {
+        let input = with_checksum(b"8=FIX.4.4|9=12|35=0|112=AB|", b'|');
+        let mut decoder = Decoder::new(&input).with_delimiter(b'|');
+
+        let msg = decoder.decode().unwrap();
+        assert_eq!(msg.begin_string(), "FIX.4.4");
+        assert_eq!(msg.msg_type(), &MsgType::Heartbeat);
+        assert_eq!(msg.get_field_str(112), Some("AB"));
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn test_decode_soh_unaffected() {
+        let input = with_checksum(b"8=FIX.4.4\x019=12\x0135=0\x01112=AB\x01", SOH);
+
+        let msg = Decoder::new(&input).decode().unwrap();
+        assert_eq!(msg.get_field_str(112), Some("AB"));
+
+        // A pipe-configured decoder does not split on SOH.
+        let mut decoder = Decoder::new(&input).with_delimiter(b'|');
+        assert!(decoder.decode().is_err());
+    }
+
+    #[test]
+    fn test_strict_accepts_ordered_header() {
+        let input = with_checksum(
+            b"8=FIX.4.4\x019=51\x0135=0\x0149=SENDER\x0156=TARGET\x0134=1\x0152=20260127-10:00:00\x01",
+            SOH,
+        );
+
+        let msg = Decoder::new(&input).strict(true).decode().unwrap();
+        assert_eq!(msg.get_field_str(34), Some("1"));
+    }
+
+    #[test]
+    fn test_strict_rejects_out_of_order_header() {
+        // MsgSeqNum before MsgType.
+        let input = with_checksum(
+            b"8=FIX.4.4\x019=51\x0134=1\x0135=0\x0149=SENDER\x0156=TARGET\x0152=20260127-10:00:00\x01",
+            SOH,
+        );
+        assert!(Decoder::new(&input).strict(true).decode().is_err());
+
+        // MsgSeqNum before SenderCompID: lenient accepts, strict rejects.
+        let input = with_checksum(
+            b"8=FIX.4.4\x019=51\x0135=0\x0134=1\x0149=SENDER\x0156=TARGET\x0152=20260127-10:00:00\x01",
+            SOH,
+        );
+        assert!(Decoder::new(&input).decode().is_ok());
+        assert_eq!(
+            Decoder::new(&input).strict(true).decode().unwrap_err(),
+            DecodeError::HeaderOutOfOrder {
+                expected: 49,
+                found: 34
+            }
+        );
+    }
+
+    #[test]
+    fn test_strict_rejects_missing_header_field() {
+        let input = with_checksum(b"8=FIX.4.4\x019=15\x0135=0\x0149=SENDER\x01", SOH);
+        assert_eq!(
+            Decoder::new(&input).strict(true).decode().unwrap_err(),
+            DecodeError::MissingRequiredField { tag: 56 }
+        );
+    }
+
+    #[test]
+    fn test_decode_malformed_tag_reports_offset() {
+        let input = b"8=FIX.4.4\x019=20\x0135=0\x0134=1\x013x=ABC\x0110=000\x01";
+        let err = Decoder::new(input).decode().unwrap_err();
+
+        // "3x=ABC" starts after "8=FIX.4.4|9=20|35=0|34=1|".
+        assert_eq!(err.offset(), Some(25));
+        assert_eq!(err.root(), &DecodeError::InvalidTag("3x".to_string()));
+        assert_eq!(err.to_string(), "invalid tag format: 3x at byte offset 25");
+    }
+
+    #[test]
+    fn test_decode_truncated_field_is_incomplete() {
+        let input = b"8=FIX.4.4\x019=20\x0135=0\x0134=1\x0152=2026";
+        assert_eq!(
+            Decoder::new(input).decode().unwrap_err(),
+            DecodeError::Incomplete
+        );
+    }
+
+    #[test]
+    fn test_decode_reframed_owned_message() {
+        let input = with_checksum(
+            b"8=FIX.4.4\x019=51\x0135=0\x0149=SENDER\x0156=TARGET\x0134=1\x0152=20260127-10:00:00\x01",
+            SOH,
+        );
+        let mut owned = Decoder::new(&input).decode().unwrap().to_owned();
+
+        // Mark as a possible duplicate for resend; framing is now stale.
+        owned.set_field(43, b"Y");
+        assert!(Decoder::new(owned.as_bytes()).decode().is_err());
+
+        owned.reframe().unwrap();
+        let msg = Decoder::new(owned.as_bytes()).decode().unwrap();
+        assert_eq!(msg.get_field_str(43), Some("Y"));
+        assert_eq!(msg.get_field_str(9), Some("56"));
+        assert_eq!(msg.body_range().len(), 56);
+    }
+
+    #[test]
+    fn test_owned_message_as_raw_matches_decoded() {
+        let input = with_checksum(
+            b"8=FIX.4.4\x019=51\x0135=0\x0149=SENDER\x0156=TARGET\x0134=7\x0152=20260127-10:00:00\x01",
+            SOH,
+        );
+        let decoded = Decoder::new(&input).decode().unwrap();
+        let owned = decoded.to_owned();
+        let view = owned.as_raw();
+
+        assert_eq!(
+            view.get_field_as::<u64>(34).unwrap(),
+            decoded.get_field_as::<u64>(34).unwrap()
+        );
+        assert_eq!(view.body_range(), decoded.body_range());
+        assert_eq!(view.begin_string(), decoded.begin_string());
+        assert_eq!(view.field_count(), decoded.field_count());
+    }
+
+    /// Builds a framed message with `count` body fields after MsgType.
+    fn message_with_fields(count: usize) -> Vec<u8> {
+        let mut body = b"35=0\x01".to_vec();
+        for i in 0..count {
+            body.extend_from_slice(format!("{}=V\x01", 5000 + i).as_bytes());
+        }
+        let mut input = format!("8=FIX.4.4\x019={}\x01", body.len()).into_bytes();
+        input.extend_from_slice(&body);
+        with_checksum(&input, SOH)
+    }
+
+    #[test]
+    fn test_decode_into_array() {
+        // 3 framing fields + 37 body fields, more than SmallVec's inline 32.
+        let input = message_with_fields(37);
+        let mut fields = [FieldRef::new(0, b""); 64];
+
+        let count = Decoder::new(&input).decode_into(&mut fields).unwrap();
+        assert_eq!(count, 40);
+        assert_eq!(fields[0].tag, 8);
+        assert_eq!(fields[2].as_str().unwrap(), "0");
+        assert_eq!(fields[39].tag, 5036);
+        assert_eq!(fields[39].value, b"V");
+    }
+
+    #[test]
+    fn test_decode_rejects_too_many_fields() {
+        let input = message_with_fields(37);
+        assert!(Decoder::new(&input).with_max_fields(40).decode().is_ok());
+        assert_eq!(
+            Decoder::new(&input)
+                .with_max_fields(39)
+                .decode()
+                .unwrap_err(),
+            DecodeError::TooManyFields { max: 39 }
+        );
+
+        // The smaller of the slice and the configured cap applies.
+        let mut fields = [FieldRef::new(0, b""); 64];
+        assert_eq!(
+            Decoder::new(&input)
+                .with_max_fields(20)
+                .decode_into(&mut fields)
+                .unwrap_err(),
+            DecodeError::TooManyFields { max: 20 }
+        );
+    }
+
+    #[test]
+    fn test_decode_validates_body_length() {
+        // Actual body length is 12.
+        let short = with_checksum(b"8=FIX.4.4\x019=10\x0135=0\x01112=AB\x01", SOH);
+        let long = with_checksum(b"8=FIX.4.4\x019=15\x0135=0\x01112=AB\x01", SOH);
+
+        assert!(Decoder::new(&short).decode().is_ok());
+        assert_eq!(
+            Decoder::new(&short)
+                .with_length_validation(true)
+                .decode()
+                .unwrap_err(),
+            DecodeError::IncorrectBodyLength {
+                declared: 10,
+                actual: 12
+            }
+        );
+        assert_eq!(
+            Decoder::new(&long)
+                .with_length_validation(true)
+                .decode()
+                .unwrap_err(),
+            DecodeError::IncorrectBodyLength {
+                declared: 15,
+                actual: 12
+            }
+        );
+
+        let exact = with_checksum(b"8=FIX.4.4\x019=12\x0135=0\x01112=AB\x01", SOH);
+        assert!(
+            Decoder::new(&exact)
+                .with_length_validation(true)
+                .decode()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_decode_into_reports_overflow() {
+        let input = message_with_fields(37);
+        let mut fields = [FieldRef::new(0, b""); 16];
+
+        assert_eq!(
+            Decoder::new(&input).decode_into(&mut fields).unwrap_err(),
+            DecodeError::TooManyFields { max: 16 }
+        );
+    }
+
+    #[test]
+    fn test_decode_fields_only_fragment() {
+        let mut decoder = Decoder::new(b"35=D|55=IBM|54=1").with_delimiter(b'|');
+        let msg = decoder.decode_fields_only().unwrap();
+
+        assert_eq!(msg.msg_type(), &MsgType::NewOrderSingle);
+        assert_eq!(msg.field_count(), 3);
+        assert_eq!(msg.get_field_str(55), Some("IBM"));
+        assert_eq!(msg.get_field_str(54), Some("1"));
+        assert_eq!(msg.begin_string(), "");
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn test_decode_fields_only_ignores_framing() {
+        // Wrong BodyLength and CheckSum, and header fields out of order.
+        let input = b"8=FIX.4.4|9=999|55=IBM|35=0|10=000|";
+        let msg = Decoder::new(input)
+            .with_delimiter(b'|')
+            .strict(true)
+            .decode_fields_only()
+            .unwrap();
+        assert_eq!(msg.begin_string(), "FIX.4.4");
+        assert_eq!(msg.msg_type(), &MsgType::Heartbeat);
+        assert_eq!(msg.get_field_str(10), Some("000"));
+
+        let msg = Decoder::new(b"55=IBM|")
+            .with_delimiter(b'|')
+            .decode_fields_only()
+            .unwrap();
+        assert_eq!(msg.msg_type(), &MsgType::Custom(String::new()));
+
+        let mut decoder = Decoder::new(b"55=IBM|garbage").with_delimiter(b'|');
+        assert!(matches!(
+            decoder.decode_fields_only(),
+            Err(DecodeError::Incomplete)
+        ));
+        assert!(matches!(
+            Decoder::new(b"X=1|")
+                .with_delimiter(b'|')
+                .decode_fields_only(),
+            Err(DecodeError::AtOffset { offset: 0, .. })
+        ));
+        assert!(matches!(
+            Decoder::new(b"1=a|2=b|3=c")
+                .with_delimiter(b'|')
+                .with_max_fields(2)
+                .decode_fields_only(),
+            Err(DecodeError::TooManyFields { max: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_parse_reusing_matches_decode() {
+        use ironfix_core::message::MessageBuilder;
+
+        let first = MessageBuilder::new("FIX.4.4", MsgType::NewOrderSingle)
+            .field(49, "SENDER")
+            .field(56, "TARGET")
+            .field(34, "1")
+            .field(52, "20260127-10:00:00.000")
+            .field(55, "IBM")
+            .build();
+        let second = MessageBuilder::new("FIX.4.2", MsgType::Heartbeat)
+            .field(49, "SENDER")
+            .field(56, "TARGET")
+            .field(34, "2")
+            .field(52, "20260127-10:00:01.000")
+            .build();
+        let mut buf = RawMessageBuf::new();
+
+        for message in [&first, &second, &first] {
+            let expected = Decoder::new(message.as_bytes()).decode().unwrap();
+            let view = Decoder::new(message.as_bytes())
+                .strict(true)
+                .parse_reusing(&mut buf)
+                .unwrap();
+            assert_eq!(view.msg_type(), expected.msg_type());
+            assert_eq!(view.begin_string(), expected.begin_string());
+            assert_eq!(view.buffer(), expected.buffer());
+            assert_eq!(&view.body_range(), expected.body_range());
+            assert_eq!(view.field_count(), expected.field_count());
+            assert!(
+                view.fields()
+                    .zip(expected.fields())
+                    .all(|(a, b)| a.tag == b.tag && a.value == b.value)
+            );
+        }
+        let capacity = buf.capacity();
+        let view = Decoder::new(second.as_bytes())
+            .parse_reusing(&mut buf)
+            .unwrap();
+        assert_eq!(view.get_field_str(34), Some("2"));
+        assert!(view.get_field(55).is_none());
+        assert_eq!(buf.capacity(), capacity);
+
+        let mut decoder = Decoder::new(b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01")
+            .with_checksum_validation(false)
+            .strict(true);
+        assert!(matches!(
+            decoder.parse_reusing(&mut buf),
+            Err(DecodeError::MissingRequiredField { tag: 49 })
+        ));
+    }
+
+    #[test]
+    fn test_decode_all_stops_at_partial_message() {
+        let messages = [
+            with_checksum(b"8=FIX.4.4\x019=5\x0135=0\x01", SOH),
+            with_checksum(b"8=FIX.4.4\x019=12\x0135=1\x01112=AB\x01", SOH),
+            with_checksum(b"8=FIX.4.4\x019=5\x0135=5\x01", SOH),
+        ];
+        let complete: usize = messages.iter().map(Vec::len).sum();
+        let mut input = messages.concat();
+        input.extend_from_slice(b"8=FIX.4.4\x019=5\x0135=0\x0110=1");
+
+        let mut decoder = Decoder::new(&input).with_length_validation(true);
+        let mut iter = decoder.decode_all();
+        let types: Vec<MsgType> = iter
+            .by_ref()
+            .map(|message| message.unwrap().msg_type().clone())
+            .collect();
+        assert_eq!(
+            types,
+            [MsgType::Heartbeat, MsgType::TestRequest, MsgType::Logout]
+        );
+        assert_eq!(iter.consumed(), complete);
+        assert!(iter.next().is_none());
+        assert_eq!(decoder.offset(), complete);
+    }
+
+    #[test]
+    fn test_decode_all_skips_invalid_message() {
+        let mut corrupt = with_checksum(b"8=FIX.4.4\x019=5\x0135=1\x01", SOH);
+        let len = corrupt.len();
+        corrupt[len - 2] = if corrupt[len - 2] == b'0' { b'1' } else { b'0' };
+        let input = [corrupt, with_checksum(b"8=FIX.4.4\x019=5\x0135=0\x01", SOH)].concat();
+
+        let mut decoder = Decoder::new(&input);
+        let results: Vec<_> = decoder.decode_all().collect();
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            results[0],
+            Err(DecodeError::ChecksumMismatch { .. })
+        ));
+        assert_eq!(results[1].as_ref().unwrap().msg_type(), &MsgType::Heartbeat);
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn test_decode_malformed_input_does_not_panic() {
+        let cases: [&[u8]; 9] = [
+            b"",
+            b"8=",
+            b"8=FIX.4.4\x019=",
+            b"8=FIX.4.4\x019=\x01",
+            b"8=FIX.4.4\x019=5\x0135=",
+            b"10=000\x01",
+            b"10=000\x018=FIX.4.4\x019=5\x0135=0\x0110=000\x01",
+            b"8=FIX.4.4\x019=99999999999999999999999\x0135=0\x0110=000\x01",
+            b"8=FIX.4.4\x019=18446744073709551615\x0135=0\x0110=000\x01",
+        ];
+        for input in cases {
+            let _ = Decoder::new(input).decode();
+            let _ = Decoder::new(input)
+                .with_length_validation(true)
+                .with_checksum_validation(false)
+                .decode();
+            let _ = Decoder::new(input).strict(true).decode();
+        }
+    }
+
+    #[test]
+    fn test_last_checksum_reports_declared_and_computed() {
+        let valid = with_checksum(b"8=FIX.4.4\x019=5\x0135=0\x01", SOH);
+        let computed = calculate_checksum(&valid[..valid.len() - 7]);
+        let wrong = (computed + 1) % 255;
+        let mut input = valid[..valid.len() - 7].to_vec();
+        input.extend_from_slice(format!("10={:03}\x01", wrong).as_bytes());
+
+        let mut decoder = Decoder::new(&input).with_checksum_validation(false);
+        assert_eq!(decoder.last_checksum(), None);
+        decoder.decode().unwrap();
+        assert_eq!(decoder.last_checksum(), Some((wrong, computed)));
+
+        let mut decoder = Decoder::new(&input);
+        assert_eq!(
+            decoder.decode().unwrap_err(),
+            DecodeError::ChecksumMismatch {
+                calculated: computed,
+                declared: wrong,
+            }
+        );
+        assert_eq!(decoder.last_checksum(), Some((wrong, computed)));
+
+        let mut decoder = Decoder::new(&valid);
+        decoder.decode().unwrap();
+        assert_eq!(decoder.last_checksum(), Some((computed, computed)));
+    }
+
+    #[test]
+    fn test_decode_short_malicious_message() {
+        let input = b"8=\x019=0\x0135=\x0110=000\x01";
+        assert!(matches!(
+            Decoder::new(input)
+                .with_length_validation(true)
+                .decode()
+                .unwrap_err(),
+            DecodeError::IncorrectBodyLength { declared: 0, .. }
+        ));
+        assert!(matches!(
+            Decoder::new(input).decode().unwrap_err(),
+            DecodeError::ChecksumMismatch { declared: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_decode_checksum_with_padded_tag() {
+        // A zero-padded CheckSum tag must not shift the checksummed range.
+        let framed = with_checksum(b"8=FIX.4.4\x019=5\x0135=0\x01", SOH);
+        let (prefix, checksum) = framed.split_at(framed.len() - 7);
+        let padded = [prefix, b"00", checksum].concat();
+
+        let msg = Decoder::new(&padded)
+            .with_length_validation(true)
+            .decode()
+            .unwrap();
+        assert_eq!(msg.msg_type(), &MsgType::Heartbeat);
+    }
+
+    #[test]
+    fn test_decode_every_prefix_does_not_panic() {
+        let input = with_checksum(
+            b"8=FIX.4.4\x019=51\x0135=0\x0149=SENDER\x0156=TARGET\x0134=1\x0152=20260127-10:00:00\x01",
+            SOH,
+        );
+        for end in 0..input.len() {
+            let prefix = &input[..end];
+            assert!(Decoder::new(prefix).decode().is_err());
+            assert!(
+                Decoder::new(prefix)
+                    .with_length_validation(true)
+                    .decode()
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_unvalidated_body_length_is_clamped() {
+        let input = b"8=FIX.4.4\x019=18446744073709551615\x0135=0\x0110=000\x01";
+        let msg = Decoder::new(input)
+            .with_checksum_validation(false)
+            .decode()
+            .unwrap();
+
+        assert_eq!(msg.body_range(), &(33..38));
+        assert_eq!(msg.to_owned().get_field_str(35), Some("0"));
+    }
+
+    #[test]
+    fn test_decode_ranges_relative_to_message() {
+        let first = with_checksum(b"8=FIX.4.4\x019=5\x0135=0\x01", SOH);
+        let second = with_checksum(b"8=FIX.4.2\x019=5\x0135=1\x01", SOH);
+        let input = [first.as_slice(), second.as_slice()].concat();
+        let mut decoder = Decoder::new(&input).with_length_validation(true);
+
+        decoder.decode().unwrap();
+        let msg = decoder.decode().unwrap();
+        assert_eq!(msg.begin_string(), "FIX.4.2");
+        assert_eq!(
+            &msg.to_owned().as_bytes()[msg.body_range().clone()],
+            b"35=1\x01"
+        );
+    }
 }
@@ -10,21 +10,67 @@
 //! standard tag=value format.
 
 use crate::checksum::{calculate_checksum, format_checksum};
+use alloc::vec::Vec;
 use bytes::{BufMut, BytesMut};
+use ironfix_core::error::EncodeError;
+use ironfix_core::field::FixField;
+use ironfix_core::message::MsgType;
+use ironfix_core::types::{CompId, SeqNum, Timestamp};
 
 /// SOH (Start of Header) delimiter used in FIX messages.
 pub const SOH: u8 = 0x01;
 
+/// Returns true for the framing tags the encoder writes itself:
+/// BeginString (8), BodyLength (9) and CheckSum (10).
+#[inline]
+const fn is_framing_tag(tag: u32) -> bool {
+    matches!(tag, 8..=10)
+}
+
+/// Optional routing fields of the standard header.
+///
+/// Absent values are not written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RoutingIds<'a> {
+    /// SenderSubID (tag 50).
+    pub sender_sub_id: Option<&'a str>,
+    /// SenderLocationID (tag 142).
+    pub sender_location_id: Option<&'a str>,
+    /// TargetSubID (tag 57).
+    pub target_sub_id: Option<&'a str>,
+    /// TargetLocationID (tag 143).
+    pub target_location_id: Option<&'a str>,
+}
+
 /// FIX message encoder.
 ///
 /// The encoder builds FIX messages by appending fields in tag=value format.
 /// It handles BeginString, BodyLength, and Checksum fields automatically.
+///
+/// The `put_*` methods always append. The `try_put_*` methods first check
+/// the value against the configured length limits (see
+/// [`with_max_field_len`](Self::with_max_field_len) and
+/// [`with_field_limit`](Self::with_field_limit)) and return
+/// `EncodeError::FieldTooLong` instead of appending an over-long value.
+///
+/// BeginString (8), BodyLength (9) and CheckSum (10) are written by
+/// [`finish`](Self::finish) and must not be appended to the body. The
+/// `try_put_*` methods reject them, [`try_finish`](Self::try_finish) reports
+/// one appended through `put_*`, and `finish` debug-asserts that none was.
 #[derive(Debug)]
 pub struct Encoder {
     /// Buffer for the message body (between BodyLength and Checksum).
     body: BytesMut,
     /// The BeginString value (e.g., "FIX.4.4").
     begin_string: &'static str,
+    /// Byte terminating each field.
+    delimiter: u8,
+    /// Maximum value length for tags without their own limit.
+    max_field_len: Option<usize>,
+    /// Per-tag maximum value lengths.
+    field_limits: Vec<(u32, usize)>,
+    /// First framing tag appended to the body, if any.
+    framing_tag: Option<u32>,
 }
 
 impl Encoder {
@@ -34,10 +80,7 @@ impl Encoder {
     /// * `begin_string` - The FIX version string (e.g., "FIX.4.4")
     #[must_use]
     pub fn new(begin_string: &'static str) -> Self {
-        Self {
-            body: BytesMut::with_capacity(256),
-            begin_string,
-        }
+        Self::with_capacity(begin_string, 256)
     }
 
     /// Creates a new encoder with pre-allocated capacity.
@@ -50,7 +93,72 @@ impl Encoder {
         Self {
             body: BytesMut::with_capacity(capacity),
             begin_string,
+            delimiter: SOH,
+            max_field_len: None,
+            field_limits: Vec::new(),
+            framing_tag: None,
+        }
+    }
+
+    /// Sets the byte terminating each field.
+    ///
+    /// Defaults to [`SOH`]. A visible delimiter such as `b'|'` produces
+    /// human-readable output for logs and fixtures. BodyLength and Checksum are
+    /// computed over the emitted bytes, so the message stays self-consistent and
+    /// decodes with a decoder configured for the same delimiter.
+    ///
+    /// Applies to fields appended after this call.
+    ///
+    /// # Arguments
+    /// * `delimiter` - The field delimiter byte
+    #[must_use]
+    pub const fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the maximum value length checked by the `try_put_*` methods.
+    ///
+    /// Applies to every tag without its own
+    /// [`with_field_limit`](Self::with_field_limit). Unlimited by default.
+    ///
+    /// # Arguments
+    /// * `max_len` - The maximum value length in bytes
+    #[must_use]
+    pub const fn with_max_field_len(mut self, max_len: usize) -> Self {
+        self.max_field_len = Some(max_len);
+        self
+    }
+
+    /// Sets the maximum value length of one tag, checked by the `try_put_*`
+    /// methods.
+    ///
+    /// Useful for venue limits such as a maximum ClOrdID (tag 11) length.
+    /// Overrides [`with_max_field_len`](Self::with_max_field_len) for this tag.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `max_len` - The maximum value length in bytes
+    #[must_use]
+    pub fn with_field_limit(mut self, tag: u32, max_len: usize) -> Self {
+        match self.field_limits.iter_mut().find(|(t, _)| *t == tag) {
+            Some((_, limit)) => *limit = max_len,
+            None => self.field_limits.push((tag, max_len)),
         }
+        self
+    }
+
+    /// Returns the maximum value length of a tag, if limited.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    #[must_use]
+    pub fn field_limit(&self, tag: u32) -> Option<usize> {
+        self.field_limits
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|&(_, limit)| limit)
+            .or(self.max_field_len)
     }
 
     /// Appends a field with a string value.
@@ -116,13 +224,199 @@ impl Encoder {
     /// * `value` - The field value bytes
     #[inline]
     pub fn put_raw(&mut self, tag: u32, value: &[u8]) {
+        if is_framing_tag(tag) && self.framing_tag.is_none() {
+            self.framing_tag = Some(tag);
+        }
         let mut tag_buf = itoa::Buffer::new();
         let tag_str = tag_buf.format(tag);
 
         self.body.put_slice(tag_str.as_bytes());
         self.body.put_u8(b'=');
         self.body.put_slice(value);
-        self.body.put_u8(SOH);
+        self.body.put_u8(self.delimiter);
+    }
+
+    /// Appends a data field preceded by its length field.
+    ///
+    /// The data may contain the delimiter; the decoder reads it by its
+    /// declared length when the pair is listed in
+    /// [`DATA_FIELDS`](crate::decoder::DATA_FIELDS).
+    ///
+    /// # Arguments
+    /// * `len_tag` - The length field tag, e.g. 95 for RawDataLength
+    /// * `data_tag` - The data field tag, e.g. 96 for RawData
+    /// * `data` - The data bytes
+    pub fn put_data(&mut self, len_tag: u32, data_tag: u32, data: &[u8]) {
+        self.put_uint(len_tag, data.len() as u64);
+        self.put_raw(data_tag, data);
+    }
+
+    /// Appends XMLDataLen (tag 212) and XMLData (tag 213).
+    ///
+    /// # Arguments
+    /// * `xml` - The XML payload
+    #[inline]
+    pub fn put_xml_data(&mut self, xml: &[u8]) {
+        self.put_data(212, 213, xml);
+    }
+
+    /// Appends a field with a string value, checking its length first.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `value` - The field value
+    ///
+    /// # Errors
+    /// Returns `EncodeError::FramingFieldInBody` for tags 8, 9 and 10, or
+    /// `EncodeError::FieldTooLong` if the value exceeds the tag's limit;
+    /// nothing is appended in either case.
+    #[inline]
+    pub fn try_put_str(&mut self, tag: u32, value: &str) -> Result<(), EncodeError> {
+        self.try_put_raw(tag, value.as_bytes())
+    }
+
+    /// Appends a field with an integer value, checking its length first.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `value` - The field value
+    ///
+    /// # Errors
+    /// Returns `EncodeError::FramingFieldInBody` for tags 8, 9 and 10, or
+    /// `EncodeError::FieldTooLong` if the formatted value exceeds the tag's
+    /// limit; nothing is appended in either case.
+    #[inline]
+    pub fn try_put_int(&mut self, tag: u32, value: i64) -> Result<(), EncodeError> {
+        let mut buf = itoa::Buffer::new();
+        self.try_put_raw(tag, buf.format(value).as_bytes())
+    }
+
+    /// Appends a field with an unsigned integer value, checking its length
+    /// first.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `value` - The field value
+    ///
+    /// # Errors
+    /// Returns `EncodeError::FramingFieldInBody` for tags 8, 9 and 10, or
+    /// `EncodeError::FieldTooLong` if the formatted value exceeds the tag's
+    /// limit; nothing is appended in either case.
+    #[inline]
+    pub fn try_put_uint(&mut self, tag: u32, value: u64) -> Result<(), EncodeError> {
+        let mut buf = itoa::Buffer::new();
+        self.try_put_raw(tag, buf.format(value).as_bytes())
+    }
+
+    /// Appends a field with raw bytes, checking its length first.
+    ///
+    /// # Arguments
+    /// * `tag` - The field tag number
+    /// * `value` - The field value bytes
+    ///
+    /// # Errors
+    /// Returns `EncodeError::FramingFieldInBody` for tags 8, 9 and 10, or
+    /// `EncodeError::FieldTooLong` if the value exceeds the tag's limit;
+    /// nothing is appended in either case.
+    pub fn try_put_raw(&mut self, tag: u32, value: &[u8]) -> Result<(), EncodeError> {
+        if is_framing_tag(tag) {
+            return Err(EncodeError::FramingFieldInBody { tag });
+        }
+        if let Some(max_length) = self.field_limit(tag)
+            && value.len() > max_length
+        {
+            return Err(EncodeError::FieldTooLong {
+                tag,
+                length: value.len(),
+                max_length,
+            });
+        }
+        self.put_raw(tag, value);
+        Ok(())
+    }
+
+    /// Appends the standard session header fields.
+    ///
+    /// Writes MsgType (35), SenderCompID (49), TargetCompID (56), MsgSeqNum
+    /// (34) and SendingTime (52, millisecond precision) in that order. Call
+    /// this first, before any body field.
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type
+    /// * `sender` - The sender CompID
+    /// * `target` - The target CompID
+    /// * `seq` - The message sequence number
+    /// * `sending_time` - The sending time
+    pub fn put_header(
+        &mut self,
+        msg_type: &MsgType,
+        sender: &CompId,
+        target: &CompId,
+        seq: SeqNum,
+        sending_time: Timestamp,
+    ) {
+        self.put_header_with_routing(
+            msg_type,
+            sender,
+            target,
+            &RoutingIds::default(),
+            seq,
+            sending_time,
+        );
+    }
+
+    /// Appends the standard session header fields, including routing fields.
+    ///
+    /// Like [`put_header`](Self::put_header), with the present
+    /// [`RoutingIds`] written after SendingTime in the order SenderSubID
+    /// (50), SenderLocationID (142), TargetSubID (57), TargetLocationID (143),
+    /// so the header still satisfies [`Decoder::strict`](crate::Decoder::strict).
+    ///
+    /// # Arguments
+    /// * `msg_type` - The message type
+    /// * `sender` - The sender CompID
+    /// * `target` - The target CompID
+    /// * `routing` - The sub and location IDs
+    /// * `seq` - The message sequence number
+    /// * `sending_time` - The sending time
+    pub fn put_header_with_routing(
+        &mut self,
+        msg_type: &MsgType,
+        sender: &CompId,
+        target: &CompId,
+        routing: &RoutingIds<'_>,
+        seq: SeqNum,
+        sending_time: Timestamp,
+    ) {
+        self.put_str(35, msg_type.as_str());
+        self.put_str(49, sender.as_str());
+        self.put_str(56, target.as_str());
+        self.put_uint(34, seq.value());
+        self.put_str(52, sending_time.format_millis().as_str());
+        let routing_fields = [
+            (50, routing.sender_sub_id),
+            (142, routing.sender_location_id),
+            (57, routing.target_sub_id),
+            (143, routing.target_location_id),
+        ];
+        for (tag, value) in routing_fields {
+            if let Some(value) = value {
+                self.put_str(tag, value);
+            }
+        }
+    }
+
+    /// Appends a typed field using its [`FixField`] implementation.
+    ///
+    /// Writes `F::TAG=` followed by the bytes produced by `F::encode` and the delimiter.
+    ///
+    /// # Arguments
+    /// * `value` - The typed field value
+    #[inline]
+    pub fn put_field<F: FixField>(&mut self, value: &F::Value) {
+        let mut value_buf = Vec::with_capacity(16);
+        F::encode(value, &mut value_buf);
+        self.put_raw(F::TAG, &value_buf);
     }
 
     /// Finalizes the message and returns the complete encoded bytes.
@@ -131,23 +425,49 @@ impl Encoder {
     /// 1. Prepends BeginString (tag 8) and BodyLength (tag 9)
     /// 2. Appends Checksum (tag 10)
     ///
+    /// Debug builds assert that no framing field (tags 8, 9, 10) was
+    /// appended to the body; use [`try_finish`](Self::try_finish) to check
+    /// in every build.
+    ///
     /// # Returns
     /// The complete FIX message as bytes.
     #[must_use]
     pub fn finish(self) -> BytesMut {
+        debug_assert!(
+            self.framing_tag.is_none(),
+            "framing field tag {:?} written to the message body",
+            self.framing_tag
+        );
+        self.frame()
+    }
+
+    /// Finalizes the message, checking that the body holds no framing field.
+    ///
+    /// # Errors
+    /// Returns `EncodeError::FramingFieldInBody` with the first of tags 8, 9
+    /// or 10 appended to the body, which would otherwise be framed twice.
+    pub fn try_finish(self) -> Result<BytesMut, EncodeError> {
+        match self.framing_tag {
+            Some(tag) => Err(EncodeError::FramingFieldInBody { tag }),
+            None => Ok(self.frame()),
+        }
+    }
+
+    /// Prepends BeginString and BodyLength and appends Checksum.
+    fn frame(self) -> BytesMut {
         let body_len = self.body.len();
 
         // Build header: 8=BeginString|9=BodyLength|
         let mut header = BytesMut::with_capacity(32);
         header.put_slice(b"8=");
         header.put_slice(self.begin_string.as_bytes());
-        header.put_u8(SOH);
+        header.put_u8(self.delimiter);
         header.put_slice(b"9=");
 
         let mut len_buf = itoa::Buffer::new();
         let len_str = len_buf.format(body_len);
         header.put_slice(len_str.as_bytes());
-        header.put_u8(SOH);
+        header.put_u8(self.delimiter);
 
         // Combine header and body
         let mut message = BytesMut::with_capacity(header.len() + body_len + 8);
@@ -160,7 +480,7 @@ impl Encoder {
 
         message.put_slice(b"10=");
         message.put_slice(&checksum_bytes);
-        message.put_u8(SOH);
+        message.put_u8(self.delimiter);
 
         message
     }
@@ -176,6 +496,7 @@ impl Encoder {
     #[inline]
     pub fn clear(&mut self) {
         self.body.clear();
+        self.framing_tag = None;
     }
 }
 
@@ -188,6 +509,28 @@ impl Default for Encoder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ironfix_core::error::DecodeError;
+
+    /// Price field (tag 44) used to exercise typed encoding.
+    struct Price;
+
+    impl FixField for Price {
+        const TAG: u32 = 44;
+        type Value = f64;
+
+        fn decode(bytes: &[u8]) -> Result<Self::Value, DecodeError> {
+            core::str::from_utf8(bytes)?
+                .parse()
+                .map_err(|_| DecodeError::InvalidFieldValue {
+                    tag: Self::TAG,
+                    reason: "invalid price".to_string(),
+                })
+        }
+
+        fn encode(value: &Self::Value, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(value.to_string().as_bytes());
+        }
+    }
 
     #[test]
     fn test_encoder_basic() {
@@ -202,6 +545,27 @@ mod tests {
         assert!(msg_str.contains("10="));
     }
 
+    #[test]
+    fn test_encoder_xml_data_round_trip() {
+        let xml = b"<a>b\x01c</a>";
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, MsgType::XmlMessage.as_str());
+        encoder.put_xml_data(xml);
+        encoder.put_str(58, "after");
+        let message = encoder.finish();
+
+        let fields = b"212=10\x01213=<a>b\x01c</a>\x01";
+        assert!(message.windows(fields.len()).any(|w| w == fields));
+        let decoded = crate::Decoder::new(&message)
+            .with_length_validation(true)
+            .decode()
+            .unwrap();
+        assert_eq!(decoded.msg_type(), &MsgType::XmlMessage);
+        assert_eq!(decoded.xml_data(), Some(&xml[..]));
+        assert_eq!(decoded.get_field(213).unwrap().value, xml);
+        assert_eq!(decoded.get_field_str(58), Some("after"));
+    }
+
     #[test]
     fn test_encoder_multiple_fields() {
         let mut encoder = Encoder::new("FIX.4.4");
@@ -243,6 +607,164 @@ mod tests {
         assert!(msg_str.contains("54=1\x01"));
     }
 
+    #[test]
+    fn test_try_put_str_rejects_long_cl_ord_id() {
+        let mut encoder = Encoder::new("FIX.4.4").with_field_limit(11, 8);
+        encoder.put_str(35, "D");
+        assert_eq!(
+            encoder.try_put_str(11, "ORDER-123456"),
+            Err(EncodeError::FieldTooLong {
+                tag: 11,
+                length: 12,
+                max_length: 8,
+            })
+        );
+        let body_len = encoder.body_len();
+        encoder.try_put_str(11, "ORDER-12").unwrap();
+        assert!(encoder.body_len() > body_len);
+
+        // Tags without a limit, and the infallible methods, are unaffected.
+        encoder.try_put_str(58, "a long free-text field").unwrap();
+        encoder.put_str(11, "ORDER-123456");
+
+        let message = encoder.finish();
+        let msg_str = String::from_utf8_lossy(&message);
+        assert!(msg_str.contains("35=D\x0111=ORDER-12\x0158="));
+        assert!(msg_str.contains("11=ORDER-123456\x01"));
+    }
+
+    #[test]
+    fn test_framing_field_in_body_is_detected() {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_str(8, "FIX.4.2");
+        encoder.put_uint(10, 0);
+        assert_eq!(
+            encoder.try_finish(),
+            Err(EncodeError::FramingFieldInBody { tag: 8 })
+        );
+
+        let mut encoder = Encoder::new("FIX.4.4");
+        assert_eq!(
+            encoder.try_put_uint(9, 12),
+            Err(EncodeError::FramingFieldInBody { tag: 9 })
+        );
+        encoder.put_str(35, "0");
+        assert!(encoder.try_finish().is_ok());
+    }
+
+    #[test]
+    fn test_clear_forgets_framing_field() {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(9, "5");
+        encoder.clear();
+        encoder.put_str(35, "0");
+        assert!(encoder.try_finish().is_ok());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "framing field")]
+    fn test_finish_debug_asserts_framing_field() {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(8, "FIX.4.4");
+        let _ = encoder.finish();
+    }
+
+    #[test]
+    fn test_try_put_default_limit() {
+        let mut encoder = Encoder::new("FIX.4.4")
+            .with_max_field_len(3)
+            .with_field_limit(34, 6);
+        assert_eq!(encoder.field_limit(55), Some(3));
+        assert_eq!(encoder.field_limit(34), Some(6));
+        assert!(encoder.try_put_str(55, "AAPL").is_err());
+        assert!(encoder.try_put_int(38, -100).is_err());
+        assert!(encoder.try_put_uint(34, 123_456).is_ok());
+        assert!(Encoder::new("FIX.4.4").field_limit(11).is_none());
+    }
+
+    #[test]
+    fn test_encoder_put_field() {
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_str(35, "D");
+        encoder.put_field::<Price>(&101.25);
+
+        let message = encoder.finish();
+        let msg_str = String::from_utf8_lossy(&message);
+
+        assert!(msg_str.contains("35=D\x0144=101.25\x01"));
+    }
+
+    #[test]
+    fn test_encoder_pipe_delimited_round_trip() {
+        let mut encoder = Encoder::new("FIX.4.4").with_delimiter(b'|');
+        encoder.put_str(35, "D");
+        encoder.put_str(49, "SENDER");
+        encoder.put_uint(34, 7);
+
+        let message = encoder.finish();
+        let msg_str = String::from_utf8_lossy(&message);
+        assert!(msg_str.starts_with("8=FIX.4.4|9=20|35=D|49=SENDER|34=7|10="));
+        assert!(!message.contains(&SOH));
+
+        let mut decoder = crate::Decoder::new(&message).with_delimiter(b'|');
+        let decoded = decoder.decode().unwrap();
+        assert_eq!(decoded.get_field_str(49), Some("SENDER"));
+        assert_eq!(decoded.get_field_str(34), Some("7"));
+    }
+
+    #[test]
+    fn test_encoder_put_header() {
+        let sender = CompId::new("SENDER").unwrap();
+        let target = CompId::new("TARGET").unwrap();
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_header(
+            &MsgType::NewOrderSingle,
+            &sender,
+            &target,
+            SeqNum::new(42),
+            Timestamp::from_millis(1_769_508_930_123),
+        );
+        encoder.put_str(11, "ORDER1");
+
+        let message = encoder.finish();
+        let decoded = crate::Decoder::new(&message).decode().unwrap();
+        let tags: Vec<u32> = decoded.fields().map(|f| f.tag).collect();
+        assert_eq!(tags, [8, 9, 35, 49, 56, 34, 52, 11]);
+        assert_eq!(decoded.get_field_str(49), Some("SENDER"));
+        assert_eq!(decoded.get_field_str(56), Some("TARGET"));
+        assert_eq!(decoded.get_field_str(34), Some("42"));
+        assert_eq!(decoded.get_field_str(52), Some("20260127-10:15:30.123"));
+    }
+
+    #[test]
+    fn test_encoder_put_header_with_routing() {
+        let sender = CompId::new("SENDER").unwrap();
+        let target = CompId::new("TARGET").unwrap();
+        let routing = RoutingIds {
+            sender_sub_id: Some("DESK1"),
+            target_location_id: Some("LDN"),
+            ..RoutingIds::default()
+        };
+        let mut encoder = Encoder::new("FIX.4.4");
+        encoder.put_header_with_routing(
+            &MsgType::Heartbeat,
+            &sender,
+            &target,
+            &routing,
+            SeqNum::new(1),
+            Timestamp::from_millis(0),
+        );
+
+        let message = encoder.finish();
+        let decoded = crate::Decoder::new(&message).strict(true).decode().unwrap();
+        let tags: Vec<u32> = decoded.fields().map(|f| f.tag).collect();
+        assert_eq!(tags, [8, 9, 35, 49, 56, 34, 52, 50, 143]);
+        assert_eq!(decoded.get_field_str(50), Some("DESK1"));
+        assert_eq!(decoded.get_field_str(143), Some("LDN"));
+    }
+
     #[test]
     fn test_encoder_clear() {
         let mut encoder = Encoder::new("FIX.4.4");
@@ -16,12 +16,21 @@
 //! - **Zero-copy parsing**: Field values reference the original buffer
 //! - **SIMD-accelerated**: Uses `memchr` for fast delimiter search
 //! - **Checksum calculation**: Optimized checksum computation
+//!
+//! ## `no_std` Support
+//!
+//! The `std` feature is enabled by default. Without it the decoder, encoder
+//! and checksum helpers build on `core` and `alloc` only.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod checksum;
 pub mod decoder;
 pub mod encoder;
 
-pub use checksum::calculate_checksum;
-pub use decoder::Decoder;
-pub use encoder::Encoder;
+pub use checksum::{Checksum, calculate_checksum};
+pub use decoder::{Decoder, Fields, MessageView, Messages, RawMessageBuf};
+pub use encoder::{Encoder, RoutingIds};
 pub use ironfix_core::message::RawMessage;
@@ -10,6 +10,7 @@
 //! including BeginString, BodyLength, and Checksum validation.
 
 use bytes::{BufMut, BytesMut};
+use ironfix_core::message::MsgType;
 use ironfix_tagvalue::checksum::{calculate_checksum, parse_checksum};
 use memchr::memchr;
 use thiserror::Error;
@@ -34,6 +35,10 @@ pub enum CodecError {
     #[error("invalid body length value")]
     InvalidBodyLength,
 
+    /// MsgType field missing after BodyLength.
+    #[error("missing msg type field (tag 35)")]
+    MissingMsgType,
+
     /// Checksum mismatch.
     #[error("checksum mismatch: calculated {calculated}, declared {declared}")]
     ChecksumMismatch {
@@ -66,6 +71,9 @@ impl From<std::io::Error> for CodecError {
 /// SOH delimiter.
 const SOH: u8 = 0x01;
 
+/// Length of the CheckSum field including its delimiter: `10=XXX|`.
+const TRAILER_LEN: usize = 7;
+
 /// Tokio codec for FIX message framing.
 ///
 /// Handles parsing of FIX messages from a byte stream, validating
@@ -76,6 +84,8 @@ pub struct FixCodec {
     max_message_size: usize,
     /// Whether to validate checksums.
     validate_checksum: bool,
+    /// Whether to delimit messages whose BodyLength is wrong.
+    validate_length: bool,
 }
 
 impl FixCodec {
@@ -85,6 +95,7 @@ impl FixCodec {
         Self {
             max_message_size: 1024 * 1024, // 1MB
             validate_checksum: true,
+            validate_length: false,
         }
     }
 
@@ -101,6 +112,31 @@ impl FixCodec {
         self.validate_checksum = validate;
         self
     }
+
+    /// Sets whether to check BodyLength against the actual message.
+    ///
+    /// When disabled, BodyLength is trusted to locate the CheckSum field.
+    /// When enabled, the codec still waits until the span BodyLength declares
+    /// is fully buffered; only if the CheckSum field is then not where
+    /// BodyLength puts it is the message delimited by its first CheckSum
+    /// field instead, so a wrong BodyLength can be rejected at the session
+    /// level rather than breaking the stream. A binary field that happens to
+    /// contain `10=XXX` is therefore never mistaken for the trailer of a
+    /// correctly framed message delivered in pieces. Disabled by default.
+    ///
+    /// # Arguments
+    /// * `validate` - Whether to validate BodyLength
+    #[must_use]
+    pub const fn with_length_validation(mut self, validate: bool) -> Self {
+        self.validate_length = validate;
+        self
+    }
+
+    /// Converts this codec into one that also returns each message's type.
+    #[must_use]
+    pub const fn with_msg_type(self) -> TypedFixCodec {
+        TypedFixCodec { inner: self }
+    }
 }
 
 impl Default for FixCodec {
@@ -156,7 +192,37 @@ impl Decoder for FixCodec {
         // Calculate total message length
         // BodyLength counts from after 9=XXX| to before 10=
         // Total = header + body + trailer (10=XXX|)
-        let total_length = body_len_soh + 1 + body_length + 7; // +7 for |10=XXX|
+        // A BodyLength over the size limit is rejected before any
+        // arithmetic so a peer-supplied value cannot overflow the total
+        if body_length > self.max_message_size {
+            return Err(CodecError::MessageTooLarge {
+                size: body_length,
+                max_size: self.max_message_size,
+            });
+        }
+        let mut total_length = (body_len_soh + 1)
+            .checked_add(body_length)
+            .and_then(|len| len.checked_add(TRAILER_LEN))
+            .ok_or(CodecError::InvalidBodyLength)?;
+
+        // Once the declared span is buffered, if the trailer is not where
+        // BodyLength puts it, delimit by the first trailer after the
+        // BodyLength field instead
+        if self.validate_length
+            && src.len() >= total_length
+            && !is_trailer(&src[total_length - TRAILER_LEN - 1..])
+        {
+            match find_trailer(src, body_len_soh) {
+                Some(end) => total_length = end,
+                None if src.len() > self.max_message_size => {
+                    return Err(CodecError::MessageTooLarge {
+                        size: src.len(),
+                        max_size: self.max_message_size,
+                    });
+                }
+                None => return Ok(None),
+            }
+        }
 
         // Check maximum size
         if total_length > self.max_message_size {
@@ -198,6 +264,106 @@ impl Decoder for FixCodec {
     }
 }
 
+/// Tokio codec for FIX message framing that also reports the MsgType.
+///
+/// Frames exactly like the wrapped [`FixCodec`], and returns each message
+/// with its MsgType (tag 35), read from the field following BodyLength, so
+/// a dispatcher can route by type without decoding the message.
+#[derive(Debug, Clone, Default)]
+pub struct TypedFixCodec {
+    /// Codec doing the framing.
+    inner: FixCodec,
+}
+
+impl TypedFixCodec {
+    /// Creates a new codec with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the codec doing the framing.
+    #[must_use]
+    pub const fn inner(&self) -> &FixCodec {
+        &self.inner
+    }
+}
+
+impl Decoder for TypedFixCodec {
+    type Item = (BytesMut, MsgType);
+    type Error = CodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(message) = self.inner.decode(src)? else {
+            return Ok(None);
+        };
+        let msg_type = peek_msg_type(&message).ok_or(CodecError::MissingMsgType)?;
+        Ok(Some((message, msg_type)))
+    }
+}
+
+impl Encoder<&[u8]> for TypedFixCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, item: &[u8], dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.inner.encode(item, dst)
+    }
+}
+
+impl Encoder<BytesMut> for TypedFixCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, item: BytesMut, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.inner.encode(item, dst)
+    }
+}
+
+/// Reads the MsgType of a framed message without decoding it.
+///
+/// # Arguments
+/// * `frame` - A complete SOH-delimited message
+///
+/// # Returns
+/// The MsgType, or `None` if the third field is not a non-empty MsgType.
+#[must_use]
+pub fn peek_msg_type(frame: &[u8]) -> Option<MsgType> {
+    let begin_string_end = memchr(SOH, frame)?;
+    let body_start = begin_string_end + 1 + memchr(SOH, &frame[begin_string_end + 1..])? + 1;
+    let value = frame[body_start..].strip_prefix(b"35=")?;
+    let value = &value[..memchr(SOH, value)?];
+    if value.is_empty() {
+        return None;
+    }
+    std::str::from_utf8(value).ok()?.parse().ok()
+}
+
+/// Returns whether `buf` starts with `|10=XXX|`, i.e. the SOH ending the
+/// last body field followed by a CheckSum field.
+fn is_trailer(buf: &[u8]) -> bool {
+    buf.len() > TRAILER_LEN
+        && buf[0] == SOH
+        && &buf[1..4] == b"10="
+        && buf[4..7].iter().all(u8::is_ascii_digit)
+        && buf[7] == SOH
+}
+
+/// Finds the end of the first CheckSum field following the SOH at `from`.
+///
+/// # Returns
+/// The offset just past the CheckSum field's delimiter, or `None` if the
+/// buffer holds no complete CheckSum field.
+fn find_trailer(buf: &[u8], from: usize) -> Option<usize> {
+    let mut pos = from;
+    while let Some(offset) = memchr(SOH, &buf[pos..]) {
+        pos += offset;
+        if is_trailer(&buf[pos..]) {
+            return Some(pos + 1 + TRAILER_LEN);
+        }
+        pos += 1;
+    }
+    None
+}
+
 impl Encoder<&[u8]> for FixCodec {
     type Error = CodecError;
 
@@ -269,6 +435,23 @@ mod tests {
         assert!(matches!(result, Err(CodecError::ChecksumMismatch { .. })));
     }
 
+    #[test]
+    fn test_codec_rejects_overflowing_body_length() {
+        let frame = format!("8=FIX.4.4\x019={}\x0135=0\x0110=000\x01", usize::MAX);
+
+        let mut codec = FixCodec::new();
+        let mut buf = BytesMut::from(frame.as_bytes());
+        let result = codec.decode(&mut buf);
+        assert!(matches!(result, Err(CodecError::MessageTooLarge { .. })));
+
+        let mut codec = FixCodec::new()
+            .with_max_message_size(usize::MAX)
+            .with_length_validation(true);
+        let mut buf = BytesMut::from(frame.as_bytes());
+        let result = codec.decode(&mut buf);
+        assert!(matches!(result, Err(CodecError::InvalidBodyLength)));
+    }
+
     #[test]
     fn test_codec_decode_no_checksum_validation() {
         let mut codec = FixCodec::new().with_checksum_validation(false);
@@ -278,6 +461,102 @@ mod tests {
         assert!(result.is_some());
     }
 
+    /// Returns a valid message for `body` with BodyLength replaced by `declared`.
+    fn with_body_length(body: &str, declared: usize) -> Vec<u8> {
+        let without_checksum = format!("8=FIX.4.4\x019={}\x01{}", declared, body);
+        let checksum = calculate_checksum(without_checksum.as_bytes());
+        format!("{}10={:03}\x01", without_checksum, checksum).into_bytes()
+    }
+
+    #[test]
+    fn test_codec_delimits_body_length_too_short() {
+        let body = "35=0\x01112=TEST\x01";
+        let msg = with_body_length(body, body.len() - 5);
+        let next = make_fix_message("35=1\x01");
+        let mut buf = BytesMut::from(&[msg.as_slice(), next.as_slice()].concat()[..]);
+
+        // BodyLength is trusted by default and points into the body.
+        let mut trusting = buf.clone();
+        assert!(FixCodec::new().decode(&mut trusting).is_err());
+
+        let mut codec = FixCodec::new().with_length_validation(true);
+        assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), &msg[..]);
+        assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), &next[..]);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_delimits_body_length_too_long() {
+        let body = "35=0\x01112=TEST\x01";
+        let msg = with_body_length(body, body.len() + 20);
+        let next = make_fix_message("35=1\x01112=NEXT\x01");
+        let mut buf = BytesMut::from(&msg[..]);
+
+        // Both modes wait until the declared span is buffered.
+        let mut trusting = buf.clone();
+        assert!(FixCodec::new().decode(&mut trusting).unwrap().is_none());
+        let mut codec = FixCodec::new().with_length_validation(true);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+
+        buf.extend_from_slice(&next);
+        assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), &msg[..]);
+        assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), &next[..]);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_length_validation_ignores_trailer_inside_binary_data() {
+        // RawData (96) holding what looks like a CheckSum field.
+        let msg = make_fix_message("35=B\x0195=12\x0196=X\x0110=123\x01Y\x01148=NEWS\x01");
+        let fake_end = msg.windows(8).position(|w| w == b"\x0110=123\x01").unwrap() + 8;
+        let mut codec = FixCodec::new().with_length_validation(true);
+
+        let mut buf = BytesMut::from(&msg[..fake_end]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        buf.extend_from_slice(&msg[fake_end..msg.len() - 4]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        buf.extend_from_slice(&msg[msg.len() - 4..]);
+        assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), &msg[..]);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_codec_length_validation_waits_for_trailer() {
+        let msg = make_fix_message("35=0\x01112=TEST\x01");
+        let mut codec = FixCodec::new().with_length_validation(true);
+
+        let mut buf = BytesMut::from(&msg[..msg.len() - 3]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        buf.extend_from_slice(&msg[msg.len() - 3..]);
+        assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), &msg[..]);
+    }
+
+    #[test]
+    fn test_typed_codec_returns_msg_type() {
+        let mut codec = FixCodec::new().with_msg_type();
+        let msg = make_fix_message("35=D\x0149=SENDER\x0156=TARGET\x0155=IBM\x01");
+        let mut buf = BytesMut::from(&msg[..]);
+        buf.extend_from_slice(&make_fix_message("35=XX\x01")[..20]);
+
+        let (frame, msg_type) = codec.decode(&mut buf).unwrap().unwrap();
+        assert_eq!(&frame[..], &msg[..]);
+        assert_eq!(msg_type, MsgType::NewOrderSingle);
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+
+        assert_eq!(
+            peek_msg_type(&make_fix_message("35=XX\x01")),
+            Some(MsgType::Custom("XX".to_string()))
+        );
+        assert_eq!(peek_msg_type(&make_fix_message("35=\x01")), None);
+    }
+
+    #[test]
+    fn test_typed_codec_rejects_missing_msg_type() {
+        let mut codec = TypedFixCodec::new();
+        let mut buf = BytesMut::from(&make_fix_message("49=SENDER\x0135=0\x01")[..]);
+        assert_eq!(codec.decode(&mut buf), Err(CodecError::MissingMsgType));
+    }
+
     #[test]
     fn test_codec_encode() {
         let mut codec = FixCodec::new();
@@ -15,4 +15,4 @@
 
 pub mod codec;
 
-pub use codec::{CodecError, FixCodec};
+pub use codec::{CodecError, FixCodec, TypedFixCodec, peek_msg_type};